@@ -5,17 +5,33 @@ fn main() -> Result<()> {
     // prost_build will automatically use the OUT_DIR environment variable
     // which is the standard place for build artifacts.
 
-    let mut config = prost_build::Config::new();
-    
-    // Customize code generation if needed, otherwise defaults are usually fine.
-    // If you need specific type attributes (like serde derive), add them here.
-    
-    config.compile_protos(
-            &["src/platforms/douyin/danmu/douyin.proto"],
-            &["src/platforms/douyin/danmu/"],
-        )
-        .expect("Failed to compile douyin danmu protos");
+    // The Douyin push stream and Kuaishou's live WebSocket are protobuf.
+    #[cfg(feature = "douyin")]
+    {
+        let mut config = prost_build::Config::new();
 
-    println!("cargo:rerun-if-changed=src/platforms/douyin/danmu/douyin.proto");
+        // Customize code generation if needed, otherwise defaults are usually fine.
+        // If you need specific type attributes (like serde derive), add them here.
+
+        config
+            .compile_protos(
+                &["src/platforms/douyin/danmu/douyin.proto"],
+                &["src/platforms/douyin/danmu/"],
+            )
+            .expect("Failed to compile douyin danmu protos");
+
+        println!("cargo:rerun-if-changed=src/platforms/douyin/danmu/douyin.proto");
+    }
+    #[cfg(feature = "kuaishou")]
+    {
+        prost_build::Config::new()
+            .compile_protos(
+                &["src/platforms/kuaishou/kuaishou.proto"],
+                &["src/platforms/kuaishou/"],
+            )
+            .expect("Failed to compile kuaishou protos");
+
+        println!("cargo:rerun-if-changed=src/platforms/kuaishou/kuaishou.proto");
+    }
     Ok(())
-}
\ No newline at end of file
+}
@@ -0,0 +1,149 @@
+// CDN line speed tests for `DtvClient`: the diagnostic `probe_lines` and the
+// `line: "auto"` resolution `get_live_stream` does before dispatching.
+use super::DtvClient;
+
+use crate::platforms::common::errors::DtvError;
+use crate::platforms::common::line_probe::{self, network_id};
+use crate::platforms::common::types::SupportedPlatform;
+use crate::platforms::common::{LineCandidate, LineOption, LineProbe, StreamOptions, AUTO_LINE};
+
+impl DtvClient {
+    /// Speed-test every CDN line of a live room, best first. Always measures
+    /// afresh; the results also feed `line: "auto"`.
+    pub async fn probe_lines(
+        &self,
+        platform: SupportedPlatform,
+        room_id: &str,
+    ) -> Result<Vec<LineProbe>, DtvError> {
+        let candidates = self.line_candidates(&platform, room_id.trim()).await?;
+        if candidates.is_empty() {
+            return Err(DtvError::api(format!(
+                "{} has no lines to test",
+                platform.as_str()
+            )));
+        }
+        Ok(self.measure(&platform, &candidates).await)
+    }
+
+    // The fastest working line, from the cache when every line of the room
+    // was measured recently on this network. None leaves the platform default.
+    pub(super) async fn auto_line(
+        &self,
+        platform: &SupportedPlatform,
+        room_id: &str,
+    ) -> Option<String> {
+        let candidates = match self.line_candidates(platform, room_id).await {
+            Ok(c) if c.len() > 1 => c,
+            Ok(c) => return c.into_iter().next().map(|c| c.line),
+            Err(e) => {
+                eprintln!(
+                    "[DtvClient] no lines to test for {} {}: {}",
+                    platform.as_str(),
+                    room_id,
+                    e
+                );
+                return None;
+            }
+        };
+        let lines: Vec<&str> = candidates.iter().map(|c| c.line.as_str()).collect();
+        let probes = match self
+            .inner
+            .line_probes
+            .lookup(platform.as_str(), &network_id(), &lines)
+        {
+            Some(cached) => cached,
+            None => self.measure(platform, &candidates).await,
+        };
+        probes
+            .into_iter()
+            .find(|p| p.error.is_none())
+            .map(|p| p.line)
+    }
+
+    async fn measure(
+        &self,
+        platform: &SupportedPlatform,
+        candidates: &[LineCandidate],
+    ) -> Vec<LineProbe> {
+        let probes = line_probe::probe_lines(&self.inner.http.0.inner, candidates).await;
+        self.inner
+            .line_probes
+            .store(platform.as_str(), &network_id(), &probes);
+        probes
+    }
+
+    #[cfg_attr(
+        not(any(
+            feature = "douyu",
+            feature = "huya",
+            feature = "bilibili",
+            feature = "cc"
+        )),
+        allow(unused_variables)
+    )]
+    async fn line_candidates(
+        &self,
+        platform: &SupportedPlatform,
+        room_id: &str,
+    ) -> Result<Vec<LineCandidate>, DtvError> {
+        match platform {
+            #[cfg(feature = "douyu")]
+            SupportedPlatform::Douyu => {
+                crate::platforms::douyu::stream_url::fetch_line_candidates(
+                    &self.inner.http.0.inner,
+                    self.inner.config.user_agent.as_deref(),
+                    room_id,
+                )
+                .await
+            }
+            #[cfg(feature = "huya")]
+            SupportedPlatform::Huya => {
+                crate::platforms::huya::stream_url::fetch_line_candidates(
+                    &self.inner.http.0.inner,
+                    room_id,
+                )
+                .await
+            }
+            #[cfg(feature = "bilibili")]
+            SupportedPlatform::Bilibili => {
+                let cookie = self.cookie(platform);
+                let client = crate::platforms::bilibili::stream_url::build_playinfo_client(
+                    self.inner.config.user_agent.as_deref(),
+                    cookie.as_deref(),
+                )?;
+                crate::platforms::bilibili::stream_url::fetch_line_candidates(
+                    &client,
+                    room_id,
+                    self.inner.config.user_agent.as_deref(),
+                )
+                .await
+            }
+            #[cfg(feature = "cc")]
+            SupportedPlatform::Cc => {
+                let room =
+                    crate::platforms::cc::room::fetch_room(&self.inner.http.0.inner, room_id)
+                        .await?;
+                if !room.is_live {
+                    return Err(DtvError::offline("主播未开播"));
+                }
+                Ok(crate::platforms::cc::room::line_candidates(&room.streams))
+            }
+            // Douyin, Kuaishou and Twitch have no selectable lines
+            _ => Ok(Vec::new()),
+        }
+    }
+}
+
+// Offer "auto" ahead of the named lines when there is a choice to make
+pub(super) fn with_auto_line(mut options: StreamOptions) -> StreamOptions {
+    if options.lines.len() > 1 {
+        options.lines.insert(
+            0,
+            LineOption {
+                id: AUTO_LINE.to_string(),
+                label: "自动（测速）".to_string(),
+            },
+        );
+    }
+    options
+}
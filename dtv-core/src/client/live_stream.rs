@@ -0,0 +1,1013 @@
+// Platform dispatch for `DtvClient::get_live_stream`. Playback URLs are the
+// upstream ones; callers that proxy (the desktop app) rewrite them afterwards.
+use super::DtvClient;
+
+use crate::media::probe_stream;
+use crate::platforms::common::errors::DtvError;
+use crate::platforms::common::types::SupportedPlatform;
+use crate::platforms::common::{
+    infer_stream_type, GetLiveStreamRequest, LiveStatus, LiveStreamMode, LiveStreamResponse,
+    Playback, QualityOption, RoomMeta, StreamType,
+};
+// Helpers below are shared by some platforms only; their imports follow the same gates.
+#[cfg(any(feature = "huya", feature = "douyin", feature = "bilibili"))]
+use crate::platforms::common::errors::classify_error_message;
+#[cfg(any(feature = "douyu", feature = "douyin", feature = "bilibili"))]
+use crate::platforms::common::infer_codec;
+#[cfg(any(feature = "douyu", feature = "huya", feature = "bilibili", feature = "twitch"))]
+use crate::platforms::common::StreamOptions;
+#[cfg(any(
+    feature = "huya",
+    feature = "douyin",
+    feature = "bilibili",
+    feature = "kuaishou",
+    feature = "cc",
+    feature = "twitch",
+))]
+use crate::platforms::common::{truncate_variants, types::StreamVariant};
+#[cfg(any(feature = "douyin", feature = "bilibili"))]
+use crate::platforms::common::{types::GetStreamUrlArgs, GetStreamUrlPayload, LiveStreamInfo};
+
+#[cfg(feature = "douyin")]
+fn normalize_douyin_quality(input: Option<&str>) -> String {
+    let raw = input.unwrap_or("OD").trim();
+    let upper = raw.to_uppercase();
+    if upper == "OD" || raw.contains("原画") {
+        return "OD".to_string();
+    }
+    if upper == "BD" || raw.contains("高清") {
+        return "BD".to_string();
+    }
+    if upper == "UHD" || raw.contains("标清") {
+        return "UHD".to_string();
+    }
+    // An `flv_pull_url` key from the room's options, e.g. FULL_HD1
+    if !raw.is_empty() && raw.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return upper;
+    }
+    "OD".to_string()
+}
+
+#[cfg(any(
+    feature = "huya",
+    feature = "douyin",
+    feature = "bilibili",
+    feature = "kuaishou",
+    feature = "cc",
+    feature = "twitch",
+))]
+fn map_variants_debug(
+    debug_enabled: bool,
+    variants: Option<Vec<StreamVariant>>,
+) -> Option<Vec<StreamVariant>> {
+    if !debug_enabled {
+        return None;
+    }
+    variants.map(truncate_variants)
+}
+
+#[cfg(any(
+    feature = "douyu",
+    feature = "huya",
+    feature = "douyin",
+    feature = "bilibili",
+    feature = "kuaishou",
+    feature = "cc",
+    feature = "twitch",
+))]
+fn map_upstream_debug(debug_enabled: bool, upstream: Option<String>) -> Option<String> {
+    if !debug_enabled {
+        return None;
+    }
+    upstream
+}
+
+// The codec of the variant a playback URL was picked from; variants may
+// still be on http where playback was upgraded to https
+#[cfg(any(feature = "douyin", feature = "bilibili"))]
+fn variant_codec(url: &str, variants: Option<&[StreamVariant]>) -> Option<String> {
+    let strip = |u: &str| u.split_once("://").map_or(u, |(_, rest)| rest).to_string();
+    let target = strip(url);
+    variants
+        .into_iter()
+        .flatten()
+        .find(|v| strip(&v.url) == target)
+        .and_then(|v| v.codec.clone())
+        .or_else(|| infer_codec(url).map(str::to_string))
+}
+
+// Options are best-effort: a failed lookup still returns the room
+#[cfg(any(feature = "douyu", feature = "huya", feature = "bilibili", feature = "twitch"))]
+fn options_or_none(
+    platform: &SupportedPlatform,
+    result: Result<StreamOptions, DtvError>,
+) -> Option<StreamOptions> {
+    match result {
+        Ok(options) => Some(options),
+        Err(e) => {
+            tracing::warn!("[{}] failed to list stream options: {}", platform.as_str(), e);
+            None
+        }
+    }
+}
+
+fn bare_room(platform: SupportedPlatform, room_id: String) -> RoomMeta {
+    RoomMeta {
+        platform,
+        room_id,
+        normalized_room_id: None,
+        web_rid: None,
+        title: None,
+        anchor_name: None,
+        avatar: None,
+        category: None,
+    }
+}
+
+#[cfg(any(feature = "douyin", feature = "bilibili"))]
+fn info_room(platform: SupportedPlatform, room_id: String, info: &LiveStreamInfo) -> RoomMeta {
+    RoomMeta {
+        platform,
+        room_id,
+        normalized_room_id: info.normalized_room_id.clone(),
+        web_rid: info.web_rid.clone(),
+        title: info.title.clone(),
+        anchor_name: info.anchor_name.clone(),
+        avatar: info.avatar.clone(),
+        category: None,
+    }
+}
+
+// Offline is a normal state, so only real failures carry an error message.
+fn failure(room: RoomMeta, err: DtvError) -> LiveStreamResponse {
+    let status = if err.is_offline() {
+        LiveStatus::Offline
+    } else {
+        LiveStatus::Error
+    };
+    LiveStreamResponse {
+        status,
+        room,
+        playback: None,
+        options: None,
+        error: if status == LiveStatus::Error {
+            Some(err.to_string())
+        } else {
+            None
+        },
+    }
+}
+
+#[cfg(any(feature = "huya", feature = "douyin", feature = "bilibili"))]
+fn failure_message(room: RoomMeta, message: String) -> LiveStreamResponse {
+    let status = if classify_error_message(&message).is_offline() {
+        LiveStatus::Offline
+    } else {
+        LiveStatus::Error
+    };
+    LiveStreamResponse {
+        status,
+        room,
+        playback: None,
+        options: None,
+        error: if status == LiveStatus::Error {
+            Some(message)
+        } else {
+            None
+        },
+    }
+}
+
+// By bitrate where every quality has one; otherwise platforms list them
+// best first
+fn lowest_quality(qualities: &[QualityOption]) -> Option<&QualityOption> {
+    if qualities.iter().all(|q| q.bitrate.is_some()) {
+        qualities.iter().min_by_key(|q| q.bitrate)
+    } else {
+        qualities.last()
+    }
+}
+
+#[cfg(any(feature = "douyin", feature = "bilibili"))]
+fn room_payload(room_id: &str) -> GetStreamUrlPayload {
+    GetStreamUrlPayload {
+        args: GetStreamUrlArgs {
+            room_id_str: room_id.to_string(),
+        },
+    }
+}
+
+impl DtvClient {
+    /// Resolve room metadata (`mode: meta`) or a playable upstream stream.
+    pub async fn get_live_stream(&self, request: GetLiveStreamRequest) -> LiveStreamResponse {
+        let debug_enabled = request.debug.unwrap_or(self.inner.config.debug);
+        let room_id = request.room_id.trim().to_string();
+        if room_id.is_empty() {
+            return LiveStreamResponse {
+                status: LiveStatus::Error,
+                room: bare_room(request.platform.clone(), room_id),
+                playback: None,
+                options: None,
+                error: Some("room_id cannot be empty".to_string()),
+            };
+        }
+
+        // Swap "auto" for the measured fastest line, or the platform default
+        let mut request = request;
+        if request.mode() == LiveStreamMode::Playback && request.auto_line() {
+            request.line = self.auto_line(&request.platform, &room_id).await;
+        }
+        // Listening needs none of the video bits
+        if request.mode() == LiveStreamMode::Playback
+            && request.audio_only()
+            && request.quality.is_none()
+        {
+            request.quality = self.lowest_quality(&request, &room_id).await;
+        }
+
+        let mut response = self.dispatch(&request, room_id, debug_enabled).await;
+        response.options = response.options.map(super::lines::with_auto_line);
+        if request.probe == Some(true) {
+            if let Some(playback) = response.playback.as_mut() {
+                self.probe_playback(playback).await;
+            }
+        }
+        if let Some(hooks) = &self.inner.webhooks {
+            hooks.observe_status(&response);
+        }
+        response
+    }
+
+    async fn dispatch(
+        &self,
+        request: &GetLiveStreamRequest,
+        room_id: String,
+        debug_enabled: bool,
+    ) -> LiveStreamResponse {
+        match &request.platform {
+            #[cfg(feature = "douyu")]
+            SupportedPlatform::Douyu => self.douyu_live_stream(request, room_id, debug_enabled).await,
+            #[cfg(feature = "huya")]
+            SupportedPlatform::Huya => self.huya_live_stream(request, room_id, debug_enabled).await,
+            #[cfg(feature = "douyin")]
+            SupportedPlatform::Douyin => {
+                self.douyin_live_stream(request, room_id, debug_enabled).await
+            }
+            #[cfg(feature = "bilibili")]
+            SupportedPlatform::Bilibili => {
+                self.bilibili_live_stream(request, room_id, debug_enabled).await
+            }
+            #[cfg(feature = "kuaishou")]
+            SupportedPlatform::Kuaishou => {
+                self.kuaishou_live_stream(request, room_id, debug_enabled).await
+            }
+            #[cfg(feature = "cc")]
+            SupportedPlatform::Cc => self.cc_live_stream(request, room_id, debug_enabled).await,
+            #[cfg(feature = "twitch")]
+            SupportedPlatform::Twitch => {
+                self.twitch_live_stream(request, room_id, debug_enabled).await
+            }
+            SupportedPlatform::Custom(id) => {
+                self.plugin_live_stream(id, request, room_id, debug_enabled).await
+            }
+        }
+    }
+
+    // The room's lowest-bitrate quality, from a meta lookup. None leaves the
+    // platform default, e.g. for plugins that list no options.
+    async fn lowest_quality(
+        &self,
+        request: &GetLiveStreamRequest,
+        room_id: &str,
+    ) -> Option<String> {
+        let meta = GetLiveStreamRequest {
+            mode: Some(LiveStreamMode::Meta),
+            with_options: Some(true),
+            ..request.clone()
+        };
+        let options = self.dispatch(&meta, room_id.to_string(), false).await.options?;
+        lowest_quality(&options.qualities).map(|q| q.id.clone())
+    }
+
+    // Trust the stream's own bytes over what its URL suggests
+    async fn probe_playback(&self, playback: &mut Playback) {
+        match probe_stream(&self.inner.http.0.inner, &playback.url, &playback.headers).await {
+            Ok(media) => {
+                if media.container.stream_type() != StreamType::Unknown {
+                    playback.stream_type = media.container.stream_type();
+                }
+                if playback.codec.is_none() {
+                    playback.codec = media.video_codec.clone();
+                }
+                playback.media = Some(media);
+            }
+            Err(e) => tracing::warn!("[Probe] {}: {}", playback.url, e),
+        }
+    }
+
+    #[cfg_attr(not(feature = "js-runtime"), allow(unused_variables))]
+    async fn plugin_live_stream(
+        &self,
+        id: &str,
+        request: &GetLiveStreamRequest,
+        room_id: String,
+        debug_enabled: bool,
+    ) -> LiveStreamResponse {
+        #[cfg(feature = "js-runtime")]
+        if let Some(plugins) = &self.inner.plugins {
+            return plugins
+                .live_stream(id, request, room_id, debug_enabled)
+                .await;
+        }
+        LiveStreamResponse {
+            status: LiveStatus::Error,
+            room: bare_room(SupportedPlatform::Custom(id.to_string()), room_id),
+            playback: None,
+            options: None,
+            error: Some(format!("No plugin provides platform {}", id)),
+        }
+    }
+
+    #[cfg(feature = "douyu")]
+    async fn douyu_live_stream(
+        &self,
+        request: &GetLiveStreamRequest,
+        room_id: String,
+        debug_enabled: bool,
+    ) -> LiveStreamResponse {
+        let platform = SupportedPlatform::Douyu;
+        if request.mode() == LiveStreamMode::Meta {
+            let info = match crate::platforms::douyu::fetch_douyu_room_info::fetch_douyu_room_info(
+                room_id.clone(),
+                &self.inner.http,
+            )
+            .await
+            {
+                Ok(i) => i,
+                Err(e) => return failure(bare_room(platform, room_id), e),
+            };
+
+            let is_live = info.show_status.unwrap_or(0) == 1;
+            let options = if is_live && request.options_enabled() {
+                options_or_none(
+                    &platform,
+                    crate::platforms::douyu::stream_url::get_stream_options(
+                        &self.inner.http.0.inner,
+                        self.inner.config.user_agent.as_deref(),
+                        &info.room_id,
+                    )
+                    .await,
+                )
+            } else {
+                None
+            };
+            return LiveStreamResponse {
+                status: if is_live { LiveStatus::Live } else { LiveStatus::Offline },
+                room: RoomMeta {
+                    platform,
+                    room_id: info.room_id,
+                    normalized_room_id: None,
+                    web_rid: None,
+                    title: info.room_name,
+                    anchor_name: info.nickname,
+                    avatar: info.avatar_url,
+                    category: info.category,
+                },
+                playback: None,
+                options,
+                error: None,
+            };
+        }
+
+        let quality = request.quality.clone().unwrap_or_else(|| "原画".to_string());
+        let upstream = match crate::platforms::douyu::stream_url::get_stream_url_with_quality(
+            &self.inner.http.0.inner,
+            self.inner.config.user_agent.as_deref(),
+            &room_id,
+            &quality,
+            request.line.as_deref(),
+            request.codec(),
+        )
+        .await
+        {
+            Ok(url) => url,
+            Err(e) => return failure(bare_room(platform, room_id), e),
+        };
+        // Douyu only says which codec it served through the stream name
+        let codec = if request.codec().accepts_hevc() {
+            infer_codec(&upstream)
+        } else {
+            Some("avc")
+        };
+
+        LiveStreamResponse {
+            status: LiveStatus::Live,
+            room: bare_room(platform, room_id),
+            playback: Some(Playback {
+                url: upstream.clone(),
+                stream_type: infer_stream_type(&upstream),
+                upstream_url: map_upstream_debug(debug_enabled, Some(upstream)),
+                codec: codec.map(str::to_string),
+                headers: crate::platforms::douyu::stream_url::stream_headers(
+                    self.inner.config.user_agent.as_deref(),
+                ),
+                variants: None,
+                media: None,
+            }),
+            options: None,
+            error: None,
+        }
+    }
+
+    #[cfg(feature = "huya")]
+    async fn huya_live_stream(
+        &self,
+        request: &GetLiveStreamRequest,
+        room_id: String,
+        debug_enabled: bool,
+    ) -> LiveStreamResponse {
+        let platform = SupportedPlatform::Huya;
+        if request.mode() == LiveStreamMode::Meta {
+            let detail = match crate::platforms::huya::stream_url::fetch_room_detail(
+                &self.inner.http.0.inner,
+                &room_id,
+            )
+            .await
+            {
+                Ok(d) => d,
+                Err(e) => return failure_message(bare_room(platform, room_id), e.to_string()),
+            };
+
+            let options = if detail.status && request.options_enabled() {
+                options_or_none(
+                    &platform,
+                    crate::platforms::huya::stream_url::fetch_stream_options(
+                        &self.inner.http.0.inner,
+                        &room_id,
+                    )
+                    .await,
+                )
+            } else {
+                None
+            };
+            return LiveStreamResponse {
+                status: if detail.status {
+                    LiveStatus::Live
+                } else {
+                    LiveStatus::Offline
+                },
+                room: RoomMeta {
+                    title: detail.title,
+                    anchor_name: detail.nick,
+                    avatar: detail.avatar180,
+                    category: detail.category,
+                    ..bare_room(platform, room_id)
+                },
+                playback: None,
+                options,
+                error: None,
+            };
+        }
+
+        let quality = request.quality.clone().unwrap_or_else(|| "原画".to_string());
+        let resp = match crate::platforms::huya::stream_url::get_huya_unified_cmd(
+            room_id.clone(),
+            Some(quality),
+            request.line.clone(),
+            request.codec(),
+            &self.inner.http,
+        )
+        .await
+        {
+            Ok(r) => r,
+            Err(e) => return failure(bare_room(platform, room_id), e),
+        };
+
+        let room = RoomMeta {
+            title: resp.title.clone(),
+            anchor_name: resp.nick.clone(),
+            avatar: resp.avatar.clone(),
+            ..bare_room(platform, room_id)
+        };
+        let url = match resp.selected_url.clone().filter(|u| !u.is_empty()) {
+            Some(url) if resp.is_live => url,
+            _ => {
+                return LiveStreamResponse {
+                    status: LiveStatus::Offline,
+                    room,
+                    playback: None,
+                    options: None,
+                    error: None,
+                }
+            }
+        };
+
+        let variants = resp
+            .flv_tx_urls
+            .iter()
+            .map(|entry| StreamVariant {
+                url: entry.url.clone(),
+                format: Some("flv".to_string()),
+                desc: Some(entry.quality.clone()),
+                qn: None,
+                protocol: entry.url.split(':').next().map(|s| s.to_string()),
+                codec: Some("avc".to_string()),
+            })
+            .collect::<Vec<_>>();
+
+        LiveStreamResponse {
+            status: LiveStatus::Live,
+            room,
+            playback: Some(Playback {
+                url: url.clone(),
+                stream_type: infer_stream_type(&url),
+                upstream_url: map_upstream_debug(debug_enabled, Some(url)),
+                codec: resp.codec.clone(),
+                headers: crate::platforms::huya::stream_url::stream_headers(),
+                variants: map_variants_debug(debug_enabled, Some(variants)),
+                media: None,
+            }),
+            options: None,
+            error: None,
+        }
+    }
+
+    #[cfg(feature = "douyin")]
+    async fn douyin_live_stream(
+        &self,
+        request: &GetLiveStreamRequest,
+        room_id: String,
+        debug_enabled: bool,
+    ) -> LiveStreamResponse {
+        let platform = SupportedPlatform::Douyin;
+        if request.mode() == LiveStreamMode::Meta {
+            let info = match crate::platforms::douyin::douyin_streamer_info::fetch_douyin_streamer_info(
+                room_payload(&room_id),
+                &self.inner.http,
+                &self.inner.douyin_signer,
+                self.inner.config.user_agent.as_deref(),
+            )
+            .await
+            {
+                Ok(i) => i,
+                Err(e) => return failure(bare_room(platform, room_id), e),
+            };
+
+            let room = info_room(platform, room_id, &info);
+            if let Some(err) = info.error_message {
+                return failure_message(room, err);
+            }
+            let is_live = info.status.unwrap_or_default() == 2;
+            let options = info
+                .available_streams
+                .as_deref()
+                .filter(|_| is_live && request.options_enabled())
+                .map(crate::platforms::douyin::douyin_streamer_detail::stream_options);
+            return LiveStreamResponse {
+                status: if is_live { LiveStatus::Live } else { LiveStatus::Offline },
+                room,
+                playback: None,
+                options,
+                error: None,
+            };
+        }
+
+        let quality = normalize_douyin_quality(request.quality.as_deref());
+        let info = match crate::platforms::douyin::douyin_streamer_detail::get_douyin_live_stream_url_with_quality(
+            &self.inner.http.0,
+            &self.inner.douyin_signer,
+            self.inner.config.user_agent.as_deref(),
+            room_payload(&room_id),
+            quality,
+            request.codec(),
+        )
+        .await
+        {
+            Ok(i) => i,
+            Err(e) => return failure(bare_room(platform, room_id), e),
+        };
+
+        let room = info_room(platform, room_id, &info);
+        if let Some(err) = info.error_message {
+            return LiveStreamResponse {
+                status: LiveStatus::Error,
+                room,
+                playback: None,
+                options: None,
+                error: Some(err),
+            };
+        }
+        if info.status.unwrap_or_default() != 2 {
+            return LiveStreamResponse {
+                status: LiveStatus::Offline,
+                room,
+                playback: None,
+                options: None,
+                error: None,
+            };
+        }
+        let Some(url) = info.stream_url else {
+            return LiveStreamResponse {
+                status: LiveStatus::Error,
+                room,
+                playback: None,
+                options: None,
+                error: Some("stream_url is empty".to_string()),
+            };
+        };
+
+        LiveStreamResponse {
+            status: LiveStatus::Live,
+            room,
+            playback: Some(Playback {
+                url: url.clone(),
+                stream_type: infer_stream_type(&url),
+                upstream_url: map_upstream_debug(debug_enabled, info.upstream_url),
+                codec: variant_codec(&url, info.available_streams.as_deref()),
+                headers: crate::platforms::douyin::douyin_streamer_detail::stream_headers(
+                    self.inner.config.user_agent.as_deref(),
+                ),
+                variants: map_variants_debug(debug_enabled, info.available_streams),
+                media: None,
+            }),
+            options: None,
+            error: None,
+        }
+    }
+
+    #[cfg(feature = "bilibili")]
+    async fn bilibili_live_stream(
+        &self,
+        request: &GetLiveStreamRequest,
+        room_id: String,
+        debug_enabled: bool,
+    ) -> LiveStreamResponse {
+        let platform = SupportedPlatform::Bilibili;
+        let cookie = self.cookie_or_stored(&platform, request.cookie.clone());
+        if request.mode() == LiveStreamMode::Meta {
+            let info = match crate::platforms::bilibili::streamer_info::fetch_bilibili_streamer_info(
+                room_payload(&room_id),
+                cookie.clone(),
+                &self.inner.http,
+                &self.inner.bilibili,
+            )
+            .await
+            {
+                Ok(i) => i,
+                Err(e) => return failure(bare_room(platform, room_id), e),
+            };
+
+            let room = info_room(platform.clone(), room_id.clone(), &info);
+            if let Some(err) = info.error_message {
+                return failure_message(room, err);
+            }
+            let is_live = info.status.unwrap_or_default() == 1;
+            let options = if is_live && request.options_enabled() {
+                let result = match crate::platforms::bilibili::stream_url::build_playinfo_client(
+                    self.inner.config.user_agent.as_deref(),
+                    cookie.as_deref(),
+                ) {
+                    Ok(client) => {
+                        crate::platforms::bilibili::stream_url::fetch_stream_options(
+                            &client, &room_id,
+                        )
+                        .await
+                    }
+                    Err(e) => Err(e),
+                };
+                options_or_none(&platform, result)
+            } else {
+                None
+            };
+            return LiveStreamResponse {
+                status: if is_live { LiveStatus::Live } else { LiveStatus::Offline },
+                room,
+                playback: None,
+                options,
+                error: None,
+            };
+        }
+
+        let quality = request.quality.clone().unwrap_or_else(|| "原画".to_string());
+        let info = match crate::platforms::bilibili::stream_url::build_playinfo_client(
+            self.inner.config.user_agent.as_deref(),
+            cookie.as_deref(),
+        ) {
+            Ok(client) => {
+                crate::platforms::bilibili::stream_url::get_bilibili_stream_url(
+                    &client,
+                    &room_id,
+                    &quality,
+                    request.line.as_deref(),
+                    request.codec(),
+                    cookie.as_deref(),
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        let info = match info {
+            Ok(i) => i,
+            Err(e) => return failure(bare_room(platform, room_id), e),
+        };
+
+        let room = info_room(platform, room_id, &info);
+        if let Some(err) = info.error_message {
+            return failure_message(room, err);
+        }
+        let Some(url) = info.stream_url else {
+            return LiveStreamResponse {
+                status: LiveStatus::Offline,
+                room,
+                playback: None,
+                options: None,
+                error: None,
+            };
+        };
+
+        LiveStreamResponse {
+            status: LiveStatus::Live,
+            room,
+            playback: Some(Playback {
+                url: url.clone(),
+                stream_type: infer_stream_type(&url),
+                upstream_url: map_upstream_debug(debug_enabled, info.upstream_url),
+                codec: variant_codec(&url, info.available_streams.as_deref()),
+                headers: crate::platforms::bilibili::stream_url::stream_headers(
+                    self.inner.config.user_agent.as_deref(),
+                ),
+                variants: map_variants_debug(debug_enabled, info.available_streams),
+                media: None,
+            }),
+            options: None,
+            error: None,
+        }
+    }
+
+    #[cfg(feature = "kuaishou")]
+    async fn kuaishou_live_stream(
+        &self,
+        request: &GetLiveStreamRequest,
+        room_id: String,
+        debug_enabled: bool,
+    ) -> LiveStreamResponse {
+        use crate::platforms::kuaishou::room;
+
+        let platform = SupportedPlatform::Kuaishou;
+        let cookie = self.cookie_or_stored(&platform, request.cookie.clone());
+        let info = match room::fetch_room(&self.inner.http.0.inner, &room_id, cookie.as_deref()).await {
+            Ok(i) => i,
+            Err(e) => return failure(bare_room(platform, room_id), e),
+        };
+
+        let room = RoomMeta {
+            normalized_room_id: Some(info.principal_id.clone()).filter(|id| *id != room_id),
+            title: info.title.clone(),
+            anchor_name: info.anchor_name.clone(),
+            avatar: info.avatar.clone(),
+            ..bare_room(platform, room_id)
+        };
+        if !info.is_live || request.mode() == LiveStreamMode::Meta {
+            return LiveStreamResponse {
+                status: if info.is_live { LiveStatus::Live } else { LiveStatus::Offline },
+                room,
+                playback: None,
+                options: (info.is_live && request.options_enabled())
+                    .then(|| room::stream_options(&info.streams)),
+                error: None,
+            };
+        }
+
+        // Kuaishou has no user-selectable lines; `line` is ignored
+        let Some(selected) =
+            room::select_stream(&info.streams, request.quality.as_deref(), request.codec())
+        else {
+            return LiveStreamResponse {
+                status: LiveStatus::Error,
+                room,
+                playback: None,
+                options: None,
+                error: Some("no playable Kuaishou stream".to_string()),
+            };
+        };
+        let url = selected.url.clone();
+        let variants = info
+            .streams
+            .iter()
+            .map(|s| StreamVariant {
+                url: s.url.clone(),
+                format: Some(s.format.clone()),
+                desc: Some(s.name.clone()),
+                qn: None,
+                protocol: s.url.split(':').next().map(|p| p.to_string()),
+                codec: s.codec.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        LiveStreamResponse {
+            status: LiveStatus::Live,
+            room,
+            playback: Some(Playback {
+                url: url.clone(),
+                stream_type: infer_stream_type(&url),
+                upstream_url: map_upstream_debug(debug_enabled, Some(url)),
+                codec: selected.codec.clone(),
+                headers: crate::platforms::kuaishou::room::stream_headers(),
+                variants: map_variants_debug(debug_enabled, Some(variants)),
+                media: None,
+            }),
+            options: None,
+            error: None,
+        }
+    }
+
+    #[cfg(feature = "cc")]
+    async fn cc_live_stream(
+        &self,
+        request: &GetLiveStreamRequest,
+        room_id: String,
+        debug_enabled: bool,
+    ) -> LiveStreamResponse {
+        use crate::platforms::cc::room;
+
+        let platform = SupportedPlatform::Cc;
+        let info = match room::fetch_room(&self.inner.http.0.inner, &room_id).await {
+            Ok(i) => i,
+            Err(e) => return failure(bare_room(platform, room_id), e),
+        };
+
+        let room = RoomMeta {
+            normalized_room_id: Some(info.room_id.clone()).filter(|id| *id != room_id),
+            title: info.title.clone(),
+            anchor_name: info.anchor_name.clone(),
+            avatar: info.avatar.clone(),
+            ..bare_room(platform, room_id)
+        };
+        if !info.is_live || request.mode() == LiveStreamMode::Meta {
+            return LiveStreamResponse {
+                status: if info.is_live { LiveStatus::Live } else { LiveStatus::Offline },
+                room,
+                playback: None,
+                options: (info.is_live && request.options_enabled())
+                    .then(|| room::stream_options(&info.streams)),
+                error: None,
+            };
+        }
+
+        // FLV per quality and line; the HLS playlist only when there is no FLV
+        let selected = room::select_stream(
+            &info.streams,
+            request.quality.as_deref(),
+            request.line.as_deref(),
+        );
+        let Some(url) = selected.map(|s| s.url.clone()).or_else(|| info.hls_url.clone()) else {
+            return LiveStreamResponse {
+                status: LiveStatus::Error,
+                room,
+                playback: None,
+                options: None,
+                error: Some("no playable CC stream".to_string()),
+            };
+        };
+        let mut variants = info
+            .streams
+            .iter()
+            .map(|s| StreamVariant {
+                url: s.url.clone(),
+                format: Some("flv".to_string()),
+                desc: Some(format!("{} ({})", s.quality, s.line)),
+                qn: s.vbr.and_then(|v| i32::try_from(v).ok()),
+                protocol: s.url.split(':').next().map(|p| p.to_string()),
+                codec: None,
+            })
+            .collect::<Vec<_>>();
+        if let Some(hls) = &info.hls_url {
+            variants.push(StreamVariant {
+                url: hls.clone(),
+                format: Some("hls".to_string()),
+                desc: Some("HLS".to_string()),
+                qn: None,
+                protocol: hls.split(':').next().map(|p| p.to_string()),
+                codec: None,
+            });
+        }
+
+        LiveStreamResponse {
+            status: LiveStatus::Live,
+            room,
+            playback: Some(Playback {
+                url: url.clone(),
+                stream_type: infer_stream_type(&url),
+                upstream_url: map_upstream_debug(debug_enabled, Some(url)),
+                // CC does not say; its streams are H.264 in practice
+                codec: None,
+                headers: room::stream_headers(),
+                variants: map_variants_debug(debug_enabled, Some(variants)),
+                media: None,
+            }),
+            options: None,
+            error: None,
+        }
+    }
+
+    #[cfg(feature = "twitch")]
+    async fn twitch_live_stream(
+        &self,
+        request: &GetLiveStreamRequest,
+        room_id: String,
+        debug_enabled: bool,
+    ) -> LiveStreamResponse {
+        use crate::platforms::twitch::{gql, playlist};
+
+        let platform = SupportedPlatform::Twitch;
+        let Some(login) = gql::parse_channel_input(&room_id) else {
+            return failure(
+                bare_room(platform, room_id.clone()),
+                DtvError::api(format!("Invalid Twitch channel: {}", room_id)),
+            );
+        };
+        let http = &self.inner.http.0.inner;
+        let channel = match gql::fetch_channel(http, &login).await {
+            Ok(c) => c,
+            Err(e) => return failure(bare_room(platform, room_id), e),
+        };
+
+        let room = RoomMeta {
+            normalized_room_id: Some(channel.login.clone()).filter(|l| *l != room_id),
+            title: channel.title.clone(),
+            anchor_name: Some(channel.display_name.clone()),
+            avatar: channel.avatar.clone(),
+            category: channel.category.clone(),
+            ..bare_room(platform.clone(), room_id)
+        };
+        if !channel.is_live || request.mode() == LiveStreamMode::Meta {
+            let options = if channel.is_live && request.options_enabled() {
+                let renditions = match gql::fetch_playback_token(http, &login).await {
+                    Ok(token) => {
+                        playlist::fetch_renditions(http, &login, &token, request.codec()).await
+                    }
+                    Err(e) => Err(e),
+                };
+                options_or_none(&platform, renditions.map(|r| playlist::stream_options(&r)))
+            } else {
+                None
+            };
+            return LiveStreamResponse {
+                status: if channel.is_live { LiveStatus::Live } else { LiveStatus::Offline },
+                room,
+                playback: None,
+                options,
+                error: None,
+            };
+        }
+
+        let renditions = match gql::fetch_playback_token(http, &login).await {
+            Ok(token) => playlist::fetch_renditions(http, &login, &token, request.codec()).await,
+            Err(e) => Err(e),
+        };
+        let renditions = match renditions {
+            Ok(r) => r,
+            Err(e) => return failure(room, e),
+        };
+        // Twitch has no CDN lines to choose from; `line` is ignored
+        let Some(selected) =
+            playlist::select_rendition(&renditions, request.quality.as_deref(), request.codec())
+        else {
+            return LiveStreamResponse {
+                status: LiveStatus::Error,
+                room,
+                playback: None,
+                options: None,
+                error: Some("no playable Twitch rendition".to_string()),
+            };
+        };
+        let url = selected.url.clone();
+        let variants = renditions
+            .iter()
+            .map(|r| StreamVariant {
+                url: r.url.clone(),
+                format: Some("hls".to_string()),
+                desc: Some(r.name.clone()),
+                qn: None,
+                protocol: r.url.split(':').next().map(|p| p.to_string()),
+                codec: r.video_codec().map(str::to_string),
+            })
+            .collect::<Vec<_>>();
+
+        LiveStreamResponse {
+            status: LiveStatus::Live,
+            room,
+            playback: Some(Playback {
+                url: url.clone(),
+                stream_type: infer_stream_type(&url),
+                upstream_url: map_upstream_debug(debug_enabled, Some(url)),
+                codec: selected.video_codec().map(str::to_string),
+                headers: crate::platforms::twitch::playlist::stream_headers(),
+                variants: map_variants_debug(debug_enabled, Some(variants)),
+                media: None,
+            }),
+            options: None,
+            error: None,
+        }
+    }
+}
@@ -0,0 +1,640 @@
+// `DtvClient`: one configurable entry point over the platform modules, for
+// embedding dtv-core in other Rust apps without the Tauri shell.
+// A build with no platform features has nothing to dispatch to.
+#![cfg_attr(
+    not(any(feature = "douyu", feature = "huya", feature = "bilibili", feature = "douyin")),
+    allow(unused)
+)]
+mod lines;
+mod live_stream;
+
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use tokio::sync::mpsc::Receiver;
+
+use crate::danmaku::DanmakuHandler;
+use crate::platforms::common::errors::DtvError;
+use crate::platforms::common::http_client::DEFAULT_TIMEOUT_SECONDS;
+use crate::platforms::common::types::SupportedPlatform;
+use crate::platforms::common::{FollowHttpClient, LineProbeCache};
+use crate::webhooks::WebhookDispatcher;
+
+#[cfg(feature = "js-runtime")]
+use crate::plugins::{PluginHost, PluginSearchItem};
+#[cfg(feature = "js-runtime")]
+use crate::scripting::ScriptHost;
+
+#[cfg(feature = "bilibili")]
+use crate::platforms::bilibili::{search::BilibiliSearchItem, state::BilibiliState};
+#[cfg(feature = "douyin")]
+use crate::platforms::douyin::{
+    douyin_streamer_list::DouyinLiveListResponse,
+    signed_url::{DouyinSignedUrlBuilder, DEFAULT_SIGN_TTL},
+};
+#[cfg(feature = "douyu")]
+use crate::platforms::douyu::{
+    fetch_douyu_main_categories::CategoriesApiResponse, live_list::FrontendLiveListResponse,
+};
+#[cfg(feature = "huya")]
+use crate::platforms::huya::{live_list::HuyaLiveListFrontendResponse, search::HuyaAnchorItem};
+#[cfg(feature = "kuaishou")]
+use crate::platforms::kuaishou::search::KuaishouSearchItem;
+#[cfg(feature = "cc")]
+use crate::platforms::{cc::live_list::CcLiveListResponse, common::types::CommonCategoryGroup};
+#[cfg(feature = "twitch")]
+use crate::platforms::twitch::search::TwitchSearchItem;
+
+#[derive(Debug, Clone)]
+pub struct DtvConfig {
+    // None keeps each platform's built-in UA
+    pub user_agent: Option<String>,
+    pub timeout: Duration,
+    // Default for requests that leave `debug` unset
+    pub debug: bool,
+}
+
+impl Default for DtvConfig {
+    fn default() -> Self {
+        Self {
+            user_agent: None,
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECONDS),
+            debug: false,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct DtvClientBuilder {
+    config: DtvConfig,
+    cookies: HashMap<SupportedPlatform, String>,
+    follow_http: Option<FollowHttpClient>,
+    webhooks: Option<WebhookDispatcher>,
+    #[cfg(feature = "js-runtime")]
+    scripts: Option<ScriptHost>,
+    #[cfg(feature = "js-runtime")]
+    plugins: Option<PluginHost>,
+    #[cfg(feature = "bilibili")]
+    bilibili_state: Option<BilibiliState>,
+    #[cfg(all(feature = "douyu", feature = "js-runtime"))]
+    js_pool_size: Option<usize>,
+    #[cfg(feature = "douyu")]
+    douyu_sign_script_ttl: Option<Duration>,
+    #[cfg(feature = "douyin")]
+    douyin_sign_ttl: Option<Duration>,
+}
+
+impl DtvClientBuilder {
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.config.user_agent = Some(user_agent.into());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.config.timeout = timeout;
+        self
+    }
+
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.config.debug = debug;
+        self
+    }
+
+    /// Login cookie used for `platform` whenever a call does not pass its own.
+    pub fn cookie(mut self, platform: SupportedPlatform, cookie: impl Into<String>) -> Self {
+        self.cookies.insert(platform, cookie.into());
+        self
+    }
+
+    /// Share an existing HTTP client instead of building one from the config.
+    pub fn follow_http(mut self, client: FollowHttpClient) -> Self {
+        self.follow_http = Some(client);
+        self
+    }
+
+    /// Send danmaku and live-status events from this client to webhooks.
+    pub fn webhooks(mut self, dispatcher: WebhookDispatcher) -> Self {
+        self.webhooks = Some(dispatcher);
+        self
+    }
+
+    /// Run danmaku events through user scripts before handlers (and webhooks) see them.
+    #[cfg(feature = "js-runtime")]
+    pub fn scripts(mut self, host: ScriptHost) -> Self {
+        self.scripts = Some(host);
+        self
+    }
+
+    /// Serve `SupportedPlatform::Custom` ids from JS platform plugins.
+    #[cfg(feature = "js-runtime")]
+    pub fn plugins(mut self, host: PluginHost) -> Self {
+        self.plugins = Some(host);
+        self
+    }
+
+    #[cfg(feature = "bilibili")]
+    pub fn bilibili_state(mut self, state: BilibiliState) -> Self {
+        self.bilibili_state = Some(state);
+        self
+    }
+
+    /// V8 threads for Douyu stream signing. The signer is process-wide (one
+    /// isolate per thread is expensive), so only the first client to start it wins.
+    #[cfg(all(feature = "douyu", feature = "js-runtime"))]
+    pub fn js_pool_size(mut self, size: usize) -> Self {
+        self.js_pool_size = Some(size);
+        self
+    }
+
+    /// How long a room's Douyu sign script is reused before it is fetched
+    /// again (default five minutes). Process-wide like the signer, so only the
+    /// first client to sign wins.
+    #[cfg(feature = "douyu")]
+    pub fn douyu_sign_script_ttl(mut self, ttl: Duration) -> Self {
+        self.douyu_sign_script_ttl = Some(ttl);
+        self
+    }
+
+    /// How long a signed Douyin API URL is reused for identical requests.
+    #[cfg(feature = "douyin")]
+    pub fn douyin_sign_ttl(mut self, ttl: Duration) -> Self {
+        self.douyin_sign_ttl = Some(ttl);
+        self
+    }
+
+    pub fn build(self) -> Result<DtvClient, DtvError> {
+        let http = match self.follow_http {
+            Some(client) => client,
+            None => FollowHttpClient::with_options(
+                self.config.user_agent.as_deref(),
+                self.config.timeout,
+            )
+            .map_err(DtvError::internal)?,
+        };
+
+        #[cfg(all(feature = "douyu", feature = "js-runtime"))]
+        if let Some(size) = self.js_pool_size {
+            if !crate::platforms::douyu::sign_worker::init_signer(size) {
+                eprintln!("[DtvClient] Douyu signer already running, js_pool_size ignored");
+            }
+        }
+
+        #[cfg(feature = "douyu")]
+        if let Some(ttl) = self.douyu_sign_script_ttl {
+            if !crate::platforms::douyu::sign_worker::init_sign_script_ttl(ttl) {
+                eprintln!(
+                    "[DtvClient] Douyu sign script cache already in use, douyu_sign_script_ttl ignored"
+                );
+            }
+        }
+
+        Ok(DtvClient {
+            inner: Arc::new(ClientInner {
+                config: self.config,
+                http,
+                cookies: RwLock::new(self.cookies),
+                webhooks: self.webhooks,
+                line_probes: LineProbeCache::default(),
+                #[cfg(feature = "js-runtime")]
+                scripts: self.scripts,
+                #[cfg(feature = "js-runtime")]
+                plugins: self.plugins,
+                #[cfg(feature = "bilibili")]
+                bilibili: self.bilibili_state.unwrap_or_default(),
+                #[cfg(feature = "douyin")]
+                douyin_signer: DouyinSignedUrlBuilder::new(
+                    self.douyin_sign_ttl.unwrap_or(DEFAULT_SIGN_TTL),
+                ),
+            }),
+        })
+    }
+}
+
+struct ClientInner {
+    config: DtvConfig,
+    http: FollowHttpClient,
+    cookies: RwLock<HashMap<SupportedPlatform, String>>,
+    webhooks: Option<WebhookDispatcher>,
+    // Speed-test results behind `line: "auto"`
+    line_probes: LineProbeCache,
+    #[cfg(feature = "js-runtime")]
+    scripts: Option<ScriptHost>,
+    #[cfg(feature = "js-runtime")]
+    plugins: Option<PluginHost>,
+    #[cfg(feature = "bilibili")]
+    bilibili: BilibiliState,
+    // a_bogus cache for the Douyin web API
+    #[cfg(feature = "douyin")]
+    douyin_signer: DouyinSignedUrlBuilder,
+}
+
+/// Cheap to clone; clones share clients, cookies and caches.
+#[derive(Clone)]
+pub struct DtvClient {
+    inner: Arc<ClientInner>,
+}
+
+impl DtvClient {
+    pub fn builder() -> DtvClientBuilder {
+        DtvClientBuilder::default()
+    }
+
+    pub fn new() -> Result<Self, DtvError> {
+        Self::builder().build()
+    }
+
+    pub fn config(&self) -> &DtvConfig {
+        &self.inner.config
+    }
+
+    pub fn follow_http(&self) -> &FollowHttpClient {
+        &self.inner.http
+    }
+
+    #[cfg(feature = "bilibili")]
+    pub fn bilibili_state(&self) -> &BilibiliState {
+        &self.inner.bilibili
+    }
+
+    pub fn webhooks(&self) -> Option<&WebhookDispatcher> {
+        self.inner.webhooks.as_ref()
+    }
+
+    #[cfg(feature = "js-runtime")]
+    pub fn scripts(&self) -> Option<&ScriptHost> {
+        self.inner.scripts.as_ref()
+    }
+
+    #[cfg(feature = "js-runtime")]
+    pub fn plugins(&self) -> Option<&PluginHost> {
+        self.inner.plugins.as_ref()
+    }
+
+    pub fn cookie(&self, platform: &SupportedPlatform) -> Option<String> {
+        self.inner
+            .cookies
+            .read()
+            .ok()
+            .and_then(|cookies| cookies.get(platform).cloned())
+    }
+
+    pub fn set_cookie(&self, platform: SupportedPlatform, cookie: Option<String>) {
+        if let Ok(mut cookies) = self.inner.cookies.write() {
+            match cookie.filter(|c| !c.trim().is_empty()) {
+                Some(c) => cookies.insert(platform, c),
+                None => cookies.remove(&platform),
+            };
+        }
+    }
+
+    // An explicit per-call cookie beats the stored one.
+    #[cfg(any(feature = "bilibili", feature = "kuaishou"))]
+    fn cookie_or_stored(&self, platform: &SupportedPlatform, explicit: Option<String>) -> Option<String> {
+        explicit
+            .filter(|c| !c.trim().is_empty())
+            .or_else(|| self.cookie(platform))
+    }
+
+    /// Run the danmaku listener for `room_id` until `rx_shutdown` fires.
+    pub async fn run_danmaku(
+        &self,
+        platform: SupportedPlatform,
+        room_id: String,
+        rx_shutdown: Receiver<()>,
+        handler: Arc<dyn DanmakuHandler>,
+    ) {
+        let handler = match &self.inner.webhooks {
+            Some(hooks) => hooks.danmaku_handler(platform.clone(), room_id.clone(), handler),
+            None => handler,
+        };
+        // Outermost, so events a script drops never reach webhooks
+        #[cfg(feature = "js-runtime")]
+        let handler = match &self.inner.scripts {
+            Some(scripts) => scripts.danmaku_handler(platform.clone(), room_id.clone(), handler),
+            None => handler,
+        };
+        match platform {
+            #[cfg(feature = "douyu")]
+            SupportedPlatform::Douyu => {
+                // The Douyu client stops on a oneshot
+                let mut rx_shutdown = rx_shutdown;
+                let (stop_tx, stop_rx) = tokio::sync::oneshot::channel();
+                tokio::spawn(async move {
+                    let _ = rx_shutdown.recv().await;
+                    let _ = stop_tx.send(());
+                });
+                let mut client =
+                    crate::platforms::douyu::danmaku::DanmakuClient::new(&room_id, handler, stop_rx);
+                if let Err(e) = client.start().await {
+                    eprintln!("[Douyu Danmaku] client for room {} failed: {}", room_id, e);
+                }
+            }
+            #[cfg(feature = "huya")]
+            SupportedPlatform::Huya => {
+                crate::platforms::huya::danmaku::run_huya_danmaku_listener(
+                    room_id,
+                    rx_shutdown,
+                    handler,
+                )
+                .await
+            }
+            #[cfg(feature = "bilibili")]
+            SupportedPlatform::Bilibili => {
+                let cookie = self.cookie(&SupportedPlatform::Bilibili);
+                crate::platforms::bilibili::danmaku::run_bilibili_danmaku_listener(
+                    room_id,
+                    cookie,
+                    rx_shutdown,
+                    handler,
+                )
+                .await
+            }
+            #[cfg(feature = "douyin")]
+            SupportedPlatform::Douyin => {
+                crate::platforms::douyin::douyin_danmu_listener::run_douyin_danmu_listener(
+                    room_id,
+                    rx_shutdown,
+                    handler,
+                )
+                .await
+            }
+            #[cfg(feature = "kuaishou")]
+            SupportedPlatform::Kuaishou => {
+                let cookie = self.cookie(&SupportedPlatform::Kuaishou);
+                crate::platforms::kuaishou::danmaku::run_kuaishou_danmaku_listener(
+                    room_id,
+                    cookie,
+                    rx_shutdown,
+                    handler,
+                )
+                .await
+            }
+            #[cfg(feature = "cc")]
+            SupportedPlatform::Cc => {
+                crate::platforms::cc::danmaku::run_cc_danmaku_listener(room_id, rx_shutdown, handler)
+                    .await
+            }
+            #[cfg(feature = "twitch")]
+            SupportedPlatform::Twitch => {
+                crate::platforms::twitch::chat::run_twitch_danmaku_listener(
+                    room_id,
+                    rx_shutdown,
+                    handler,
+                )
+                .await
+            }
+            // Plugins only resolve rooms and streams
+            SupportedPlatform::Custom(id) => {
+                eprintln!("[DtvClient] Danmaku is not available for plugin platform {}", id);
+            }
+        }
+    }
+
+    /// Search rooms on a plugin platform.
+    #[cfg(feature = "js-runtime")]
+    pub async fn plugin_search(
+        &self,
+        id: &str,
+        keyword: &str,
+        page: Option<u32>,
+    ) -> Result<Vec<PluginSearchItem>, String> {
+        match &self.inner.plugins {
+            Some(plugins) => plugins.search(id, keyword, page.unwrap_or(1)).await,
+            None => Err(format!("No plugin provides platform {}", id)),
+        }
+    }
+}
+
+#[cfg(feature = "douyu")]
+impl DtvClient {
+    pub async fn douyu_categories(&self) -> Result<CategoriesApiResponse, String> {
+        crate::platforms::douyu::fetch_douyu_main_categories::fetch_categories(
+            &self.inner.http.0.inner,
+            self.inner.config.user_agent.as_deref(),
+        )
+        .await
+    }
+
+    pub async fn douyu_three_cate(
+        &self,
+        tag_id: i32,
+    ) -> Result<Vec<crate::platforms::common::types::CommonPlatformCategory>, String> {
+        crate::platforms::douyu::three_cate::fetch_three_cate(&self.inner.http.0.inner, tag_id).await
+    }
+
+    pub async fn douyu_live_list(
+        &self,
+        offset: u32,
+        cate2: String,
+        limit: u32,
+    ) -> FrontendLiveListResponse {
+        crate::platforms::douyu::live_list::fetch_live_list(
+            &self.inner.http.0.inner,
+            self.inner.config.user_agent.as_deref(),
+            offset,
+            cate2,
+            limit,
+        )
+        .await
+    }
+
+    pub async fn douyu_live_list_for_cate3(
+        &self,
+        cate3_id: String,
+        page: u32,
+        limit: u32,
+    ) -> FrontendLiveListResponse {
+        crate::platforms::douyu::live_list::fetch_live_list_for_cate3(
+            &self.inner.http.0.inner,
+            self.inner.config.user_agent.as_deref(),
+            cate3_id,
+            page,
+            limit,
+        )
+        .await
+    }
+
+    pub async fn douyu_search(&self, keyword: &str) -> Result<String, String> {
+        crate::platforms::douyu::search_anchor::perform_anchor_search(
+            &self.inner.http.0.inner,
+            self.inner.config.user_agent.as_deref(),
+            keyword,
+        )
+        .await
+        .map_err(|e| e.to_string())
+    }
+
+    #[cfg(feature = "js-runtime")]
+    pub fn douyu_signer_metrics(
+        &self,
+    ) -> Option<crate::platforms::common::js_function_worker::JsWorkerMetrics> {
+        crate::platforms::douyu::sign_worker::signer_metrics()
+    }
+}
+
+#[cfg(feature = "huya")]
+impl DtvClient {
+    pub async fn huya_live_list(
+        &self,
+        gid: String,
+        page_no: u32,
+        page_size: u32,
+    ) -> HuyaLiveListFrontendResponse {
+        crate::platforms::huya::live_list::fetch_huya_live_list(
+            &self.inner.http.0,
+            gid,
+            page_no,
+            page_size,
+        )
+        .await
+    }
+
+    pub async fn huya_search(
+        &self,
+        keyword: String,
+        page: Option<usize>,
+    ) -> Result<Vec<HuyaAnchorItem>, String> {
+        crate::platforms::huya::search::search_huya_anchors(&self.inner.http.0.inner, self.inner.config.user_agent.as_deref(), keyword, page)
+            .await
+    }
+}
+
+#[cfg(feature = "bilibili")]
+impl DtvClient {
+    pub async fn bilibili_live_list(
+        &self,
+        area_id: String,
+        parent_area_id: String,
+        page: u32,
+    ) -> Result<String, String> {
+        crate::platforms::bilibili::live_list::fetch_bilibili_live_list(
+            &self.inner.http.0.inner,
+            self.inner.config.user_agent.as_deref(),
+            area_id,
+            parent_area_id,
+            page,
+            &self.inner.bilibili,
+        )
+        .await
+    }
+
+    pub async fn bilibili_search(
+        &self,
+        keyword: String,
+        page: Option<u32>,
+        cookie: Option<String>,
+    ) -> Result<Vec<BilibiliSearchItem>, String> {
+        let cookie = self.cookie_or_stored(&SupportedPlatform::Bilibili, cookie);
+        crate::platforms::bilibili::search::search_bilibili_rooms(
+            &self.inner.http.0.inner,
+            self.inner.config.user_agent.as_deref(),
+            keyword,
+            page,
+            cookie,
+        )
+        .await
+    }
+
+    pub async fn bilibili_refresh_w_webid(&self) -> Result<String, String> {
+        crate::platforms::bilibili::state::refresh_w_webid(
+            &self.inner.http.0.inner,
+            self.inner.config.user_agent.as_deref(),
+            &self.inner.bilibili,
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "kuaishou")]
+impl DtvClient {
+    pub async fn kuaishou_search(
+        &self,
+        keyword: String,
+        page: Option<u32>,
+        cookie: Option<String>,
+    ) -> Result<Vec<KuaishouSearchItem>, String> {
+        let cookie = self.cookie_or_stored(&SupportedPlatform::Kuaishou, cookie);
+        crate::platforms::kuaishou::search::search_kuaishou_authors(
+            &self.inner.http.0.inner,
+            self.inner.config.user_agent.as_deref(),
+            keyword,
+            page,
+            cookie,
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "cc")]
+impl DtvClient {
+    /// All category groups; a group that fails to load is skipped.
+    pub async fn cc_categories(&self) -> Result<Vec<CommonCategoryGroup>, String> {
+        use crate::platforms::cc::live_list::{fetch_cc_categories, CATEGORY_GROUPS};
+
+        let mut groups = Vec::new();
+        let mut last_err = None;
+        for (catetype, _) in CATEGORY_GROUPS {
+            match fetch_cc_categories(&self.inner.http.0.inner, self.inner.config.user_agent.as_deref(), *catetype).await {
+                Ok(group) => groups.push(group),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        match last_err {
+            Some(e) if groups.is_empty() => Err(e),
+            _ => Ok(groups),
+        }
+    }
+
+    pub async fn cc_live_list(
+        &self,
+        gametype: Option<String>,
+        page: u32,
+        page_size: u32,
+    ) -> Result<CcLiveListResponse, String> {
+        crate::platforms::cc::live_list::fetch_cc_live_list(
+            &self.inner.http.0.inner,
+            self.inner.config.user_agent.as_deref(),
+            gametype,
+            page,
+            page_size,
+        )
+        .await
+    }
+}
+
+#[cfg(feature = "twitch")]
+impl DtvClient {
+    pub async fn twitch_search(
+        &self,
+        keyword: String,
+        page: Option<u32>,
+    ) -> Result<Vec<TwitchSearchItem>, String> {
+        crate::platforms::twitch::search::search_twitch_channels(&self.inner.http.0.inner, keyword, page).await
+    }
+}
+
+#[cfg(feature = "douyin")]
+impl DtvClient {
+    /// Partition room list; a fresh msToken is generated when none is given.
+    pub async fn douyin_partition_rooms(
+        &self,
+        partition: String,
+        partition_type: String,
+        offset: i32,
+        ms_token: Option<String>,
+    ) -> Result<DouyinLiveListResponse, String> {
+        let ms_token = ms_token
+            .unwrap_or_else(crate::platforms::douyin::danmu::signature::generate_douyin_ms_token);
+        crate::platforms::douyin::douyin_streamer_list::fetch_douyin_partition_rooms(
+            &self.inner.http.0,
+            &self.inner.douyin_signer,
+            self.inner.config.user_agent.as_deref(),
+            partition,
+            partition_type,
+            offset,
+            ms_token,
+        )
+        .await
+    }
+}
@@ -10,3 +10,37 @@ pub trait DanmakuHandler: Send + Sync + 'static {
         }
     }
 }
+
+/// Dotted name for a danmaku event, shared by webhooks and scripts: the
+/// generic chat channel is `danmaku.message`, per-room typed events
+/// (`danmaku-{room}`) become `danmaku.{type}`. None for anything else.
+pub fn event_kind(channel: &str, payload: &serde_json::Value) -> Option<String> {
+    if channel == "danmaku-message" {
+        return Some("danmaku.message".to_string());
+    }
+    if !channel.starts_with("danmaku-") {
+        return None;
+    }
+    let kind = payload.get("type").and_then(serde_json::Value::as_str)?;
+    Some(format!("danmaku.{}", kind.to_lowercase()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn event_kinds() {
+        assert_eq!(
+            event_kind("danmaku-message", &serde_json::Value::Null).as_deref(),
+            Some("danmaku.message")
+        );
+        assert_eq!(
+            event_kind("danmaku-6", &json!({ "type": "Gift" })).as_deref(),
+            Some("danmaku.gift")
+        );
+        assert_eq!(event_kind("danmaku-6", &json!({})), None);
+        assert_eq!(event_kind("other", &json!({ "type": "x" })), None);
+    }
+}
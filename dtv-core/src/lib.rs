@@ -1,2 +1,12 @@
+pub mod client;
 pub mod danmaku;
+pub mod media;
 pub mod platforms;
+pub mod player;
+#[cfg(feature = "js-runtime")]
+pub mod plugins;
+#[cfg(feature = "js-runtime")]
+pub mod scripting;
+pub mod webhooks;
+
+pub use client::{DtvClient, DtvClientBuilder, DtvConfig};
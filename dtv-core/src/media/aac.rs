@@ -0,0 +1,122 @@
+// AAC from FLV audio tags, rewrapped as ADTS so it plays as a bare .aac
+// stream: FLV carries the decoder config once, ADTS repeats it per frame.
+
+const ADTS_HEADER_LEN: usize = 7;
+// frame_length is 13 bits and counts the header
+const MAX_ADTS_FRAME: usize = (1 << 13) - 1;
+
+/// The fields of an AudioSpecificConfig that ADTS headers repeat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AacConfig {
+    pub object_type: u8,
+    pub sampling_index: u8,
+    pub channels: u8,
+}
+
+impl AacConfig {
+    /// From the AudioSpecificConfig of an AAC sequence header. None for
+    /// explicit sample rates and object types ADTS cannot express. For
+    /// HE-AAC (5, 29) the rate read here is the AAC-LC core's; the SBR rate
+    /// follows it.
+    pub fn parse(asc: &[u8]) -> Option<Self> {
+        let [first, second, ..] = *asc else {
+            return None;
+        };
+        let object_type = first >> 3;
+        let sampling_index = ((first & 0x07) << 1) | (second >> 7);
+        let channels = (second >> 3) & 0x0f;
+        let supported = matches!(object_type, 1..=5 | 29);
+        (supported && sampling_index < 13).then_some(Self {
+            object_type,
+            sampling_index,
+            channels,
+        })
+    }
+
+    pub fn adts_header(&self, payload_len: usize) -> [u8; ADTS_HEADER_LEN] {
+        let len = payload_len + ADTS_HEADER_LEN;
+        // Two bits of profile: HE-AAC goes out as its AAC-LC core
+        let profile = match self.object_type {
+            2 | 5 | 29 => 1,
+            object_type => object_type - 1,
+        };
+        [
+            0xff,
+            // MPEG-4, no CRC
+            0xf1,
+            (profile << 6) | (self.sampling_index << 2) | ((self.channels >> 2) & 0x01),
+            ((self.channels & 0x03) << 6) | ((len >> 11) & 0x03) as u8,
+            (len >> 3) as u8,
+            (((len & 0x07) << 5) as u8) | 0x1f,
+            // Buffer fullness 0x7ff (VBR), one raw data block
+            0xfc,
+        ]
+    }
+}
+
+/// Turns the bodies of FLV audio tags into ADTS frames.
+#[derive(Debug, Default)]
+pub struct AdtsMuxer {
+    config: Option<AacConfig>,
+}
+
+impl AdtsMuxer {
+    /// The config from the last sequence header, once there is one.
+    pub fn config(&self) -> Option<AacConfig> {
+        self.config
+    }
+
+    /// The ADTS frame for an AAC tag body. None for the sequence header,
+    /// which only updates the config, and for anything that is not AAC.
+    pub fn push(&mut self, data: &[u8]) -> Option<Vec<u8>> {
+        if data.first()? >> 4 != 10 {
+            return None;
+        }
+        match *data.get(1)? {
+            0 => {
+                self.config = AacConfig::parse(&data[2..]);
+                None
+            }
+            _ => {
+                let payload = &data[2..];
+                if payload.len() + ADTS_HEADER_LEN > MAX_ADTS_FRAME {
+                    return None;
+                }
+                let mut frame = self.config?.adts_header(payload.len()).to_vec();
+                frame.extend_from_slice(payload);
+                Some(frame)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_aac_in_adts() {
+        let mut muxer = AdtsMuxer::default();
+        // Raw frames before the config cannot be framed
+        assert_eq!(muxer.push(&[0xaf, 0x01, 0x21, 0x10, 0x04]), None);
+        // AAC-LC, 44.1 kHz, stereo
+        assert_eq!(muxer.push(&[0xaf, 0x00, 0x12, 0x10]), None);
+        assert_eq!(
+            muxer.push(&[0xaf, 0x01, 0x21, 0x10, 0x04]),
+            Some(vec![
+                0xff, 0xf1, 0x50, 0x80, 0x01, 0x5f, 0xfc, 0x21, 0x10, 0x04
+            ])
+        );
+        // HE-AAC: 22.05 kHz stereo LC core under 44.1 kHz SBR
+        assert_eq!(muxer.push(&[0xaf, 0x00, 0x2b, 0x92, 0x08]), None);
+        assert_eq!(
+            muxer.push(&[0xaf, 0x01, 0x21, 0x10, 0x04]),
+            Some(vec![
+                0xff, 0xf1, 0x5c, 0x80, 0x01, 0x5f, 0xfc, 0x21, 0x10, 0x04
+            ])
+        );
+        // MP3
+        assert_eq!(muxer.push(&[0x2f, 0x01, 0xff]), None);
+        assert_eq!(AacConfig::parse(&[0x17, 0x80]), None);
+    }
+}
@@ -0,0 +1,145 @@
+// AMF0, as far as FLV script tags use it: onMetaData is a name followed by
+// an ECMA array (or object) of numbers, booleans and strings.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Amf0Value {
+    Number(f64),
+    Boolean(bool),
+    String(String),
+    // Objects and ECMA arrays, in wire order
+    Object(Vec<(String, Amf0Value)>),
+    Array(Vec<Amf0Value>),
+    Date(f64),
+    Null,
+}
+
+impl Amf0Value {
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Amf0Value::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Amf0Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Property of an object or ECMA array.
+    pub fn get(&self, key: &str) -> Option<&Amf0Value> {
+        match self {
+            Amf0Value::Object(props) => props.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+}
+
+// Nesting deeper than this is not metadata
+const MAX_DEPTH: usize = 16;
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        let bytes = self.buf.get(self.pos..self.pos.checked_add(n)?)?;
+        self.pos += n;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.take(2).map(|b| u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.take(4)
+            .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn f64(&mut self) -> Option<f64> {
+        self.take(8)
+            .map(|b| f64::from_be_bytes(b.try_into().expect("8 bytes")))
+    }
+
+    fn string(&mut self, len: usize) -> Option<String> {
+        self.take(len)
+            .map(|b| String::from_utf8_lossy(b).into_owned())
+    }
+
+    fn properties(&mut self, depth: usize) -> Option<Vec<(String, Amf0Value)>> {
+        let mut props = Vec::new();
+        loop {
+            let len = self.u16()? as usize;
+            if len == 0 {
+                // Empty name then the object-end marker; some encoders omit it at the end
+                if self.buf.get(self.pos) == Some(&0x09) {
+                    self.pos += 1;
+                }
+                return Some(props);
+            }
+            let key = self.string(len)?;
+            props.push((key, self.value(depth + 1)?));
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Option<Amf0Value> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        Some(match self.u8()? {
+            0x00 => Amf0Value::Number(self.f64()?),
+            0x01 => Amf0Value::Boolean(self.u8()? != 0),
+            0x02 => {
+                let len = self.u16()? as usize;
+                Amf0Value::String(self.string(len)?)
+            }
+            0x03 => Amf0Value::Object(self.properties(depth)?),
+            0x05 | 0x06 => Amf0Value::Null,
+            0x08 => {
+                // The count is advisory; the end marker is what counts
+                self.u32()?;
+                Amf0Value::Object(self.properties(depth)?)
+            }
+            0x0a => {
+                let count = self.u32()? as usize;
+                let mut items = Vec::with_capacity(count.min(64));
+                for _ in 0..count {
+                    items.push(self.value(depth + 1)?);
+                }
+                Amf0Value::Array(items)
+            }
+            0x0b => {
+                let millis = self.f64()?;
+                self.u16()?; // time zone, unused
+                Amf0Value::Date(millis)
+            }
+            0x0c => {
+                let len = self.u32()? as usize;
+                Amf0Value::String(self.string(len)?)
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// Every value in `buf`, in order, up to the first one that does not decode.
+pub fn decode_all(buf: &[u8]) -> Vec<Amf0Value> {
+    let mut reader = Reader { buf, pos: 0 };
+    let mut values = Vec::new();
+    while reader.pos < buf.len() {
+        match reader.value(0) {
+            Some(value) => values.push(value),
+            None => break,
+        }
+    }
+    values
+}
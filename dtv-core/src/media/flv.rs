@@ -0,0 +1,483 @@
+// FLV framing as HTTP-FLV CDNs send it: a file header, then tags each
+// followed by a PreviousTagSize, plus the onMetaData script tag.
+use super::amf0::{self, Amf0Value};
+use crate::platforms::common::normalize_codec;
+
+pub const SIGNATURE: &[u8] = b"FLV";
+pub const TAG_HEADER_LEN: usize = 11;
+const PREVIOUS_TAG_SIZE_LEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagKind {
+    Audio,
+    Video,
+    Script,
+    Other(u8),
+}
+
+impl TagKind {
+    fn from_byte(byte: u8) -> Self {
+        // The top bits are reserved / the encryption flag
+        match byte & 0x1f {
+            8 => TagKind::Audio,
+            9 => TagKind::Video,
+            18 => TagKind::Script,
+            other => TagKind::Other(other),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tag<'a> {
+    pub kind: TagKind,
+    // Milliseconds, extended byte included
+    pub timestamp: u32,
+    pub data: &'a [u8],
+}
+
+fn u24(b: &[u8]) -> u32 {
+    u32::from_be_bytes([0, b[0], b[1], b[2]])
+}
+
+/// Length of the file header plus PreviousTagSize0, if `buf` starts with one.
+pub fn header_len(buf: &[u8]) -> Option<usize> {
+    if buf.len() < 9 || !buf.starts_with(SIGNATURE) {
+        return None;
+    }
+    let offset = u32::from_be_bytes([buf[5], buf[6], buf[7], buf[8]]) as usize;
+    (offset >= 9).then_some(offset + PREVIOUS_TAG_SIZE_LEN)
+}
+
+/// The tag at the start of `buf` and the bytes it spans, its trailing
+/// PreviousTagSize included. None until the whole tag is in `buf`.
+pub fn read_tag(buf: &[u8]) -> Option<(Tag<'_>, usize)> {
+    if buf.len() < TAG_HEADER_LEN {
+        return None;
+    }
+    let size = u24(&buf[1..4]) as usize;
+    let timestamp = u24(&buf[4..7]) | (buf[7] as u32) << 24;
+    let end = TAG_HEADER_LEN + size;
+    let total = end + PREVIOUS_TAG_SIZE_LEN;
+    if buf.len() < total {
+        return None;
+    }
+    let tag = Tag {
+        kind: TagKind::from_byte(buf[0]),
+        timestamp,
+        data: &buf[TAG_HEADER_LEN..end],
+    };
+    Some((tag, total))
+}
+
+/// The complete tags in the first bytes of an FLV stream, in order.
+pub fn tags(buf: &[u8]) -> impl Iterator<Item = Tag<'_>> {
+    let mut rest = header_len(buf)
+        .and_then(|n| buf.get(n..))
+        .unwrap_or_default();
+    std::iter::from_fn(move || {
+        let (tag, used) = read_tag(rest)?;
+        rest = &rest[used..];
+        Some(tag)
+    })
+}
+
+/// "avc", "hevc" or "av1" for a video tag body: the classic codec ids (7,
+/// and 12 for H.265 as Chinese CDNs send it) and Enhanced RTMP FourCCs.
+pub fn video_codec(data: &[u8]) -> Option<&'static str> {
+    let first = *data.first()?;
+    if first & 0x80 != 0 {
+        return match data.get(1..5)? {
+            b"avc1" => Some("avc"),
+            b"hvc1" => Some("hevc"),
+            b"av01" => Some("av1"),
+            _ => None,
+        };
+    }
+    match first & 0x0f {
+        7 => Some("avc"),
+        12 => Some("hevc"),
+        _ => None,
+    }
+}
+
+/// Codec of an audio tag body.
+pub fn audio_codec(data: &[u8]) -> Option<&'static str> {
+    match data.first()? >> 4 {
+        10 => Some("aac"),
+        2 | 14 => Some("mp3"),
+        _ => None,
+    }
+}
+
+/// Whether a video tag body is a keyframe, where a decoder can start.
+pub fn is_keyframe(data: &[u8]) -> bool {
+    data.first().is_some_and(|b| (b >> 4) & 0x07 == 1)
+}
+
+/// Whether a tag body carries decoder configuration rather than media: the
+/// AVC/HEVC sequence header (Enhanced RTMP's SequenceStart) or the AAC
+/// AudioSpecificConfig.
+pub fn is_sequence_header(kind: TagKind, data: &[u8]) -> bool {
+    match (kind, data) {
+        (TagKind::Video, [first, ..]) if first & 0x80 != 0 => first & 0x0f == 0,
+        (TagKind::Video, [first, 0, ..]) => matches!(first & 0x0f, 7 | 12),
+        (TagKind::Audio, [first, 0, ..]) => first >> 4 == 10,
+        _ => false,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoPacketKind {
+    // AVC/HEVC decoder configuration record
+    Config,
+    // Length-prefixed NAL units
+    Frames,
+}
+
+/// An H.264/H.265 video tag body, classic or Enhanced RTMP.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VideoPacket<'a> {
+    // "avc" or "hevc"
+    pub codec: &'static str,
+    pub kind: VideoPacketKind,
+    pub keyframe: bool,
+    // Presentation minus decode time, in milliseconds
+    pub composition_time: i32,
+    pub data: &'a [u8],
+}
+
+fn i24(b: &[u8]) -> i32 {
+    (u24(b) as i32) << 8 >> 8
+}
+
+/// The packet in a video tag body; None for other codecs, end-of-sequence
+/// markers and Enhanced RTMP metadata.
+pub fn video_packet(data: &[u8]) -> Option<VideoPacket<'_>> {
+    let codec = video_codec(data).filter(|c| *c != "av1")?;
+    let keyframe = is_keyframe(data);
+    let packet = |kind, composition_time, data| VideoPacket {
+        codec,
+        kind,
+        keyframe,
+        composition_time,
+        data,
+    };
+    if data[0] & 0x80 != 0 {
+        return match data[0] & 0x0f {
+            0 => Some(packet(VideoPacketKind::Config, 0, data.get(5..)?)),
+            1 => Some(packet(
+                VideoPacketKind::Frames,
+                i24(data.get(5..8)?),
+                data.get(8..)?,
+            )),
+            // CodedFramesX: no composition time
+            3 => Some(packet(VideoPacketKind::Frames, 0, data.get(5..)?)),
+            _ => None,
+        };
+    }
+    let composition_time = i24(data.get(2..5)?);
+    match data[1] {
+        0 => Some(packet(VideoPacketKind::Config, 0, &data[5..])),
+        1 => Some(packet(
+            VideoPacketKind::Frames,
+            composition_time,
+            &data[5..],
+        )),
+        _ => None,
+    }
+}
+
+/// A tag read off a live stream, with its header and trailing
+/// PreviousTagSize so it can be forwarded as-is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawTag {
+    pub kind: TagKind,
+    pub timestamp: u32,
+    pub bytes: Vec<u8>,
+}
+
+impl RawTag {
+    pub fn data(&self) -> &[u8] {
+        &self.bytes[TAG_HEADER_LEN..self.bytes.len() - PREVIOUS_TAG_SIZE_LEN]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlvItem {
+    // File header plus PreviousTagSize0
+    Header(Vec<u8>),
+    Tag(RawTag),
+}
+
+/// The bytes did not start with an FLV header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotFlv;
+
+/// Splits an FLV byte stream into its header and tags as chunks arrive,
+/// whatever their boundaries.
+#[derive(Debug, Default)]
+pub struct TagReader {
+    buf: Vec<u8>,
+    pos: usize,
+    header_read: bool,
+}
+
+impl TagReader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.buf.drain(..self.pos);
+        self.pos = 0;
+        self.buf.extend_from_slice(chunk);
+    }
+
+    /// Bytes not yet returned as an item, e.g. to pass on a stream that
+    /// turned out not to be FLV.
+    pub fn take_buffered(&mut self) -> Vec<u8> {
+        let rest = self.buf.split_off(self.pos);
+        self.buf.clear();
+        self.pos = 0;
+        rest
+    }
+
+    /// The next complete item: the header first, then each tag. Ok(None)
+    /// until more bytes arrive.
+    pub fn next_item(&mut self) -> Result<Option<FlvItem>, NotFlv> {
+        let rest = &self.buf[self.pos..];
+        if !self.header_read {
+            // Enough bytes to tell, and they are not FLV
+            let prefix = rest.len().min(SIGNATURE.len());
+            if rest[..prefix] != SIGNATURE[..prefix] {
+                return Err(NotFlv);
+            }
+            let Some(len) = header_len(rest) else {
+                return if rest.len() >= 9 {
+                    Err(NotFlv)
+                } else {
+                    Ok(None)
+                };
+            };
+            if rest.len() < len {
+                return Ok(None);
+            }
+            self.header_read = true;
+            self.pos += len;
+            return Ok(Some(FlvItem::Header(rest[..len].to_vec())));
+        }
+        let Some((tag, used)) = read_tag(rest) else {
+            return Ok(None);
+        };
+        let item = FlvItem::Tag(RawTag {
+            kind: tag.kind,
+            timestamp: tag.timestamp,
+            bytes: rest[..used].to_vec(),
+        });
+        self.pos += used;
+        Ok(Some(item))
+    }
+}
+
+/// What an `onMetaData` tag says about the stream; encoders fill in
+/// different subsets.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metadata {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub framerate: Option<f64>,
+    pub video_codec: Option<String>,
+    pub audio_codec: Option<String>,
+    // Video plus audio
+    pub bitrate_kbps: Option<u32>,
+}
+
+fn codec_name(value: &Amf0Value, numbered: fn(u32) -> Option<&'static str>) -> Option<String> {
+    match value {
+        Amf0Value::Number(n) => numbered(*n as u32).map(str::to_string),
+        Amf0Value::String(s) => Some(
+            normalize_codec(s)
+                .map(str::to_string)
+                .unwrap_or_else(|| s.to_ascii_lowercase()),
+        ),
+        _ => None,
+    }
+}
+
+/// Metadata from a script tag body; None when it is not `onMetaData`.
+pub fn parse_metadata(data: &[u8]) -> Option<Metadata> {
+    let values = amf0::decode_all(data);
+    let mut values = values.iter();
+    if values.next()?.as_str()? != "onMetaData" {
+        return None;
+    }
+    let props = values.next()?;
+    let number = |key: &str| props.get(key).and_then(Amf0Value::as_f64);
+    let positive = |key: &str| number(key).filter(|n| n.is_finite() && *n > 0.0);
+
+    let bitrate = ["videodatarate", "audiodatarate"]
+        .iter()
+        .filter_map(|key| positive(key))
+        .sum::<f64>();
+    Some(Metadata {
+        width: positive("width").map(|n| n.round() as u32),
+        height: positive("height").map(|n| n.round() as u32),
+        framerate: positive("framerate")
+            .or_else(|| positive("videoframerate"))
+            .or_else(|| positive("fps")),
+        video_codec: props.get("videocodecid").and_then(|v| {
+            codec_name(v, |id| match id {
+                7 => Some("avc"),
+                12 => Some("hevc"),
+                _ => None,
+            })
+        }),
+        audio_codec: props.get("audiocodecid").and_then(|v| {
+            codec_name(v, |id| match id {
+                10 => Some("aac"),
+                2 => Some("mp3"),
+                _ => None,
+            })
+        }),
+        bitrate_kbps: (bitrate > 0.0).then(|| bitrate.round() as u32),
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn tag(kind: u8, timestamp: u32, data: &[u8]) -> Vec<u8> {
+        let mut out = vec![kind];
+        out.extend_from_slice(&(data.len() as u32).to_be_bytes()[1..]);
+        out.extend_from_slice(&timestamp.to_be_bytes()[1..]);
+        out.push((timestamp >> 24) as u8);
+        out.extend_from_slice(&[0, 0, 0]);
+        out.extend_from_slice(data);
+        out.extend_from_slice(&((TAG_HEADER_LEN + data.len()) as u32).to_be_bytes());
+        out
+    }
+
+    pub(crate) fn header() -> Vec<u8> {
+        let mut out = b"FLV\x01\x05\x00\x00\x00\x09".to_vec();
+        out.extend_from_slice(&[0, 0, 0, 0]);
+        out
+    }
+
+    fn amf_string(s: &str) -> Vec<u8> {
+        let mut out = (s.len() as u16).to_be_bytes().to_vec();
+        out.extend_from_slice(s.as_bytes());
+        out
+    }
+
+    pub(crate) fn on_metadata() -> Vec<u8> {
+        let mut out = vec![0x02];
+        out.extend(amf_string("onMetaData"));
+        out.push(0x08);
+        out.extend_from_slice(&4u32.to_be_bytes());
+        for (key, value) in [("width", 1920.0), ("height", 1080.0), ("framerate", 60.0)] {
+            out.extend(amf_string(key));
+            out.push(0x00);
+            out.extend_from_slice(&f64::to_be_bytes(value));
+        }
+        out.extend(amf_string("videodatarate"));
+        out.push(0x00);
+        out.extend_from_slice(&f64::to_be_bytes(7872.0));
+        out.extend(amf_string("audiodatarate"));
+        out.push(0x00);
+        out.extend_from_slice(&f64::to_be_bytes(128.0));
+        out.extend(amf_string("encoder"));
+        out.push(0x02);
+        out.extend(amf_string("obs-output module"));
+        out.extend(amf_string("videocodecid"));
+        out.push(0x02);
+        out.extend(amf_string("hvc1"));
+        out.extend(amf_string("audiocodecid"));
+        out.push(0x00);
+        out.extend_from_slice(&f64::to_be_bytes(10.0));
+        out.extend_from_slice(&[0, 0, 0x09]);
+        out
+    }
+
+    #[test]
+    fn reads_tags_and_metadata() {
+        let mut stream = header();
+        stream.extend(tag(18, 0, &on_metadata()));
+        stream.extend(tag(9, 0, &[0x1c, 0x00, 0, 0, 0]));
+        stream.extend(tag(8, 0x0100_0000, &[0xaf, 0x00, 0x12, 0x10]));
+        // A tag cut off mid-body is left for later
+        stream.extend(&tag(9, 40, &[0x2c, 0x01, 0, 0, 0, 1, 2, 3])[..14]);
+
+        let tags: Vec<_> = tags(&stream).collect();
+        assert_eq!(tags.len(), 3);
+        assert_eq!(tags[0].kind, TagKind::Script);
+        assert_eq!(video_codec(tags[1].data), Some("hevc"));
+        assert_eq!(tags[2].timestamp, 0x0100_0000);
+        assert_eq!(audio_codec(tags[2].data), Some("aac"));
+        assert_eq!(video_codec(b"\x90hvc1"), Some("hevc"));
+
+        assert_eq!(
+            parse_metadata(tags[0].data),
+            Some(Metadata {
+                width: Some(1920),
+                height: Some(1080),
+                framerate: Some(60.0),
+                video_codec: Some("hevc".to_string()),
+                audio_codec: Some("aac".to_string()),
+                bitrate_kbps: Some(8000),
+            })
+        );
+        assert_eq!(parse_metadata(&[0x02, 0, 3, b'f', b'o', b'o']), None);
+        assert_eq!(header_len(b"FLV\x01\x05\x00\x00\x00"), None);
+    }
+
+    #[test]
+    fn reads_tags_across_chunks() {
+        let mut stream = header();
+        stream.extend(tag(9, 0, &[0x17, 0x00, 0, 0, 0, 1]));
+        stream.extend(tag(9, 0, &[0x17, 0x01, 0, 0, 0]));
+        stream.extend(tag(8, 23, &[0xaf, 0x01, 0x21]));
+        stream.extend(tag(9, 40, &[0x27, 0x01, 0, 0, 0]));
+
+        let mut reader = TagReader::new();
+        let mut items = Vec::new();
+        for chunk in stream.chunks(7) {
+            reader.push(chunk);
+            while let Some(item) = reader.next_item().unwrap() {
+                items.push(item);
+            }
+        }
+        assert_eq!(items.len(), 5);
+        assert_eq!(items[0], FlvItem::Header(header()));
+        let tags: Vec<_> = items[1..]
+            .iter()
+            .map(|item| match item {
+                FlvItem::Tag(tag) => tag,
+                FlvItem::Header(_) => panic!("second header"),
+            })
+            .collect();
+        assert!(is_sequence_header(TagKind::Video, tags[0].data()));
+        assert!(!is_sequence_header(TagKind::Video, tags[1].data()));
+        assert!(is_keyframe(tags[1].data()));
+        assert!(!is_sequence_header(TagKind::Audio, tags[2].data()));
+        assert!(!is_keyframe(tags[3].data()));
+        assert_eq!(tags[2].timestamp, 23);
+        assert_eq!(
+            tags.iter().map(|t| t.bytes.len()).sum::<usize>() + 13,
+            stream.len()
+        );
+
+        let packet = video_packet(tags[1].data()).unwrap();
+        assert_eq!(
+            (packet.codec, packet.kind),
+            ("avc", VideoPacketKind::Frames)
+        );
+        let packet = video_packet(b"\x91hvc1\xff\xff\xfe\x00\x00\x00\x02\x26").unwrap();
+        assert_eq!(packet.composition_time, -2);
+        assert_eq!(packet.data, b"\x00\x00\x00\x02\x26");
+
+        let mut reader = TagReader::new();
+        reader.push(b"\x47\x40\x11");
+        assert_eq!(reader.next_item(), Err(NotFlv));
+    }
+}
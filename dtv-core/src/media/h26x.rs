@@ -0,0 +1,179 @@
+// H.264/H.265 as FLV carries it (a decoder configuration record, then
+// length-prefixed NAL units) turned into the Annex B byte stream MPEG-TS
+// wants, with parameter sets repeated before every keyframe.
+
+const START_CODE: [u8; 4] = [0, 0, 0, 1];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoCodec {
+    Avc,
+    Hevc,
+}
+
+impl VideoCodec {
+    /// From the names `flv::video_codec` reports.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "avc" => Some(VideoCodec::Avc),
+            "hevc" => Some(VideoCodec::Hevc),
+            _ => None,
+        }
+    }
+
+    fn nal_type(self, header: u8) -> u8 {
+        match self {
+            VideoCodec::Avc => header & 0x1f,
+            VideoCodec::Hevc => (header >> 1) & 0x3f,
+        }
+    }
+
+    // Access unit delimiter, which some HLS players look for
+    fn aud(self) -> &'static [u8] {
+        match self {
+            VideoCodec::Avc => &[0x09, 0xf0],
+            VideoCodec::Hevc => &[0x46, 0x01, 0x50],
+        }
+    }
+
+    fn is_aud(self, nal_type: u8) -> bool {
+        match self {
+            VideoCodec::Avc => nal_type == 9,
+            VideoCodec::Hevc => nal_type == 35,
+        }
+    }
+}
+
+/// What a decoder configuration record holds that the byte stream needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VideoConfig {
+    pub codec: VideoCodec,
+    // Bytes in each NAL unit's length prefix
+    pub length_size: usize,
+    // VPS (H.265), SPS and PPS, in order
+    pub parameter_sets: Vec<Vec<u8>>,
+}
+
+struct Cursor<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, n: usize) -> Option<&'a [u8]> {
+        let bytes = self.buf.get(..n)?;
+        self.buf = &self.buf[n..];
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn u16(&mut self) -> Option<usize> {
+        self.take(2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]) as usize)
+    }
+
+    fn nal(&mut self) -> Option<Vec<u8>> {
+        let len = self.u16()?;
+        self.take(len).map(<[u8]>::to_vec)
+    }
+}
+
+impl VideoConfig {
+    /// From an AVCDecoderConfigurationRecord or HEVCDecoderConfigurationRecord.
+    pub fn parse(codec: VideoCodec, record: &[u8]) -> Option<Self> {
+        let mut cur = Cursor { buf: record };
+        let mut parameter_sets = Vec::new();
+        let length_size = match codec {
+            VideoCodec::Avc => {
+                cur.take(4)?;
+                let length_size = (cur.u8()? & 0x03) as usize + 1;
+                for _ in 0..cur.u8()? & 0x1f {
+                    parameter_sets.push(cur.nal()?);
+                }
+                for _ in 0..cur.u8()? {
+                    parameter_sets.push(cur.nal()?);
+                }
+                length_size
+            }
+            VideoCodec::Hevc => {
+                cur.take(21)?;
+                let length_size = (cur.u8()? & 0x03) as usize + 1;
+                for _ in 0..cur.u8()? {
+                    cur.u8()?; // completeness flag and NAL unit type
+                    for _ in 0..cur.u16()? {
+                        parameter_sets.push(cur.nal()?);
+                    }
+                }
+                length_size
+            }
+        };
+        Some(Self {
+            codec,
+            length_size,
+            parameter_sets,
+        })
+    }
+
+    /// Annex B for one frame's NAL units; None if the lengths do not add up.
+    pub fn annex_b(&self, frames: &[u8], keyframe: bool) -> Option<Vec<u8>> {
+        let mut out = Vec::with_capacity(frames.len() + 64);
+        out.extend_from_slice(&START_CODE);
+        out.extend_from_slice(self.codec.aud());
+        if keyframe {
+            for nal in &self.parameter_sets {
+                out.extend_from_slice(&START_CODE);
+                out.extend_from_slice(nal);
+            }
+        }
+        let mut rest = frames;
+        while !rest.is_empty() {
+            let len = rest
+                .get(..self.length_size)?
+                .iter()
+                .fold(0usize, |len, b| len << 8 | *b as usize);
+            let nal = rest.get(self.length_size..self.length_size + len)?;
+            rest = &rest[self.length_size + len..];
+            if nal.is_empty() || self.codec.is_aud(self.codec.nal_type(nal[0])) {
+                continue;
+            }
+            out.extend_from_slice(&START_CODE);
+            out.extend_from_slice(nal);
+        }
+        Some(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_to_annex_b() {
+        // Baseline profile, 4-byte lengths, one SPS and one PPS
+        let record = [
+            0x01, 0x42, 0xc0, 0x1e, 0xff, 0xe1, 0x00, 0x03, 0x67, 0x42, 0xc0, 0x01, 0x00, 0x02,
+            0x68, 0xce,
+        ];
+        let config = VideoConfig::parse(VideoCodec::Avc, &record).unwrap();
+        assert_eq!(config.length_size, 4);
+        assert_eq!(
+            config.parameter_sets,
+            vec![vec![0x67, 0x42, 0xc0], vec![0x68, 0xce]]
+        );
+
+        // An AUD to drop, then an IDR slice
+        let frames = [0, 0, 0, 2, 0x09, 0xf0, 0, 0, 0, 3, 0x65, 0x88, 0x84];
+        assert_eq!(
+            config.annex_b(&frames, true).unwrap(),
+            [
+                &[0, 0, 0, 1, 0x09, 0xf0][..],
+                &[0, 0, 0, 1, 0x67, 0x42, 0xc0],
+                &[0, 0, 0, 1, 0x68, 0xce],
+                &[0, 0, 0, 1, 0x65, 0x88, 0x84],
+            ]
+            .concat()
+        );
+        assert_eq!(config.annex_b(&frames[..12], false), None);
+    }
+}
@@ -0,0 +1,235 @@
+// Cuts a live FLV stream into MPEG-TS segments for an HLS playlist. Each
+// segment starts at a keyframe (at any AAC frame for audio-only streams)
+// and opens with PAT/PMT and the parameter sets, so it decodes on its own.
+use super::aac::AdtsMuxer;
+use super::flv::{self, RawTag, TagKind, VideoPacketKind};
+use super::h26x::{VideoCodec, VideoConfig};
+use super::ts::{ticks, TsMuxer};
+
+/// A finished segment. `duration` is in seconds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub sequence: u64,
+    pub duration: f64,
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug)]
+struct OpenSegment {
+    start: u32,
+    last: u32,
+    data: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct HlsSegmenter {
+    // Milliseconds a segment runs before the next random access point ends it
+    target: u32,
+    expect_video: bool,
+    video: Option<VideoConfig>,
+    audio: AdtsMuxer,
+    muxer: TsMuxer,
+    open: Option<OpenSegment>,
+    sequence: u64,
+}
+
+impl HlsSegmenter {
+    pub fn new(target_ms: u32) -> Self {
+        Self {
+            target: target_ms,
+            expect_video: true,
+            video: None,
+            audio: AdtsMuxer::default(),
+            muxer: TsMuxer::new(None, false),
+            open: None,
+            sequence: 0,
+        }
+    }
+
+    /// The FLV file header. Its flags say whether to wait for video
+    /// keyframes or cut on audio alone.
+    pub fn push_header(&mut self, header: &[u8]) {
+        self.expect_video = header.get(4).is_none_or(|flags| flags & 0x01 != 0);
+    }
+
+    /// Feed one tag; returns the segment it closed, if any. Tags before the
+    /// first keyframe, and codecs TS cannot carry here, are dropped.
+    pub fn push(&mut self, tag: &RawTag) -> Option<Segment> {
+        match tag.kind {
+            TagKind::Video => self.push_video(tag.timestamp, tag.data()),
+            TagKind::Audio => self.push_audio(tag.timestamp, tag.data()),
+            _ => None,
+        }
+    }
+
+    fn push_video(&mut self, ts: u32, data: &[u8]) -> Option<Segment> {
+        let packet = flv::video_packet(data)?;
+        let codec = VideoCodec::from_name(packet.codec)?;
+        if packet.kind == VideoPacketKind::Config {
+            self.video = VideoConfig::parse(codec, packet.data);
+            return None;
+        }
+        let frame = self
+            .video
+            .as_ref()
+            .filter(|config| config.codec == codec)?
+            .annex_b(packet.data, packet.keyframe)?;
+        let done = if packet.keyframe { self.cut(ts) } else { None };
+        let open = self.open.as_mut()?;
+        open.last = ts;
+        let dts = ticks(ts as i64);
+        let pts = ticks(ts as i64 + packet.composition_time as i64);
+        self.muxer
+            .write_video(&mut open.data, pts, dts, packet.keyframe, &frame);
+        done
+    }
+
+    fn push_audio(&mut self, ts: u32, data: &[u8]) -> Option<Segment> {
+        let frame = self.audio.push(data)?;
+        let done = if self.expect_video {
+            None
+        } else {
+            self.cut(ts)
+        };
+        let open = self.open.as_mut()?;
+        open.last = ts;
+        self.muxer
+            .write_audio(&mut open.data, ticks(ts as i64), &frame);
+        done
+    }
+
+    // At a random access point: close the open segment if it has run long
+    // enough, and start the next one.
+    fn cut(&mut self, ts: u32) -> Option<Segment> {
+        if let Some(open) = &self.open {
+            // A timestamp going backwards always cuts
+            if ts
+                .checked_sub(open.start)
+                .is_some_and(|ms| ms < self.target)
+            {
+                return None;
+            }
+        }
+        let done = self.open.take().map(|open| {
+            let end = if ts >= open.start { ts } else { open.last };
+            self.sequence += 1;
+            Segment {
+                sequence: self.sequence - 1,
+                duration: end.saturating_sub(open.start) as f64 / 1000.0,
+                data: open.data,
+            }
+        });
+
+        // Tracks can appear between segments
+        let video = self.video.as_ref().map(|config| config.codec);
+        let audio = self.audio.config().is_some();
+        if self.muxer.video() != video || self.muxer.has_audio() != audio {
+            self.muxer = TsMuxer::new(video, audio);
+        }
+        let mut data = Vec::new();
+        self.muxer.write_tables(&mut data);
+        self.open = Some(OpenSegment {
+            start: ts,
+            last: ts,
+            data,
+        });
+        done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::flv::tests::tag;
+    use crate::media::ts::PACKET_LEN;
+
+    fn raw(kind: TagKind, timestamp: u32, data: &[u8]) -> RawTag {
+        let byte = match kind {
+            TagKind::Audio => 8,
+            _ => 9,
+        };
+        RawTag {
+            kind,
+            timestamp,
+            bytes: tag(byte, timestamp, data),
+        }
+    }
+
+    #[test]
+    fn cuts_at_keyframes() {
+        let mut segmenter = HlsSegmenter::new(2000);
+        let config = [
+            0x17, 0x00, 0, 0, 0, 0x01, 0x42, 0xc0, 0x1e, 0xff, 0xe1, 0x00, 0x03, 0x67, 0x42, 0xc0,
+            0x01, 0x00, 0x02, 0x68, 0xce,
+        ];
+        let keyframe = [0x17, 0x01, 0, 0, 0, 0, 0, 0, 2, 0x65, 0x88];
+        let inter = [0x27, 0x01, 0, 0, 40, 0, 0, 0, 2, 0x41, 0x9a];
+
+        assert_eq!(segmenter.push(&raw(TagKind::Video, 0, &config)), None);
+        assert_eq!(
+            segmenter.push(&raw(TagKind::Audio, 0, &[0xaf, 0x00, 0x12, 0x10])),
+            None
+        );
+        // Audio before the first keyframe has no segment to go in
+        assert_eq!(
+            segmenter.push(&raw(TagKind::Audio, 10, &[0xaf, 0x01, 0x21])),
+            None
+        );
+        assert_eq!(segmenter.push(&raw(TagKind::Video, 40, &keyframe)), None);
+        assert_eq!(segmenter.push(&raw(TagKind::Video, 1000, &inter)), None);
+        // Too soon to cut
+        assert_eq!(segmenter.push(&raw(TagKind::Video, 1040, &keyframe)), None);
+        assert_eq!(
+            segmenter.push(&raw(TagKind::Audio, 1050, &[0xaf, 0x01, 0x21])),
+            None
+        );
+
+        let segment = segmenter
+            .push(&raw(TagKind::Video, 2540, &keyframe))
+            .unwrap();
+        assert_eq!(segment.sequence, 0);
+        assert_eq!(segment.duration, 2.5);
+        // PAT, PMT, three video frames and an audio frame
+        assert_eq!(segment.data.len(), 6 * PACKET_LEN);
+        assert_eq!(
+            &segment.data[PACKET_LEN + 17..PACKET_LEN + 22],
+            &[0x1b, 0xe1, 0x00, 0xf0, 0x00]
+        );
+
+        // Audio-only streams cut on audio
+        let mut segmenter = HlsSegmenter::new(2000);
+        segmenter.push_header(b"FLV\x01\x04\x00\x00\x00\x09");
+        segmenter.push(&raw(TagKind::Audio, 0, &[0xaf, 0x00, 0x12, 0x10]));
+        assert_eq!(
+            segmenter.push(&raw(TagKind::Audio, 0, &[0xaf, 0x01, 0x21])),
+            None
+        );
+        let segment = segmenter
+            .push(&raw(TagKind::Audio, 2000, &[0xaf, 0x01, 0x21]))
+            .unwrap();
+        assert_eq!(segment.data.len(), 3 * PACKET_LEN);
+    }
+
+    #[test]
+    fn survives_a_timestamp_reset_after_early_audio() {
+        let mut segmenter = HlsSegmenter::new(2000);
+        let config = [
+            0x17, 0x00, 0, 0, 0, 0x01, 0x42, 0xc0, 0x1e, 0xff, 0xe1, 0x00, 0x03, 0x67, 0x42, 0xc0,
+            0x01, 0x00, 0x02, 0x68, 0xce,
+        ];
+        let keyframe = [0x17, 0x01, 0, 0, 0, 0, 0, 0, 2, 0x65, 0x88];
+
+        segmenter.push(&raw(TagKind::Video, 0, &config));
+        segmenter.push(&raw(TagKind::Audio, 0, &[0xaf, 0x00, 0x12, 0x10]));
+        assert_eq!(segmenter.push(&raw(TagKind::Video, 5000, &keyframe)), None);
+        // Interleaved audio stamped just before the keyframe that opened the segment
+        assert_eq!(
+            segmenter.push(&raw(TagKind::Audio, 4990, &[0xaf, 0x01, 0x21])),
+            None
+        );
+        // The upstream restarts its clock
+        let segment = segmenter.push(&raw(TagKind::Video, 0, &keyframe)).unwrap();
+        assert_eq!(segment.sequence, 0);
+        assert_eq!(segment.duration, 0.0);
+    }
+}
@@ -0,0 +1,12 @@
+//! Media formats as they come off the CDN: FLV framing and metadata, AAC
+//! audio, telling stream formats apart by their first bytes, and remuxing
+//! FLV into MPEG-TS segments for HLS.
+pub mod aac;
+pub mod amf0;
+pub mod flv;
+pub mod h26x;
+pub mod hls;
+pub mod sniff;
+pub mod ts;
+
+pub use sniff::{media_info, probe_stream, sniff_container, Container, MediaInfo};
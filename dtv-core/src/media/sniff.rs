@@ -0,0 +1,205 @@
+// Tells a stream's format from its first bytes rather than its URL, which for
+// many Douyu/Huya CDNs says nothing, and reads what the FLV metadata reports.
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::flv::{self, TagKind};
+use crate::platforms::common::errors::DtvError;
+use crate::platforms::common::{StreamHeaders, StreamType};
+
+// Room for the header, onMetaData and the first audio and video tags
+const PROBE_BYTES: usize = 256 * 1024;
+// Anything this long that matched no signature is not a stream we know
+const SNIFF_BYTES: usize = 512;
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+const TS_PACKET_LEN: usize = 188;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Container {
+    Flv,
+    Hls,
+    Mpegts,
+    Fmp4,
+}
+
+impl Container {
+    pub fn stream_type(self) -> StreamType {
+        match self {
+            Container::Flv => StreamType::Flv,
+            Container::Hls => StreamType::Hls,
+            Container::Mpegts | Container::Fmp4 => StreamType::Unknown,
+        }
+    }
+}
+
+/// What the first bytes of a stream showed. Only FLV says more than the
+/// container.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct MediaInfo {
+    pub container: Container,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub framerate: Option<f64>,
+    // "avc" / "hevc" / "av1", "aac" / "mp3"
+    pub video_codec: Option<String>,
+    pub audio_codec: Option<String>,
+    pub bitrate_kbps: Option<u32>,
+}
+
+impl MediaInfo {
+    fn new(container: Container) -> Self {
+        Self {
+            container,
+            width: None,
+            height: None,
+            framerate: None,
+            video_codec: None,
+            audio_codec: None,
+            bitrate_kbps: None,
+        }
+    }
+}
+
+/// The container `buf` starts with, if it is one we can play.
+pub fn sniff_container(buf: &[u8]) -> Option<Container> {
+    if buf.starts_with(flv::SIGNATURE) {
+        return Some(Container::Flv);
+    }
+    let text = buf.strip_prefix(b"\xef\xbb\xbf").unwrap_or(buf);
+    let start = text.iter().position(|b| !b.is_ascii_whitespace());
+    if start.is_some_and(|i| text[i..].starts_with(b"#EXTM3U")) {
+        return Some(Container::Hls);
+    }
+    // One sync byte could be anything; two a packet apart is TS
+    if buf.first() == Some(&0x47) && buf.get(TS_PACKET_LEN) == Some(&0x47) {
+        return Some(Container::Mpegts);
+    }
+    match buf.get(4..8) {
+        Some(b"ftyp" | b"styp" | b"moof") => Some(Container::Fmp4),
+        _ => None,
+    }
+}
+
+/// MediaInfo for a stream prefix, and whether more bytes could add to it.
+/// FLV fields fill in as tags arrive: onMetaData, then the first audio and
+/// video tags, whose codecs win over the metadata's.
+fn read_prefix(buf: &[u8]) -> Option<(MediaInfo, bool)> {
+    let mut info = MediaInfo::new(sniff_container(buf)?);
+    if info.container != Container::Flv {
+        return Some((info, true));
+    }
+    let mut meta = None;
+    let (mut video, mut audio) = (None, None);
+    for tag in flv::tags(buf) {
+        match tag.kind {
+            TagKind::Script if meta.is_none() => meta = flv::parse_metadata(tag.data),
+            TagKind::Video if video.is_none() => video = flv::video_codec(tag.data),
+            TagKind::Audio if audio.is_none() => audio = flv::audio_codec(tag.data),
+            _ => {}
+        }
+        if meta.is_some() && video.is_some() && audio.is_some() {
+            break;
+        }
+    }
+    let complete = meta.is_some() && video.is_some() && audio.is_some();
+    let meta = meta.unwrap_or_default();
+    info.width = meta.width;
+    info.height = meta.height;
+    info.framerate = meta.framerate;
+    info.bitrate_kbps = meta.bitrate_kbps;
+    info.video_codec = video.map(str::to_string).or(meta.video_codec);
+    info.audio_codec = audio.map(str::to_string).or(meta.audio_codec);
+    Some((info, complete))
+}
+
+/// MediaInfo for the first bytes of a stream.
+pub fn media_info(buf: &[u8]) -> Option<MediaInfo> {
+    read_prefix(buf).map(|(info, _)| info)
+}
+
+/// Read the start of `url` with the headers its CDN wants and report what
+/// it holds. Gives up after a few seconds with whatever arrived by then.
+pub async fn probe_stream(
+    client: &reqwest::Client,
+    url: &str,
+    headers: &StreamHeaders,
+) -> Result<MediaInfo, DtvError> {
+    let mut request = client.get(url);
+    for (name, value) in headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+
+    let mut buf = Vec::new();
+    let mut info = None;
+    let read = async {
+        let mut resp = request
+            .send()
+            .await
+            .map_err(|e| DtvError::network(e.to_string()))?;
+        if !resp.status().is_success() {
+            return Err(DtvError::api(format!(
+                "stream returned HTTP {}",
+                resp.status()
+            )));
+        }
+        while let Some(chunk) = resp
+            .chunk()
+            .await
+            .map_err(|e| DtvError::network(e.to_string()))?
+        {
+            buf.extend_from_slice(&chunk);
+            let prefix = read_prefix(&buf);
+            let done = match &prefix {
+                Some((_, complete)) => *complete,
+                None => buf.len() >= SNIFF_BYTES,
+            };
+            info = prefix.map(|(info, _)| info);
+            if done || buf.len() >= PROBE_BYTES {
+                break;
+            }
+        }
+        Ok(())
+    };
+    if let Ok(Err(e)) = tokio::time::timeout(PROBE_TIMEOUT, read).await {
+        return Err(e);
+    }
+    info.ok_or_else(|| DtvError::api("unrecognized stream format"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::media::flv::tests::{header, on_metadata, tag};
+
+    #[test]
+    fn sniffs_containers() {
+        let mut ts = vec![0u8; TS_PACKET_LEN + 1];
+        ts[0] = 0x47;
+        ts[TS_PACKET_LEN] = 0x47;
+        assert_eq!(sniff_container(&ts), Some(Container::Mpegts));
+        assert_eq!(sniff_container(&ts[..TS_PACKET_LEN]), None);
+        assert_eq!(
+            sniff_container(b"\xef\xbb\xbf\n#EXTM3U\n#EXT-X-VERSION:3"),
+            Some(Container::Hls)
+        );
+        assert_eq!(
+            sniff_container(b"\x00\x00\x00\x18ftypiso6"),
+            Some(Container::Fmp4)
+        );
+        assert_eq!(sniff_container(b"<html>403</html>"), None);
+
+        let mut flv = header();
+        assert_eq!(media_info(&flv), Some(MediaInfo::new(Container::Flv)));
+        flv.extend(tag(18, 0, &on_metadata()));
+        flv.extend(tag(9, 0, &[0x17, 0x00, 0, 0, 0]));
+        let info = media_info(&flv).unwrap();
+        // The video tag wins over the metadata's codec id
+        assert_eq!(info.video_codec.as_deref(), Some("avc"));
+        assert_eq!(info.audio_codec.as_deref(), Some("aac"));
+        assert_eq!((info.width, info.height), (Some(1920), Some(1080)));
+        assert_eq!(info.bitrate_kbps, Some(8000));
+        assert_eq!(info.container.stream_type(), StreamType::Flv);
+    }
+}
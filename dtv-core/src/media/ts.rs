@@ -0,0 +1,293 @@
+// MPEG-TS muxing for HLS segments: PAT/PMT at the start of each segment,
+// then one PES per video frame or AAC frame on fixed PIDs.
+use super::h26x::VideoCodec;
+
+pub const PACKET_LEN: usize = 188;
+const PAYLOAD_LEN: usize = PACKET_LEN - 4;
+const PMT_PID: u16 = 0x1000;
+const VIDEO_PID: u16 = 0x100;
+const AUDIO_PID: u16 = 0x101;
+// PTS/DTS are 33 bits of 90 kHz ticks
+const TIMESTAMP_MASK: u64 = (1 << 33) - 1;
+
+// CRC-32/MPEG-2, for PSI sections
+fn crc32(data: &[u8]) -> u32 {
+    data.iter().fold(0xffff_ffff, |mut crc, byte| {
+        crc ^= (*byte as u32) << 24;
+        for _ in 0..8 {
+            crc = if crc & 0x8000_0000 != 0 {
+                (crc << 1) ^ 0x04c1_1db7
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+fn timestamp(prefix: u8, ts: u64) -> [u8; 5] {
+    let ts = ts & TIMESTAMP_MASK;
+    [
+        (prefix << 4) | (((ts >> 30) as u8 & 0x07) << 1) | 1,
+        (ts >> 22) as u8,
+        (((ts >> 15) as u8 & 0x7f) << 1) | 1,
+        (ts >> 7) as u8,
+        ((ts as u8 & 0x7f) << 1) | 1,
+    ]
+}
+
+fn pcr(base: u64) -> [u8; 6] {
+    let base = base & TIMESTAMP_MASK;
+    [
+        (base >> 25) as u8,
+        (base >> 17) as u8,
+        (base >> 9) as u8,
+        (base >> 1) as u8,
+        ((base as u8 & 0x01) << 7) | 0x7e,
+        0x00,
+    ]
+}
+
+/// Milliseconds to 90 kHz ticks.
+pub fn ticks(ms: i64) -> u64 {
+    (ms * 90) as u64 & TIMESTAMP_MASK
+}
+
+/// Writes an H.264/H.265 track and an AAC (ADTS) track as MPEG-TS. Either
+/// may be absent.
+#[derive(Debug, Clone)]
+pub struct TsMuxer {
+    video: Option<VideoCodec>,
+    audio: bool,
+    // Continuity counters: PAT, PMT, video, audio
+    counters: [u8; 4],
+}
+
+impl TsMuxer {
+    pub fn new(video: Option<VideoCodec>, audio: bool) -> Self {
+        Self {
+            video,
+            audio,
+            counters: [0; 4],
+        }
+    }
+
+    pub fn video(&self) -> Option<VideoCodec> {
+        self.video
+    }
+
+    pub fn has_audio(&self) -> bool {
+        self.audio
+    }
+
+    fn counter(&mut self, pid: u16) -> u8 {
+        let slot = match pid {
+            0 => 0,
+            PMT_PID => 1,
+            VIDEO_PID => 2,
+            _ => 3,
+        };
+        let cc = self.counters[slot];
+        self.counters[slot] = (cc + 1) & 0x0f;
+        cc
+    }
+
+    fn pcr_pid(&self) -> u16 {
+        if self.video.is_some() {
+            VIDEO_PID
+        } else {
+            AUDIO_PID
+        }
+    }
+
+    fn write_section(&mut self, out: &mut Vec<u8>, pid: u16, section: &[u8]) {
+        let start = out.len();
+        let cc = self.counter(pid);
+        out.extend_from_slice(&[0x47, 0x40 | (pid >> 8) as u8, pid as u8, 0x10 | cc, 0x00]);
+        out.extend_from_slice(section);
+        out.extend_from_slice(&crc32(section).to_be_bytes());
+        out.resize(start + PACKET_LEN, 0xff);
+    }
+
+    /// PAT and PMT, so a segment can be decoded on its own.
+    pub fn write_tables(&mut self, out: &mut Vec<u8>) {
+        let pat = [
+            0x00,
+            0xb0,
+            13,
+            0x00,
+            0x01, // transport_stream_id
+            0xc1, // version 0, current
+            0x00,
+            0x00,
+            0x00,
+            0x01, // program 1
+            0xe0 | (PMT_PID >> 8) as u8,
+            PMT_PID as u8,
+        ];
+        self.write_section(out, 0, &pat);
+
+        let mut streams = Vec::new();
+        if let Some(codec) = self.video {
+            let stream_type = match codec {
+                VideoCodec::Avc => 0x1b,
+                VideoCodec::Hevc => 0x24,
+            };
+            streams.extend_from_slice(&[
+                stream_type,
+                0xe0 | (VIDEO_PID >> 8) as u8,
+                VIDEO_PID as u8,
+                0xf0,
+                0x00,
+            ]);
+        }
+        if self.audio {
+            streams.extend_from_slice(&[
+                0x0f,
+                0xe0 | (AUDIO_PID >> 8) as u8,
+                AUDIO_PID as u8,
+                0xf0,
+                0x00,
+            ]);
+        }
+        let pcr_pid = self.pcr_pid();
+        let mut pmt = vec![
+            0x02,
+            0xb0,
+            (9 + streams.len() + 4) as u8,
+            0x00,
+            0x01, // program 1
+            0xc1,
+            0x00,
+            0x00,
+            0xe0 | (pcr_pid >> 8) as u8,
+            pcr_pid as u8,
+            0xf0,
+            0x00, // no program descriptors
+        ];
+        pmt.extend_from_slice(&streams);
+        self.write_section(out, PMT_PID, &pmt);
+    }
+
+    /// One video frame in Annex B. Times in 90 kHz ticks.
+    pub fn write_video(
+        &mut self,
+        out: &mut Vec<u8>,
+        pts: u64,
+        dts: u64,
+        keyframe: bool,
+        frame: &[u8],
+    ) {
+        if self.video.is_none() {
+            return;
+        }
+        let mut pes = vec![0x00, 0x00, 0x01, 0xe0, 0x00, 0x00, 0x80, 0xc0, 10];
+        pes.extend_from_slice(&timestamp(0x3, pts));
+        pes.extend_from_slice(&timestamp(0x1, dts));
+        pes.extend_from_slice(frame);
+        self.write_pes(out, VIDEO_PID, &pes, Some(dts), keyframe);
+    }
+
+    /// One ADTS frame.
+    pub fn write_audio(&mut self, out: &mut Vec<u8>, pts: u64, frame: &[u8]) {
+        if !self.audio {
+            return;
+        }
+        let mut pes = vec![0x00, 0x00, 0x01, 0xc0, 0x00, 0x00, 0x80, 0x80, 5];
+        pes.extend_from_slice(&timestamp(0x2, pts));
+        pes.extend_from_slice(frame);
+        // Video PES may leave the length 0; audio should not
+        let len = pes.len() - 6;
+        if len <= u16::MAX as usize {
+            pes[4..6].copy_from_slice(&(len as u16).to_be_bytes());
+        }
+        let pcr = (self.video.is_none()).then_some(pts);
+        self.write_pes(out, AUDIO_PID, &pes, pcr, pcr.is_some());
+    }
+
+    fn write_pes(
+        &mut self,
+        out: &mut Vec<u8>,
+        pid: u16,
+        pes: &[u8],
+        pcr_base: Option<u64>,
+        random_access: bool,
+    ) {
+        let mut rest = pes;
+        let mut first = true;
+        while !rest.is_empty() {
+            // Adaptation field, its length byte included
+            let mut adaptation = Vec::new();
+            if first && (pcr_base.is_some() || random_access) {
+                let flags = if random_access { 0x40 } else { 0 }
+                    | if pcr_base.is_some() { 0x10 } else { 0 };
+                adaptation.extend_from_slice(&[0, flags]);
+                if let Some(base) = pcr_base {
+                    adaptation.extend_from_slice(&pcr(base));
+                }
+            }
+            let room = PAYLOAD_LEN - adaptation.len();
+            if rest.len() < room {
+                // Pad the last packet through the adaptation field
+                let stuffing = room - rest.len();
+                match (adaptation.is_empty(), stuffing) {
+                    (true, 1) => adaptation.push(0),
+                    (true, _) => {
+                        adaptation.extend_from_slice(&[0, 0x00]);
+                        adaptation.resize(stuffing, 0xff);
+                    }
+                    (false, _) => adaptation.resize(adaptation.len() + stuffing, 0xff),
+                }
+            }
+            if !adaptation.is_empty() {
+                adaptation[0] = (adaptation.len() - 1) as u8;
+            }
+
+            let cc = self.counter(pid);
+            let control = if adaptation.is_empty() { 0x10 } else { 0x30 };
+            let start = if first { 0x40 } else { 0x00 };
+            out.extend_from_slice(&[0x47, start | (pid >> 8) as u8, pid as u8, control | cc]);
+            out.extend_from_slice(&adaptation);
+            let take = PAYLOAD_LEN - adaptation.len();
+            out.extend_from_slice(&rest[..take]);
+            rest = &rest[take..];
+            first = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn muxes_packets() {
+        assert_eq!(crc32(b"123456789"), 0x0376_e6e7);
+
+        let mut muxer = TsMuxer::new(Some(VideoCodec::Avc), true);
+        let mut out = Vec::new();
+        muxer.write_tables(&mut out);
+        muxer.write_video(&mut out, ticks(1040), ticks(1000), true, &[0xab; 400]);
+        muxer.write_audio(&mut out, ticks(1000), &[0xcd; 182]);
+        // PAT, PMT, three video packets, two audio packets
+        assert_eq!(out.len(), 7 * PACKET_LEN);
+        let packets: Vec<&[u8]> = out.chunks(PACKET_LEN).collect();
+        assert!(packets.iter().all(|p| p[0] == 0x47));
+
+        // The first video packet starts the PES, flags a keyframe and carries the PCR
+        let video = packets[2];
+        assert_eq!(&video[1..4], &[0x41, 0x00, 0x30]);
+        assert_eq!(&video[4..6], &[7, 0x50]);
+        assert_eq!(&video[12..16], &[0x00, 0x00, 0x01, 0xe0]);
+        // Continuations, then the stuffed last one
+        assert_eq!(&packets[3][1..4], &[0x01, 0x00, 0x11]);
+        assert_eq!(packets[4][3], 0x32);
+        assert_eq!(*packets[4].last().unwrap(), 0xab);
+
+        // 182 bytes of ADTS plus 14 of PES header: one full packet, then 12 bytes
+        let audio = packets[5];
+        assert_eq!(&audio[1..4], &[0x41, 0x01, 0x10]);
+        assert_eq!(&audio[8..10], &[0, 190]);
+        assert_eq!(packets[6][4] as usize, PAYLOAD_LEN - 12 - 1);
+    }
+}
@@ -80,22 +80,25 @@ fn _encode_wbi(
     query + &format!("&w_rid={}", web_sign)
 }
 
-fn get_wbi_keys(headers: HeaderMap) -> Result<(String, String), reqwest::Error> {
-    let client = reqwest::blocking::Client::builder()
+async fn get_wbi_keys(headers: HeaderMap) -> Result<(String, String), reqwest::Error> {
+    let client = reqwest::Client::builder()
         .https_only(true)
         .no_proxy()
-        .build()
-        .unwrap();
+        .build()?;
 
     let mut request_headers = headers;
     request_headers.insert("user-agent", USER_AGENT.parse().unwrap());
 
-    let response = client.get(UID_INIT_URL).headers(request_headers).send()?;
+    let response = client
+        .get(UID_INIT_URL)
+        .headers(request_headers)
+        .send()
+        .await?;
 
-    let res_wbi: ResWbi = response.json()?;
+    let res_wbi: ResWbi = response.json().await?;
     Ok((
-        take_filename(res_wbi.data.wbi_img.img_url).unwrap(),
-        take_filename(res_wbi.data.wbi_img.sub_url).unwrap(),
+        take_filename(res_wbi.data.wbi_img.img_url).unwrap_or_default(),
+        take_filename(res_wbi.data.wbi_img.sub_url).unwrap_or_default(),
     ))
 }
 
@@ -112,54 +115,54 @@ pub const USER_AGENT: &str =
     "Mozilla/5.0 (X11; Linux x86_64; rv:138.0) Gecko/20100101 Firefox/138.0";
 
 /// Get UID using cookie (optional). If request fails or no cookie, returns (status, body).
-pub fn init_uid(headers: HeaderMap) -> (reqwest::StatusCode, String) {
-    let client = reqwest::blocking::Client::builder()
-        .https_only(true)
-        .no_proxy()
-        .build()
-        .unwrap();
+pub async fn init_uid(headers: HeaderMap) -> (reqwest::StatusCode, String) {
+    let client = match reqwest::Client::builder().https_only(true).no_proxy().build() {
+        Ok(c) => c,
+        Err(e) => {
+            return (
+                reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+                format!("{{\"error\":\"{}\"}}", e),
+            )
+        }
+    };
 
     let mut request_headers = headers;
     request_headers.insert("user-agent", USER_AGENT.parse().unwrap());
 
-    let response = client.get(UID_INIT_URL).headers(request_headers).send();
-    let stat: reqwest::StatusCode;
-    let body: String;
-    match response {
+    match client.get(UID_INIT_URL).headers(request_headers).send().await {
         Ok(resp) => {
-            let s: reqwest::StatusCode = resp.status();
-            stat = s;
-            match resp.text() {
-                Ok(b) => body = b,
-                Err(e) => body = format!("{{\"error\":\"{}\"}}", e),
-            }
-        }
-        Err(err) => {
-            stat = reqwest::StatusCode::INTERNAL_SERVER_ERROR;
-            body = format!("{{\"error\":\"{}\"}}", err);
+            let stat = resp.status();
+            let body = match resp.text().await {
+                Ok(b) => b,
+                Err(e) => format!("{{\"error\":\"{}\"}}", e),
+            };
+            (stat, body)
         }
+        Err(err) => (
+            reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+            format!("{{\"error\":\"{}\"}}", err),
+        ),
     }
-    (stat, body)
 }
 
 /// Query danmaku server host list and token via signed URL, with given headers
-pub fn init_host_server(headers: HeaderMap, room_id: u64) -> (reqwest::StatusCode, String) {
-    let client = reqwest::blocking::Client::builder()
+pub async fn init_host_server(
+    headers: HeaderMap,
+    room_id: u64,
+) -> Result<(reqwest::StatusCode, String), String> {
+    let client = reqwest::Client::builder()
         .https_only(true)
         .no_proxy()
         .build()
-        .unwrap();
+        .map_err(|e| format!("Failed to build client: {}", e))?;
 
     let mut request_headers = headers.clone();
     request_headers.insert("user-agent", USER_AGENT.parse().unwrap());
 
-    let wbi_keys = match get_wbi_keys(request_headers.clone()) {
-        Ok(keys) => keys,
-        Err(e) => {
-            log::error!("Failed to get WBI keys: {:?}", e);
-            panic!("Failed to get WBI keys");
-        }
-    };
+    let wbi_keys = get_wbi_keys(request_headers.clone()).await.map_err(|e| {
+        log::error!("Failed to get WBI keys: {:?}", e);
+        format!("Failed to get WBI keys: {}", e)
+    })?;
 
     let params = vec![
         ("id", room_id.to_string()),
@@ -170,34 +173,46 @@ pub fn init_host_server(headers: HeaderMap, room_id: u64) -> (reqwest::StatusCod
     let signed_query = encode_wbi(params, wbi_keys);
     let url = format!("{}?{}", DANMAKU_SERVER_CONF_URL, signed_query);
 
-    let response = client.get(url).headers(request_headers).send();
-    let stat: reqwest::StatusCode;
-    let body: String;
-    match response {
-        Ok(resp) => {
-            let s: reqwest::StatusCode = resp.status();
-            stat = s;
-            body = resp.text().unwrap_or_default();
-        }
-        Err(_) => {
-            stat = reqwest::StatusCode::INTERNAL_SERVER_ERROR;
-            body = String::new();
-        }
-    }
-    (stat, body)
+    let resp = client
+        .get(url)
+        .headers(request_headers)
+        .send()
+        .await
+        .map_err(|e| format!("getDanmuInfo request failed: {}", e))?;
+    let stat = resp.status();
+    let body = resp.text().await.unwrap_or_default();
+    Ok((stat, body))
 }
 
 use super::models::AuthMessage;
 use serde_json::Value;
 use std::collections::HashMap;
 
+/// Pull `data` and the auth token out of a getDanmuInfo response body.
+fn parse_danmu_info(body: &str) -> Result<(Value, String), String> {
+    let res: Value =
+        serde_json::from_str(body).map_err(|e| format!("Invalid getDanmuInfo body: {}", e))?;
+    let token = res["data"]["token"].as_str().ok_or_else(|| {
+        format!(
+            "getDanmuInfo returned no token (code={}, message={})",
+            res["code"],
+            res["message"].as_str().unwrap_or("")
+        )
+    })?;
+    Ok((res["data"].clone(), token.to_string()))
+}
+
 /// Initialize server info and auth message using cookie
-pub fn init_server_with_cookie(cookies: &str, room_id: &str) -> (Value, AuthMessage) {
+pub async fn init_server_with_cookie(
+    cookies: &str,
+    room_id: &str,
+) -> Result<(Value, AuthMessage), String> {
     let mut auth_map = HashMap::new();
     let mut headers = reqwest::header::HeaderMap::new();
     headers.insert(
         reqwest::header::COOKIE,
-        reqwest::header::HeaderValue::from_str(cookies).unwrap(),
+        reqwest::header::HeaderValue::from_str(cookies)
+            .map_err(|e| format!("Invalid Cookie: {}", e))?,
     );
     headers.insert(
         reqwest::header::USER_AGENT,
@@ -205,7 +220,7 @@ pub fn init_server_with_cookie(cookies: &str, room_id: &str) -> (Value, AuthMess
     );
 
     // Try get uid via cookie (optional)
-    let (_, body1) = init_uid(headers.clone());
+    let (_, body1) = init_uid(headers.clone()).await;
     let body1_v: Value = serde_json::from_str(body1.as_str()).unwrap_or(Value::Null);
     if let Some(mid) = body1_v["data"]["mid"].as_i64() {
         auth_map.insert("uid".to_string(), mid.to_string());
@@ -215,19 +230,19 @@ pub fn init_server_with_cookie(cookies: &str, room_id: &str) -> (Value, AuthMess
 
     auth_map.insert("room_id".to_string(), room_id.to_string());
 
-    let room_id_num = room_id.parse::<u64>().expect("room_id must be a valid u64");
-    let (_, body4) = init_host_server(headers.clone(), room_id_num);
-    let body4_res: Value = serde_json::from_str(body4.as_str()).unwrap();
-    let server_info = &body4_res["data"];
-    let token = &body4_res["data"]["token"].as_str().unwrap();
-    auth_map.insert("token".to_string(), token.to_string());
+    let room_id_num = room_id
+        .parse::<u64>()
+        .map_err(|_| format!("room_id must be a valid u64: {}", room_id))?;
+    let (_, body4) = init_host_server(headers.clone(), room_id_num).await?;
+    let (server_info, token) = parse_danmu_info(&body4)?;
+    auth_map.insert("token".to_string(), token);
 
     let auth_msg = AuthMessage::from(&auth_map);
-    (server_info.clone(), auth_msg)
+    Ok((server_info, auth_msg))
 }
 
 /// Initialize server info and auth message without cookie (uid=0)
-pub fn init_server_no_cookie(room_id: &str) -> (Value, AuthMessage) {
+pub async fn init_server_no_cookie(room_id: &str) -> Result<(Value, AuthMessage), String> {
     let mut auth_map = HashMap::new();
     let mut headers = reqwest::header::HeaderMap::new();
     headers.insert(
@@ -238,13 +253,13 @@ pub fn init_server_no_cookie(room_id: &str) -> (Value, AuthMessage) {
     auth_map.insert("uid".to_string(), "0".to_string());
     auth_map.insert("room_id".to_string(), room_id.to_string());
 
-    let room_id_num = room_id.parse::<u64>().expect("room_id must be a valid u64");
-    let (_, body4) = init_host_server(headers.clone(), room_id_num);
-    let body4_res: Value = serde_json::from_str(body4.as_str()).unwrap();
-    let server_info = &body4_res["data"];
-    let token = &body4_res["data"]["token"].as_str().unwrap();
-    auth_map.insert("token".to_string(), token.to_string());
+    let room_id_num = room_id
+        .parse::<u64>()
+        .map_err(|_| format!("room_id must be a valid u64: {}", room_id))?;
+    let (_, body4) = init_host_server(headers.clone(), room_id_num).await?;
+    let (server_info, token) = parse_danmu_info(&body4)?;
+    auth_map.insert("token".to_string(), token);
 
     let auth_msg = AuthMessage::from(&auth_map);
-    (server_info.clone(), auth_msg)
+    Ok((server_info, auth_msg))
 }
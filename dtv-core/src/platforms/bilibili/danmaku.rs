@@ -1,59 +1,119 @@
-use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::sync::mpsc as tokio_mpsc;
+use tokio::time::{sleep, Duration};
 
 use crate::danmaku::DanmakuHandler;
 use crate::platforms::bilibili::models::BiliMessage;
-use crate::platforms::bilibili::websocket::BiliLiveClient;
+use crate::platforms::bilibili::websocket::{BiliLiveClient, ConnectionOutcome};
 use crate::platforms::common::DanmakuFrontendPayload;
 
+fn emit_bili_message(handler: &Arc<dyn DanmakuHandler>, room_id: &str, msg: BiliMessage) {
+    if let BiliMessage::Unsupported { .. } = msg {
+        return;
+    }
+
+    // Typed event for overlays/stat panels, same channel name as Douyu's per-room events
+    // LiveStart/LiveEnd carry their own numeric room_id; keep it as is
+    if let Ok(mut v) = serde_json::to_value(&msg) {
+        if let Some(obj) = v.as_object_mut() {
+            obj.entry("room_id")
+                .or_insert_with(|| serde_json::Value::String(room_id.to_string()));
+        }
+        handler.emit_json(&format!("danmaku-{}", room_id), v);
+    }
+
+    // Chat-list entries for the cross-platform DanmuList
+    let chat = match msg {
+        BiliMessage::Danmu {
+            user,
+            text,
+            user_level,
+            medal,
+            ..
+        } => Some((user, text, user_level, medal.map(|m| m.level).unwrap_or(0))),
+        BiliMessage::Gift {
+            user, gift, num, ..
+        } => Some((user, format!("[礼物] {} x{}", gift, num), 0, 0)),
+        BiliMessage::SuperChat {
+            user,
+            message,
+            price,
+            ..
+        } => Some((user, format!("[醒目留言 ¥{}] {}", price, message), 0, 0)),
+        BiliMessage::GuardBuy {
+            user,
+            gift_name,
+            num,
+            ..
+        } => Some((user, format!("[上舰] {} x{}", gift_name, num), 0, 0)),
+        _ => None,
+    };
+    if let Some((user, content, user_level, fans_club_level)) = chat {
+        handler.on_danmaku(DanmakuFrontendPayload {
+            room_id: room_id.to_string(),
+            user,
+            content,
+            user_level,
+            fans_club_level: fans_club_level as i32,
+        });
+    }
+}
+
 pub async fn run_bilibili_danmaku_listener(
     room_id: String,
     cookie: Option<String>,
     mut rx_shutdown: tokio_mpsc::Receiver<()>,
     handler: Arc<dyn DanmakuHandler>,
 ) {
-    // Use atomic flag to signal std::thread to stop
-    let stop_flag = Arc::new(AtomicBool::new(false));
-    let stop_flag_for_thread = stop_flag.clone();
-    let handler_for_thread = handler.clone();
-    let room_id_for_thread = room_id.clone();
-
-    std::thread::spawn(move || {
-        let mut client = match cookie.as_deref() {
-            Some(c) => BiliLiveClient::new_with_cookie(c, room_id_for_thread.as_str()),
-            None => BiliLiveClient::new_without_cookie(room_id_for_thread.as_str()),
-        };
-        client.send_auth();
-
-        while !stop_flag_for_thread.load(Ordering::Relaxed) {
-            if let Some(msg) = client.read_once() {
-                match msg {
-                    BiliMessage::Danmu { user, text } => {
-                        handler_for_thread.on_danmaku(DanmakuFrontendPayload {
-                            room_id: room_id_for_thread.clone(),
-                            user,
-                            content: text,
-                            user_level: 0,
-                            fans_club_level: 0,
-                        });
-                    }
-                    BiliMessage::Gift { user, gift } => {
-                        handler_for_thread.on_danmaku(DanmakuFrontendPayload {
-                            room_id: room_id_for_thread.clone(),
-                            user,
-                            content: format!("[礼物] {}", gift),
-                            user_level: 0,
-                            fans_club_level: 0,
-                        });
-                    }
-                    BiliMessage::Unsupported { .. } => {}
+    let mut client: Option<BiliLiveClient> = None;
+    let mut backoff_secs = 1u64;
+
+    loop {
+        if client.is_none() {
+            tokio::select! {
+                res = BiliLiveClient::new(&room_id, cookie.as_deref()) => match res {
+                    Ok(c) => client = Some(c),
+                    Err(e) => eprintln!("[Bilibili Danmaku {}] Failed to fetch danmaku server info: {}", room_id, e),
+                },
+                _ = rx_shutdown.recv() => break,
+            }
+        }
+
+        if let Some(c) = client.as_mut() {
+            let outcome = c
+                .run(&mut rx_shutdown, |msg| emit_bili_message(&handler, &room_id, msg))
+                .await;
+            match outcome {
+                Ok(ConnectionOutcome::Stop) => {
+                    eprintln!("[Bilibili Danmaku {}] Listener stopped.", room_id);
+                    break;
+                }
+                Ok(ConnectionOutcome::Disconnected) => {
+                    // The link was up, so the next server is worth trying right away.
+                    backoff_secs = 1;
+                    eprintln!(
+                        "[Bilibili Danmaku {}] Disconnected, retrying in {}s.",
+                        room_id, backoff_secs
+                    );
+                }
+                Err(e) => {
+                    // Every server failed, auth was rejected or the link went silent: refresh the host list and token.
+                    client = None;
+                    eprintln!(
+                        "[Bilibili Danmaku {}] Connection error: {}. Retrying in {}s.",
+                        room_id, e, backoff_secs
+                    );
                 }
             }
-            std::thread::sleep(std::time::Duration::from_millis(50));
         }
-    });
 
-    let _ = rx_shutdown.recv().await;
-    stop_flag.store(true, Ordering::Relaxed);
+        let sleep_fut = sleep(Duration::from_secs(backoff_secs));
+        tokio::select! {
+            _ = sleep_fut => {}
+            _ = rx_shutdown.recv() => break,
+        }
+        if client.is_none() {
+            backoff_secs = (backoff_secs * 2).min(30);
+        }
+    }
 }
@@ -1,8 +1,10 @@
-use crate::platforms::bilibili::state::{refresh_w_webid, BilibiliState};
+use crate::platforms::bilibili::state::{refresh_w_webid, BilibiliState, LIVE_UA};
 use crate::platforms::common::signing::hash::md5_hex;
 use crate::platforms::common::signing::query::join_kv_pairs;
 
 pub async fn fetch_bilibili_live_list(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
     area_id: String,
     parent_area_id: String,
     page: u32,
@@ -11,7 +13,7 @@ pub async fn fetch_bilibili_live_list(
     use std::time::{SystemTime, UNIX_EPOCH};
 
     // 每次请求前都刷新一次 w_webid，避免使用过期的 ID
-    let w_webid = match refresh_w_webid(state).await {
+    let w_webid = match refresh_w_webid(client, user_agent, state).await {
         Ok(id) => {
             println!("[Bilibili] Refreshed w_webid: {}", id);
             id
@@ -59,7 +61,7 @@ pub async fn fetch_bilibili_live_list(
         pairs.into_iter().map(|(k, v)| (k.to_string(), v)).collect();
     params.push(("w_rid".to_string(), w_rid));
 
-    let ua = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/135.0.0.0 Safari/537.36";
+    let ua = user_agent.unwrap_or(LIVE_UA);
     let url = "https://api.live.bilibili.com/xlive/web-interface/v1/second/getList";
     let query_str = join_kv_pairs(params.iter().map(|(k, v)| (k.as_str(), v.as_str())));
     let full_url = format!("{}?{}", url, query_str);
@@ -71,14 +73,9 @@ pub async fn fetch_bilibili_live_list(
         ua, "https://www.bilibili.com/", "buvid3=i;"
     );
 
-    let client = reqwest::Client::builder()
-        .user_agent(ua)
-        .no_proxy()
-        .build()
-        .map_err(|e| format!("Failed to build client: {}", e))?;
-
     let resp = client
         .get(url)
+        .header("User-Agent", ua)
         .header("Referer", "https://www.bilibili.com/")
         .header("Cookie", "buvid3=i;")
         .query(&params)
@@ -22,6 +22,12 @@ impl Default for DanmuServer {
     }
 }
 
+impl DanmuServer {
+    pub fn wss_url(&self) -> String {
+        format!("wss://{}:{}/sub", self.host, self.wss_port)
+    }
+}
+
 #[derive(Copy, Clone, Debug)]
 pub struct MsgHead {
     pub pack_len: u32,
@@ -37,6 +43,7 @@ pub struct AuthMessage {
     pub roomid: u64,
     pub protover: i32,
     pub platform: String,
+    #[serde(rename = "type")]
     pub type_: i32,
     pub key: String,
 }
@@ -55,8 +62,111 @@ impl AuthMessage {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FanMedal {
+    pub name: String,
+    pub level: i64,
+    pub anchor_name: String,
+    pub anchor_room_id: i64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InteractKind {
+    Enter,
+    Follow,
+    Share,
+    SpecialFollow,
+    MutualFollow,
+    Other,
+}
+
+impl InteractKind {
+    pub fn from_msg_type(msg_type: i64) -> Self {
+        match msg_type {
+            1 => InteractKind::Enter,
+            2 => InteractKind::Follow,
+            3 => InteractKind::Share,
+            4 => InteractKind::SpecialFollow,
+            5 => InteractKind::MutualFollow,
+            _ => InteractKind::Other,
+        }
+    }
+}
+
+// guard_level: 0 = none, 1 = 总督, 2 = 提督, 3 = 舰长
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum BiliMessage {
-    Danmu { user: String, text: String },
-    Gift { user: String, gift: String },
-    Unsupported { cmd: String },
+    Danmu {
+        uid: i64,
+        user: String,
+        text: String,
+        user_level: i64,
+        medal: Option<FanMedal>,
+        guard_level: i64,
+        color: u32,
+        // Image URL when the danmu is a sticker/emoticon
+        emoticon: Option<String>,
+    },
+    Gift {
+        uid: i64,
+        user: String,
+        gift: String,
+        gift_id: i64,
+        num: i64,
+        // Unit price in the gift's coin type (gold is 1/1000 CNY)
+        price: i64,
+        coin_type: String,
+        action: String,
+    },
+    SuperChat {
+        uid: i64,
+        user: String,
+        message: String,
+        // CNY
+        price: i64,
+        // Seconds the SC stays pinned
+        duration: i64,
+    },
+    GuardBuy {
+        uid: i64,
+        user: String,
+        guard_level: i64,
+        num: i64,
+        price: i64,
+        gift_name: String,
+    },
+    Interact {
+        uid: i64,
+        user: String,
+        kind: InteractKind,
+    },
+    LikeCount {
+        count: i64,
+    },
+    WatchedChange {
+        num: i64,
+        text: String,
+    },
+    OnlineRankCount {
+        count: i64,
+    },
+    LiveStart {
+        room_id: i64,
+    },
+    LiveEnd {
+        room_id: i64,
+    },
+    RoomChange {
+        title: String,
+        area_name: String,
+        parent_area_name: String,
+    },
+    // op=3 heartbeat reply
+    Popularity {
+        value: u32,
+    },
+    Unsupported {
+        cmd: String,
+    },
 }
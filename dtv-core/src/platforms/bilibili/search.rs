@@ -90,7 +90,11 @@ fn build_cookie_header(cookies: &[(String, String)]) -> String {
         .join("; ")
 }
 
-async fn ensure_buvid(client: &reqwest::Client, cookie_header: &mut String) -> Result<(), String> {
+async fn ensure_buvid(
+    client: &reqwest::Client,
+    user_agent: &str,
+    cookie_header: &mut String,
+) -> Result<(), String> {
     let mut cookies = parse_cookie_pairs(cookie_header);
     let has_buvid3 = find_cookie(&cookies, "buvid3").is_some();
     let has_buvid4 = find_cookie(&cookies, "buvid4").is_some();
@@ -101,7 +105,7 @@ async fn ensure_buvid(client: &reqwest::Client, cookie_header: &mut String) -> R
 
     let mut request = client
         .get(FINGERPRINT_ENDPOINT)
-        .header(USER_AGENT, DEFAULT_UA)
+        .header(USER_AGENT, user_agent)
         .header(REFERER, LIVE_REFERER);
 
     if !cookie_header.trim().is_empty() {
@@ -138,6 +142,8 @@ async fn ensure_buvid(client: &reqwest::Client, cookie_header: &mut String) -> R
 }
 
 pub async fn search_bilibili_rooms(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
     keyword: String,
     page: Option<u32>,
     cookie: Option<String>,
@@ -148,17 +154,13 @@ pub async fn search_bilibili_rooms(
     }
 
     let mut cookie_header = cookie.unwrap_or_default();
+    let user_agent = user_agent.unwrap_or(DEFAULT_UA);
 
-    let client = reqwest::Client::builder()
-        .no_proxy()
-        .build()
-        .map_err(|e| format!("Failed to build client: {}", e))?;
-
-    let _ = ensure_buvid(&client, &mut cookie_header).await;
+    let _ = ensure_buvid(client, user_agent, &mut cookie_header).await;
 
     let mut req = client
         .get(SEARCH_ENDPOINT)
-        .header(USER_AGENT, DEFAULT_UA)
+        .header(USER_AGENT, user_agent)
         .header(REFERER, LIVE_REFERER)
         .query(&[
             ("context", ""),
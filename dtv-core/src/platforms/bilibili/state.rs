@@ -35,8 +35,15 @@ impl BilibiliState {
     }
 }
 
-pub async fn refresh_w_webid(state: &BilibiliState) -> Result<String, String> {
-    let ua = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/135.0.0.0 Safari/537.36";
+/// UA for the live-centre pages; w_webid is bound to it, so the list request must match.
+pub(crate) const LIVE_UA: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/135.0.0.0 Safari/537.36";
+
+pub async fn refresh_w_webid(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
+    state: &BilibiliState,
+) -> Result<String, String> {
+    let ua = user_agent.unwrap_or(LIVE_UA);
     let url = "https://live.bilibili.com/lol";
     println!("[Bilibili] Generating w_webid: GET {}", url);
     println!(
@@ -44,14 +51,9 @@ pub async fn refresh_w_webid(state: &BilibiliState) -> Result<String, String> {
         ua, "https://www.bilibili.com/"
     );
 
-    let client = reqwest::Client::builder()
-        .user_agent(ua)
-        .no_proxy()
-        .build()
-        .map_err(|e| format!("Failed to build client: {}", e))?;
-
     let resp = client
         .get(url)
+        .header("User-Agent", ua)
         .header("Referer", "https://www.bilibili.com/")
         .send()
         .await
@@ -1,13 +1,240 @@
+use reqwest::header::{HeaderMap, HeaderValue, COOKIE, ORIGIN, REFERER, USER_AGENT};
 use serde_json::Value;
 
 use crate::platforms::common::types::StreamVariant;
 use crate::platforms::common::errors::DtvError;
-use crate::platforms::common::LiveStreamInfo;
+use crate::platforms::common::logging::mask_sensitive;
+use crate::platforms::common::{
+    normalize_codec, CodecPreference, LineCandidate, LineOption, LiveStreamInfo, QualityOption,
+    StreamHeaders, StreamOptions,
+};
 
+const PLAYINFO_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/138.0.0.0 Safari/537.36";
+
+/// Client for getRoomPlayInfo: live.bilibili.com referer/origin plus the optional login cookie.
+pub fn build_playinfo_client(
+    user_agent: Option<&str>,
+    cookie: Option<&str>,
+) -> Result<reqwest::Client, DtvError> {
+    let ua = user_agent.unwrap_or(PLAYINFO_USER_AGENT);
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        USER_AGENT,
+        HeaderValue::from_str(ua)
+            .map_err(|e| DtvError::internal(format!("Invalid user agent: {}", e)))?,
+    );
+    headers.insert(REFERER, HeaderValue::from_static("https://live.bilibili.com/"));
+    if let Some(c) = cookie {
+        let c_trimmed = c.trim();
+        if !c_trimmed.is_empty() {
+            match HeaderValue::from_str(c_trimmed) {
+                Ok(val) => {
+                    headers.insert(COOKIE, val);
+                    tracing::debug!("[Bilibili] Cookie header set (content: {})", mask_sensitive(c_trimmed));
+                }
+                Err(err) => {
+                    tracing::warn!("[Bilibili] Invalid cookie header, skipping. Error: {}", err);
+                }
+            }
+        }
+    }
+    headers.insert(ORIGIN, HeaderValue::from_static("https://live.bilibili.com"));
+
+    reqwest::Client::builder()
+        .default_headers(headers)
+        .no_proxy()
+        .build()
+        .map_err(|e| DtvError::internal(format!("Failed to build client: {}", e)))
+}
+
+/// Headers bilivideo CDNs expect, with the UA the play info was requested with.
+pub fn stream_headers(user_agent: Option<&str>) -> StreamHeaders {
+    crate::platforms::common::stream_headers(
+        user_agent.unwrap_or(PLAYINFO_USER_AGENT),
+        "https://live.bilibili.com/",
+        Some("https://live.bilibili.com"),
+    )
+}
+
+// getRoomPlayInfo with an optional qn; codec 1 (HEVC) only when playable
+async fn request_playinfo(
+    client: &reqwest::Client,
+    room_id: &str,
+    qn: Option<i32>,
+    codec: CodecPreference,
+) -> Result<Value, DtvError> {
+    let url = "https://api.live.bilibili.com/xlive/web-room/v2/index/getRoomPlayInfo";
+    let mut params = vec![
+        ("room_id", room_id.to_string()),
+        ("protocol", "0,1".to_string()),
+        ("format", "0,1,2".to_string()),
+        (
+            "codec",
+            if codec.accepts_hevc() { "0,1" } else { "0" }.to_string(),
+        ),
+        ("platform", "html5".to_string()),
+        ("dolby", "5".to_string()),
+    ];
+    if let Some(q) = qn {
+        params.push(("qn", q.to_string()));
+    }
+    let resp = client
+        .get(url)
+        .query(&params)
+        .send()
+        .await
+        .map_err(|e| DtvError::network(format!("PlayInfo request failed: {}", e)))?;
+    let status = resp.status();
+    let text = resp
+        .text()
+        .await
+        .map_err(|e| DtvError::network(format!("Read text failed: {}", e)))?;
+    if !status.is_success() {
+        return Err(DtvError::api(format!("PlayInfo status: {} body: {}", status, text)));
+    }
+    serde_json::from_str::<Value>(&text)
+        .map_err(|e| DtvError::api(format!("JSON parse failed: {} | body: {}", e, text)))
+}
+
+// `https://cn-gddg-ct-01-01.bilivideo.com` -> `cn-gddg-ct-01-01.bilivideo.com`
+fn host_id(host: &str) -> &str {
+    host.split("://").last().unwrap_or(host).trim_end_matches('/')
+}
+
+/// Qualities from `accept_qn` (labelled by `g_qn_desc`) and the CDN hosts
+/// of a getRoomPlayInfo `playurl`. Line ids are host names.
+pub fn parse_stream_options(playurl: &Value) -> StreamOptions {
+    let desc = |qn: i64| {
+        playurl
+            .get("g_qn_desc")
+            .and_then(|v| v.as_array())
+            .and_then(|arr| {
+                arr.iter()
+                    .find(|item| item.get("qn").and_then(|v| v.as_i64()) == Some(qn))
+            })
+            .and_then(|item| item.get("desc"))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| qn.to_string())
+    };
+    let mut options = StreamOptions::default();
+    let codecs = playurl
+        .get("stream")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|stream| stream.get("format").and_then(|v| v.as_array()))
+        .flatten()
+        .filter_map(|format| format.get("codec").and_then(|v| v.as_array()))
+        .flatten();
+    for codec in codecs {
+        let codec_name = codec
+            .get("codec_name")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+        let accept_qn = codec.get("accept_qn").and_then(|v| v.as_array());
+        for qn in accept_qn.into_iter().flatten().filter_map(|v| v.as_i64()) {
+            let id = qn.to_string();
+            if !options.qualities.iter().any(|q| q.id == id) {
+                options.qualities.push(QualityOption {
+                    id,
+                    label: desc(qn),
+                    bitrate: None,
+                    codec: codec_name.clone(),
+                });
+            }
+        }
+        let url_info = codec.get("url_info").and_then(|v| v.as_array());
+        for host in url_info
+            .into_iter()
+            .flatten()
+            .filter_map(|ui| ui.get("host").and_then(|v| v.as_str()))
+        {
+            let id = host_id(host).to_string();
+            if id.is_empty() || options.lines.iter().any(|l| l.id == id) {
+                continue;
+            }
+            options.lines.push(LineOption {
+                label: id.split('.').next().unwrap_or(&id).to_string(),
+                id,
+            });
+        }
+    }
+    options
+}
+
+/// One stream per CDN host of a `playurl`, FLV where the host serves it,
+/// for the line prober. Line ids match `parse_stream_options`.
+pub fn parse_line_candidates(playurl: &Value, headers: &StreamHeaders) -> Vec<LineCandidate> {
+    let mut found: Vec<(bool, LineCandidate)> = Vec::new();
+    let streams = playurl.get("stream").and_then(|v| v.as_array());
+    for stream in streams.into_iter().flatten() {
+        let flv = stream.get("protocol_name").and_then(|v| v.as_str()) == Some("http_stream");
+        let codecs = stream
+            .get("format")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|format| format.get("codec").and_then(|v| v.as_array()))
+            .flatten();
+        for codec in codecs {
+            let base_url = codec.get("base_url").and_then(|v| v.as_str()).unwrap_or("");
+            let url_info = codec.get("url_info").and_then(|v| v.as_array());
+            for ui in url_info.into_iter().flatten() {
+                let host = ui.get("host").and_then(|v| v.as_str()).unwrap_or("");
+                let extra = ui.get("extra").and_then(|v| v.as_str()).unwrap_or("");
+                let id = host_id(host).to_string();
+                if id.is_empty() {
+                    continue;
+                }
+                let url = format!("{}{}{}", host.trim_end_matches('/'), base_url, extra);
+                let candidate = LineCandidate::new(&id, id.split('.').next().unwrap_or(&id), url)
+                    .with_headers(headers.clone());
+                found.push((!flv, candidate));
+            }
+        }
+    }
+    found.sort_by_key(|(not_flv, _)| *not_flv);
+    let mut candidates: Vec<LineCandidate> = Vec::new();
+    for (_, candidate) in found {
+        if !candidates.iter().any(|c| c.line == candidate.line) {
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+/// One H.264 stream per CDN host of a live room; `user_agent` as for the client.
+pub async fn fetch_line_candidates(
+    client: &reqwest::Client,
+    room_id: &str,
+    user_agent: Option<&str>,
+) -> Result<Vec<LineCandidate>, DtvError> {
+    let playinfo = request_playinfo(client, room_id, None, CodecPreference::Avc).await?;
+    Ok(parse_line_candidates(
+        &playinfo["data"]["playurl_info"]["playurl"],
+        &stream_headers(user_agent),
+    ))
+}
+
+/// Qualities and CDN hosts of a live room.
+pub async fn fetch_stream_options(
+    client: &reqwest::Client,
+    room_id: &str,
+) -> Result<StreamOptions, DtvError> {
+    let playinfo = request_playinfo(client, room_id, None, CodecPreference::Auto).await?;
+    Ok(parse_stream_options(&playinfo["data"]["playurl_info"]["playurl"]))
+}
+
+/// `quality` is a label (原画/高清/标清) or a qn; `line` is a CDN host from
+/// [`parse_stream_options`], preferred when the room still offers it.
+/// H.265 is only served over HLS, so preferring it skips FLV when offered.
 pub async fn get_bilibili_stream_url(
     client: &reqwest::Client,
     room_id: &str,
     quality: &str,
+    line: Option<&str>,
+    codec: CodecPreference,
     _cookie: Option<&str>,
 ) -> Result<LiveStreamInfo, DtvError> {
     if room_id.trim().is_empty() {
@@ -25,44 +252,8 @@ pub async fn get_bilibili_stream_url(
         });
     }
 
-    // Helper: request playinfo with optional qn
-    async fn request_playinfo(
-        client: &reqwest::Client,
-        room_id: &str,
-        qn: Option<i32>,
-    ) -> Result<Value, DtvError> {
-        let url = "https://api.live.bilibili.com/xlive/web-room/v2/index/getRoomPlayInfo";
-        let mut params = vec![
-            ("room_id", room_id.to_string()),
-            ("protocol", "0,1".to_string()),
-            ("format", "0,1,2".to_string()),
-            ("codec", "0".to_string()),
-            ("platform", "html5".to_string()),
-            ("dolby", "5".to_string()),
-        ];
-        if let Some(q) = qn {
-            params.push(("qn", q.to_string()));
-        }
-        let resp = client
-            .get(url)
-            .query(&params)
-            .send()
-            .await
-            .map_err(|e| DtvError::network(format!("PlayInfo request failed: {}", e)))?;
-        let status = resp.status();
-        let text = resp
-            .text()
-            .await
-            .map_err(|e| DtvError::network(format!("Read text failed: {}", e)))?;
-        if !status.is_success() {
-            return Err(DtvError::api(format!("PlayInfo status: {} body: {}", status, text)));
-        }
-        serde_json::from_str::<Value>(&text)
-            .map_err(|e| DtvError::api(format!("JSON parse failed: {} | body: {}", e, text)))
-    }
-
     // 1) First request to get qn mapping
-    let playinfo = request_playinfo(client, room_id, None).await?;
+    let playinfo = request_playinfo(client, room_id, None, codec).await?;
     let playurl = playinfo["data"]["playurl_info"]["playurl"].clone();
 
     // Build qn->desc map
@@ -86,6 +277,9 @@ pub async fn get_bilibili_stream_url(
         qns.sort();
         let has = |v: i32| qns.binary_search(&v).is_ok();
 
+        if let Some(qn) = q.parse::<i32>().ok().filter(|qn| has(*qn)) {
+            return Some(qn);
+        }
         match q {
             "原画" => {
                 if has(10000) {
@@ -175,10 +369,16 @@ pub async fn get_bilibili_stream_url(
         playurl: &Value,
         selected_desc: &Option<String>,
         selected_qn: Option<i32>,
+        line: Option<&str>,
+        codec: CodecPreference,
     ) -> (Vec<StreamVariant>, Option<String>, Vec<String>) {
         let mut variants: Vec<StreamVariant> = Vec::new();
-        let mut hls_candidates: Vec<String> = Vec::new();
+        let mut hls_candidates: Vec<(String, Option<&'static str>)> = Vec::new();
         let mut flv_candidate: Option<String> = None;
+        let mut flv_on_line = false;
+        let on_line = |host: &str| {
+            line.is_some_and(|l| !l.trim().is_empty() && host_id(host) == host_id(l.trim()))
+        };
 
         if let Some(streams) = playurl.get("stream").and_then(|v| v.as_array()) {
             for stream_item in streams {
@@ -195,6 +395,10 @@ pub async fn get_bilibili_stream_url(
                             .unwrap_or("");
                         if let Some(codecs) = format_item.get("codec").and_then(|v| v.as_array()) {
                             for codec_item in codecs {
+                                let codec_name = codec_item
+                                    .get("codec_name")
+                                    .and_then(|v| v.as_str())
+                                    .and_then(normalize_codec);
                                 let base_url = codec_item
                                     .get("base_url")
                                     .and_then(|v| v.as_str())
@@ -222,6 +426,7 @@ pub async fn get_bilibili_stream_url(
                                             } else {
                                                 Some(protocol_name.clone())
                                             },
+                                            codec: codec_name.map(str::to_string),
                                         });
 
                                         let is_hls_format = matches!(
@@ -230,10 +435,20 @@ pub async fn get_bilibili_stream_url(
                                         );
                                         let is_hls_protocol = protocol_name.contains("hls");
                                         if is_hls_format || is_hls_protocol {
-                                            hls_candidates.push(composed.clone());
+                                            // The requested line goes first
+                                            if on_line(host) {
+                                                hls_candidates
+                                                    .insert(0, (composed.clone(), codec_name));
+                                            } else {
+                                                hls_candidates.push((composed.clone(), codec_name));
+                                            }
                                         }
-                                        if format_name == "flv" && flv_candidate.is_none() {
+                                        if format_name == "flv"
+                                            && (flv_candidate.is_none()
+                                                || (!flv_on_line && on_line(host)))
+                                        {
                                             flv_candidate = Some(composed.clone());
+                                            flv_on_line = on_line(host);
                                         }
                                     }
                                 }
@@ -244,6 +459,12 @@ pub async fn get_bilibili_stream_url(
             }
         }
 
+        // FLV is H.264 only; a preferred H.265 stream beats it
+        hls_candidates.sort_by_key(|(_, c)| codec.rank(*c));
+        if codec.rank(hls_candidates.first().and_then(|(_, c)| *c)) < codec.rank(Some("avc")) {
+            flv_candidate = None;
+        }
+        let hls_candidates = hls_candidates.into_iter().map(|(url, _)| url).collect();
         (variants, flv_candidate, hls_candidates)
     }
 
@@ -271,10 +492,10 @@ pub async fn get_bilibili_stream_url(
     let mut fallback_variants: Option<Vec<StreamVariant>> = None;
 
     for attempt in 0..=MAX_HLS_RETRY {
-        let playinfo_attempt = request_playinfo(client, room_id, selected_qn).await?;
+        let playinfo_attempt = request_playinfo(client, room_id, selected_qn, codec).await?;
         let playurl_attempt = playinfo_attempt["data"]["playurl_info"]["playurl"].clone();
         let (variants, flv_candidate, hls_candidates) =
-            parse_stream_variants(&playurl_attempt, &selected_desc, selected_qn);
+            parse_stream_variants(&playurl_attempt, &selected_desc, selected_qn, line, codec);
 
         variants_for_response = variants.clone();
 
@@ -372,4 +593,97 @@ pub async fn get_bilibili_stream_url(
             web_rid: None,
         }),
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn options_list_accept_qn_and_hosts() {
+        let playurl = json!({
+            "g_qn_desc": [
+                { "qn": 10000, "desc": "原画" },
+                { "qn": 400, "desc": "蓝光" },
+                { "qn": 250, "desc": "超清" },
+                { "qn": 150, "desc": "高清" }
+            ],
+            "stream": [
+                { "protocol_name": "http_stream", "format": [{ "format_name": "flv", "codec": [{
+                    "codec_name": "avc",
+                    "accept_qn": [10000, 400, 250],
+                    "url_info": [
+                        { "host": "https://cn-gddg-ct-01-01.bilivideo.com", "extra": "?a=1" },
+                        { "host": "https://d1--cn-gotcha03.bilivideo.com", "extra": "?a=2" }
+                    ]
+                }] }] },
+                { "protocol_name": "http_hls", "format": [{ "format_name": "ts", "codec": [{
+                    "codec_name": "avc",
+                    "accept_qn": [10000, 400, 250],
+                    "url_info": [{ "host": "https://d1--cn-gotcha03.bilivideo.com/", "extra": "" }]
+                }] }] }
+            ]
+        });
+        let options = parse_stream_options(&playurl);
+        let qualities: Vec<(&str, &str)> = options
+            .qualities
+            .iter()
+            .map(|q| (q.id.as_str(), q.label.as_str()))
+            .collect();
+        assert_eq!(qualities, [("10000", "原画"), ("400", "蓝光"), ("250", "超清")]);
+        assert_eq!(options.qualities[0].codec.as_deref(), Some("avc"));
+        let lines: Vec<(&str, &str)> = options
+            .lines
+            .iter()
+            .map(|l| (l.id.as_str(), l.label.as_str()))
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                ("cn-gddg-ct-01-01.bilivideo.com", "cn-gddg-ct-01-01"),
+                ("d1--cn-gotcha03.bilivideo.com", "d1--cn-gotcha03")
+            ]
+        );
+        assert!(parse_stream_options(&Value::Null).qualities.is_empty());
+    }
+
+    #[test]
+    fn line_candidates_prefer_flv_per_host() {
+        let playurl = json!({
+            "stream": [
+                { "protocol_name": "http_hls", "format": [{ "format_name": "ts", "codec": [{
+                    "codec_name": "avc",
+                    "base_url": "/live-bvc/1/live_1.m3u8?",
+                    "url_info": [
+                        { "host": "https://d1--cn-gotcha03.bilivideo.com/", "extra": "e=1" },
+                        { "host": "https://cn-hbwh-cm-01-12.bilivideo.com", "extra": "e=2" }
+                    ]
+                }] }] },
+                { "protocol_name": "http_stream", "format": [{ "format_name": "flv", "codec": [{
+                    "codec_name": "avc",
+                    "base_url": "/live-bvc/1/live_1.flv?",
+                    "url_info": [{ "host": "https://d1--cn-gotcha03.bilivideo.com", "extra": "e=3" }]
+                }] }] }
+            ]
+        });
+        let candidates = parse_line_candidates(&playurl, &stream_headers(None));
+        let urls: Vec<(&str, &str)> = candidates
+            .iter()
+            .map(|c| (c.line.as_str(), c.url.as_str()))
+            .collect();
+        assert_eq!(
+            urls,
+            [
+                (
+                    "d1--cn-gotcha03.bilivideo.com",
+                    "https://d1--cn-gotcha03.bilivideo.com/live-bvc/1/live_1.flv?e=3"
+                ),
+                (
+                    "cn-hbwh-cm-01-12.bilivideo.com",
+                    "https://cn-hbwh-cm-01-12.bilivideo.com/live-bvc/1/live_1.m3u8?e=2"
+                )
+            ]
+        );
+        assert_eq!(candidates[0].label, "d1--cn-gotcha03");
+    }
+}
@@ -1,16 +1,22 @@
 // src/websocket.rs
-use native_tls::TlsStream;
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
 use serde_json::Value;
-use std::collections::VecDeque;
-use std::net::TcpStream;
 use std::sync::OnceLock;
-use std::time::{Duration, Instant};
-use tungstenite::{client, Message, WebSocket};
-use url::Url;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::time::{interval, sleep, timeout, Duration, Instant, MissedTickBehavior};
+use tokio_tungstenite::tungstenite::{Error as WsError, Message as WsMessage};
+use tokio_tungstenite::{connect_async, MaybeTlsStream};
 
 use super::auth::{init_server_no_cookie, init_server_with_cookie};
-use super::models::{BiliMessage, DanmuServer, MsgHead};
+use super::models::{BiliMessage, DanmuServer, FanMedal, InteractKind, MsgHead};
 
+type WsStream = tokio_tungstenite::WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+// The server answers every heartbeat (op=3), so a silent socket past this point is dead.
+const READ_IDLE_TIMEOUT: Duration = Duration::from_secs(75);
 static DEBUG_FLAG: OnceLock<bool> = OnceLock::new();
 
 pub fn is_debug_enabled() -> bool {
@@ -29,239 +35,282 @@ macro_rules! ws_debug {
     }
 }
 
+pub enum ConnectionOutcome {
+    Stop,
+    Disconnected,
+}
+
 pub struct BiliLiveClient {
-    ws: WebSocket<TlsStream<TcpStream>>,
     auth_msg: String,
-    // Keep server host list for reconnection
-    host_list: Value,
-    // Heartbeat scheduling
-    last_heartbeat: Instant,
-    heartbeat_interval: Duration,
-    // Pending messages parsed from current/previous frames
-    pending: VecDeque<BiliMessage>,
+    // Server list from getDanmuInfo, tried in order for failover
+    servers: Vec<DanmuServer>,
+    next_server: usize,
 }
 
 impl BiliLiveClient {
-    pub fn new_with_cookie(cookies: &str, room_id: &str) -> Self {
-        let (v, auth) = init_server_with_cookie(cookies, room_id);
+    /// Fetch the danmaku host list and auth token for `room_id`.
+    pub async fn new(room_id: &str, cookie: Option<&str>) -> Result<Self, String> {
+        let (v, auth) = match cookie.map(str::trim).filter(|c| !c.is_empty()) {
+            Some(c) => init_server_with_cookie(c, room_id).await?,
+            None => init_server_no_cookie(room_id).await?,
+        };
         ws_debug!("[websocket] server_info host_list: {:?}", v["host_list"]);
-        let ws = connect(v["host_list"].clone());
-        ws_debug!("[websocket] connected via cookie for room {}", room_id);
-        BiliLiveClient {
-            ws,
-            auth_msg: serde_json::to_string(&auth).unwrap(),
-            host_list: v["host_list"].clone(),
-            last_heartbeat: Instant::now(),
-            heartbeat_interval: Duration::from_secs(30),
-            pending: VecDeque::new(),
-        }
+        let auth_msg = serde_json::to_string(&auth).map_err(|e| e.to_string())?;
+        ws_debug!("[websocket] auth prepared for room {}", room_id);
+        Ok(BiliLiveClient {
+            auth_msg,
+            servers: gen_damu_list(&v["host_list"]),
+            next_server: 0,
+        })
     }
 
-    pub fn new_without_cookie(room_id: &str) -> Self {
-        let (v, auth) = init_server_no_cookie(room_id);
-        ws_debug!("[websocket] server_info host_list: {:?}", v["host_list"]);
-        let ws = connect(v["host_list"].clone());
-        ws_debug!("[websocket] connected without cookie for room {}", room_id);
-        BiliLiveClient {
-            ws,
-            auth_msg: serde_json::to_string(&auth).unwrap(),
-            host_list: v["host_list"].clone(),
-            last_heartbeat: Instant::now(),
-            heartbeat_interval: Duration::from_secs(30),
-            pending: VecDeque::new(),
+    // Try every server once, starting from the last one that worked.
+    async fn connect(&mut self) -> Result<WsStream, String> {
+        let count = self.servers.len();
+        let mut last_err = String::from("no danmaku server available");
+        for i in 0..count {
+            let idx = (self.next_server + i) % count;
+            let ws_url = self.servers[idx].wss_url();
+            ws_debug!("[websocket] connecting {}", ws_url);
+            match timeout(CONNECT_TIMEOUT, connect_async(ws_url.as_str())).await {
+                Ok(Ok((ws, _))) => {
+                    ws_debug!("[websocket] websocket handshake complete ({})", ws_url);
+                    self.next_server = idx;
+                    return Ok(ws);
+                }
+                Ok(Err(e)) => last_err = format!("{}: {}", ws_url, e),
+                Err(_) => last_err = format!("{}: connect timed out", ws_url),
+            }
+            ws_debug!("[websocket] {}", last_err);
         }
+        Err(last_err)
     }
 
-    pub fn send_auth(&mut self) {
-        let pkt = make_packet(self.auth_msg.as_str(), Operation::AUTH);
-        ws_debug!("[websocket] sending auth packet, len={}", pkt.len());
-        let _ = self.ws.send(Message::Binary(pkt));
+    // Move on to the next server after a dropped connection.
+    fn rotate_server(&mut self) {
+        if !self.servers.is_empty() {
+            self.next_server = (self.next_server + 1) % self.servers.len();
+        }
     }
 
-    pub fn send_heart_beat(&mut self) {
-        let pkt = make_packet("{}", Operation::HEARTBEAT);
-        ws_debug!("[websocket] sending heartbeat, len={}", pkt.len());
-        let _ = self.ws.send(Message::Binary(pkt));
-        // update heartbeat timestamp
-        self.last_heartbeat = Instant::now();
-    }
+    /// Connect, authenticate and pump messages into `on_message` until the
+    /// connection drops or a shutdown signal arrives.
+    ///
+    /// Returns `Err` when no server in the list could be reached, the server
+    /// rejected the auth packet, or the connection went silent (half-open);
+    /// callers should then refresh the token with [`BiliLiveClient::new`].
+    pub async fn run<F>(
+        &mut self,
+        rx_shutdown: &mut tokio_mpsc::Receiver<()>,
+        mut on_message: F,
+    ) -> Result<ConnectionOutcome, String>
+    where
+        F: FnMut(BiliMessage),
+    {
+        let ws = tokio::select! {
+            res = self.connect() => res?,
+            _ = rx_shutdown.recv() => return Ok(ConnectionOutcome::Stop),
+        };
+        let (mut write, mut read) = ws.split();
 
-    // Periodically send heartbeat to keep the connection alive
-    fn maybe_send_heartbeat(&mut self) {
-        if self.last_heartbeat.elapsed() >= self.heartbeat_interval {
-            ws_debug!("[websocket] periodic heartbeat due");
-            self.send_heart_beat();
+        let auth_pkt = make_packet(self.auth_msg.as_str(), Operation::AUTH);
+        ws_debug!("[websocket] sending auth packet, len={}", auth_pkt.len());
+        if let Err(e) = write.send(WsMessage::Binary(auth_pkt)).await {
+            self.rotate_server();
+            return Err(format!("send auth failed: {}", e));
         }
+
+        self.read_loop(
+            &mut write,
+            &mut read,
+            rx_shutdown,
+            &mut on_message,
+            HEARTBEAT_INTERVAL,
+            READ_IDLE_TIMEOUT,
+        )
+        .await
     }
 
-    // Try to reconnect using the cached host list, and re-authenticate
-    fn reconnect(&mut self) {
-        for attempt in 1..=2 {
-            ws_debug!("[websocket] attempting reconnect (attempt {attempt}/2)...");
-            match std::panic::catch_unwind({
-                let host_list = self.host_list.clone();
-                move || connect(host_list)
-            }) {
-                Ok(new_ws) => {
-                    self.ws = new_ws;
-                    ws_debug!(
-                        "[websocket] reconnect successful on attempt {attempt}, resending auth"
-                    );
-                    self.send_auth();
-                    return;
+    // Heartbeats go out on their own schedule; `idle` counts from the last
+    // frame received, whatever else happens in between.
+    async fn read_loop<W, R, F>(
+        &mut self,
+        write: &mut W,
+        read: &mut R,
+        rx_shutdown: &mut tokio_mpsc::Receiver<()>,
+        on_message: &mut F,
+        heartbeat_every: Duration,
+        idle: Duration,
+    ) -> Result<ConnectionOutcome, String>
+    where
+        W: Sink<WsMessage> + Unpin,
+        W::Error: std::fmt::Debug,
+        R: Stream<Item = Result<WsMessage, WsError>> + Unpin,
+        F: FnMut(BiliMessage),
+    {
+        let mut heartbeat = interval(heartbeat_every);
+        heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // The first tick fires immediately; the first real heartbeat follows the auth reply.
+        heartbeat.tick().await;
+        let idle_timer = sleep(idle);
+        tokio::pin!(idle_timer);
+
+        loop {
+            tokio::select! {
+                _ = rx_shutdown.recv() => {
+                    let _ = write.send(WsMessage::Close(None)).await;
+                    return Ok(ConnectionOutcome::Stop);
+                }
+                _ = heartbeat.tick() => {
+                    ws_debug!("[websocket] periodic heartbeat due");
+                    if let Err(e) = write.send(WsMessage::Binary(heartbeat_packet())).await {
+                        ws_debug!("[websocket] heartbeat send error: {:?}", e);
+                        self.rotate_server();
+                        return Ok(ConnectionOutcome::Disconnected);
+                    }
+                }
+                _ = &mut idle_timer => {
+                    ws_debug!("[websocket] no data for {:?}, reconnecting", idle);
+                    self.rotate_server();
+                    return Err(format!("no data for {:?}", idle));
                 }
-                Err(_) => {
-                    ws_debug!("[websocket] reconnect attempt {attempt} failed");
+                msg = read.next() => {
+                    idle_timer.as_mut().reset(Instant::now() + idle);
+                    match msg {
+                        Some(Ok(WsMessage::Binary(data))) => {
+                            ws_debug!("[websocket] read frame bytes={}", data.len());
+                            if let Some(code) = auth_reply_code(&data) {
+                                if code != 0 {
+                                    self.rotate_server();
+                                    return Err(format!("auth rejected, code={}", code));
+                                }
+                                ws_debug!("[websocket] op=8 (auth reply), sending heartbeat");
+                                if write.send(WsMessage::Binary(heartbeat_packet())).await.is_err() {
+                                    self.rotate_server();
+                                    return Ok(ConnectionOutcome::Disconnected);
+                                }
+                                heartbeat.reset();
+                                continue;
+                            }
+                            for m in parse_ws_message(&data) {
+                                on_message(m);
+                            }
+                        }
+                        Some(Ok(WsMessage::Ping(payload))) => {
+                            let _ = write.send(WsMessage::Pong(payload)).await;
+                        }
+                        Some(Ok(WsMessage::Close(_))) | Some(Err(_)) | None => {
+                            ws_debug!("[websocket] connection closed");
+                            self.rotate_server();
+                            return Ok(ConnectionOutcome::Disconnected);
+                        }
+                        Some(Ok(_)) => {}
+                    }
                 }
             }
         }
-        ws_debug!("[websocket] reconnect failed after 2 attempts; will retry on next read cycle");
     }
+}
+
+/// Parse one websocket frame, which may carry several (possibly compressed) packets.
+pub fn parse_ws_message(resv: &[u8]) -> Vec<BiliMessage> {
+    let mut out = Vec::new();
+    parse_packets(resv, &mut out);
+    out
+}
 
-    // Parse one frame and collect all messages into pending queue
-    pub fn parse_ws_message(&mut self, resv: Vec<u8>) -> Option<BiliMessage> {
-        ws_debug!("[websocket] parse_ws_message: total_len={}", resv.len());
-        let mut offset = 0;
-        let header = &resv[0..16];
-        let mut head_1 = get_msg_header(header);
+fn parse_packets(resv: &[u8], out: &mut Vec<BiliMessage>) {
+    ws_debug!("[websocket] parse_ws_message: total_len={}", resv.len());
+    let mut offset = 0;
+    while offset + 16 <= resv.len() {
+        let head = get_msg_header(&resv[offset..offset + 16]);
         ws_debug!(
             "[websocket] header op={} ver={} pack_len={} seq={} hdr_size={}",
-            head_1.operation,
-            head_1.ver,
-            head_1.pack_len,
-            head_1.seq_id,
-            head_1.raw_header_size
+            head.operation,
+            head.ver,
+            head.pack_len,
+            head.seq_id,
+            head.raw_header_size
         );
-        if head_1.operation == 5 || head_1.operation == 8 {
-            loop {
-                let body: &[u8] = &resv[offset + 16..offset + (head_1.pack_len as usize)];
+        let pack_len = head.pack_len as usize;
+        let header_len = (head.raw_header_size as usize).max(16);
+        if pack_len < header_len || offset + pack_len > resv.len() {
+            ws_debug!("[websocket] truncated packet at offset={}, skip rest", offset);
+            break;
+        }
+        let body = &resv[offset + header_len..offset + pack_len];
+        match head.operation {
+            5 => parse_business_message(head, body, out),
+            3 if body.len() >= 4 => {
+                let popularity = u32::from_be_bytes([body[0], body[1], body[2], body[3]]);
                 ws_debug!(
-                    "[websocket] chunk offset={} pack_len={} ver={} op={}",
-                    offset,
-                    head_1.pack_len,
-                    head_1.ver,
-                    head_1.operation
+                    "[websocket] popularity message op=3; popularity={}",
+                    popularity
                 );
-                if let Some(msg) = self.parse_business_message(head_1, body) {
-                    // push and continue to collect more messages
-                    self.pending.push_back(msg);
-                }
-                offset += head_1.pack_len as usize;
-                if offset >= resv.len() {
-                    break;
-                }
-                let temp_head = &resv[offset..(offset + 16)];
-                head_1 = get_msg_header(temp_head);
+                out.push(BiliMessage::Popularity { value: popularity });
             }
-        } else if head_1.operation == 3 {
-            let mut body: [u8; 4] = [0, 0, 0, 0];
-            body[0] = resv[16];
-            body[1] = resv[17];
-            body[2] = resv[18];
-            body[3] = resv[19];
-            let _popularity = i32::from_be_bytes(body);
-            ws_debug!(
-                "[websocket] popularity message op=3; popularity={}",
-                _popularity
-            );
-        } else {
-            ws_debug!("[websocket] unknown op={}, ignoring", head_1.operation);
+            op => ws_debug!("[websocket] unknown op={}, ignoring", op),
         }
-        None
+        offset += pack_len;
     }
+}
 
-    fn parse_business_message(&mut self, h: MsgHead, b: &[u8]) -> Option<BiliMessage> {
-        ws_debug!(
-            "[websocket] parse_business_message op={} ver={} body_len={} ",
-            h.operation,
-            h.ver,
-            b.len()
-        );
-        if h.operation == 5 {
-            if h.ver == 3 {
-                let res: Vec<u8> = match decompress(b) {
-                    Ok(r) => r,
-                    Err(e) => {
-                        ws_debug!("[websocket] decompress error: {:?}", e);
-                        return None;
-                    }
-                };
+fn parse_business_message(h: MsgHead, b: &[u8], out: &mut Vec<BiliMessage>) {
+    ws_debug!(
+        "[websocket] parse_business_message op={} ver={} body_len={} ",
+        h.operation,
+        h.ver,
+        b.len()
+    );
+    match h.ver {
+        3 => match decompress(b) {
+            Ok(res) => {
                 ws_debug!("[websocket] decompressed len={}", res.len());
-                return self.parse_ws_message(res);
-            } else if h.ver == 0 {
-                let s = match String::from_utf8(b.to_vec()) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        ws_debug!("[websocket] utf8 error: {:?}", e);
-                        return None;
-                    }
-                };
-                ws_debug!("[websocket] ver0 business json str len={}", s.len());
-                let res_json: Value = match serde_json::from_str(s.as_str()) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        ws_debug!("[websocket] json parse error: {:?}", e);
-                        return None;
-                    }
-                };
-                ws_debug!(
-                    "[websocket] business cmd={}",
-                    res_json["cmd"].as_str().unwrap_or("<unknown>")
-                );
-                if let Some(m) = handle(res_json) {
-                    // push into queue, but do not return immediately
-                    self.pending.push_back(m);
-                }
-                None
-            } else {
-                ws_debug!("[websocket] unknown compression ver={}, skip", h.ver);
-                None
+                parse_packets(&res, out);
             }
-        } else if h.operation == 8 {
-            ws_debug!("[websocket] op=8 (auth reply), sending heartbeat");
-            self.send_heart_beat();
-            None
-        } else {
-            ws_debug!("[websocket] unsupported business op={}, skip", h.operation);
-            None
-        }
-    }
-
-    pub fn read_once(&mut self) -> Option<BiliMessage> {
-        // If we already have pending messages, deliver one immediately
-        if let Some(m) = self.pending.pop_front() {
-            return Some(m);
-        }
-
-        // ensure heartbeat keeps alive
-        self.maybe_send_heartbeat();
-
-        let readable = self.ws.can_read();
-        ws_debug!("[websocket] can_read={} ", readable);
-        if self.ws.can_read() {
-            let msg = self.ws.read();
-            match msg {
-                Ok(m) => {
-                    let res = m.into_data();
-                    ws_debug!("[websocket] read frame bytes={} ", res.len());
-                    if res.len() >= 16 {
-                        // parse and fill pending queue
-                        let _ = self.parse_ws_message(res);
-                        // return one
-                        return self.pending.pop_front();
-                    } else {
-                        ws_debug!("[websocket] frame too short (<16), ignore");
-                    }
-                }
+            Err(e) => ws_debug!("[websocket] decompress error: {:?}", e),
+        },
+        2 => match inflate(b) {
+            Ok(res) => parse_packets(&res, out),
+            Err(e) => ws_debug!("[websocket] inflate error: {:?}", e),
+        },
+        0 | 1 => {
+            let res_json: Value = match serde_json::from_slice(b) {
+                Ok(v) => v,
                 Err(e) => {
-                    ws_debug!("[websocket] read error: {:?}", e);
-                    // try to reconnect on read error
-                    self.reconnect();
+                    ws_debug!("[websocket] json parse error: {:?}", e);
+                    return;
                 }
+            };
+            ws_debug!(
+                "[websocket] business cmd={}",
+                res_json["cmd"].as_str().unwrap_or("<unknown>")
+            );
+            if let Some(m) = handle(res_json) {
+                out.push(m);
             }
         }
-        None
+        v => ws_debug!("[websocket] unknown compression ver={}, skip", v),
+    }
+}
+
+// Returns the auth result code when `data` is an op=8 auth reply.
+fn auth_reply_code(data: &[u8]) -> Option<i64> {
+    if data.len() < 16 {
+        return None;
     }
+    let head = get_msg_header(&data[..16]);
+    if head.operation != 8 {
+        return None;
+    }
+    let end = (head.pack_len as usize).min(data.len());
+    let start = (head.raw_header_size as usize).max(16).min(end);
+    let body: Value = serde_json::from_slice(&data[start..end]).unwrap_or(Value::Null);
+    Some(body["code"].as_i64().unwrap_or(0))
+}
+
+fn heartbeat_packet() -> Vec<u8> {
+    make_packet("{}", Operation::HEARTBEAT)
 }
 
 pub fn gen_damu_list(list: &serde_json::Value) -> Vec<DanmuServer> {
@@ -301,34 +350,6 @@ pub fn gen_damu_list(list: &serde_json::Value) -> Vec<DanmuServer> {
     res
 }
 
-fn find_server(vd: Vec<DanmuServer>) -> (String, String, String) {
-    let (host, wss_port) = (vd.get(0).unwrap().host.clone(), vd.get(0).unwrap().wss_port);
-    ws_debug!(
-        "[websocket] choose server host={} wss_port={}",
-        host,
-        wss_port
-    );
-    (
-        host.clone(),
-        format!("{}:{}", host.clone(), wss_port),
-        format!("wss://{}:{}/sub", host, wss_port),
-    )
-}
-
-pub fn connect(v: Value) -> WebSocket<TlsStream<TcpStream>> {
-    let danmu_server = gen_damu_list(&v);
-    let (host, url, ws_url) = find_server(danmu_server);
-    ws_debug!("[websocket] connecting tcp {} and ws {}", url, ws_url);
-    let connector: native_tls::TlsConnector = native_tls::TlsConnector::new().unwrap();
-    let stream: TcpStream = TcpStream::connect(url).unwrap();
-    let stream: native_tls::TlsStream<TcpStream> =
-        connector.connect(host.as_str(), stream).unwrap();
-    let (socket, _resp) =
-        client(Url::parse(ws_url.as_str()).unwrap(), stream).expect("Can't connect");
-    ws_debug!("[websocket] websocket handshake complete");
-    socket
-}
-
 pub enum Operation {
     AUTH,
     HEARTBEAT,
@@ -338,7 +359,7 @@ pub fn make_packet(body: &str, ops: Operation) -> Vec<u8> {
     let json: Value = serde_json::from_str(body).unwrap();
     let temp = json.to_string();
     let body_content: &[u8] = temp.as_bytes();
-    let pack_len: [u8; 4] = ((16 + body.len()) as u32).to_be_bytes();
+    let pack_len: [u8; 4] = ((16 + body_content.len()) as u32).to_be_bytes();
     let raw_header_size: [u8; 2] = (16 as u16).to_be_bytes();
     let ver: [u8; 2] = (1 as u16).to_be_bytes();
     let operation: [u8; 4] = match ops {
@@ -401,25 +422,317 @@ pub fn decompress(body: &[u8]) -> std::io::Result<Vec<u8>> {
     Ok(decoded_input)
 }
 
+pub fn inflate(body: &[u8]) -> std::io::Result<Vec<u8>> {
+    use flate2::read::ZlibDecoder;
+    use std::io::Read;
+    let mut decoded = Vec::new();
+    ZlibDecoder::new(body).read_to_end(&mut decoded)?;
+    Ok(decoded)
+}
+
+fn as_i64(v: &Value) -> i64 {
+    v.as_i64()
+        .or_else(|| v.as_str().and_then(|s| s.parse().ok()))
+        .unwrap_or(0)
+}
+
+fn as_string(v: &Value) -> String {
+    v.as_str().unwrap_or("").to_string()
+}
+
+fn parse_medal(v: &Value) -> Option<FanMedal> {
+    let arr = v.as_array()?;
+    if arr.len() < 4 {
+        return None;
+    }
+    Some(FanMedal {
+        level: as_i64(&arr[0]),
+        name: as_string(&arr[1]),
+        anchor_name: as_string(&arr[2]),
+        anchor_room_id: as_i64(&arr[3]),
+    })
+}
+
+fn parse_danmu(info: &Value) -> BiliMessage {
+    let meta = &info[0];
+    // meta[12] == 1 marks a sticker danmu; its image lives in meta[13]
+    let emoticon = if as_i64(&meta[12]) == 1 {
+        meta[13]["url"].as_str().map(str::to_string)
+    } else {
+        None
+    };
+    BiliMessage::Danmu {
+        uid: as_i64(&info[2][0]),
+        user: info[2][1].as_str().unwrap_or("<unknown>").to_string(),
+        text: as_string(&info[1]),
+        user_level: as_i64(&info[4][0]),
+        medal: parse_medal(&info[3]),
+        guard_level: as_i64(&info[7]),
+        color: meta[3].as_u64().unwrap_or(0xFFFFFF) as u32,
+        emoticon,
+    }
+}
+
 pub fn handle(json: Value) -> Option<BiliMessage> {
-    let category = json["cmd"].as_str().unwrap_or("");
-    match category {
-        "DANMU_MSG" => Some(BiliMessage::Danmu {
-            user: json["info"][2][1]
+    // Commands may carry protocol suffixes, e.g. "DANMU_MSG:4:0:2:2:2:0"
+    let cmd = json["cmd"].as_str().unwrap_or("");
+    let category = cmd.split(':').next().unwrap_or("");
+    let data = &json["data"];
+    let msg = match category {
+        "DANMU_MSG" => parse_danmu(&json["info"]),
+        "SEND_GIFT" => BiliMessage::Gift {
+            uid: as_i64(&data["uid"]),
+            user: data["uname"].as_str().unwrap_or("<unknown>").to_string(),
+            gift: as_string(&data["giftName"]),
+            gift_id: as_i64(&data["giftId"]),
+            num: as_i64(&data["num"]),
+            price: as_i64(&data["price"]),
+            coin_type: as_string(&data["coin_type"]),
+            action: as_string(&data["action"]),
+        },
+        "SUPER_CHAT_MESSAGE" => BiliMessage::SuperChat {
+            uid: as_i64(&data["uid"]),
+            user: data["user_info"]["uname"]
                 .as_str()
                 .unwrap_or("<unknown>")
                 .to_string(),
-            text: json["info"][1].as_str().unwrap_or("").to_string(),
-        }),
-        "SEND_GIFT" => Some(BiliMessage::Gift {
-            user: json["info"][2][1]
-                .as_str()
-                .unwrap_or("<unknown>")
-                .to_string(),
-            gift: json["info"][1].as_str().unwrap_or("").to_string(),
-        }),
-        _ => Some(BiliMessage::Unsupported {
+            message: as_string(&data["message"]),
+            price: as_i64(&data["price"]),
+            duration: as_i64(&data["time"]),
+        },
+        "GUARD_BUY" => BiliMessage::GuardBuy {
+            uid: as_i64(&data["uid"]),
+            user: data["username"].as_str().unwrap_or("<unknown>").to_string(),
+            guard_level: as_i64(&data["guard_level"]),
+            num: as_i64(&data["num"]),
+            price: as_i64(&data["price"]),
+            gift_name: as_string(&data["gift_name"]),
+        },
+        "INTERACT_WORD" => BiliMessage::Interact {
+            uid: as_i64(&data["uid"]),
+            user: data["uname"].as_str().unwrap_or("<unknown>").to_string(),
+            kind: InteractKind::from_msg_type(as_i64(&data["msg_type"])),
+        },
+        "LIKE_INFO_V3_UPDATE" => BiliMessage::LikeCount {
+            count: as_i64(&data["click_count"]),
+        },
+        "WATCHED_CHANGE" => BiliMessage::WatchedChange {
+            num: as_i64(&data["num"]),
+            text: as_string(&data["text_large"]),
+        },
+        "ONLINE_RANK_COUNT" => BiliMessage::OnlineRankCount {
+            count: as_i64(&data["count"]),
+        },
+        "LIVE" => BiliMessage::LiveStart {
+            room_id: as_i64(&json["roomid"]),
+        },
+        "PREPARING" => BiliMessage::LiveEnd {
+            room_id: as_i64(&json["roomid"]),
+        },
+        "ROOM_CHANGE" => BiliMessage::RoomChange {
+            title: as_string(&data["title"]),
+            area_name: as_string(&data["area_name"]),
+            parent_area_name: as_string(&data["parent_area_name"]),
+        },
+        _ => BiliMessage::Unsupported {
             cmd: category.to_string(),
-        }),
+        },
+    };
+    Some(msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn business_packet(body: &str) -> Vec<u8> {
+        let mut pkt = Vec::new();
+        pkt.extend_from_slice(&((16 + body.len()) as u32).to_be_bytes());
+        pkt.extend_from_slice(&16u16.to_be_bytes());
+        pkt.extend_from_slice(&0u16.to_be_bytes());
+        pkt.extend_from_slice(&5u32.to_be_bytes());
+        pkt.extend_from_slice(&0u32.to_be_bytes());
+        pkt.extend_from_slice(body.as_bytes());
+        pkt
+    }
+
+    #[tokio::test]
+    async fn read_loop_errors_when_reads_stall() {
+        let mut client = BiliLiveClient {
+            auth_msg: String::new(),
+            servers: Vec::new(),
+            next_server: 0,
+        };
+        let (_tx, mut rx_shutdown) = tokio_mpsc::channel(1);
+        // Heartbeats keep going out while nothing ever comes back
+        let mut write = futures_util::sink::drain();
+        let mut read = futures_util::stream::pending();
+        let outcome = timeout(
+            Duration::from_secs(5),
+            client.read_loop(
+                &mut write,
+                &mut read,
+                &mut rx_shutdown,
+                &mut |_| {},
+                Duration::from_millis(10),
+                Duration::from_millis(100),
+            ),
+        )
+        .await
+        .expect("idle timeout never fired");
+        assert!(outcome.is_err());
+    }
+
+    #[test]
+    fn parse_ws_message_splits_concatenated_packets() {
+        let mut frame = business_packet(r#"{"cmd":"DANMU_MSG","info":[[],"hello",[1,"alice"]]}"#);
+        frame.extend(business_packet(r#"{"cmd":"STOP_LIVE_ROOM_LIST"}"#));
+
+        let msgs = parse_ws_message(&frame);
+        assert_eq!(msgs.len(), 2);
+        assert!(matches!(
+            &msgs[0],
+            BiliMessage::Danmu { user, text, .. } if user == "alice" && text == "hello"
+        ));
+    }
+
+    #[test]
+    fn parse_ws_message_ignores_truncated_packet() {
+        let mut frame = business_packet(r#"{"cmd":"DANMU_MSG","info":[[],"hi",[1,"bob"]]}"#);
+        frame.truncate(frame.len() - 3);
+        assert!(parse_ws_message(&frame).is_empty());
+    }
+
+    #[test]
+    fn auth_reply_code_reads_op8_body() {
+        let body = r#"{"code":0}"#;
+        let mut pkt = business_packet(body);
+        pkt[8..12].copy_from_slice(&8u32.to_be_bytes());
+        assert_eq!(auth_reply_code(&pkt), Some(0));
+        assert_eq!(auth_reply_code(&business_packet(body)), None);
+    }
+
+    #[test]
+    fn handle_danmu_msg_reads_level_medal_guard_and_emoticon() {
+        let json = serde_json::json!({
+            "cmd": "DANMU_MSG:4:0:2:2:2:0",
+            "info": [
+                [0, 1, 25, 16772431, 1700000000000i64, 0, 0, "", 0, 0, 0, "", 1,
+                    {"emoticon_unique": "upower_x", "url": "https://i0.hdslb.com/e.png"}],
+                "[dog]",
+                [12345, "alice", 0, 0, 0, 10000, 1, ""],
+                [21, "小孩梓", "阿梓从小就很可爱", 510, 1725515, "", 0],
+                [33, 0, 6406234, ">50000"],
+                ["", ""],
+                0,
+                3
+            ]
+        });
+        let msg = handle(json).unwrap();
+        assert_eq!(
+            msg,
+            BiliMessage::Danmu {
+                uid: 12345,
+                user: "alice".to_string(),
+                text: "[dog]".to_string(),
+                user_level: 33,
+                medal: Some(FanMedal {
+                    name: "小孩梓".to_string(),
+                    level: 21,
+                    anchor_name: "阿梓从小就很可爱".to_string(),
+                    anchor_room_id: 510,
+                }),
+                guard_level: 3,
+                color: 16772431,
+                emoticon: Some("https://i0.hdslb.com/e.png".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn handle_send_gift_uses_data_gift_name() {
+        let json = serde_json::json!({
+            "cmd": "SEND_GIFT",
+            "data": {
+                "uid": 42, "uname": "bob", "giftName": "小心心", "giftId": 30607,
+                "num": 3, "price": 0, "coin_type": "silver", "action": "投喂"
+            }
+        });
+        match handle(json).unwrap() {
+            BiliMessage::Gift { user, gift, num, coin_type, .. } => {
+                assert_eq!(user, "bob");
+                assert_eq!(gift, "小心心");
+                assert_eq!(num, 3);
+                assert_eq!(coin_type, "silver");
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn handle_super_chat_and_interact_word() {
+        let sc = serde_json::json!({
+            "cmd": "SUPER_CHAT_MESSAGE",
+            "data": {"uid": 7, "price": 30, "time": 60, "message": "加油",
+                     "user_info": {"uname": "carol"}}
+        });
+        assert_eq!(
+            handle(sc).unwrap(),
+            BiliMessage::SuperChat {
+                uid: 7,
+                user: "carol".to_string(),
+                message: "加油".to_string(),
+                price: 30,
+                duration: 60,
+            }
+        );
+
+        let follow = serde_json::json!({
+            "cmd": "INTERACT_WORD",
+            "data": {"uid": 8, "uname": "dave", "msg_type": 2}
+        });
+        assert_eq!(
+            handle(follow).unwrap(),
+            BiliMessage::Interact {
+                uid: 8,
+                user: "dave".to_string(),
+                kind: InteractKind::Follow,
+            }
+        );
+    }
+
+    #[test]
+    fn handle_status_and_counters() {
+        let preparing = serde_json::json!({"cmd": "PREPARING", "roomid": "21452505"});
+        assert_eq!(
+            handle(preparing).unwrap(),
+            BiliMessage::LiveEnd { room_id: 21452505 }
+        );
+        let watched = serde_json::json!({
+            "cmd": "WATCHED_CHANGE",
+            "data": {"num": 1234, "text_small": "1234", "text_large": "1234人看过"}
+        });
+        assert_eq!(
+            handle(watched).unwrap(),
+            BiliMessage::WatchedChange {
+                num: 1234,
+                text: "1234人看过".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn parse_ws_message_surfaces_popularity() {
+        let mut pkt = Vec::new();
+        pkt.extend_from_slice(&20u32.to_be_bytes());
+        pkt.extend_from_slice(&16u16.to_be_bytes());
+        pkt.extend_from_slice(&1u16.to_be_bytes());
+        pkt.extend_from_slice(&3u32.to_be_bytes());
+        pkt.extend_from_slice(&1u32.to_be_bytes());
+        pkt.extend_from_slice(&9527u32.to_be_bytes());
+        assert_eq!(
+            parse_ws_message(&pkt),
+            vec![BiliMessage::Popularity { value: 9527 }]
+        );
     }
 }
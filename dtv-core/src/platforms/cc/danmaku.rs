@@ -0,0 +1,253 @@
+// CC web danmaku: msgpack bodies behind an 8-byte header (sid u16, cid u16,
+// flags u32, all little-endian) over a WebSocket. The client registers a
+// device, joins the room's channel and then sends an empty heartbeat; chat
+// arrives as sid 512 / cid 32784 with numeric field keys. Server bodies may
+// be zlib-compressed.
+use std::io::Read;
+use std::sync::Arc;
+
+use flate2::read::ZlibDecoder;
+use futures_util::{SinkExt, StreamExt};
+use log::info;
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::time::{sleep, Duration};
+use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
+
+use super::msgpack::{self, Value};
+use super::room::fetch_room;
+use crate::danmaku::DanmakuHandler;
+use crate::platforms::common::DanmakuFrontendPayload;
+
+const WS_URL: &str = "wss://weblink.cc.163.com/";
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+
+const SID_CLIENT: u16 = 6144;
+const CID_REGISTER: u16 = 2;
+const CID_HEARTBEAT: u16 = 5;
+const SID_ROOM: u16 = 512;
+const CID_JOIN: u16 = 1;
+const CID_CHAT: u16 = 32784;
+
+// Chat fields are keyed by number
+const KEY_NICKNAME: &str = "197";
+const KEY_CONTENT: &str = "4";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub sid: u16,
+    pub cid: u16,
+    pub body: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CcChat {
+    pub user: String,
+    pub content: String,
+}
+
+pub fn encode_frame(sid: u16, cid: u16, body: &Value) -> Vec<u8> {
+    let mut out = Vec::with_capacity(8 + 64);
+    out.extend_from_slice(&sid.to_le_bytes());
+    out.extend_from_slice(&cid.to_le_bytes());
+    out.extend_from_slice(&0u32.to_le_bytes());
+    out.extend_from_slice(&msgpack::encode(body));
+    out
+}
+
+/// Device registration, sent first. `device` is a random per-connection id.
+pub fn encode_register(timestamp_ms: i64, device: &str) -> Vec<u8> {
+    let body = msgpack::map([
+        ("web-cc", Value::from(timestamp_ms * 1000)),
+        ("macAdd_key", Value::from(device)),
+        ("device_token", Value::from(device)),
+        ("page_uuid", Value::from(device)),
+        ("update", Value::from(0)),
+        ("system", Value::from("win")),
+        ("memory", Value::from(1)),
+        ("version", Value::from(1)),
+        ("webccType", Value::from(4253)),
+    ]);
+    encode_frame(SID_CLIENT, CID_REGISTER, &body)
+}
+
+pub fn encode_join(channel_id: i64, gametype: i64, room_id: i64) -> Vec<u8> {
+    let body = msgpack::map([
+        ("cid", Value::from(channel_id)),
+        ("gametype", Value::from(gametype)),
+        ("roomId", Value::from(room_id)),
+    ]);
+    encode_frame(SID_ROOM, CID_JOIN, &body)
+}
+
+pub fn encode_heartbeat() -> Vec<u8> {
+    encode_frame(SID_CLIENT, CID_HEARTBEAT, &Value::Map(Vec::new()))
+}
+
+/// Decodes one binary frame from the server.
+pub fn decode_frame(bin: &[u8]) -> Result<Frame, String> {
+    if bin.len() < 8 {
+        return Err(format!("frame too short ({} bytes)", bin.len()));
+    }
+    let sid = u16::from_le_bytes([bin[0], bin[1]]);
+    let cid = u16::from_le_bytes([bin[2], bin[3]]);
+    let payload = &bin[8..];
+    if payload.is_empty() {
+        return Ok(Frame {
+            sid,
+            cid,
+            body: Value::Nil,
+        });
+    }
+    // A zlib stream starts with 0x78; fall back to the raw bytes if it
+    // does not inflate
+    let mut inflated = Vec::new();
+    let body =
+        if payload[0] == 0x78 && ZlibDecoder::new(payload).read_to_end(&mut inflated).is_ok() {
+            msgpack::decode(&inflated)
+        } else {
+            msgpack::decode(payload)
+        }
+        .map_err(|e| format!("sid {} cid {}: {}", sid, cid, e))?;
+    Ok(Frame { sid, cid, body })
+}
+
+/// Chat messages in a frame; empty for anything else.
+pub fn chat_messages(frame: &Frame) -> Vec<CcChat> {
+    if frame.sid != SID_ROOM || frame.cid != CID_CHAT {
+        return Vec::new();
+    }
+    let Some(msgs) = frame.body.get("msg").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+    msgs.iter()
+        .filter_map(|m| {
+            let content = m.get(KEY_CONTENT)?.as_str()?.trim();
+            if content.is_empty() {
+                return None;
+            }
+            Some(CcChat {
+                user: m
+                    .get(KEY_NICKNAME)
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                content: content.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn device_id() -> String {
+    use rand::Rng;
+    format!("{:032x}", rand::thread_rng().gen::<u128>())
+}
+
+enum ConnectionOutcome {
+    Stop,
+    Disconnected,
+}
+
+pub async fn run_cc_danmaku_listener(
+    room_id: String,
+    mut rx_shutdown: tokio_mpsc::Receiver<()>,
+    handler: Arc<dyn DanmakuHandler>,
+) {
+    info!("[CC Danmaku] spawned worker for room_id={}", room_id);
+    let client = match reqwest::Client::builder().no_proxy().build() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("[CC Danmaku] http client: {}", e);
+            return;
+        }
+    };
+
+    let mut backoff_secs = 1u64;
+    loop {
+        let result: anyhow::Result<ConnectionOutcome> = async {
+            // The channel changes between broadcasts, so look it up per connection
+            let room = fetch_room(&client, &room_id)
+                .await
+                .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+            let channel_id = room
+                .channel_id
+                .ok_or_else(|| anyhow::anyhow!("room {} has no channel (offline?)", room_id))?;
+            let numeric_room: i64 = room.room_id.parse().unwrap_or(0);
+
+            let (ws_stream, _) = connect_async(WS_URL).await?;
+            let (mut ws_write, mut ws_read) = ws_stream.split();
+            let now = chrono::Utc::now().timestamp_millis();
+            ws_write
+                .send(WsMessage::Binary(encode_register(now, &device_id())))
+                .await?;
+            ws_write
+                .send(WsMessage::Binary(encode_join(
+                    channel_id,
+                    room.gametype.unwrap_or(0),
+                    numeric_room,
+                )))
+                .await?;
+
+            let hb_task = async {
+                loop {
+                    if ws_write
+                        .send(WsMessage::Binary(encode_heartbeat()))
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
+                    sleep(HEARTBEAT_INTERVAL).await;
+                }
+                Err::<(), anyhow::Error>(anyhow::anyhow!("CC heartbeat send failed"))
+            };
+
+            let recv_task = async {
+                while let Some(m) = ws_read.next().await {
+                    let WsMessage::Binary(bin) = m? else {
+                        continue;
+                    };
+                    match decode_frame(&bin) {
+                        Ok(frame) => {
+                            for chat in chat_messages(&frame) {
+                                handler.on_danmaku(DanmakuFrontendPayload {
+                                    room_id: room_id.clone(),
+                                    user: chat.user,
+                                    content: chat.content,
+                                    user_level: 0,
+                                    fans_club_level: 0,
+                                });
+                            }
+                        }
+                        Err(e) => eprintln!("[CC Danmaku] bad frame: {}", e),
+                    }
+                }
+                anyhow::Ok(())
+            };
+
+            tokio::select! {
+                _ = rx_shutdown.recv() => Ok(ConnectionOutcome::Stop),
+                it = hb_task => {
+                    if let Err(e) = it { eprintln!("[CC Danmaku] {}", e); }
+                    Ok(ConnectionOutcome::Disconnected)
+                }
+                it = recv_task => {
+                    if let Err(e) = it { eprintln!("[CC Danmaku] recv error: {}", e); }
+                    Ok(ConnectionOutcome::Disconnected)
+                }
+            }
+        }
+        .await;
+
+        match result {
+            Ok(ConnectionOutcome::Stop) => break,
+            Ok(ConnectionOutcome::Disconnected) => {}
+            Err(e) => eprintln!("[CC Danmaku] room {}: {}", room_id, e),
+        }
+
+        tokio::select! {
+            _ = sleep(Duration::from_secs(backoff_secs)) => {}
+            _ = rx_shutdown.recv() => break,
+        }
+        backoff_secs = (backoff_secs * 2).min(30);
+    }
+}
@@ -0,0 +1,178 @@
+// CC category and live-list browsing. Categories come in four fixed groups
+// (`catetype`); a category's rooms are paged by `start`/`size`.
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, REFERER, USER_AGENT};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::room::{DESKTOP_UA, REFERER_URL};
+use crate::platforms::common::types::{
+    CommonCategoryGroup, CommonPlatformCategory, SupportedPlatform,
+};
+use crate::platforms::common::RoomMeta;
+
+/// `catetype` ids and their names on the site.
+pub const CATEGORY_GROUPS: &[(u32, &str)] = &[(1, "网游"), (2, "单机"), (4, "竞技"), (5, "综艺")];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CcLiveRoom {
+    #[serde(flatten)]
+    pub room: RoomMeta,
+    pub cover: Option<String>,
+    pub viewers: i64,
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CcLiveListResponse {
+    pub rooms: Vec<CcLiveRoom>,
+    pub has_more: bool,
+}
+
+fn text(v: &Value, key: &str) -> Option<String> {
+    match v.get(key)? {
+        Value::String(s) if !s.trim().is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn headers(user_agent: Option<&str>) -> Result<HeaderMap, String> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        USER_AGENT,
+        HeaderValue::from_str(user_agent.unwrap_or(DESKTOP_UA)).map_err(|e| e.to_string())?,
+    );
+    headers.insert(REFERER, HeaderValue::from_static(REFERER_URL));
+    headers.insert(ACCEPT, HeaderValue::from_static("application/json, */*"));
+    Ok(headers)
+}
+
+/// Parses a `gamecategory` response for one group.
+pub fn parse_categories(body: &str, catetype: u32) -> Result<CommonCategoryGroup, String> {
+    let v: Value = serde_json::from_str(body).map_err(|e| e.to_string())?;
+    let list = v
+        .pointer("/data/category_info/game_list")
+        .and_then(Value::as_array)
+        .ok_or_else(|| format!("CC categories: unexpected response {}", v))?;
+    let group_name = CATEGORY_GROUPS
+        .iter()
+        .find(|(id, _)| *id == catetype)
+        .map(|(_, name)| name.to_string())
+        .unwrap_or_else(|| catetype.to_string());
+    Ok(CommonCategoryGroup {
+        group_name,
+        platform: SupportedPlatform::Cc,
+        categories: list
+            .iter()
+            .filter_map(|item| {
+                Some(CommonPlatformCategory {
+                    id: text(item, "gametype")?,
+                    name: text(item, "name")?,
+                    platform: SupportedPlatform::Cc,
+                    icon_url: text(item, "cover"),
+                    parent_id: Some(catetype.to_string()),
+                })
+            })
+            .collect(),
+    })
+}
+
+/// Parses a category or recommended live list.
+pub fn parse_live_list(body: &str, page_size: u32) -> Result<CcLiveListResponse, String> {
+    let v: Value = serde_json::from_str(body).map_err(|e| e.to_string())?;
+    let lives = v
+        .get("lives")
+        .and_then(Value::as_array)
+        .ok_or_else(|| format!("CC live list: unexpected response {}", v))?;
+    let rooms: Vec<CcLiveRoom> = lives
+        .iter()
+        .filter_map(|item| {
+            Some(CcLiveRoom {
+                room: RoomMeta {
+                    platform: SupportedPlatform::Cc,
+                    room_id: text(item, "cuteid")?,
+                    normalized_room_id: None,
+                    web_rid: None,
+                    title: text(item, "title"),
+                    anchor_name: text(item, "nickname"),
+                    avatar: text(item, "purl"),
+                    category: None,
+                },
+                cover: text(item, "poster").or_else(|| text(item, "cover")),
+                viewers: item.get("visitor").and_then(Value::as_i64).unwrap_or(0),
+                category: text(item, "game_name").or_else(|| text(item, "gamename")),
+            })
+        })
+        .collect();
+    Ok(CcLiveListResponse {
+        has_more: lives.len() >= page_size as usize,
+        rooms,
+    })
+}
+
+async fn get_text(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
+    url: &str,
+    query: &[(&str, String)],
+) -> Result<String, String> {
+    client
+        .get(url)
+        .headers(headers(user_agent)?)
+        .query(query)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?
+        .text()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub async fn fetch_cc_categories(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
+    catetype: u32,
+) -> Result<CommonCategoryGroup, String> {
+    let body = get_text(
+        client,
+        user_agent,
+        "https://api.cc.163.com/v1/wapcc/gamecategory",
+        &[("catetype", catetype.to_string())],
+    )
+    .await?;
+    parse_categories(&body, catetype)
+}
+
+/// Rooms in category `gametype`, or the recommended list when `None`.
+pub async fn fetch_cc_live_list(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
+    gametype: Option<String>,
+    page: u32,
+    page_size: u32,
+) -> Result<CcLiveListResponse, String> {
+    let page_size = page_size.clamp(1, 100);
+    let url = match gametype.as_deref().filter(|g| !g.is_empty()) {
+        Some(g) if g.chars().all(|c| c.is_ascii_digit()) => {
+            format!("https://cc.163.com/api/category/{}/", g)
+        }
+        Some(g) => return Err(format!("invalid CC category: {}", g)),
+        None => "https://cc.163.com/api/category/live/".to_string(),
+    };
+    let start = page.saturating_sub(1) * page_size;
+    let body = get_text(
+        client,
+        user_agent,
+        &url,
+        &[
+            ("format", "json".to_string()),
+            ("tag_id", "0".to_string()),
+            ("start", start.to_string()),
+            ("size", page_size.to_string()),
+        ],
+    )
+    .await?;
+    parse_live_list(&body, page_size)
+}
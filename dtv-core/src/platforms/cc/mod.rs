@@ -0,0 +1,4 @@
+pub mod danmaku;
+pub mod live_list;
+pub mod msgpack;
+pub mod room;
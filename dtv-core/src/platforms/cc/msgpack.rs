@@ -0,0 +1,374 @@
+// Minimal MessagePack codec for CC danmaku frames. Covers the types CC
+// sends (nil, bool, ints, floats, str, bin, array, map); extension types
+// are skipped over as `Nil`.
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct DecodeErr(pub String);
+
+impl fmt::Display for DecodeErr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for DecodeErr {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Nil,
+    Bool(bool),
+    Int(i64),
+    // Only for values above i64::MAX
+    UInt(u64),
+    Float(f64),
+    Str(String),
+    Bin(Vec<u8>),
+    Array(Vec<Value>),
+    // Kept in wire order; CC maps are small
+    Map(Vec<(Value, Value)>),
+}
+
+impl Value {
+    /// Map lookup by key. CC keys are strings or small integers ("197" and
+    /// 197 both match `"197"`).
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        let Value::Map(entries) = self else {
+            return None;
+        };
+        entries
+            .iter()
+            .find(|(k, _)| match k {
+                Value::Str(s) => s == key,
+                Value::Int(i) => key.parse::<i64>().ok() == Some(*i),
+                _ => false,
+            })
+            .map(|(_, v)| v)
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Int(i) => Some(*i),
+            Value::UInt(u) => i64::try_from(*u).ok(),
+            // Some ids arrive as strings
+            Value::Str(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Lossy conversion for logging and typed events.
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::Value as J;
+        match self {
+            Value::Nil => J::Null,
+            Value::Bool(b) => J::Bool(*b),
+            Value::Int(i) => J::from(*i),
+            Value::UInt(u) => J::from(*u),
+            Value::Float(f) => serde_json::Number::from_f64(*f).map_or(J::Null, J::Number),
+            Value::Str(s) => J::String(s.clone()),
+            Value::Bin(b) => J::String(String::from_utf8_lossy(b).into_owned()),
+            Value::Array(items) => J::Array(items.iter().map(Value::to_json).collect()),
+            Value::Map(entries) => J::Object(
+                entries
+                    .iter()
+                    .map(|(k, v)| {
+                        let key = match k {
+                            Value::Str(s) => s.clone(),
+                            other => other.to_json().to_string(),
+                        };
+                        (key, v.to_json())
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::Str(s.to_string())
+    }
+}
+
+impl From<i64> for Value {
+    fn from(i: i64) -> Self {
+        Value::Int(i)
+    }
+}
+
+/// Builds a string-keyed map.
+pub fn map<const N: usize>(entries: [(&str, Value); N]) -> Value {
+    Value::Map(
+        entries
+            .into_iter()
+            .map(|(k, v)| (Value::from(k), v))
+            .collect(),
+    )
+}
+
+fn write_len(out: &mut Vec<u8>, len: usize, fix: Option<(u8, usize)>, markers: [u8; 3]) {
+    match fix {
+        Some((base, max)) if len <= max => out.push(base | len as u8),
+        _ if len <= u8::MAX as usize && markers[0] != 0 => {
+            out.push(markers[0]);
+            out.push(len as u8);
+        }
+        _ if len <= u16::MAX as usize => {
+            out.push(markers[1]);
+            out.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        _ => {
+            out.push(markers[2]);
+            out.extend_from_slice(&(len as u32).to_be_bytes());
+        }
+    }
+}
+
+fn encode_into(value: &Value, out: &mut Vec<u8>) {
+    match value {
+        Value::Nil => out.push(0xc0),
+        Value::Bool(b) => out.push(if *b { 0xc3 } else { 0xc2 }),
+        Value::Int(i) => {
+            let i = *i;
+            if (0..=0x7f).contains(&i) || (-32..0).contains(&i) {
+                out.push(i as u8);
+            } else if i >= 0 {
+                encode_into(&Value::UInt(i as u64), out);
+            } else if i >= i8::MIN as i64 {
+                out.push(0xd0);
+                out.push(i as i8 as u8);
+            } else if i >= i16::MIN as i64 {
+                out.push(0xd1);
+                out.extend_from_slice(&(i as i16).to_be_bytes());
+            } else if i >= i32::MIN as i64 {
+                out.push(0xd2);
+                out.extend_from_slice(&(i as i32).to_be_bytes());
+            } else {
+                out.push(0xd3);
+                out.extend_from_slice(&i.to_be_bytes());
+            }
+        }
+        Value::UInt(u) => {
+            let u = *u;
+            if u <= 0x7f {
+                out.push(u as u8);
+            } else if u <= u8::MAX as u64 {
+                out.push(0xcc);
+                out.push(u as u8);
+            } else if u <= u16::MAX as u64 {
+                out.push(0xcd);
+                out.extend_from_slice(&(u as u16).to_be_bytes());
+            } else if u <= u32::MAX as u64 {
+                out.push(0xce);
+                out.extend_from_slice(&(u as u32).to_be_bytes());
+            } else {
+                out.push(0xcf);
+                out.extend_from_slice(&u.to_be_bytes());
+            }
+        }
+        Value::Float(f) => {
+            out.push(0xcb);
+            out.extend_from_slice(&f.to_be_bytes());
+        }
+        Value::Str(s) => {
+            write_len(out, s.len(), Some((0xa0, 31)), [0xd9, 0xda, 0xdb]);
+            out.extend_from_slice(s.as_bytes());
+        }
+        Value::Bin(b) => {
+            write_len(out, b.len(), None, [0xc4, 0xc5, 0xc6]);
+            out.extend_from_slice(b);
+        }
+        Value::Array(items) => {
+            write_len(out, items.len(), Some((0x90, 15)), [0, 0xdc, 0xdd]);
+            for item in items {
+                encode_into(item, out);
+            }
+        }
+        Value::Map(entries) => {
+            write_len(out, entries.len(), Some((0x80, 15)), [0, 0xde, 0xdf]);
+            for (k, v) in entries {
+                encode_into(k, out);
+                encode_into(v, out);
+            }
+        }
+    }
+}
+
+pub fn encode(value: &Value) -> Vec<u8> {
+    let mut out = Vec::new();
+    encode_into(value, &mut out);
+    out
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], DecodeErr> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| DecodeErr(format!("unexpected EOF at {}", self.pos)))?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, DecodeErr> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn uint(&mut self, n: usize) -> Result<u64, DecodeErr> {
+        Ok(self
+            .take(n)?
+            .iter()
+            .fold(0u64, |acc, b| (acc << 8) | *b as u64))
+    }
+
+    fn string(&mut self, len: usize) -> Result<Value, DecodeErr> {
+        let bytes = self.take(len)?;
+        Ok(Value::Str(String::from_utf8_lossy(bytes).into_owned()))
+    }
+
+    fn array(&mut self, len: usize, depth: usize) -> Result<Value, DecodeErr> {
+        // Every element takes at least a byte; don't trust the length blindly
+        let mut items = Vec::with_capacity(len.min(self.data.len() - self.pos));
+        for _ in 0..len {
+            items.push(self.value(depth + 1)?);
+        }
+        Ok(Value::Array(items))
+    }
+
+    fn map(&mut self, len: usize, depth: usize) -> Result<Value, DecodeErr> {
+        let mut entries = Vec::with_capacity(len.min(self.data.len() - self.pos));
+        for _ in 0..len {
+            let k = self.value(depth + 1)?;
+            let v = self.value(depth + 1)?;
+            entries.push((k, v));
+        }
+        Ok(Value::Map(entries))
+    }
+
+    fn ext(&mut self, len: usize) -> Result<Value, DecodeErr> {
+        self.take(len + 1)?; // type byte + data
+        Ok(Value::Nil)
+    }
+
+    fn value(&mut self, depth: usize) -> Result<Value, DecodeErr> {
+        if depth > 64 {
+            return Err(DecodeErr("nesting too deep".to_string()));
+        }
+        let b = self.byte()?;
+        Ok(match b {
+            0x00..=0x7f => Value::Int(b as i64),
+            0x80..=0x8f => return self.map((b & 0x0f) as usize, depth),
+            0x90..=0x9f => return self.array((b & 0x0f) as usize, depth),
+            0xa0..=0xbf => return self.string((b & 0x1f) as usize),
+            0xc0 => Value::Nil,
+            0xc2 => Value::Bool(false),
+            0xc3 => Value::Bool(true),
+            0xc4..=0xc6 => {
+                let len = self.uint(1 << (b - 0xc4))? as usize;
+                Value::Bin(self.take(len)?.to_vec())
+            }
+            0xc7..=0xc9 => {
+                let len = self.uint(1 << (b - 0xc7))? as usize;
+                return self.ext(len);
+            }
+            0xca => Value::Float(f32::from_bits(self.uint(4)? as u32) as f64),
+            0xcb => Value::Float(f64::from_bits(self.uint(8)?)),
+            0xcc..=0xcf => {
+                let u = self.uint(1 << (b - 0xcc))?;
+                i64::try_from(u).map_or(Value::UInt(u), Value::Int)
+            }
+            0xd0 => Value::Int(self.uint(1)? as u8 as i8 as i64),
+            0xd1 => Value::Int(self.uint(2)? as u16 as i16 as i64),
+            0xd2 => Value::Int(self.uint(4)? as u32 as i32 as i64),
+            0xd3 => Value::Int(self.uint(8)? as i64),
+            0xd4..=0xd8 => return self.ext(1 << (b - 0xd4)),
+            0xd9..=0xdb => {
+                let len = self.uint(1 << (b - 0xd9))? as usize;
+                return self.string(len);
+            }
+            0xdc | 0xdd => {
+                let len = self.uint(if b == 0xdc { 2 } else { 4 })? as usize;
+                return self.array(len, depth);
+            }
+            0xde | 0xdf => {
+                let len = self.uint(if b == 0xde { 2 } else { 4 })? as usize;
+                return self.map(len, depth);
+            }
+            0xe0..=0xff => Value::Int(b as i8 as i64),
+            0xc1 => return Err(DecodeErr(format!("reserved byte 0xc1 at {}", self.pos - 1))),
+        })
+    }
+}
+
+/// Decodes one value; trailing bytes are an error.
+pub fn decode(data: &[u8]) -> Result<Value, DecodeErr> {
+    let mut reader = Reader { data, pos: 0 };
+    let value = reader.value(0)?;
+    if reader.pos != data.len() {
+        return Err(DecodeErr(format!(
+            "{} trailing bytes",
+            data.len() - reader.pos
+        )));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_values() {
+        let value = map([
+            ("nil", Value::Nil),
+            ("yes", Value::Bool(true)),
+            ("small", Value::Int(7)),
+            ("neg", Value::Int(-5)),
+            ("i16", Value::Int(-300)),
+            ("u32", Value::Int(70_000)),
+            ("big", Value::UInt(u64::MAX)),
+            ("pi", Value::Float(3.5)),
+            ("long", Value::Str("弹".repeat(20))),
+            ("bin", Value::Bin(vec![1, 2, 3])),
+            ("list", Value::Array((0..20).map(Value::Int).collect())),
+        ]);
+        assert_eq!(decode(&encode(&value)).unwrap(), value);
+    }
+
+    #[test]
+    fn decodes_known_bytes() {
+        // {"197": "cc", 4: "hi"} as written by msgpack-python
+        let bytes = [
+            0x82, 0xa3, b'1', b'9', b'7', 0xa2, b'c', b'c', 0x04, 0xa2, b'h', b'i',
+        ];
+        let value = decode(&bytes).unwrap();
+        assert_eq!(value.get("197").and_then(Value::as_str), Some("cc"));
+        assert_eq!(value.get("4").and_then(Value::as_str), Some("hi"));
+        assert_eq!(value.to_json()["197"], "cc");
+
+        assert!(decode(&[0x92, 0x01]).is_err());
+        assert!(decode(&[0x01, 0x02]).is_err());
+        assert!(decode(&[0xdd, 0xff, 0xff, 0xff, 0xff]).is_err());
+    }
+}
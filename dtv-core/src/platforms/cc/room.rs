@@ -0,0 +1,282 @@
+// Room resolution for cc.163.com. The room page is a Next.js app; its
+// `__NEXT_DATA__` script carries the room, the anchor and, while live,
+// `quickplay.resolution.{quality}.cdn.{line}` FLV URLs plus an HLS `sharefile`.
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, ACCEPT_LANGUAGE, REFERER, USER_AGENT};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::platforms::common::errors::DtvError;
+use crate::platforms::common::{
+    LineCandidate, LineOption, QualityOption, StreamHeaders, StreamOptions,
+};
+
+pub(crate) const REFERER_URL: &str = "https://cc.163.com/";
+pub(crate) const DESKTOP_UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+// Resolution keys, best first, with the names DTV shows
+const QUALITIES: &[(&str, &str)] = &[
+    ("original", "原画"),
+    ("blueray", "蓝光"),
+    ("ultra", "超清"),
+    ("high", "高清"),
+    ("medium", "标清"),
+    ("standard", "标清"),
+];
+
+/// One FLV URL: a quality on a CDN line.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CcStream {
+    pub url: String,
+    // CC's resolution key, e.g. "blueray"
+    pub quality_key: String,
+    pub quality: String,
+    // CDN key, e.g. "ali", "ks", "ws"
+    pub line: String,
+    pub vbr: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CcRoom {
+    pub room_id: String,
+    // Needed to join the danmaku room
+    pub channel_id: Option<i64>,
+    pub gametype: Option<i64>,
+    pub ccid: Option<i64>,
+    pub is_live: bool,
+    pub title: Option<String>,
+    pub anchor_name: Option<String>,
+    pub avatar: Option<String>,
+    pub cover: Option<String>,
+    // Best quality first
+    pub streams: Vec<CcStream>,
+    pub hls_url: Option<String>,
+}
+
+/// Accepts `cc.163.com/{id}` URLs (with or without scheme) and bare ids.
+pub fn parse_room_input(input: &str) -> Option<String> {
+    let input = input.trim();
+    let without_scheme = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .unwrap_or(input);
+    let id = match without_scheme.split_once('/') {
+        Some((host, rest)) if host.ends_with("cc.163.com") => {
+            rest.split(['/', '?', '#']).next().unwrap_or("")
+        }
+        Some(_) => return None,
+        None => without_scheme,
+    };
+    (!id.is_empty() && id.len() <= 20 && id.chars().all(|c| c.is_ascii_digit()))
+        .then(|| id.to_string())
+}
+
+fn text(v: &Value, key: &str) -> Option<String> {
+    match v.get(key)? {
+        Value::String(s) if !s.trim().is_empty() => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn int(v: &Value, key: &str) -> Option<i64> {
+    match v.get(key)? {
+        Value::Number(n) => n.as_i64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+fn streams(quickplay: &Value) -> Vec<CcStream> {
+    let Some(resolution) = quickplay.get("resolution").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    let mut keys: Vec<&String> = resolution.keys().collect();
+    // Known keys by rank, unknown ones after them by bitrate
+    let rank = |k: &str| QUALITIES.iter().position(|(key, _)| *key == k);
+    let vbr = |k: &str| int(&resolution[k], "vbr").unwrap_or(0);
+    keys.sort_by(|a, b| match (rank(a), rank(b)) {
+        (Some(x), Some(y)) => x.cmp(&y),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => vbr(b).cmp(&vbr(a)),
+    });
+
+    let mut streams = Vec::new();
+    for key in keys {
+        let entry = &resolution[key.as_str()];
+        let Some(cdn) = entry.get("cdn").and_then(Value::as_object) else {
+            continue;
+        };
+        let quality = QUALITIES
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, name)| name.to_string())
+            .unwrap_or_else(|| key.clone());
+        for (line, url) in cdn {
+            let Some(url) = url.as_str().filter(|u| u.starts_with("http")) else {
+                continue;
+            };
+            streams.push(CcStream {
+                url: url.to_string(),
+                quality_key: key.clone(),
+                quality: quality.clone(),
+                line: line.clone(),
+                vbr: int(entry, "vbr"),
+            });
+        }
+    }
+    streams
+}
+
+/// The room in a page's `__NEXT_DATA__`.
+pub fn parse_room_page(room_id: &str, html: &str) -> Result<CcRoom, DtvError> {
+    let start = html
+        .find("id=\"__NEXT_DATA__\"")
+        .and_then(|i| html[i..].find('>').map(|j| i + j + 1))
+        .ok_or_else(|| DtvError::api("CC page has no __NEXT_DATA__"))?;
+    let end = html[start..]
+        .find("</script>")
+        .map(|j| start + j)
+        .ok_or_else(|| DtvError::api("CC page: unterminated __NEXT_DATA__"))?;
+    let data: Value = serde_json::from_str(&html[start..end])
+        .map_err(|e| DtvError::api(format!("CC __NEXT_DATA__: {}", e)))?;
+    let info = data
+        .pointer("/props/pageProps/roomInfoInitData")
+        .filter(|v| v.is_object())
+        .ok_or_else(|| DtvError::api(format!("CC room {} does not exist", room_id)))?;
+    parse_room_info(room_id, info)
+}
+
+/// `roomInfoInitData` from the room page.
+pub fn parse_room_info(room_id: &str, info: &Value) -> Result<CcRoom, DtvError> {
+    let live = info.get("live").cloned().unwrap_or(Value::Null);
+    let anchor = info.get("micfirst").cloned().unwrap_or(Value::Null);
+    let quickplay = live.get("quickplay").cloned().unwrap_or(Value::Null);
+    let streams = streams(&quickplay);
+    let hls_url = text(&live, "sharefile").filter(|u| u.starts_with("http"));
+    // `status` is 1 while live; older pages only had quickplay
+    let is_live = match int(&live, "status") {
+        Some(status) => status == 1,
+        None => !streams.is_empty(),
+    };
+    Ok(CcRoom {
+        room_id: text(&live, "cuteid").unwrap_or_else(|| room_id.to_string()),
+        channel_id: int(&live, "channel_id").or_else(|| int(&live, "cid")),
+        gametype: int(&live, "gametype"),
+        ccid: int(&live, "ccid").or_else(|| int(&anchor, "ccid")),
+        is_live,
+        title: text(&live, "title"),
+        anchor_name: text(&live, "nickname").or_else(|| text(&anchor, "nickname")),
+        avatar: text(&anchor, "purl").or_else(|| text(&live, "purl")),
+        cover: text(&live, "poster").or_else(|| text(&live, "cover")),
+        streams: if is_live { streams } else { Vec::new() },
+        hls_url: hls_url.filter(|_| is_live),
+    })
+}
+
+/// Picks a quality, then a line within it. `原画` (or nothing) is the best
+/// quality; an unknown quality or line falls back to the best / first one.
+pub fn select_stream<'a>(
+    streams: &'a [CcStream],
+    quality: Option<&str>,
+    line: Option<&str>,
+) -> Option<&'a CcStream> {
+    let best_key = &streams.first()?.quality_key;
+    let key = match quality.map(str::trim) {
+        None | Some("") | Some("原画") => best_key,
+        Some(q) => streams
+            .iter()
+            .find(|s| s.quality == q || s.quality_key == q)
+            .map(|s| &s.quality_key)
+            .unwrap_or(best_key),
+    };
+    let mut candidates = streams.iter().filter(|s| &s.quality_key == key);
+    let first = candidates.clone().next();
+    line.and_then(|l| candidates.find(|s| s.line == l.trim()))
+        .or(first)
+}
+
+/// Qualities (by resolution key) and CDN lines, in stream order.
+pub fn stream_options(streams: &[CcStream]) -> StreamOptions {
+    let mut options = StreamOptions::default();
+    for s in streams {
+        if !options.qualities.iter().any(|q| q.id == s.quality_key) {
+            options.qualities.push(QualityOption {
+                id: s.quality_key.clone(),
+                label: s.quality.clone(),
+                bitrate: s.vbr,
+                codec: None,
+            });
+        }
+        if !options.lines.iter().any(|l| l.id == s.line) {
+            options.lines.push(LineOption {
+                id: s.line.clone(),
+                label: s.line.to_uppercase(),
+            });
+        }
+    }
+    options
+}
+
+/// Headers CC's FLV CDNs expect.
+pub fn stream_headers() -> StreamHeaders {
+    crate::platforms::common::stream_headers(DESKTOP_UA, REFERER_URL, None)
+}
+
+/// The best quality on each line, for the line prober.
+pub fn line_candidates(streams: &[CcStream]) -> Vec<LineCandidate> {
+    let options = stream_options(streams);
+    options
+        .lines
+        .into_iter()
+        .filter_map(|line| {
+            let stream = select_stream(streams, None, Some(&line.id))?;
+            (stream.line == line.id).then(|| {
+                LineCandidate::new(line.id, line.label, stream.url.clone())
+                    .with_headers(stream_headers())
+            })
+        })
+        .collect()
+}
+
+/// Room info and streams for `input` (URL or room id).
+pub async fn fetch_room(client: &reqwest::Client, input: &str) -> Result<CcRoom, DtvError> {
+    let room_id = parse_room_input(input)
+        .ok_or_else(|| DtvError::api(format!("Invalid CC room: {}", input)))?;
+    let mut headers = HeaderMap::new();
+    headers.insert(USER_AGENT, HeaderValue::from_static(DESKTOP_UA));
+    headers.insert(REFERER, HeaderValue::from_static(REFERER_URL));
+    headers.insert(ACCEPT, HeaderValue::from_static("text/html,*/*;q=0.8"));
+    headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("zh-CN,zh;q=0.9"));
+    let html = client
+        .get(format!("https://cc.163.com/{}/", room_id))
+        .headers(headers)
+        .send()
+        .await
+        .map_err(|e| DtvError::network(format!("CC room page: {}", e)))?
+        .text()
+        .await
+        .map_err(|e| DtvError::network(format!("CC room page: {}", e)))?;
+    parse_room_page(&room_id, &html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_room_inputs() {
+        assert_eq!(parse_room_input(" 361433 ").as_deref(), Some("361433"));
+        assert_eq!(
+            parse_room_input("https://cc.163.com/361433/?from=follow").as_deref(),
+            Some("361433")
+        );
+        assert_eq!(
+            parse_room_input("cc.163.com/361433").as_deref(),
+            Some("361433")
+        );
+        assert_eq!(parse_room_input("https://example.com/361433"), None);
+        assert_eq!(parse_room_input("https://cc.163.com/category/"), None);
+        assert_eq!(parse_room_input("abc"), None);
+    }
+}
@@ -15,6 +15,10 @@ pub enum DtvError {
     
     #[error("Internal error: {0}")]
     Internal(String),
+
+    // Needs a cargo feature this build was compiled without
+    #[error("Unsupported: {0}")]
+    Unsupported(String),
 }
 
 impl DtvError {
@@ -37,6 +41,10 @@ impl DtvError {
     pub fn internal(msg: impl Into<String>) -> Self {
         DtvError::Internal(msg.into())
     }
+
+    pub fn unsupported(msg: impl Into<String>) -> Self {
+        DtvError::Unsupported(msg.into())
+    }
 }
 
 pub fn classify_error_message(message: &str) -> DtvError {
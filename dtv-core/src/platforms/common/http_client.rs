@@ -4,7 +4,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 pub const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/109.0.0.0 Safari/537.36";
-const DEFAULT_TIMEOUT_SECONDS: u64 = 20;
+pub const DEFAULT_TIMEOUT_SECONDS: u64 = 20;
 const FOLLOW_POOL_MAX_IDLE_PER_HOST: usize = 2;
 const FOLLOW_POOL_IDLE_TIMEOUT_SECONDS: u64 = 15;
 
@@ -287,4 +287,33 @@ impl FollowHttpClient {
             FOLLOW_POOL_MAX_IDLE_PER_HOST,
         )?))
     }
+
+    /// 自定义 UA / 超时；设置 UA 时也作用于直接使用 `inner` 的请求
+    pub fn with_options(user_agent: Option<&str>, timeout: Duration) -> Result<Self, String> {
+        let ua = user_agent.unwrap_or(DEFAULT_USER_AGENT);
+        let mut default_headers = ReqwestHeaderMap::new();
+        default_headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(ua).map_err(|e| format!("Invalid user agent: {}", e))?,
+        );
+
+        let mut client_builder = Client::builder()
+            .timeout(timeout)
+            .cookie_provider(Arc::new(Jar::default()))
+            .no_proxy()
+            .pool_max_idle_per_host(FOLLOW_POOL_MAX_IDLE_PER_HOST)
+            .pool_idle_timeout(Duration::from_secs(FOLLOW_POOL_IDLE_TIMEOUT_SECONDS));
+        if let Some(ua) = user_agent {
+            client_builder = client_builder.user_agent(ua);
+        }
+
+        let inner_client = client_builder
+            .build()
+            .map_err(|e| format!("Failed to build direct connection reqwest client: {}", e))?;
+
+        Ok(Self(HttpClient {
+            inner: inner_client,
+            headers: default_headers,
+        }))
+    }
 }
@@ -1,11 +1,18 @@
-use std::sync::mpsc::{self, Sender};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
 
-use deno_core::FastString;
+use deno_core::v8::IsolateHandle;
+use deno_core::{Extension, FastString, JsRuntime};
+use serde::Serialize;
 use tokio::sync::oneshot;
 
 use crate::platforms::common::js_runtime::{
-    bootstrap_basic_browser_env, ensure_js_runtime_platform_initialized, new_js_runtime,
+    bootstrap_basic_browser_env, ensure_js_runtime_platform_initialized,
+    new_js_runtime_with_heap_limit,
 };
 
 struct EvalJob {
@@ -13,67 +20,380 @@ struct EvalJob {
     respond_to: oneshot::Sender<Result<String, String>>,
 }
 
-pub struct JsFunctionWorker {
-    tx: Sender<EvalJob>,
+#[derive(Debug, Clone)]
+pub struct JsWorkerOptions {
+    // Number of V8 threads sharing the job queue
+    pub pool_size: usize,
+    // Wall-clock limit for one eval; the isolate is terminated past it
+    pub job_timeout: Duration,
+    // How long a caller waits in total, queueing included
+    pub queue_timeout: Duration,
+    pub max_heap_bytes: usize,
+    // Pause before rebuilding a runtime whose bootstrap failed
+    pub restart_backoff: Duration,
+    // Ops for every runtime; a factory because each runtime consumes its extensions
+    pub extensions: Option<fn() -> Vec<Extension>>,
 }
 
-impl JsFunctionWorker {
-    pub fn new(
-        user_agent: &'static str,
-        preload_scripts: Vec<(&'static str, &'static str)>,
-    ) -> Result<Self, String> {
-        let (tx, rx) = mpsc::channel::<EvalJob>();
+impl Default for JsWorkerOptions {
+    fn default() -> Self {
+        Self {
+            pool_size: 1,
+            job_timeout: Duration::from_secs(5),
+            queue_timeout: Duration::from_secs(15),
+            max_heap_bytes: 64 * 1024 * 1024,
+            restart_backoff: Duration::from_millis(500),
+            extensions: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct JsWorkerMetrics {
+    pub jobs_ok: u64,
+    pub jobs_failed: u64,
+    pub timeouts: u64,
+    pub heap_limit_hits: u64,
+    pub restarts: u64,
+    pub bootstrap_failures: u64,
+    pub total_eval_ms: u64,
+}
+
+#[derive(Default)]
+struct MetricsInner {
+    jobs_ok: AtomicU64,
+    jobs_failed: AtomicU64,
+    timeouts: AtomicU64,
+    heap_limit_hits: AtomicU64,
+    restarts: AtomicU64,
+    bootstrap_failures: AtomicU64,
+    total_eval_ms: AtomicU64,
+}
+
+impl MetricsInner {
+    fn snapshot(&self) -> JsWorkerMetrics {
+        JsWorkerMetrics {
+            jobs_ok: self.jobs_ok.load(Ordering::Relaxed),
+            jobs_failed: self.jobs_failed.load(Ordering::Relaxed),
+            timeouts: self.timeouts.load(Ordering::Relaxed),
+            heap_limit_hits: self.heap_limit_hits.load(Ordering::Relaxed),
+            restarts: self.restarts.load(Ordering::Relaxed),
+            bootstrap_failures: self.bootstrap_failures.load(Ordering::Relaxed),
+            total_eval_ms: self.total_eval_ms.load(Ordering::Relaxed),
+        }
+    }
+}
+
+struct WorkerConfig {
+    user_agent: &'static str,
+    preload_scripts: Vec<(&'static str, &'static str)>,
+    options: JsWorkerOptions,
+    metrics: Arc<MetricsInner>,
+}
+
+enum EvalOutcome {
+    Done(Result<String, String>),
+    // The isolate was terminated or panicked and must be rebuilt
+    Poisoned(String),
+}
 
+// One per worker thread, for the worker's lifetime: terminates the isolate
+// of an eval still running at its deadline
+struct Watchdog {
+    shared: Arc<WatchdogShared>,
+}
+
+#[derive(Default)]
+struct WatchdogShared {
+    state: Mutex<WatchdogState>,
+    wake: Condvar,
+}
+
+#[derive(Default)]
+struct WatchdogState {
+    armed: Option<(Instant, IsolateHandle)>,
+    fired: bool,
+    stopped: bool,
+}
+
+impl Watchdog {
+    fn spawn(name: String) -> Result<Self, String> {
+        let shared = Arc::new(WatchdogShared::default());
+        let watched = shared.clone();
         thread::Builder::new()
-            .name("js-function-worker".to_string())
-            .spawn(move || {
-                ensure_js_runtime_platform_initialized();
-                let mut runtime = new_js_runtime();
-
-                if let Err(e) = bootstrap_basic_browser_env(&mut runtime, user_agent) {
-                    // If we can't bootstrap, fail all incoming jobs.
-                    while let Ok(job) = rx.recv() {
-                        let _ = job.respond_to.send(Err(format!(
-                            "js runtime bootstrap failed: {}",
-                            e
-                        )));
+            .name(name)
+            .spawn(move || watched.run())
+            .map_err(|e| format!("Failed to spawn js watchdog thread: {e}"))?;
+        Ok(Self { shared })
+    }
+
+    fn arm(&self, isolate: IsolateHandle, deadline: Instant) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.armed = Some((deadline, isolate));
+        state.fired = false;
+        self.shared.wake.notify_one();
+    }
+
+    // True if the deadline passed and the isolate was terminated
+    fn disarm(&self) -> bool {
+        let mut state = self.shared.state.lock().unwrap();
+        state.armed = None;
+        std::mem::take(&mut state.fired)
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.shared.state.lock() {
+            state.stopped = true;
+        }
+        self.shared.wake.notify_one();
+    }
+}
+
+impl WatchdogShared {
+    fn run(&self) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        while !state.stopped {
+            let wait = match &state.armed {
+                None => None,
+                Some((deadline, isolate)) => {
+                    let now = Instant::now();
+                    if *deadline <= now {
+                        isolate.terminate_execution();
+                        state.armed = None;
+                        state.fired = true;
+                        continue;
                     }
-                    return;
+                    Some(*deadline - now)
                 }
+            };
+            // A poisoned lock means the worker is gone
+            let woken = match wait {
+                None => self.wake.wait(state).ok(),
+                Some(wait) => self
+                    .wake
+                    .wait_timeout(state, wait)
+                    .ok()
+                    .map(|(state, _)| state),
+            };
+            let Some(woken) = woken else {
+                return;
+            };
+            state = woken;
+        }
+    }
+}
+
+struct LiveRuntime {
+    runtime: JsRuntime,
+    heap_exhausted: Arc<AtomicBool>,
+}
+
+impl LiveRuntime {
+    fn boot(config: &WorkerConfig) -> Result<Self, String> {
+        let extensions = config.options.extensions.map(|f| f()).unwrap_or_default();
+        let mut runtime =
+            new_js_runtime_with_heap_limit(config.options.max_heap_bytes, extensions);
 
-                for (name, source) in preload_scripts {
-                    if let Err(e) = runtime.execute_script(name, FastString::from_static(source)) {
-                        while let Ok(job) = rx.recv() {
-                            let _ = job.respond_to.send(Err(format!(
-                                "js preload script failed ({}): {}",
-                                name, e
-                            )));
+        let heap_exhausted = Arc::new(AtomicBool::new(false));
+        let isolate_handle = runtime.v8_isolate().thread_safe_handle();
+        let flag = heap_exhausted.clone();
+        runtime.add_near_heap_limit_callback(move |current, _initial| {
+            flag.store(true, Ordering::SeqCst);
+            isolate_handle.terminate_execution();
+            // Headroom so V8 can unwind instead of aborting the process
+            current * 2
+        });
+
+        bootstrap_basic_browser_env(&mut runtime, config.user_agent)
+            .map_err(|e| format!("js runtime bootstrap failed: {}", e))?;
+        for (name, source) in &config.preload_scripts {
+            runtime
+                .execute_script(name, FastString::from_static(source))
+                .map_err(|e| format!("js preload script failed ({}): {}", name, e))?;
+        }
+
+        Ok(Self {
+            runtime,
+            heap_exhausted,
+        })
+    }
+
+    fn eval(
+        &mut self,
+        expr: String,
+        timeout: Duration,
+        watchdog: &Watchdog,
+        metrics: &MetricsInner,
+    ) -> EvalOutcome {
+        let isolate_handle = self.runtime.v8_isolate().thread_safe_handle();
+        watchdog.arm(isolate_handle, Instant::now() + timeout);
+
+        let runtime = &mut self.runtime;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            match runtime.execute_script("[js-worker-eval]", FastString::from(expr)) {
+                Ok(v8_value) => {
+                    let scope = &mut runtime.handle_scope();
+                    let mut local_value = deno_core::v8::Local::new(scope, v8_value);
+                    // Async functions: there is no event loop, so only promises
+                    // that settle on a microtask checkpoint (sync ops) resolve
+                    if let Ok(promise) =
+                        deno_core::v8::Local::<deno_core::v8::Promise>::try_from(local_value)
+                    {
+                        scope.perform_microtask_checkpoint();
+                        match promise.state() {
+                            deno_core::v8::PromiseState::Fulfilled => {
+                                local_value = promise.result(scope)
+                            }
+                            deno_core::v8::PromiseState::Rejected => {
+                                return Err(format!(
+                                    "js promise rejected: {}",
+                                    promise.result(scope).to_rust_string_lossy(scope)
+                                ))
+                            }
+                            deno_core::v8::PromiseState::Pending => {
+                                return Err("js promise did not settle".to_string())
+                            }
                         }
-                        return;
+                    }
+                    if local_value.is_string() {
+                        Ok(local_value.to_rust_string_lossy(scope))
+                    } else {
+                        Err("js eval did not return a string".to_string())
                     }
                 }
+                Err(e) => Err(format!("js eval failed: {}", e)),
+            }
+        }));
 
-                while let Ok(job) = rx.recv() {
-                    let result = runtime.execute_script("[js-worker-eval]", FastString::from(job.expr));
-                    let response = match result {
-                        Ok(v8_value) => {
-                            let scope = &mut runtime.handle_scope();
-                            let local_value = deno_core::v8::Local::new(scope, v8_value);
-                            if local_value.is_string() {
-                                Ok(local_value.to_rust_string_lossy(scope))
-                            } else {
-                                Err("js eval did not return a string".to_string())
-                            }
-                        }
-                        Err(e) => Err(format!("js eval failed: {}", e)),
-                    };
+        if watchdog.disarm() {
+            metrics.timeouts.fetch_add(1, Ordering::Relaxed);
+            return EvalOutcome::Poisoned(format!("js eval timed out after {:?}", timeout));
+        }
+        if self.heap_exhausted.load(Ordering::SeqCst) {
+            metrics.heap_limit_hits.fetch_add(1, Ordering::Relaxed);
+            return EvalOutcome::Poisoned("js eval exceeded the heap limit".to_string());
+        }
+        match result {
+            Ok(r) => EvalOutcome::Done(r),
+            Err(_) => EvalOutcome::Poisoned("js eval panicked".to_string()),
+        }
+    }
+}
+
+fn worker_loop(config: Arc<WorkerConfig>, jobs: Arc<Mutex<Receiver<EvalJob>>>, watchdog: Watchdog) {
+    ensure_js_runtime_platform_initialized();
+    let metrics = &config.metrics;
+    let mut live: Option<LiveRuntime> = None;
+    let mut booted_once = false;
 
-                    let _ = job.respond_to.send(response);
+    loop {
+        let job = match jobs.lock() {
+            Ok(rx) => match rx.recv() {
+                Ok(job) => job,
+                Err(_) => return,
+            },
+            Err(_) => return,
+        };
+        // The caller already gave up waiting
+        if job.respond_to.is_closed() {
+            continue;
+        }
+
+        if live.is_none() {
+            match LiveRuntime::boot(&config) {
+                Ok(rt) => {
+                    if booted_once {
+                        metrics.restarts.fetch_add(1, Ordering::Relaxed);
+                    }
+                    booted_once = true;
+                    live = Some(rt);
                 }
-            })
-            .map_err(|e| format!("Failed to spawn js worker thread: {e}"))?;
+                Err(e) => {
+                    eprintln!("[JsFunctionWorker] {}", e);
+                    metrics.bootstrap_failures.fetch_add(1, Ordering::Relaxed);
+                    metrics.jobs_failed.fetch_add(1, Ordering::Relaxed);
+                    let _ = job.respond_to.send(Err(e));
+                    // Next job retries the bootstrap
+                    thread::sleep(config.options.restart_backoff);
+                    continue;
+                }
+            }
+        }
+
+        let Some(rt) = live.as_mut() else {
+            continue;
+        };
+        let started = Instant::now();
+        let response = match rt.eval(job.expr, config.options.job_timeout, &watchdog, metrics) {
+            EvalOutcome::Done(r) => r,
+            EvalOutcome::Poisoned(e) => {
+                eprintln!("[JsFunctionWorker] {}, recreating runtime", e);
+                live = None;
+                Err(e)
+            }
+        };
+        metrics
+            .total_eval_ms
+            .fetch_add(started.elapsed().as_millis() as u64, Ordering::Relaxed);
+        match &response {
+            Ok(_) => metrics.jobs_ok.fetch_add(1, Ordering::Relaxed),
+            Err(_) => metrics.jobs_failed.fetch_add(1, Ordering::Relaxed),
+        };
 
-        Ok(Self { tx })
+        let _ = job.respond_to.send(response);
+    }
+}
+
+pub struct JsFunctionWorker {
+    tx: Sender<EvalJob>,
+    queue_timeout: Duration,
+    metrics: Arc<MetricsInner>,
+}
+
+impl JsFunctionWorker {
+    pub fn new(
+        user_agent: &'static str,
+        preload_scripts: Vec<(&'static str, &'static str)>,
+    ) -> Result<Self, String> {
+        Self::with_options(user_agent, preload_scripts, JsWorkerOptions::default())
+    }
+
+    pub fn with_options(
+        user_agent: &'static str,
+        preload_scripts: Vec<(&'static str, &'static str)>,
+        options: JsWorkerOptions,
+    ) -> Result<Self, String> {
+        let (tx, rx) = mpsc::channel::<EvalJob>();
+        let jobs = Arc::new(Mutex::new(rx));
+        let metrics = Arc::new(MetricsInner::default());
+        let queue_timeout = options.queue_timeout;
+        let pool_size = options.pool_size.max(1);
+        let config = Arc::new(WorkerConfig {
+            user_agent,
+            preload_scripts,
+            options,
+            metrics: metrics.clone(),
+        });
+
+        for i in 0..pool_size {
+            let config = config.clone();
+            let jobs = jobs.clone();
+            let watchdog = Watchdog::spawn(format!("js-function-watchdog-{}", i))?;
+            thread::Builder::new()
+                .name(format!("js-function-worker-{}", i))
+                .spawn(move || worker_loop(config, jobs, watchdog))
+                .map_err(|e| format!("Failed to spawn js worker thread: {e}"))?;
+        }
+
+        Ok(Self {
+            tx,
+            queue_timeout,
+            metrics,
+        })
     }
 
     pub async fn eval_string(&self, expr: String) -> Result<String, String> {
@@ -85,7 +405,18 @@ impl JsFunctionWorker {
             })
             .map_err(|e| format!("Failed to send js job: {e}"))?;
 
-        rx.await
-            .map_err(|e| format!("Failed to receive js response: {e}"))?
+        match tokio::time::timeout(self.queue_timeout, rx).await {
+            Ok(received) => {
+                received.map_err(|e| format!("Failed to receive js response: {e}"))?
+            }
+            Err(_) => Err(format!(
+                "js job not answered within {:?}",
+                self.queue_timeout
+            )),
+        }
+    }
+
+    pub fn metrics(&self) -> JsWorkerMetrics {
+        self.metrics.snapshot()
     }
 }
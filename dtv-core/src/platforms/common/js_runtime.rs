@@ -1,4 +1,4 @@
-use deno_core::{FastString, JsRuntime, RuntimeOptions};
+use deno_core::{Extension, FastString, JsRuntime, RuntimeOptions};
 
 #[cfg(target_os = "linux")]
 use std::sync::Once;
@@ -33,3 +33,16 @@ pub fn bootstrap_basic_browser_env(
 pub fn new_js_runtime() -> JsRuntime {
     JsRuntime::new(RuntimeOptions::default())
 }
+
+// Same as `new_js_runtime`, but with the V8 heap capped at `max_heap_bytes`
+// and the given extensions (ops) registered.
+pub fn new_js_runtime_with_heap_limit(
+    max_heap_bytes: usize,
+    extensions: Vec<Extension>,
+) -> JsRuntime {
+    JsRuntime::new(RuntimeOptions {
+        create_params: Some(deno_core::v8::CreateParams::default().heap_limits(0, max_heap_bytes)),
+        extensions,
+        ..Default::default()
+    })
+}
@@ -0,0 +1,354 @@
+// CDN line speed test. Each candidate stream of a room is pulled for a short
+// window, all at once, and scored by connect time, time to first byte and
+// throughput. Results are cached per platform, line and network, since the
+// fastest CDN depends on where (and on which ISP) the user is.
+use std::collections::HashMap;
+use std::net::UdpSocket;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tokio::net::TcpStream;
+use tokio::task::JoinSet;
+
+use super::live_stream_v2::StreamHeaders;
+
+/// `line` value that asks for the fastest measured line.
+pub const AUTO_LINE: &str = "auto";
+
+// How long each candidate is read once its first byte arrives
+const PROBE_WINDOW: Duration = Duration::from_secs(3);
+// Connect or first byte later than this counts as a failure
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+const CACHE_TTL: Duration = Duration::from_secs(30 * 60);
+// Throughput within one bucket is a tie, decided by latency
+const THROUGHPUT_BUCKET_KBPS: u64 = 500;
+
+/// One line of a room and the stream URL to measure it with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineCandidate {
+    pub line: String,
+    pub label: String,
+    pub url: String,
+    // As `Playback.headers` would carry them
+    pub headers: StreamHeaders,
+}
+
+impl LineCandidate {
+    pub fn new(line: impl Into<String>, label: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            line: line.into(),
+            label: label.into(),
+            url: url.into(),
+            headers: StreamHeaders::new(),
+        }
+    }
+
+    pub fn with_headers(mut self, headers: StreamHeaders) -> Self {
+        self.headers = headers;
+        self
+    }
+}
+
+/// What one line measured.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct LineProbe {
+    pub line: String,
+    pub label: String,
+    pub host: String,
+    // TCP connect, DNS included
+    pub connect_ms: Option<u64>,
+    // Request sent to first body byte
+    pub ttfb_ms: Option<u64>,
+    pub throughput_kbps: Option<u64>,
+    pub bytes: u64,
+    pub error: Option<String>,
+    // Served from the cache instead of measured now
+    pub cached: bool,
+}
+
+impl LineProbe {
+    fn failed(candidate: &LineCandidate, error: String) -> Self {
+        Self {
+            line: candidate.line.clone(),
+            label: candidate.label.clone(),
+            host: host_of(&candidate.url),
+            connect_ms: None,
+            ttfb_ms: None,
+            throughput_kbps: None,
+            bytes: 0,
+            error: Some(error),
+            cached: false,
+        }
+    }
+
+    // Working lines first, then by throughput bucket, then by latency
+    fn sort_key(&self) -> (bool, std::cmp::Reverse<u64>, u64) {
+        let bucket = self.throughput_kbps.unwrap_or(0) / THROUGHPUT_BUCKET_KBPS;
+        let latency = self.connect_ms.unwrap_or(0) + self.ttfb_ms.unwrap_or(u64::MAX / 2);
+        (self.error.is_some(), std::cmp::Reverse(bucket), latency)
+    }
+}
+
+fn host_of(url: &str) -> String {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Best first; lines that failed go last.
+pub fn rank_probes(probes: &mut [LineProbe]) {
+    probes.sort_by_key(LineProbe::sort_key);
+}
+
+async fn connect_ms(url: &str) -> Result<u64, String> {
+    let parsed = url::Url::parse(url).map_err(|e| e.to_string())?;
+    let host = parsed.host_str().ok_or("URL has no host")?.to_string();
+    let port = parsed.port_or_known_default().unwrap_or(443);
+    let started = Instant::now();
+    match tokio::time::timeout(PROBE_TIMEOUT, TcpStream::connect((host.as_str(), port))).await {
+        Ok(Ok(_)) => Ok(started.elapsed().as_millis() as u64),
+        Ok(Err(e)) => Err(format!("connect: {}", e)),
+        Err(_) => Err("connect: timed out".to_string()),
+    }
+}
+
+/// Measure one line: connect time, then a GET read for `window` after its
+/// first byte.
+pub async fn probe_line(
+    client: &reqwest::Client,
+    candidate: &LineCandidate,
+    window: Duration,
+) -> LineProbe {
+    let connect = match connect_ms(&candidate.url).await {
+        Ok(ms) => ms,
+        Err(e) => return LineProbe::failed(candidate, e),
+    };
+
+    let mut request = client.get(&candidate.url);
+    for (name, value) in &candidate.headers {
+        request = request.header(name.as_str(), value.as_str());
+    }
+    let started = Instant::now();
+    let first = tokio::time::timeout(PROBE_TIMEOUT, async {
+        let mut resp = request
+            .send()
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| e.to_string())?;
+        let chunk = resp.chunk().await.map_err(|e| e.to_string())?;
+        Ok::<_, String>((resp, chunk))
+    })
+    .await;
+    let (mut resp, chunk) = match first {
+        Ok(Ok((resp, Some(chunk)))) => (resp, chunk),
+        Ok(Ok((_, None))) => return LineProbe::failed(candidate, "empty response".to_string()),
+        Ok(Err(e)) => return LineProbe::failed(candidate, e),
+        Err(_) => return LineProbe::failed(candidate, "first byte: timed out".to_string()),
+    };
+    let ttfb = started.elapsed();
+
+    // Count what arrives within the window; a stream never ends on its own
+    let mut bytes = chunk.len() as u64;
+    let reading = Instant::now();
+    let _ = tokio::time::timeout(window, async {
+        while let Ok(Some(chunk)) = resp.chunk().await {
+            bytes += chunk.len() as u64;
+        }
+    })
+    .await;
+    let elapsed = reading.elapsed().as_secs_f64().max(0.001);
+
+    LineProbe {
+        line: candidate.line.clone(),
+        label: candidate.label.clone(),
+        host: host_of(&candidate.url),
+        connect_ms: Some(connect),
+        ttfb_ms: Some(ttfb.as_millis() as u64),
+        throughput_kbps: Some((bytes as f64 * 8.0 / 1000.0 / elapsed) as u64),
+        bytes,
+        error: None,
+        cached: false,
+    }
+}
+
+/// Measure every candidate concurrently; best first.
+pub async fn probe_lines(client: &reqwest::Client, candidates: &[LineCandidate]) -> Vec<LineProbe> {
+    let mut tasks = JoinSet::new();
+    for (index, candidate) in candidates.iter().enumerate() {
+        let client = client.clone();
+        let candidate = candidate.clone();
+        tasks.spawn(async move { (index, probe_line(&client, &candidate, PROBE_WINDOW).await) });
+    }
+    let mut probes: Vec<(usize, LineProbe)> = Vec::with_capacity(candidates.len());
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok(probe) = joined {
+            probes.push(probe);
+        }
+    }
+    // Keep the platform's order among equals
+    probes.sort_by_key(|(index, _)| *index);
+    let mut probes: Vec<LineProbe> = probes.into_iter().map(|(_, p)| p).collect();
+    rank_probes(&mut probes);
+    probes
+}
+
+/// Identifies the network by the local address of the default route; no
+/// packet is sent. Changes when switching Wi-Fi, VPN or ISP.
+pub fn network_id() -> String {
+    UdpSocket::bind("0.0.0.0:0")
+        .and_then(|socket| {
+            socket.connect("223.5.5.5:53")?;
+            socket.local_addr()
+        })
+        .map(|addr| addr.ip().to_string())
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+// (platform, line, network)
+type CacheKey = (String, String, String);
+
+/// Probe results per (platform, line, network), valid for half an hour.
+#[derive(Default)]
+pub struct LineProbeCache {
+    entries: Mutex<HashMap<CacheKey, (Instant, LineProbe)>>,
+}
+
+impl LineProbeCache {
+    pub fn store(&self, platform: &str, network: &str, probes: &[LineProbe]) {
+        if let Ok(mut entries) = self.entries.lock() {
+            let now = Instant::now();
+            entries.retain(|_, (at, _)| now.duration_since(*at) < CACHE_TTL);
+            for probe in probes {
+                entries.insert(
+                    (
+                        platform.to_string(),
+                        probe.line.clone(),
+                        network.to_string(),
+                    ),
+                    (now, probe.clone()),
+                );
+            }
+        }
+    }
+
+    /// Cached results for all `lines`, ranked; None if any is missing or stale.
+    pub fn lookup(&self, platform: &str, network: &str, lines: &[&str]) -> Option<Vec<LineProbe>> {
+        let entries = self.entries.lock().ok()?;
+        let mut probes = lines
+            .iter()
+            .map(|line| {
+                let key = (platform.to_string(), line.to_string(), network.to_string());
+                entries
+                    .get(&key)
+                    .filter(|(at, _)| at.elapsed() < CACHE_TTL)
+                    .map(|(_, probe)| LineProbe {
+                        cached: true,
+                        ..probe.clone()
+                    })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        rank_probes(&mut probes);
+        Some(probes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platforms::common::stream_headers;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn probe(line: &str, kbps: Option<u64>, ttfb: Option<u64>) -> LineProbe {
+        LineProbe {
+            line: line.to_string(),
+            label: line.to_string(),
+            host: String::new(),
+            connect_ms: Some(10),
+            ttfb_ms: ttfb,
+            throughput_kbps: kbps,
+            bytes: 0,
+            error: kbps.is_none().then(|| "timed out".to_string()),
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn ranks_by_throughput_then_latency() {
+        let mut probes = vec![
+            probe("dead", None, None),
+            probe("slow", Some(900), Some(50)),
+            probe("fast-far", Some(4200), Some(400)),
+            probe("fast-near", Some(4400), Some(80)),
+        ];
+        rank_probes(&mut probes);
+        let order: Vec<&str> = probes.iter().map(|p| p.line.as_str()).collect();
+        assert_eq!(order, ["fast-near", "fast-far", "slow", "dead"]);
+    }
+
+    #[test]
+    fn cache_needs_every_line() {
+        let cache = LineProbeCache::default();
+        cache.store(
+            "huya",
+            "10.0.0.2",
+            &[
+                probe("al", Some(100), Some(90)),
+                probe("tx", Some(3000), Some(20)),
+            ],
+        );
+        let hit = cache.lookup("huya", "10.0.0.2", &["al", "tx"]).unwrap();
+        assert_eq!(hit[0].line, "tx");
+        assert!(hit[0].cached);
+        assert!(cache.lookup("huya", "10.0.0.2", &["al", "hs"]).is_none());
+        assert!(cache.lookup("huya", "192.168.1.5", &["al", "tx"]).is_none());
+    }
+
+    // Serves `body` after `delay`, then holds the connection open
+    async fn stand_in(delay: Duration, body: Vec<u8>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let body = body.clone();
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 1024];
+                    let _ = socket.read(&mut buf).await;
+                    tokio::time::sleep(delay).await;
+                    let head = "HTTP/1.1 200 OK\r\ncontent-type: video/x-flv\r\n\r\n";
+                    let _ = socket.write_all(head.as_bytes()).await;
+                    let _ = socket.write_all(&body).await;
+                    tokio::time::sleep(Duration::from_secs(10)).await;
+                });
+            }
+        });
+        format!("http://{}/live.flv", addr)
+    }
+
+    #[tokio::test]
+    async fn races_candidates() {
+        let fast = stand_in(Duration::ZERO, vec![0u8; 256 * 1024]).await;
+        let slow = stand_in(Duration::from_millis(300), vec![0u8; 1024]).await;
+        let candidates = vec![
+            LineCandidate::new("slow", "Slow", slow),
+            LineCandidate::new("dead", "Dead", "http://127.0.0.1:9/live.flv"),
+            LineCandidate::new("fast", "Fast", fast).with_headers(stream_headers(
+                "dtv-test",
+                "https://example.com/",
+                None,
+            )),
+        ];
+        let client = reqwest::Client::builder().no_proxy().build().unwrap();
+        let started = Instant::now();
+        let probes = probe_lines(&client, &candidates).await;
+        // Concurrent: roughly one window, not three
+        assert!(started.elapsed() < PROBE_WINDOW * 2);
+        let order: Vec<&str> = probes.iter().map(|p| p.line.as_str()).collect();
+        assert_eq!(order, ["fast", "slow", "dead"]);
+        assert_eq!(probes[0].bytes, 256 * 1024);
+        assert!(probes[1].ttfb_ms.unwrap() >= 300);
+        assert!(probes[2].error.is_some());
+    }
+}
@@ -1,5 +1,9 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::media::MediaInfo;
+use crate::platforms::common::line_probe::AUTO_LINE;
 use crate::platforms::common::types::StreamVariant;
 use crate::platforms::common::types::SupportedPlatform;
 
@@ -10,17 +14,58 @@ pub enum LiveStreamMode {
     Meta,
 }
 
+/// Video codec wanted when a room offers several.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CodecPreference {
+    // H.264 only, so every player can decode it
+    #[default]
+    Avc,
+    // H.265 where the room offers it, H.264 otherwise
+    Hevc,
+    // Whatever the platform serves first, H.265 included
+    Auto,
+}
+
+impl CodecPreference {
+    /// Sort key for streams of one quality; the lowest rank wins and ties
+    /// keep the platform's order. Unknown codecs count as H.264.
+    pub fn rank(self, codec: Option<&str>) -> u8 {
+        let hevc = codec == Some("hevc");
+        match self {
+            CodecPreference::Avc => u8::from(hevc),
+            CodecPreference::Hevc => u8::from(!hevc),
+            CodecPreference::Auto => 0,
+        }
+    }
+
+    /// Whether upstream APIs should be told H.265 is playable.
+    pub fn accepts_hevc(self) -> bool {
+        self != CodecPreference::Avc
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct GetLiveStreamRequest {
     pub platform: SupportedPlatform,
     pub room_id: String,
     pub quality: Option<String>,
+    // "auto" picks the fastest line by speed test
     pub line: Option<String>,
     pub cookie: Option<String>,
     // Default behavior: debug is enabled unless explicitly set to false.
     pub debug: Option<bool>,
     // Default behavior: playback mode.
     pub mode: Option<LiveStreamMode>,
+    // Default behavior: meta mode also lists the qualities and lines of a live room.
+    pub with_options: Option<bool>,
+    // Default behavior: H.264 only.
+    pub codec: Option<CodecPreference>,
+    // Default behavior: stream type from the URL; true also reads the stream's first bytes.
+    pub probe: Option<bool>,
+    // Default behavior: audio and video; true is for listening only, and without a
+    // `quality` picks the lowest-bitrate one.
+    pub audio_only: Option<bool>,
 }
 
 impl GetLiveStreamRequest {
@@ -31,6 +76,26 @@ impl GetLiveStreamRequest {
     pub fn mode(&self) -> LiveStreamMode {
         self.mode.unwrap_or(LiveStreamMode::Playback)
     }
+
+    pub fn codec(&self) -> CodecPreference {
+        self.codec.unwrap_or_default()
+    }
+
+    /// Whether `line` asks for the fastest line instead of a named one.
+    pub fn auto_line(&self) -> bool {
+        self.line
+            .as_deref()
+            .is_some_and(|l| l.trim().eq_ignore_ascii_case(AUTO_LINE))
+    }
+
+    pub fn audio_only(&self) -> bool {
+        self.audio_only == Some(true)
+    }
+
+    /// Whether a meta-mode lookup should also enumerate stream options.
+    pub fn options_enabled(&self) -> bool {
+        self.mode() == LiveStreamMode::Meta && self.with_options != Some(false)
+    }
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -38,6 +103,8 @@ pub struct LiveStreamResponse {
     pub status: LiveStatus,
     pub room: RoomMeta,
     pub playback: Option<Playback>,
+    // Meta mode only, for live rooms
+    pub options: Option<StreamOptions>,
     pub error: Option<String>,
 }
 
@@ -58,6 +125,8 @@ pub struct RoomMeta {
     pub title: Option<String>,
     pub anchor_name: Option<String>,
     pub avatar: Option<String>,
+    // Game/section name, for platforms whose room lookup reports it
+    pub category: Option<String>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -65,7 +134,52 @@ pub struct Playback {
     pub url: String,
     pub stream_type: StreamType,
     pub upstream_url: Option<String>,
+    // "avc", "hevc" or "av1" when the platform says
+    pub codec: Option<String>,
+    // Sent with every request for `url`; empty once `url` is a local proxy
+    pub headers: StreamHeaders,
     pub variants: Option<Vec<StreamVariant>>,
+    // What the stream's first bytes showed, when the request asked to probe
+    pub media: Option<MediaInfo>,
+}
+
+/// Request headers a stream's CDN checks (User-Agent, Referer, Origin,
+/// Cookie), by name. Whoever fetches the stream replays them as-is.
+pub type StreamHeaders = BTreeMap<String, String>;
+
+/// User-Agent plus the page the stream is embedded in.
+pub fn stream_headers(user_agent: &str, referer: &str, origin: Option<&str>) -> StreamHeaders {
+    let mut headers = StreamHeaders::new();
+    headers.insert("User-Agent".to_string(), user_agent.to_string());
+    headers.insert("Referer".to_string(), referer.to_string());
+    if let Some(origin) = origin {
+        headers.insert("Origin".to_string(), origin.to_string());
+    }
+    headers
+}
+
+/// What a live room offers. Each `id` can be passed back as the request's
+/// `quality` / `line`.
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+pub struct StreamOptions {
+    pub qualities: Vec<QualityOption>,
+    // Empty for platforms without selectable CDNs
+    pub lines: Vec<LineOption>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct QualityOption {
+    pub id: String,
+    pub label: String,
+    // kbps
+    pub bitrate: Option<i64>,
+    pub codec: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct LineOption {
+    pub id: String,
+    pub label: String,
 }
 
 #[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
@@ -87,6 +201,34 @@ pub fn infer_stream_type(url: &str) -> StreamType {
     StreamType::Unknown
 }
 
+/// Maps codec names and RFC 6381 strings ("avc1.64002A", "hvc1", "h265")
+/// to "avc", "hevc" or "av1".
+pub fn normalize_codec(name: &str) -> Option<&'static str> {
+    let lower = name.trim().to_ascii_lowercase();
+    let lower = lower.replace('.', "");
+    if ["avc", "h264", "264"].iter().any(|p| lower.starts_with(p)) {
+        Some("avc")
+    } else if ["hvc", "hev", "h265", "265"]
+        .iter()
+        .any(|p| lower.starts_with(p))
+    {
+        Some("hevc")
+    } else if ["av01", "av1"].iter().any(|p| lower.starts_with(p)) {
+        Some("av1")
+    } else {
+        None
+    }
+}
+
+/// H.265 stream names usually say so; anything else is unknown.
+pub fn infer_codec(url: &str) -> Option<&'static str> {
+    let lower = url.to_ascii_lowercase();
+    ["hevc", "h265", "_265"]
+        .iter()
+        .any(|m| lower.contains(m))
+        .then_some("hevc")
+}
+
 pub fn truncate_variants(mut variants: Vec<StreamVariant>) -> Vec<StreamVariant> {
     const MAX_VARIANTS: usize = 20;
     if variants.len() > MAX_VARIANTS {
@@ -1,8 +1,11 @@
 #![allow(unused_imports)]
 pub mod http_client;
 pub mod http_headers;
+#[cfg(feature = "js-runtime")]
 pub mod js_function_worker;
+#[cfg(feature = "js-runtime")]
 pub mod js_runtime;
+pub mod line_probe;
 pub mod logging;
 pub mod errors;
 pub mod signing;
@@ -11,15 +14,22 @@ pub mod types;
 
 // Re-export necessary types to make them available directly under platforms::common::TypeName
 pub use http_client::FollowHttpClient;
+pub use line_probe::{LineCandidate, LineProbe, LineProbeCache, AUTO_LINE};
+#[cfg(feature = "js-runtime")]
 pub use js_runtime::{bootstrap_basic_browser_env, ensure_js_runtime_platform_initialized, new_js_runtime};
 pub use live_stream_v2::{
-    infer_stream_type, truncate_variants, GetLiveStreamRequest, LiveStatus, LiveStreamMode,
-    LiveStreamResponse, Playback, RoomMeta, StreamType,
+    infer_codec, infer_stream_type, normalize_codec, stream_headers, truncate_variants,
+    CodecPreference, GetLiveStreamRequest, LineOption, LiveStatus, LiveStreamMode,
+    LiveStreamResponse, Playback, QualityOption, RoomMeta, StreamHeaders, StreamOptions,
+    StreamType,
 };
 pub use types::BilibiliDanmakuState;
+pub use types::CcDanmakuState;
 pub use types::DanmakuFrontendPayload;
 pub use types::DouyinDanmakuState;
 pub use types::DouyuDanmakuState;
 pub use types::GetStreamUrlPayload;
 pub use types::HuyaDanmakuState;
+pub use types::KuaishouDanmakuState;
 pub use types::LiveStreamInfo;
+pub use types::TwitchDanmakuState;
@@ -36,6 +36,7 @@ pub struct StreamVariant {
     pub desc: Option<String>,     // e.g. 原画/高清
     pub qn: Option<i32>,          // B 站的清晰度编号
     pub protocol: Option<String>, // e.g. http, https, ws/hls
+    pub codec: Option<String>,    // avc / hevc，未知为空
 }
 
 // For the return type of get_douyin_live_stream_url
@@ -85,6 +86,21 @@ pub struct DouyuDanmakuState(pub std::sync::Mutex<Option<tokio::sync::mpsc::Send
 #[allow(dead_code)]
 pub struct HuyaDanmakuState(pub std::sync::Mutex<Option<tokio::sync::mpsc::Sender<()>>>);
 
+// State for the Kuaishou Danmaku listener
+#[derive(Default)]
+#[allow(dead_code)]
+pub struct KuaishouDanmakuState(pub std::sync::Mutex<Option<tokio::sync::mpsc::Sender<()>>>);
+
+// State for the CC Danmaku listener
+#[derive(Default)]
+#[allow(dead_code)]
+pub struct CcDanmakuState(pub std::sync::Mutex<Option<tokio::sync::mpsc::Sender<()>>>);
+
+// State for the Twitch chat listener
+#[derive(Default)]
+#[allow(dead_code)]
+pub struct TwitchDanmakuState(pub std::sync::Mutex<Option<tokio::sync::mpsc::Sender<()>>>);
+
 #[derive(Serialize, Clone, Debug, specta::Type)]
 pub struct DanmakuFrontendPayload {
     pub room_id: String,
@@ -96,17 +112,111 @@ pub struct DanmakuFrontendPayload {
 
 // --- Merged from types_rust.rs ---
 
-// Enum mirroring TypeScript SupportedPlatform
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+// Enum mirroring TypeScript SupportedPlatform; variants follow the enabled platform features.
+// Serialized as the bare id ("douyu", or a plugin's own id for `Custom`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SupportedPlatform {
-    #[serde(rename = "douyu")]
+    #[cfg(feature = "douyu")]
     Douyu,
-    #[serde(rename = "bilibili")]
+    #[cfg(feature = "bilibili")]
     Bilibili,
-    #[serde(rename = "douyin")]
+    #[cfg(feature = "douyin")]
     Douyin,
-    #[serde(rename = "huya")]
+    #[cfg(feature = "huya")]
     Huya,
+    #[cfg(feature = "kuaishou")]
+    Kuaishou,
+    #[cfg(feature = "cc")]
+    Cc,
+    #[cfg(feature = "twitch")]
+    Twitch,
+    // A platform implemented by a JS plugin, keyed by the plugin's id
+    Custom(String),
+}
+
+// Ids of the built-in platforms, whether or not they are compiled in; plugins may not use them
+const BUILTIN_PLATFORM_IDS: &[&str] = &["douyu", "bilibili", "douyin", "huya", "kuaishou", "cc", "twitch"];
+
+/// Plugin ids: 1-32 chars of `a-z`, `0-9`, `_`, `-`, starting with a letter.
+pub fn is_valid_custom_platform_id(id: &str) -> bool {
+    id.len() <= 32
+        && id.starts_with(|c: char| c.is_ascii_lowercase())
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        && !BUILTIN_PLATFORM_IDS.contains(&id)
+}
+
+impl SupportedPlatform {
+    pub fn as_str(&self) -> &str {
+        match self {
+            #[cfg(feature = "douyu")]
+            SupportedPlatform::Douyu => "douyu",
+            #[cfg(feature = "bilibili")]
+            SupportedPlatform::Bilibili => "bilibili",
+            #[cfg(feature = "douyin")]
+            SupportedPlatform::Douyin => "douyin",
+            #[cfg(feature = "huya")]
+            SupportedPlatform::Huya => "huya",
+            #[cfg(feature = "kuaishou")]
+            SupportedPlatform::Kuaishou => "kuaishou",
+            #[cfg(feature = "cc")]
+            SupportedPlatform::Cc => "cc",
+            #[cfg(feature = "twitch")]
+            SupportedPlatform::Twitch => "twitch",
+            SupportedPlatform::Custom(id) => id,
+        }
+    }
+
+    // Every built-in platform compiled into this build (plugins are listed by their host)
+    pub fn all() -> Vec<SupportedPlatform> {
+        vec![
+            #[cfg(feature = "douyu")]
+            SupportedPlatform::Douyu,
+            #[cfg(feature = "bilibili")]
+            SupportedPlatform::Bilibili,
+            #[cfg(feature = "douyin")]
+            SupportedPlatform::Douyin,
+            #[cfg(feature = "huya")]
+            SupportedPlatform::Huya,
+            #[cfg(feature = "kuaishou")]
+            SupportedPlatform::Kuaishou,
+            #[cfg(feature = "cc")]
+            SupportedPlatform::Cc,
+            #[cfg(feature = "twitch")]
+            SupportedPlatform::Twitch,
+        ]
+    }
+}
+
+impl std::str::FromStr for SupportedPlatform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(p) = Self::all().into_iter().find(|p| p.as_str() == s) {
+            return Ok(p);
+        }
+        if BUILTIN_PLATFORM_IDS.contains(&s) {
+            return Err(format!("platform {} is not enabled in this build", s));
+        }
+        if is_valid_custom_platform_id(s) {
+            return Ok(SupportedPlatform::Custom(s.to_string()));
+        }
+        Err(format!("invalid platform id: {:?}", s))
+    }
+}
+
+impl Serialize for SupportedPlatform {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SupportedPlatform {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
 }
 
 // Struct mirroring TypeScript CommonPlatformCategory
@@ -130,4 +240,25 @@ pub struct CommonCategoryGroup {
     pub group_name: String,
     pub platform: SupportedPlatform,
     pub categories: Vec<CommonPlatformCategory>,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn platform_ids_round_trip() {
+        for p in SupportedPlatform::all() {
+            let json = serde_json::to_string(&p).unwrap();
+            assert_eq!(serde_json::from_str::<SupportedPlatform>(&json).unwrap(), p);
+        }
+        let custom: SupportedPlatform = serde_json::from_str("\"my-site\"").unwrap();
+        assert_eq!(custom, SupportedPlatform::Custom("my-site".into()));
+        assert_eq!(serde_json::to_string(&custom).unwrap(), "\"my-site\"");
+
+        for bad in ["", "My-Site", "9tv", "a/b", &"x".repeat(33)] {
+            assert!(bad.parse::<SupportedPlatform>().is_err(), "{:?}", bad);
+        }
+        // Built-in ids are never plugins, even when compiled out
+        assert!(!is_valid_custom_platform_id("huya"));
+    }
+}
@@ -0,0 +1,182 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+// Typed non-chat events decoded from the Douyin push stream.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DouyinEvent {
+    Gift {
+        user_id: u64,
+        user: String,
+        gift_id: u64,
+        gift: String,
+        // Total pieces in this (possibly combined) send
+        count: u64,
+        // Unit price in 抖币
+        diamond_count: u32,
+    },
+    Member {
+        user_id: u64,
+        user: String,
+        user_level: i64,
+        fans_club_level: i32,
+        member_count: u64,
+    },
+    Like {
+        user_id: u64,
+        user: String,
+        count: u64,
+        total: u64,
+    },
+    Social {
+        user_id: u64,
+        user: String,
+        // 1 = follow, 3 = share
+        action: u64,
+        follow_count: u64,
+    },
+    OnlineCount {
+        online: i64,
+        online_str: String,
+        total_user: i64,
+    },
+    RoomStats {
+        display: String,
+        total: i64,
+    },
+    MatchScore {
+        left_name: String,
+        left_goal: String,
+        right_name: String,
+        right_goal: String,
+        match_status: u32,
+    },
+    LiveEnd,
+}
+
+// Combo gifts arrive as a burst of GiftMessage with a growing repeat_count;
+// only the last one (repeat_end == 1) carries the final count.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct ComboKey {
+    user_id: u64,
+    gift_id: u64,
+    group_id: u64,
+}
+
+struct PendingCombo {
+    event: DouyinEvent,
+    updated_at: Instant,
+}
+
+pub struct GiftComboTracker {
+    pending: HashMap<ComboKey, PendingCombo>,
+    // Flush a combo whose closing message never arrived after this long
+    stale_after: Duration,
+}
+
+impl Default for GiftComboTracker {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5))
+    }
+}
+
+impl GiftComboTracker {
+    pub fn new(stale_after: Duration) -> Self {
+        Self {
+            pending: HashMap::new(),
+            stale_after,
+        }
+    }
+
+    /// Feed one decoded gift. Returns the event to emit, if the send is complete.
+    pub fn push(
+        &mut self,
+        event: DouyinEvent,
+        group_id: u64,
+        is_combo: bool,
+        repeat_end: bool,
+    ) -> Option<DouyinEvent> {
+        let DouyinEvent::Gift {
+            user_id, gift_id, ..
+        } = &event
+        else {
+            return Some(event);
+        };
+        if !is_combo {
+            return Some(event);
+        }
+        let key = ComboKey {
+            user_id: *user_id,
+            gift_id: *gift_id,
+            group_id,
+        };
+        if repeat_end {
+            self.pending.remove(&key);
+            return Some(event);
+        }
+        self.pending.insert(
+            key,
+            PendingCombo {
+                event,
+                updated_at: Instant::now(),
+            },
+        );
+        None
+    }
+
+    /// Drain combos that went quiet without a closing message.
+    pub fn flush_stale(&mut self, now: Instant) -> Vec<DouyinEvent> {
+        let stale_after = self.stale_after;
+        let keys: Vec<ComboKey> = self
+            .pending
+            .iter()
+            .filter(|(_, p)| now.duration_since(p.updated_at) >= stale_after)
+            .map(|(k, _)| k.clone())
+            .collect();
+        keys.into_iter()
+            .filter_map(|k| self.pending.remove(&k).map(|p| p.event))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gift(count: u64) -> DouyinEvent {
+        DouyinEvent::Gift {
+            user_id: 1,
+            user: "u".to_string(),
+            gift_id: 463,
+            gift: "小心心".to_string(),
+            count,
+            diamond_count: 1,
+        }
+    }
+
+    #[test]
+    fn combo_emits_only_final_count() {
+        let mut tracker = GiftComboTracker::default();
+        assert_eq!(tracker.push(gift(1), 99, true, false), None);
+        assert_eq!(tracker.push(gift(2), 99, true, false), None);
+        assert_eq!(tracker.push(gift(3), 99, true, true), Some(gift(3)));
+        assert!(tracker.flush_stale(Instant::now() + Duration::from_secs(60)).is_empty());
+    }
+
+    #[test]
+    fn non_combo_passes_through() {
+        let mut tracker = GiftComboTracker::default();
+        assert_eq!(tracker.push(gift(5), 0, false, false), Some(gift(5)));
+    }
+
+    #[test]
+    fn stale_combo_is_flushed_with_last_count() {
+        let mut tracker = GiftComboTracker::new(Duration::from_secs(5));
+        tracker.push(gift(1), 7, true, false);
+        tracker.push(gift(4), 7, true, false);
+        assert!(tracker.flush_stale(Instant::now()).is_empty());
+        let flushed = tracker.flush_stale(Instant::now() + Duration::from_secs(6));
+        assert_eq!(flushed, vec![gift(4)]);
+    }
+}
@@ -3,14 +3,76 @@ use futures_util::{stream::SplitStream, StreamExt};
 use prost::Message as ProstMessage; // For decode/encode
 use std::io::Read;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc::Sender;
 use tokio_tungstenite::tungstenite::protocol::Message as WsMessage; // Import the Emitter trait for app_handle.emit()
 
 use crate::danmaku::DanmakuHandler;
+use crate::platforms::common::DanmakuFrontendPayload;
+use crate::platforms::douyin::danmu::events::{DouyinEvent, GiftComboTracker};
 use crate::platforms::douyin::danmu::gen::{PushFrame, Response}; // Removed ::douyin
 use crate::platforms::douyin::danmu::message_parsers;
 use crate::platforms::douyin::danmu::websocket_connection::WsStream; // Corrected path // Corrected path
 
+fn emit_event(handler: &Arc<dyn DanmakuHandler>, room_id: &str, event: DouyinEvent) {
+    // Gifts also go to the shared chat list, like Bilibili/Douyu
+    if let DouyinEvent::Gift {
+        user, gift, count, ..
+    } = &event
+    {
+        handler.on_danmaku(DanmakuFrontendPayload {
+            room_id: room_id.to_string(),
+            user: user.clone(),
+            content: format!("[礼物] {} x{}", gift, count),
+            user_level: 0,
+            fans_club_level: 0,
+        });
+    }
+    if let Ok(mut v) = serde_json::to_value(&event) {
+        v["room_id"] = serde_json::Value::String(room_id.to_string());
+        handler.emit_json(&format!("danmaku-{}", room_id), v);
+    }
+}
+
+fn dispatch_message(
+    method: &str,
+    payload: &[u8],
+    room_id: &str,
+    handler: &Arc<dyn DanmakuHandler>,
+    combos: &mut GiftComboTracker,
+) {
+    let parsed = match method {
+        "WebcastChatMessage" => {
+            // Parse errors are intentionally silent for chat, see parse_chat_message
+            if let Ok(Some(chat_payload)) = message_parsers::parse_chat_message(payload, room_id) {
+                handler.on_danmaku(chat_payload);
+            }
+            return;
+        }
+        "WebcastGiftMessage" => message_parsers::parse_gift_message(payload)
+            .map(|(event, group_id, is_combo, repeat_end)| {
+                combos.push(event, group_id, is_combo, repeat_end)
+            }),
+        "WebcastMemberMessage" => message_parsers::parse_member_message(payload).map(Some),
+        "WebcastLikeMessage" => message_parsers::parse_like_message(payload).map(Some),
+        "WebcastSocialMessage" => message_parsers::parse_social_message(payload).map(Some),
+        "WebcastRoomUserSeqMessage" => {
+            message_parsers::parse_room_user_seq_message(payload).map(Some)
+        }
+        "WebcastRoomStatsMessage" => message_parsers::parse_room_stats_message(payload).map(Some),
+        "WebcastMatchAgainstScoreMessage" => {
+            message_parsers::parse_match_against_score_message(payload).map(Some)
+        }
+        "WebcastControlMessage" => message_parsers::parse_control_message(payload),
+        _ => return,
+    };
+    match parsed {
+        Ok(Some(event)) => emit_event(handler, room_id, event),
+        Ok(None) => {}
+        Err(e) => eprintln!("[Douyin Danmaku] Failed to parse {}: {}", method, e),
+    }
+}
+
 // This function will handle the message receiving loop and parsing
 pub async fn handle_received_messages(
     mut read_stream: SplitStream<WsStream>,
@@ -22,7 +84,21 @@ pub async fn handle_received_messages(
         "[Douyin Danmaku] Message handler started for room_id: {}",
         room_id
     );
-    while let Some(message_result) = read_stream.next().await {
+    let mut combos = GiftComboTracker::default();
+    let mut flush_tick = tokio::time::interval(Duration::from_secs(1));
+    loop {
+        let message_result = tokio::select! {
+            next = read_stream.next() => match next {
+                Some(m) => m,
+                None => break,
+            },
+            _ = flush_tick.tick() => {
+                for event in combos.flush_stale(Instant::now()) {
+                    emit_event(&handler, &room_id, event);
+                }
+                continue;
+            }
+        };
         match message_result {
             Ok(ws_msg) => {
                 if let WsMessage::Binary(bin_data) = ws_msg {
@@ -60,28 +136,13 @@ pub async fn handle_received_messages(
                                             }
                                         }
                                         for msg in response.messages_list {
-                                            // println!("  -> Method: {}, Payload Length: {}", msg.method, msg.payload.len());
-                                            let mut danmaku_to_send = None;
-                                            if msg.method == "WebcastChatMessage" {
-                                                match message_parsers::parse_chat_message(
-                                                    &msg.payload,
-                                                    &room_id,
-                                                ) {
-                                                    Ok(Some(chat_payload)) => {
-                                                        danmaku_to_send = Some(chat_payload);
-                                                    }
-                                                    Ok(None) => { /* Not a message to display or ignored */
-                                                    }
-                                                    Err(_e) => { /* Error already logged in parser */
-                                                    }
-                                                }
-                                            }
-                                            // Add other message types here if needed, similar to ChatMessage
-                                            // else if msg.method == "WebcastMemberMessage" { ... }
-
-                                            if let Some(payload) = danmaku_to_send {
-                                                handler.on_danmaku(payload);
-                                            }
+                                            dispatch_message(
+                                                &msg.method,
+                                                &msg.payload,
+                                                &room_id,
+                                                &handler,
+                                                &mut combos,
+                                            );
                                         }
                                     }
                                     Err(e) => eprintln!(
@@ -1,4 +1,8 @@
-use super::gen::{ChatMessage, LikeMessage, MemberMessage, RoomStatsMessage}; // Updated to directly use types from gen
+use super::events::DouyinEvent;
+use super::gen::{
+    ChatMessage, ControlMessage, GiftMessage, LikeMessage, MatchAgainstScoreMessage,
+    MemberMessage, RoomStatsMessage, RoomUserSeqMessage, SocialMessage, User,
+}; // Updated to directly use types from gen
 use crate::platforms::common::DanmakuFrontendPayload;
 use prost::Message as ProstMessage; // For .decode() // Use shared payload type
 
@@ -53,80 +57,180 @@ pub fn parse_chat_message(
     }
 }
 
-// Demo 中此函数返回 Result<(), ...> 并且只打印，这里保持原有返回 Option<DanmakuFrontendPayload> 结构
-// 如果不需要将进场消息发送到前端，可以保持返回 Ok(None)
-#[allow(dead_code)] // ADDED to suppress warning
-pub fn parse_member_message(
-    payload: &[u8],
-    _current_room_id: &str,
-) -> Result<Option<DanmakuFrontendPayload>, Box<dyn std::error::Error + Send + Sync>> {
-    match MemberMessage::decode(payload) {
-        Ok(member_msg) => {
-            if let Some(user) = member_msg.user {
-                let user_level = user.pay_grade.as_ref().map(|pg| pg.level).unwrap_or(0);
-                let fans_club_level = user
-                    .fans_club
-                    .as_ref()
-                    .and_then(|fc| fc.data.as_ref())
-                    .map(|fcd| fcd.level)
-                    .unwrap_or(0);
+type ParseResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
 
-                println!(
-                    "    【进场msg】[用户等级: {}][粉丝牌等级: {}]{} 进入了直播间",
-                    user_level, fans_club_level, user.nick_name
-                );
-                Ok(None) // 当前不发送到前端
-            } else {
-                println!("    【进场msg】MemberMessage without user details.");
-                Ok(None)
-            }
-        }
-        Err(e) => {
-            eprintln!("    【X】Failed to parse MemberMessage in parser: {}", e);
-            Err(Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
-        }
+fn user_fields(user: Option<&User>) -> (u64, String, i64, i32) {
+    match user {
+        Some(u) => (
+            u.id,
+            u.nick_name.clone(),
+            u.pay_grade.as_ref().map(|pg| pg.level).unwrap_or(0),
+            u.fans_club
+                .as_ref()
+                .and_then(|fc| fc.data.as_ref())
+                .map(|fcd| fcd.level)
+                .unwrap_or(0),
+        ),
+        None => (0, "匿名".to_string(), 0, 0),
     }
 }
 
+// 进场消息
+pub fn parse_member_message(payload: &[u8]) -> ParseResult<DouyinEvent> {
+    let member_msg = MemberMessage::decode(payload)?;
+    let (user_id, user, user_level, fans_club_level) = user_fields(member_msg.user.as_ref());
+    Ok(DouyinEvent::Member {
+        user_id,
+        user,
+        user_level,
+        fans_club_level,
+        member_count: member_msg.member_count,
+    })
+}
+
 // Parser for LikeMessage (点赞消息)
-// Demo 中此函数返回 Result<(), ...> 并且只打印
-#[allow(dead_code)] // ADDED to suppress warning
-pub fn parse_like_message(
-    payload: &[u8],
-    _current_room_id: &str,
-) -> Result<Option<DanmakuFrontendPayload>, Box<dyn std::error::Error + Send + Sync>> {
-    match LikeMessage::decode(payload) {
-        Ok(like_msg) => {
-            if let Some(user) = like_msg.user {
-                println!(
-                    "    【点赞msg】{} 点了{}个赞",
-                    user.nick_name, like_msg.count
-                );
-            } else {
-                println!("    【点赞msg】点赞 {} 个 (无用户信息)", like_msg.count);
+pub fn parse_like_message(payload: &[u8]) -> ParseResult<DouyinEvent> {
+    let like_msg = LikeMessage::decode(payload)?;
+    let (user_id, user, _, _) = user_fields(like_msg.user.as_ref());
+    Ok(DouyinEvent::Like {
+        user_id,
+        user,
+        count: like_msg.count,
+        total: like_msg.total,
+    })
+}
+
+pub fn parse_room_stats_message(payload: &[u8]) -> ParseResult<DouyinEvent> {
+    let stats_msg = RoomStatsMessage::decode(payload)?;
+    Ok(DouyinEvent::RoomStats {
+        display: stats_msg.display_long,
+        total: stats_msg.total,
+    })
+}
+
+/// Decoded gift plus the combo bookkeeping fields (group_id, is_combo, repeat_end).
+pub fn parse_gift_message(payload: &[u8]) -> ParseResult<(DouyinEvent, u64, bool, bool)> {
+    let gift_msg = GiftMessage::decode(payload)?;
+    let (user_id, user, _, _) = user_fields(gift_msg.user.as_ref());
+    let (gift_name, diamond_count, is_combo) = gift_msg
+        .gift
+        .as_ref()
+        .map(|g| (g.name.clone(), g.diamond_count, g.combo))
+        .unwrap_or_default();
+    let event = DouyinEvent::Gift {
+        user_id,
+        user,
+        gift_id: gift_msg.gift_id,
+        gift: gift_name,
+        count: gift_msg.repeat_count.max(1) * gift_msg.group_count.max(1),
+        diamond_count,
+    };
+    Ok((
+        event,
+        gift_msg.group_id,
+        is_combo,
+        gift_msg.repeat_end == 1,
+    ))
+}
+
+// 关注/分享
+pub fn parse_social_message(payload: &[u8]) -> ParseResult<DouyinEvent> {
+    let social_msg = SocialMessage::decode(payload)?;
+    let (user_id, user, _, _) = user_fields(social_msg.user.as_ref());
+    Ok(DouyinEvent::Social {
+        user_id,
+        user,
+        action: social_msg.action,
+        follow_count: social_msg.follow_count,
+    })
+}
+
+// 在线人数
+pub fn parse_room_user_seq_message(payload: &[u8]) -> ParseResult<DouyinEvent> {
+    let seq_msg = RoomUserSeqMessage::decode(payload)?;
+    Ok(DouyinEvent::OnlineCount {
+        online: seq_msg.total,
+        online_str: seq_msg.total_str,
+        total_user: seq_msg.total_user,
+    })
+}
+
+pub fn parse_match_against_score_message(payload: &[u8]) -> ParseResult<DouyinEvent> {
+    let score_msg = MatchAgainstScoreMessage::decode(payload)?;
+    let against = score_msg.against.unwrap_or_default();
+    Ok(DouyinEvent::MatchScore {
+        left_name: against.left_name,
+        left_goal: against.left_goal,
+        right_name: against.right_name,
+        right_goal: against.right_goal,
+        match_status: score_msg.match_status,
+    })
+}
+
+// status = 3 下播; other statuses are not surfaced
+pub fn parse_control_message(payload: &[u8]) -> ParseResult<Option<DouyinEvent>> {
+    let control_msg = ControlMessage::decode(payload)?;
+    Ok((control_msg.status == 3).then_some(DouyinEvent::LiveEnd))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platforms::douyin::danmu::gen::GiftStruct;
+
+    #[test]
+    fn parse_gift_message_reads_combo_fields() {
+        let msg = GiftMessage {
+            gift_id: 463,
+            group_count: 1,
+            repeat_count: 10,
+            repeat_end: 1,
+            group_id: 1717,
+            user: Some(User {
+                id: 42,
+                nick_name: "观众".to_string(),
+                ..Default::default()
+            }),
+            gift: Some(GiftStruct {
+                name: "小心心".to_string(),
+                diamond_count: 1,
+                combo: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let (event, group_id, is_combo, repeat_end) =
+            parse_gift_message(&msg.encode_to_vec()).unwrap();
+        assert_eq!(group_id, 1717);
+        assert!(is_combo);
+        assert!(repeat_end);
+        assert_eq!(
+            event,
+            DouyinEvent::Gift {
+                user_id: 42,
+                user: "观众".to_string(),
+                gift_id: 463,
+                gift: "小心心".to_string(),
+                count: 10,
+                diamond_count: 1,
             }
-            Ok(None) // 点赞消息通常不直接作为弹幕显示在列表
-        }
-        Err(e) => {
-            eprintln!("    【X】Failed to parse LikeMessage in parser: {}", e);
-            Err(Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
-        }
+        );
     }
-}
 
-#[allow(dead_code)] // ADDED to suppress warning
-pub fn parse_room_stats_message(
-    payload: &[u8],
-    _current_room_id: &str,
-) -> Result<Option<DanmakuFrontendPayload>, Box<dyn std::error::Error + Send + Sync>> {
-    match RoomStatsMessage::decode(payload) {
-        Ok(stats_msg) => {
-            println!("    【直播间统计msg】{}", stats_msg.display_long);
-            Ok(None) // 统计信息通常不作为普通弹幕显示
-        }
-        Err(e) => {
-            eprintln!("    【X】Failed to parse RoomStatsMessage in parser: {}", e);
-            Err(Box::new(e) as Box<dyn std::error::Error + Send + Sync>)
-        }
+    #[test]
+    fn parse_control_message_only_surfaces_live_end() {
+        let ended = ControlMessage {
+            status: 3,
+            ..Default::default()
+        };
+        let other = ControlMessage {
+            status: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_control_message(&ended.encode_to_vec()).unwrap(),
+            Some(DouyinEvent::LiveEnd)
+        );
+        assert_eq!(parse_control_message(&other.encode_to_vec()).unwrap(), None);
     }
 }
@@ -5,9 +5,10 @@ pub mod gen {
     include!(concat!(env!("OUT_DIR"), "/douyin.rs"));
 }
 
+pub mod events;
+pub mod frontier_sign;
 pub mod message_handler;
 pub mod message_parsers;
 pub mod signature;
-pub mod sign_worker;
 pub mod web_fetcher;
 pub mod websocket_connection;
\ No newline at end of file
@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use url::Url;
 
 use crate::platforms::common::signing::hash::md5_hex;
-use crate::platforms::douyin::danmu::sign_worker;
+use crate::platforms::douyin::danmu::frontier_sign;
 
 pub async fn generate_signature(
     wss_url: &str,
@@ -40,10 +40,7 @@ pub async fn generate_signature(
     let to_sign_str = tpl_params_vec.join(",");
     let md5_param = md5_hex(&to_sign_str);
 
-    let signature = sign_worker::get_sign(&md5_param)
-        .await
-        .map_err(|e| Box::from(e) as Box<dyn std::error::Error + Send + Sync>)?;
-    Ok(signature)
+    Ok(frontier_sign::sign_ms_stub(&md5_param))
 }
 
 pub fn generate_ms_token(length: usize) -> String {
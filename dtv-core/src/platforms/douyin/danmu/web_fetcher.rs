@@ -1,4 +1,5 @@
 use crate::platforms::common::http_client::HttpClient;
+use crate::platforms::douyin::signed_url::global_builder;
 use crate::platforms::douyin::web_api::{
     fetch_room_data, normalize_douyin_live_id, DouyinRoomData, DEFAULT_USER_AGENT,
 };
@@ -62,7 +63,16 @@ impl DouyinLiveWebFetcher {
         let live_id = self.live_id.clone();
         let cookies = self.dy_cookie.as_deref();
         // 直接使用和 douyin_rust 相同的接口 + a_bogus，避免 HTML 解析失败。
-        match fetch_room_data(&self.http_client, &live_id, cookies, false).await {
+        match fetch_room_data(
+            &self.http_client,
+            global_builder(),
+            &self.user_agent,
+            &live_id,
+            cookies,
+            false,
+        )
+        .await
+        {
             Ok(DouyinRoomData { room }) => {
                 let room_id = room
                     .get("id_str")
@@ -1,7 +1,11 @@
 use crate::platforms::common::http_client::HttpClient;
 use crate::platforms::common::types::StreamVariant;
-use crate::platforms::common::GetStreamUrlPayload;
+use crate::platforms::common::{
+    infer_codec, normalize_codec, CodecPreference, GetStreamUrlPayload, QualityOption,
+    StreamHeaders, StreamOptions,
+};
 use crate::platforms::common::LiveStreamInfo as CommonLiveStreamInfo;
+use crate::platforms::douyin::signed_url::DouyinSignedUrlBuilder;
 use crate::platforms::douyin::web_api::{
     choose_flv_stream, fetch_room_data, normalize_douyin_live_id, DouyinRoomData,
     DEFAULT_USER_AGENT,
@@ -16,9 +20,22 @@ const QUALITY_OD: &str = "OD";
 const QUALITY_BD: &str = "BD";
 const QUALITY_UHD: &str = "UHD";
 
+/// Headers Douyin's pull CDNs expect, with the UA the room was fetched with.
+pub fn stream_headers(user_agent: Option<&str>) -> StreamHeaders {
+    crate::platforms::common::stream_headers(
+        user_agent.unwrap_or(DEFAULT_USER_AGENT),
+        "https://live.douyin.com/",
+        None,
+    )
+}
+
 pub async fn get_douyin_live_stream_url_with_quality(
+    http_client: &HttpClient,
+    signer: &DouyinSignedUrlBuilder,
+    user_agent: Option<&str>,
     payload: GetStreamUrlPayload,
     quality: String,
+    codec: CodecPreference,
 ) -> Result<CommonLiveStreamInfo, DtvError> {
     let requested_id = payload.args.room_id_str.trim().to_string();
     if requested_id.is_empty() {
@@ -41,12 +58,11 @@ pub async fn get_douyin_live_stream_url_with_quality(
         requested_id, quality
     );
 
-    let http_client = HttpClient::new_direct_connection()
-        .map_err(|e| DtvError::internal(format!("Failed to create direct connection HttpClient: {}", e)))?;
-
+    let user_agent = user_agent.unwrap_or(DEFAULT_USER_AGENT);
     let normalized_id = normalize_douyin_live_id(&requested_id);
-    let DouyinRoomData { mut room } = fetch_room_data(&http_client, &normalized_id, None, true).await?;
-    let origin_from_html = fetch_origin_flv_from_live_page(&http_client, &normalized_id)
+    let DouyinRoomData { mut room } =
+        fetch_room_data(http_client, signer, user_agent, &normalized_id, None, true).await?;
+    let origin_from_html = fetch_origin_flv_from_live_page(http_client, user_agent, &normalized_id)
         .await
         .unwrap_or_else(|err| {
             tracing::warn!(
@@ -91,13 +107,14 @@ pub async fn get_douyin_live_stream_url_with_quality(
     }
 
     let target_quality = normalize_quality_tag(&quality);
-    let selected = pick_douyin_flv_by_quality(&room, target_quality, origin_from_html.as_deref())
+    let selected = flv_stream_by_key(&room, &quality)
+        .or_else(|| pick_douyin_flv_by_quality(&room, target_quality, origin_from_html.as_deref()))
         .or_else(|| choose_flv_stream(&room, target_quality))
         .or_else(|| first_flv_stream(&room))
         .ok_or_else(|| {
             DtvError::api("[Douyin Stream Detail] No FLV streams available in stream_url.flv_pull_url".to_string())
         })?;
-    let (selected_key, real_url) = selected;
+    let (selected_key, real_url) = prefer_codec(available_streams.as_deref(), selected, codec);
     tracing::debug!(
         "[Douyin Stream Detail] Selected FLV stream key='{}' url='{}'",
         selected_key, real_url
@@ -205,9 +222,17 @@ pub(crate) fn collect_available_streams(room: &Value) -> Option<Vec<StreamVarian
                 desc: Some(k.to_string()),
                 qn: None,
                 protocol: url.split(':').next().map(|s| s.to_string()),
+                codec: infer_codec(url).map(str::to_string),
             })
         })
         .collect::<Vec<_>>();
+    // The SDK's H.264 entries repeat `flv_pull_url`; only H.265 is new
+    let mut variants = variants;
+    for sdk in sdk_flv_streams(room) {
+        if sdk.codec.as_deref() == Some("hevc") && !variants.iter().any(|v| v.url == sdk.url) {
+            variants.push(sdk);
+        }
+    }
     if variants.is_empty() {
         None
     } else {
@@ -215,6 +240,138 @@ pub(crate) fn collect_available_streams(room: &Value) -> Option<Vec<StreamVarian
     }
 }
 
+// `stream_data` qualities and the `flv_pull_url` keys they match
+const SDK_QUALITY_KEYS: [(&str, &str); 5] = [
+    ("origin", "ORIGIN"),
+    ("uhd", "FULL_HD1"),
+    ("hd", "HD1"),
+    ("sd", "SD1"),
+    ("ld", "SD2"),
+];
+
+// FLV entries of the player SDK's `stream_data` JSON, tagged with the
+// `VCodec` of their `sdk_params`
+fn sdk_flv_streams(room: &Value) -> Vec<StreamVariant> {
+    let Some(stream_url) = room.get("stream_url") else {
+        return Vec::new();
+    };
+    let pull_datas = stream_url
+        .get("pull_datas")
+        .and_then(|v| v.as_object())
+        .into_iter()
+        .flat_map(|pd| pd.values())
+        .filter_map(|entry| entry.get("stream_data"));
+    let sdk_data = stream_url
+        .get("live_core_sdk_data")
+        .and_then(|d| d.get("pull_data"))
+        .and_then(|p| p.get("stream_data"));
+
+    let mut variants = Vec::new();
+    for raw in pull_datas.chain(sdk_data).filter_map(|v| v.as_str()) {
+        let Ok(parsed) = serde_json::from_str::<Value>(raw) else {
+            continue;
+        };
+        for (sdk_key, flv_key) in SDK_QUALITY_KEYS {
+            let Some(main) = parsed.pointer(&format!("/data/{}/main", sdk_key)) else {
+                continue;
+            };
+            let Some(url) = main
+                .get("flv")
+                .and_then(|v| v.as_str())
+                .filter(|u| !u.is_empty())
+            else {
+                continue;
+            };
+            let codec = main
+                .get("sdk_params")
+                .and_then(|s| s.as_str())
+                .and_then(|s| serde_json::from_str::<Value>(s).ok())
+                .and_then(|v| {
+                    v.get("VCodec")
+                        .and_then(|c| c.as_str())
+                        .and_then(normalize_codec)
+                });
+            variants.push(StreamVariant {
+                url: url.to_string(),
+                format: Some("flv".to_string()),
+                desc: Some(flv_key.to_string()),
+                qn: None,
+                protocol: url.split(':').next().map(|s| s.to_string()),
+                codec: codec.map(str::to_string),
+            });
+        }
+    }
+    variants
+}
+
+// Swap the picked stream for one of the same quality in a better-ranked codec
+fn prefer_codec(
+    streams: Option<&[StreamVariant]>,
+    selected: (String, String),
+    codec: CodecPreference,
+) -> (String, String) {
+    let current = codec.rank(infer_codec(&selected.1));
+    let better = streams
+        .into_iter()
+        .flatten()
+        .filter(|s| s.desc.as_deref() == Some(selected.0.as_str()))
+        .min_by_key(|s| codec.rank(s.codec.as_deref()))
+        .filter(|s| codec.rank(s.codec.as_deref()) < current);
+    match better {
+        Some(s) => (selected.0, s.url.clone()),
+        None => selected,
+    }
+}
+
+// `flv_pull_url` keys, best first, with the names the web player shows
+const FLV_KEY_LABELS: [(&str, &str); 5] = [
+    ("ORIGIN", "原画"),
+    ("FULL_HD1", "蓝光"),
+    ("HD1", "超清"),
+    ("SD1", "高清"),
+    ("SD2", "标清"),
+];
+
+/// Qualities from `collect_available_streams`; ids are `flv_pull_url` keys.
+/// Douyin offers no line choice.
+pub fn stream_options(streams: &[StreamVariant]) -> StreamOptions {
+    let rank = |key: &str| {
+        FLV_KEY_LABELS
+            .iter()
+            .position(|(k, _)| *k == key)
+            .unwrap_or(FLV_KEY_LABELS.len())
+    };
+    let mut keys: Vec<&str> = streams.iter().filter_map(|s| s.desc.as_deref()).collect();
+    keys.sort_by_key(|k| rank(k));
+    keys.dedup();
+    StreamOptions {
+        qualities: keys
+            .into_iter()
+            .map(|key| QualityOption {
+                id: key.to_string(),
+                label: FLV_KEY_LABELS
+                    .iter()
+                    .find(|(k, _)| *k == key)
+                    .map(|(_, label)| label.to_string())
+                    .unwrap_or_else(|| key.to_string()),
+                bitrate: None,
+                codec: None,
+            })
+            .collect(),
+        lines: Vec::new(),
+    }
+}
+
+// A quality passed back by its `flv_pull_url` key
+fn flv_stream_by_key(room: &Value, key: &str) -> Option<(String, String)> {
+    let key = key.trim().to_uppercase();
+    room.get("stream_url")
+        .and_then(|v| v.get("flv_pull_url"))
+        .and_then(|v| v.get(&key))
+        .and_then(|v| v.as_str())
+        .map(|url| (key.clone(), url.to_string()))
+}
+
 fn first_flv_stream(room: &Value) -> Option<(String, String)> {
     let flv_map = room
         .get("stream_url")
@@ -285,11 +442,16 @@ fn insert_origin_flv(room: &mut Value, origin_url: &str) {
 
 async fn fetch_origin_flv_from_live_page(
     http_client: &HttpClient,
+    user_agent: &str,
     web_id: &str,
 ) -> Result<Option<String>, String> {
     let url = format!("https://live.douyin.com/{}", web_id);
     let mut headers = HeaderMap::new();
-    headers.insert(USER_AGENT, HeaderValue::from_static(DEFAULT_USER_AGENT));
+    headers.insert(
+        USER_AGENT,
+        HeaderValue::from_str(user_agent)
+            .map_err(|e| format!("Invalid User-Agent header: {}", e))?,
+    );
     headers.insert(
         REFERER,
         HeaderValue::from_str(&url).map_err(|e| format!("Invalid Referer header: {}", e))?,
@@ -1,12 +1,17 @@
 use crate::platforms::common::http_client::HttpClient;
 use crate::platforms::common::{FollowHttpClient, GetStreamUrlPayload, LiveStreamInfo};
-use crate::platforms::douyin::web_api::{fetch_room_data, normalize_douyin_live_id, DouyinRoomData};
+use crate::platforms::douyin::signed_url::DouyinSignedUrlBuilder;
+use crate::platforms::douyin::web_api::{
+    fetch_room_data, normalize_douyin_live_id, DouyinRoomData, DEFAULT_USER_AGENT,
+};
 
 use crate::platforms::common::errors::DtvError;
 
 pub async fn fetch_douyin_streamer_info(
     payload: GetStreamUrlPayload,
     follow_http: &FollowHttpClient,
+    signer: &DouyinSignedUrlBuilder,
+    user_agent: Option<&str>,
 ) -> Result<LiveStreamInfo, DtvError> {
     let requested_id = payload.args.room_id_str.trim().to_string();
     if requested_id.is_empty() {
@@ -28,7 +33,8 @@ pub async fn fetch_douyin_streamer_info(
 
     let normalized_id = normalize_douyin_live_id(&requested_id);
 
-    match fetch_room_data(http_client, &normalized_id, None, false).await {
+    let user_agent = user_agent.unwrap_or(DEFAULT_USER_AGENT);
+    match fetch_room_data(http_client, signer, user_agent, &normalized_id, None, false).await {
         Ok(DouyinRoomData { room }) => {
             let web_rid = super::douyin_streamer_detail::extract_web_rid(&room)
                 .unwrap_or_else(|| normalized_id.clone());
@@ -1,5 +1,5 @@
 use crate::platforms::common::http_client::HttpClient;
-use crate::platforms::douyin::signed_url::global_builder;
+use crate::platforms::douyin::signed_url::DouyinSignedUrlBuilder;
 use crate::platforms::douyin::web_api::DEFAULT_USER_AGENT;
 use reqwest::header::{HeaderMap, HeaderValue, COOKIE, USER_AGENT};
 use serde::{Deserialize, Serialize};
@@ -76,6 +76,9 @@ pub struct DouyinLiveListResponse {
 }
 
 pub async fn fetch_douyin_partition_rooms(
+    http_client: &HttpClient,
+    signer: &DouyinSignedUrlBuilder,
+    user_agent: Option<&str>,
     partition: String,
     partition_type: String,
     offset: i32, // This is the offset for the current request (0, 15, 30...)
@@ -83,9 +86,8 @@ pub async fn fetch_douyin_partition_rooms(
 ) -> Result<DouyinLiveListResponse, String> {
     let count: i32 = 15; // Number of items requested per page, explicitly typed as i32
 
-    // 使用直连HTTP客户端，绕过所有代理设置
-    let local_client = HttpClient::new_direct_connection()
-        .map_err(|e| format!("Failed to create direct connection HttpClient: {}", e))?;
+    // Sent and signed into a_bogus, so both must be the same
+    let user_agent = user_agent.unwrap_or(DEFAULT_USER_AGENT);
 
     // Use hardcoded ttwid and odin_tt from the user's working test for now
     let hardcoded_odin_tt = "54c68ba8fa8ce792ad017c55272d171c283baedc87b2f6282ca8706df295cbd89c5d55449b587b7ebe0a2e352e394a86975955c9ed7f98f209996bdca2749479619aceecc7b75c2374e146b5a722b2e1";
@@ -101,7 +103,8 @@ pub async fn fetch_douyin_partition_rooms(
     );
     headers.insert(
         USER_AGENT,
-        HeaderValue::from_static(DEFAULT_USER_AGENT),
+        HeaderValue::from_str(user_agent)
+            .map_err(|e| format!("Failed to create user agent header value: {}", e))?,
     );
 
     let params: Vec<(String, String)> = vec![
@@ -126,16 +129,25 @@ pub async fn fetch_douyin_partition_rooms(
         ("msToken".to_string(), ms_token.clone()),
     ];
 
-    let url = global_builder().build_signed_url(
+    let url = signer.build_signed_url(
         "https://live.douyin.com/webcast/web/partition/detail/room/v2/",
         params,
-        DEFAULT_USER_AGENT,
+        user_agent,
     )?;
 
-    match local_client
-        .get_json_with_headers::<DouyinPartitionApiResponse>(&url, Some(headers))
-        .await
-    {
+    // Straight to reqwest: the wrapper's default headers would replace the signed UA
+    let response = async {
+        http_client
+            .inner
+            .get(&url)
+            .headers(headers)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<DouyinPartitionApiResponse>()
+            .await
+    };
+    match response.await {
         Ok(api_response) => {
             if api_response.status_code == 0 {
                 let mut frontend_rooms = Vec::new();
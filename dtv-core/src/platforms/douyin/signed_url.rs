@@ -17,15 +17,19 @@ pub struct DouyinSignedUrlBuilder {
     miss_count: AtomicU64,
 }
 
+/// How long a signed URL is reused for the same request.
+pub const DEFAULT_SIGN_TTL: Duration = Duration::from_secs(30);
+
 static GLOBAL: OnceLock<DouyinSignedUrlBuilder> = OnceLock::new();
 
 pub fn global_builder() -> &'static DouyinSignedUrlBuilder {
     GLOBAL.get_or_init(|| {
-        let ttl_secs = env::var("DTV_DOUYIN_SIGN_TTL")
+        let ttl = env::var("DTV_DOUYIN_SIGN_TTL")
             .ok()
             .and_then(|v| v.parse().ok())
-            .unwrap_or(30);
-        DouyinSignedUrlBuilder::new(Duration::from_secs(ttl_secs))
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_SIGN_TTL);
+        DouyinSignedUrlBuilder::new(ttl)
     })
 }
 
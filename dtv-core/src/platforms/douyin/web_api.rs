@@ -1,6 +1,6 @@
 use crate::platforms::common::http_client::HttpClient;
 use crate::platforms::common::http_headers::{headers_with_user_agent_and_referer, insert_cookie};
-use crate::platforms::douyin::signed_url::global_builder;
+use crate::platforms::douyin::signed_url::DouyinSignedUrlBuilder;
 use reqwest::header::{HeaderValue, ACCEPT_ENCODING};
 use serde_json::Value;
 
@@ -111,12 +111,14 @@ fn merge_origin_stream(room: &mut Value) {
 
 async fn fetch_room_from_api(
     http_client: &HttpClient,
+    signer: &DouyinSignedUrlBuilder,
+    user_agent: &str,
     web_id: &str,
     cookies: Option<&str>,
     include_stream: bool,
 ) -> Result<DouyinRoomData, DtvError> {
     let mut headers = headers_with_user_agent_and_referer(
-        DEFAULT_USER_AGENT,
+        user_agent,
         &format!("https://live.douyin.com/{web_id}"),
     ).map_err(|e| DtvError::internal(e))?;
     headers.insert(ACCEPT_ENCODING, HeaderValue::from_static("identity"));
@@ -135,13 +137,13 @@ async fn fetch_room_from_api(
         ("web_rid", web_id),
         ("msToken", ""),
     ];
-    let api = global_builder().build_signed_url(
+    let api = signer.build_signed_url(
         "https://live.douyin.com/webcast/room/web/enter/",
         params
             .into_iter()
             .map(|(k, v)| (k.to_string(), v.to_string()))
             .collect(),
-        DEFAULT_USER_AGENT,
+        user_agent,
     ).map_err(|e| DtvError::internal(e))?;
     let json: Value = http_client
         .inner
@@ -234,15 +236,18 @@ pub fn normalize_douyin_live_id(id_or_url: &str) -> String {
         .to_string()
 }
 
+/// `user_agent` is both sent and signed into `a_bogus`, so the two match.
 pub async fn fetch_room_data(
     http_client: &HttpClient,
+    signer: &DouyinSignedUrlBuilder,
+    user_agent: &str,
     raw_id: &str,
     cookies: Option<&str>,
     include_stream: bool,
 ) -> Result<DouyinRoomData, DtvError> {
     let web_id = normalize_douyin_live_id(raw_id);
     // 简化逻辑：直接走网页版接口 + a_bogus，避免 HTML 解析失败。
-    fetch_room_from_api(http_client, &web_id, cookies, include_stream).await
+    fetch_room_from_api(http_client, signer, user_agent, &web_id, cookies, include_stream).await
 }
 
 pub fn choose_flv_stream(room: &Value, desired_quality: &str) -> Option<(String, String)> {
@@ -1,5 +1,8 @@
 use reqwest::header::USER_AGENT;
 use serde::{Deserialize, Serialize};
+
+const MOBILE_UA: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 13_2_3 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/13.0.3 Mobile/15E148 Safari/604.1";
+
 // Structs expected by the frontend
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct FrontendCate3Item {
@@ -132,9 +135,12 @@ fn transform_raw_to_frontend_items(
         .collect()
 }
 
-pub async fn fetch_categories() -> Result<CategoriesApiResponse, String> {
+pub async fn fetch_categories(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
+) -> Result<CategoriesApiResponse, String> {
     log::info!("[API Command] fetch_categories called");
-    match fetch_categories_douyu_raw().await {
+    match fetch_categories_douyu_raw(client, user_agent).await {
         Ok(raw_data) => {
             let frontend_data = transform_raw_to_frontend_items(raw_data);
             Ok(CategoriesApiResponse {
@@ -149,16 +155,15 @@ pub async fn fetch_categories() -> Result<CategoriesApiResponse, String> {
 }
 
 // Internal function to fetch and parse to the old frontend-specific structure
-async fn fetch_categories_douyu_raw() -> Result<Vec<RawFrontendCate1Item>, String> {
-    let client = reqwest::Client::builder()
-        .no_proxy()
-        .build()
-        .map_err(|e| e.to_string())?;
+async fn fetch_categories_douyu_raw(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
+) -> Result<Vec<RawFrontendCate1Item>, String> {
     let url = "https://m.douyu.com/api/cate/list";
 
     let response = client
         .get(url)
-        .header(USER_AGENT, "Mozilla/5.0 (iPhone; CPU iPhone OS 13_2_3 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/13.0.3 Mobile/15E148 Safari/604.1")
+        .header(USER_AGENT, user_agent.unwrap_or(MOBILE_UA))
         .send()
         .await;
 
@@ -16,6 +16,7 @@ pub struct DouyuFollowInfo {
     pub room_name: Option<String>,
     pub nickname: Option<String>,
     pub avatar_url: Option<String>,
+    pub category: Option<String>,
     pub video_loop: Option<i64>,
     pub show_status: Option<i64>,
 }
@@ -58,6 +59,7 @@ pub(crate) fn parse_douyu_room_info(
         room_name: get_str(room_data, "room_name"),
         nickname: get_str(room_data, "nickname"),
         avatar_url: avatar_final_url,
+        category: get_str(room_data, "second_lvl_name").or_else(|| get_str(room_data, "cate_name")),
         video_loop: get_i64(room_data, "videoLoop"),
         show_status: get_i64(room_data, "show_status"),
     })
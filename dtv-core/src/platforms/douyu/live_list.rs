@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+const MOBILE_UA: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 13_2_3 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/13.0.3 Mobile/15E148 Safari/604.1";
+const DESKTOP_UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36";
+
 #[derive(Debug, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct LiveStreamer {
@@ -95,20 +98,21 @@ struct DouyuV1ApiResponse {
     data: Option<DouyuV1Data>,
 }
 
-pub async fn fetch_live_list(offset: u32, cate2: String, limit: u32) -> FrontendLiveListResponse {
+pub async fn fetch_live_list(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
+    offset: u32,
+    cate2: String,
+    limit: u32,
+) -> FrontendLiveListResponse {
     let url = format!(
         "https://m.douyu.com/hgapi/live/cate/newRecList?offset={}&cate2={}&limit={}",
         offset, cate2, limit
     );
 
-    let client = reqwest::Client::builder()
-        .no_proxy()
-        .build()
-        .map_err(|e| e.to_string())
-        .unwrap();
     let response_result = client
         .get(&url)
-        .header("User-Agent", "Mozilla/5.0 (iPhone; CPU iPhone OS 13_2_3 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/13.0.3 Mobile/15E148 Safari/604.1")
+        .header("User-Agent", user_agent.unwrap_or(MOBILE_UA))
         .send()
         .await;
 
@@ -224,6 +228,8 @@ pub async fn fetch_live_list(offset: u32, cate2: String, limit: u32) -> Frontend
 
 // New command for third-level categories
 pub async fn fetch_live_list_for_cate3(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
     cate3_id: String,
     page: u32,
     limit: u32,
@@ -236,19 +242,9 @@ pub async fn fetch_live_list_for_cate3(
     );
     println!("[Backend fetch_live_list_for_cate3] Fetching URL: {}", url);
 
-    let client = match reqwest::Client::builder().no_proxy().build() {
-        Ok(c) => c,
-        Err(e) => {
-            return FrontendLiveListResponse {
-                error: 500,
-                msg: Some(format!("Failed to build HTTP client: {}", e)),
-                data: None,
-            };
-        }
-    };
     let response_result = client
         .get(&url)
-        .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/91.0.4472.124 Safari/537.36")
+        .header("User-Agent", user_agent.unwrap_or(DESKTOP_UA))
         .send()
         .await;
 
@@ -1,19 +1,128 @@
-use std::sync::OnceLock;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
-use crate::platforms::common::js_function_worker::JsFunctionWorker;
+use crate::platforms::common::errors::DtvError;
+#[cfg(feature = "js-runtime")]
+use crate::platforms::common::js_function_worker::{
+    JsFunctionWorker, JsWorkerMetrics, JsWorkerOptions,
+};
 
+#[cfg(feature = "js-runtime")]
 const DOUYU_UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/114.0.0.0 Safari/537.36";
+#[cfg(feature = "js-runtime")]
 const CRYPTO_JS: &str = include_str!("cryptojs.min.js");
+// Follow-list refreshes sign many rooms at once
+#[cfg(feature = "js-runtime")]
+const SIGNER_POOL_SIZE: usize = 2;
 
+#[cfg(feature = "js-runtime")]
 static SIGNER: OnceLock<Result<JsFunctionWorker, String>> = OnceLock::new();
 
+#[cfg(feature = "js-runtime")]
+fn start_signer(pool_size: usize) -> Result<JsFunctionWorker, String> {
+    JsFunctionWorker::with_options(
+        DOUYU_UA,
+        vec![("cryptojs.min.js", CRYPTO_JS)],
+        JsWorkerOptions {
+            pool_size,
+            ..Default::default()
+        },
+    )
+}
+
+#[cfg(feature = "js-runtime")]
 fn get_worker() -> Result<&'static JsFunctionWorker, String> {
-    let result = SIGNER.get_or_init(|| {
-        JsFunctionWorker::new(DOUYU_UA, vec![("cryptojs.min.js", CRYPTO_JS)])
-    });
+    let result = SIGNER.get_or_init(|| start_signer(SIGNER_POOL_SIZE));
     result.as_ref().map_err(|e| e.clone())
 }
 
+/// Start the process-wide signer with `pool_size` V8 threads.
+/// Returns false if it was already running (the existing pool is kept).
+#[cfg(feature = "js-runtime")]
+pub fn init_signer(pool_size: usize) -> bool {
+    let mut created = false;
+    SIGNER.get_or_init(|| {
+        created = true;
+        start_signer(pool_size)
+    });
+    created
+}
+
+#[cfg(feature = "js-runtime")]
+pub fn signer_metrics() -> Option<JsWorkerMetrics> {
+    SIGNER.get().and_then(|r| r.as_ref().ok()).map(|w| w.metrics())
+}
+
+/// Stream signing evaluates server-provided JS, so it needs the `js-runtime` feature.
+pub fn ensure_available() -> Result<(), DtvError> {
+    if cfg!(feature = "js-runtime") {
+        Ok(())
+    } else {
+        Err(DtvError::unsupported(
+            "Douyu stream signing requires dtv-core's `js-runtime` feature",
+        ))
+    }
+}
+
+// homeH5Enc scripts per room id, reused until the TTL runs out
+struct SignScriptCache {
+    entries: Mutex<HashMap<String, (Instant, String)>>,
+    ttl: Duration,
+}
+
+pub const DEFAULT_SIGN_SCRIPT_TTL: Duration = Duration::from_secs(300);
+
+static SCRIPT_CACHE: OnceLock<SignScriptCache> = OnceLock::new();
+
+fn new_script_cache(ttl: Duration) -> SignScriptCache {
+    SignScriptCache {
+        entries: Mutex::new(HashMap::new()),
+        ttl,
+    }
+}
+
+fn script_cache() -> &'static SignScriptCache {
+    SCRIPT_CACHE.get_or_init(|| new_script_cache(DEFAULT_SIGN_SCRIPT_TTL))
+}
+
+/// Set how long a room's sign script is reused. The cache is process-wide
+/// like the signer; returns false if it was already in use (the TTL is kept).
+pub fn init_sign_script_ttl(ttl: Duration) -> bool {
+    let mut created = false;
+    SCRIPT_CACHE.get_or_init(|| {
+        created = true;
+        new_script_cache(ttl)
+    });
+    created
+}
+
+pub fn cached_sign_script(room_id: &str) -> Option<String> {
+    let entries = script_cache().entries.lock().ok()?;
+    entries
+        .get(room_id)
+        .filter(|(expires_at, _)| *expires_at > Instant::now())
+        .map(|(_, script)| script.clone())
+}
+
+pub fn store_sign_script(room_id: &str, script: &str) {
+    let cache = script_cache();
+    if let Ok(mut entries) = cache.entries.lock() {
+        let now = Instant::now();
+        if entries.len() >= 256 {
+            entries.retain(|_, (exp, _)| *exp > now);
+        }
+        entries.insert(room_id.to_string(), (now + cache.ttl, script.to_string()));
+    }
+}
+
+pub fn invalidate_sign_script(room_id: &str) {
+    if let Ok(mut entries) = script_cache().entries.lock() {
+        entries.remove(room_id);
+    }
+}
+
+#[cfg(feature = "js-runtime")]
 pub async fn execute_js_sign(
     script: &str,
     rid: &str,
@@ -34,3 +143,13 @@ pub async fn execute_js_sign(
 
     worker.eval_string(expr).await
 }
+
+#[cfg(not(feature = "js-runtime"))]
+pub async fn execute_js_sign(
+    _script: &str,
+    _rid: &str,
+    _did: &str,
+    _ts: i64,
+) -> Result<String, String> {
+    Err("Douyu stream signing requires dtv-core's `js-runtime` feature".to_string())
+}
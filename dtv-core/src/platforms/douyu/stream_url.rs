@@ -1,7 +1,6 @@
 use html_escape::decode_html_entities;
 use reqwest::{
     header::{HeaderMap, HeaderValue},
-    redirect::Policy,
     Client,
 };
 use serde::Deserialize;
@@ -11,6 +10,9 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use crate::platforms::douyu::sign_worker;
 
 use crate::platforms::common::errors::DtvError;
+use crate::platforms::common::{
+    CodecPreference, LineCandidate, LineOption, QualityOption, StreamHeaders, StreamOptions,
+};
 
 #[derive(Deserialize, Debug)]
 struct BetardRoomInfo {
@@ -26,7 +28,14 @@ struct BetardResponse {
 #[derive(Clone, Debug)]
 struct DouyuPlayInfo {
     variants: Vec<DouyuRateVariant>,
-    cdns: Vec<String>,
+    cdns: Vec<DouyuCdn>,
+}
+
+// One `cdnsWithName` entry, e.g. cdn "hs-h5" named "线路3"
+#[derive(Clone, Debug)]
+struct DouyuCdn {
+    cdn: String,
+    name: String,
 }
 
 #[derive(Clone, Debug)]
@@ -52,10 +61,14 @@ fn value_to_string(value: &Value) -> Option<String> {
     }
 }
 
-struct DouYu {
+struct DouYu<'a> {
     did: String,
     rid: String,
-    client: Client,
+    client: &'a Client,
+    // UA and language sent with every API request
+    headers: HeaderMap,
+    // getH5Play `hevc` flag: whether H.265 streams may be returned
+    hevc: bool,
 }
 
 const DEFAULT_DOUYU_CDN: &str = "ws-h5";
@@ -76,25 +89,30 @@ fn normalize_douyu_cdn(input: Option<&str>) -> &'static str {
     }
 }
 
-impl DouYu {
-    async fn new(rid: &str) -> Result<Self, Box<dyn std::error::Error>> {
-        // 迁移到 reqwest：禁用系统代理、限制重定向、设置默认 UA/语言等头部
-        let mut default_headers = HeaderMap::new();
-        default_headers.insert("User-Agent", HeaderValue::from_static(DEFAULT_DOUYU_UA));
-        default_headers.insert(
+impl<'a> DouYu<'a> {
+    fn new(
+        client: &'a Client,
+        user_agent: Option<&str>,
+        rid: &str,
+        hevc: bool,
+    ) -> Result<Self, DtvError> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "User-Agent",
+            HeaderValue::from_str(user_agent.unwrap_or(DEFAULT_DOUYU_UA))
+                .map_err(|e| DtvError::internal(format!("Invalid user agent: {}", e)))?,
+        );
+        headers.insert(
             "Accept-Language",
             HeaderValue::from_static("zh-CN,zh;q=0.9"),
         );
-        let client = Client::builder()
-            .redirect(Policy::limited(10))
-            .no_proxy()
-            .default_headers(default_headers)
-            .build()?;
 
         Ok(Self {
             did: DEFAULT_DOUYU_DID.to_string(),
             rid: rid.to_string(),
             client,
+            headers,
+            hevc,
         })
     }
 
@@ -103,6 +121,7 @@ impl DouYu {
         let json = self
             .client
             .get(url)
+            .headers(self.headers.clone())
             .header("Referer", format!("https://www.douyu.com/{}", self.rid))
             .send()
             .await?
@@ -125,6 +144,7 @@ impl DouYu {
         let json = self
             .client
             .get(url)
+            .headers(self.headers.clone())
             .header("Referer", format!("https://www.douyu.com/{}", room_id))
             .send()
             .await?
@@ -145,13 +165,26 @@ impl DouYu {
         Ok(crptext.to_string())
     }
 
-    async fn build_sign_params(&self, room_id: &str) -> Result<String, Box<dyn std::error::Error>> {
-        let crptext = self.get_h5_enc(room_id).await?;
+    // Sign params, and whether they came from a cached script
+    async fn build_sign_params(
+        &self,
+        room_id: &str,
+    ) -> Result<(String, bool), Box<dyn std::error::Error>> {
         let ts = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as i64;
+        if let Some(crptext) = sign_worker::cached_sign_script(room_id) {
+            match sign_worker::execute_js_sign(&crptext, room_id, &self.did, ts).await {
+                Ok(params) => return Ok((params, true)),
+                // Server may have rotated the script; refetch below
+                Err(_) => sign_worker::invalidate_sign_script(room_id),
+            }
+        }
+
+        let crptext = self.get_h5_enc(room_id).await?;
         let params = sign_worker::execute_js_sign(&crptext, room_id, &self.did, ts)
             .await
             .map_err(|e| -> Box<dyn std::error::Error> { e.into() })?;
-        Ok(params)
+        sign_worker::store_sign_script(room_id, &crptext);
+        Ok((params, false))
     }
 
     async fn get_play_qualities(
@@ -160,13 +193,15 @@ impl DouYu {
         sign_data: &str,
     ) -> Result<DouyuPlayInfo, Box<dyn std::error::Error>> {
         let payload = format!(
-            "{}&cdn=&rate=-1&ver=Douyu_223061205&iar=1&ive=1&hevc=0&fa=0",
-            sign_data
+            "{}&cdn=&rate=-1&ver=Douyu_223061205&iar=1&ive=1&hevc={}&fa=0",
+            sign_data,
+            u8::from(self.hevc)
         );
         let url = format!("https://www.douyu.com/lapi/live/getH5Play/{}", room_id);
         let json = self
             .client
             .post(url)
+            .headers(self.headers.clone())
             .header("Content-Type", "application/x-www-form-urlencoded")
             .body(payload)
             .send()
@@ -190,19 +225,24 @@ impl DouYu {
             .map(|arr| {
                 arr.iter()
                     .filter_map(|item| {
-                        item.get("cdn")
+                        let cdn = item.get("cdn").and_then(|v| v.as_str())?.to_string();
+                        let name = item
+                            .get("name")
                             .and_then(|v| v.as_str())
+                            .filter(|s| !s.trim().is_empty())
                             .map(|s| s.to_string())
+                            .unwrap_or_else(|| cdn.clone());
+                        Some(DouyuCdn { cdn, name })
                     })
-                    .collect::<Vec<String>>()
+                    .collect::<Vec<DouyuCdn>>()
             })
             .unwrap_or_default();
 
         let mut cdns_sorted = cdns;
         cdns_sorted.sort_by(|a, b| {
-            let a_is_scdn = a.starts_with("scdn");
-            let b_is_scdn = b.starts_with("scdn");
-            (a_is_scdn, a).cmp(&(b_is_scdn, b))
+            let a_is_scdn = a.cdn.starts_with("scdn");
+            let b_is_scdn = b.cdn.starts_with("scdn");
+            (a_is_scdn, &a.cdn).cmp(&(b_is_scdn, &b.cdn))
         });
 
         let variants = data
@@ -240,11 +280,18 @@ impl DouYu {
         rate: i32,
         cdn: &str,
     ) -> Result<String, Box<dyn std::error::Error>> {
-        let payload = format!("{}&cdn={}&rate={}", sign_data, cdn, rate);
+        let payload = format!(
+            "{}&cdn={}&rate={}&hevc={}",
+            sign_data,
+            cdn,
+            rate,
+            u8::from(self.hevc)
+        );
         let url = format!("https://www.douyu.com/lapi/live/getH5Play/{}", room_id);
         let json = self
             .client
             .post(url)
+            .headers(self.headers.clone())
             .header("Content-Type", "application/x-www-form-urlencoded")
             .header("Referer", format!("https://www.douyu.com/{}", room_id))
             .body(payload)
@@ -275,30 +322,28 @@ impl DouYu {
         Ok(format!("{}/{}", rtmp_url, rtmp_live))
     }
 
-    fn select_cdn(requested: Option<&str>, available: &[String]) -> String {
+    fn select_cdn(requested: Option<&str>, available: &[DouyuCdn]) -> String {
         if let Some(cdn) = requested {
             let trimmed = cdn.trim();
             if !trimmed.is_empty() {
                 let target = trimmed.to_ascii_lowercase();
                 if let Some(hit) = available
                     .iter()
-                    .find(|item| item.to_ascii_lowercase() == target)
+                    .find(|item| item.cdn.to_ascii_lowercase() == target)
                 {
-                    return hit.clone();
+                    return hit.cdn.clone();
                 }
             }
         }
         available
             .first()
-            .cloned()
+            .map(|item| item.cdn.clone())
             .unwrap_or_else(|| normalize_douyu_cdn(requested).to_string())
     }
 
-    pub async fn get_real_url_with_quality(
-        &self,
-        quality: &str,
-        cdn: Option<&str>,
-    ) -> Result<String, DtvError> {
+    // Real room id, sign params and the rates/CDNs of a live room
+    async fn live_play_info(&self) -> Result<(String, String, DouyuPlayInfo), DtvError> {
+        sign_worker::ensure_available()?;
         let (real_room_id, is_live) = self
             .fetch_room_detail()
             .await
@@ -307,14 +352,42 @@ impl DouYu {
             return Err(DtvError::offline("主播未开播"));
         }
 
-        let sign_data = self
+        let (mut sign_data, cached) = self
             .build_sign_params(&real_room_id)
             .await
             .map_err(|e| DtvError::api(e.to_string()))?;
-        let play_info = self
+        // (is a network error, message): the boxed error is not Send
+        let first = self
             .get_play_qualities(&real_room_id, &sign_data)
             .await
-            .map_err(|e| DtvError::api(e.to_string()))?;
+            .map_err(|e| (e.is::<reqwest::Error>(), e.to_string()));
+        let play_info = match first {
+            Ok(info) => info,
+            // getH5Play turned down a sign from a stale cached script: drop
+            // it and sign once more with a fresh one
+            Err((false, e)) if cached => {
+                tracing::debug!(target: "douyu_stream", "[Douyu] Cached sign rejected ({}), refetching script", e);
+                sign_worker::invalidate_sign_script(&real_room_id);
+                sign_data = self
+                    .build_sign_params(&real_room_id)
+                    .await
+                    .map_err(|e| DtvError::api(e.to_string()))?
+                    .0;
+                self.get_play_qualities(&real_room_id, &sign_data)
+                    .await
+                    .map_err(|e| DtvError::api(e.to_string()))?
+            }
+            Err((_, e)) => return Err(DtvError::api(e)),
+        };
+        Ok((real_room_id, sign_data, play_info))
+    }
+
+    pub async fn get_real_url_with_quality(
+        &self,
+        quality: &str,
+        cdn: Option<&str>,
+    ) -> Result<String, DtvError> {
+        let (real_room_id, sign_data, play_info) = self.live_play_info().await?;
         let selected_rate = Self::resolve_rate_for_quality(quality, &play_info.variants)
             .or_else(|| play_info.variants.iter().map(|v| v.rate).max())
             .unwrap_or(0);
@@ -338,6 +411,12 @@ impl DouYu {
         }
 
         let trimmed = quality.trim();
+        // A rate from the room's options
+        if let Ok(rate) = trimmed.parse::<i32>() {
+            if variants.iter().any(|v| v.rate == rate) {
+                return Some(rate);
+            }
+        }
         let ascii_lower = trimmed.to_ascii_lowercase();
         let canonical = if trimmed.contains('原') || ascii_lower == "origin" {
             "原画"
@@ -436,18 +515,90 @@ impl DouYu {
     }
 }
 
+/// `codec` decides whether getH5Play may answer with an H.265 stream.
+/// `user_agent` replaces the built-in desktop UA when set.
 pub async fn get_stream_url_with_quality(
+    client: &Client,
+    user_agent: Option<&str>,
     room_id: &str,
     quality: &str,
     cdn: Option<&str>,
+    codec: CodecPreference,
 ) -> Result<String, DtvError> {
-    let douyu = DouYu::new(room_id)
-        .await
-        .map_err(|e| DtvError::internal(e.to_string()))?;
+    let douyu = DouYu::new(client, user_agent, room_id, codec.accepts_hevc())?;
     let url = douyu.get_real_url_with_quality(quality, cdn).await?;
     Ok(url)
 }
 
+// Quality ids are `multirates` rates, line ids are `cdnsWithName` CDNs
+fn stream_options(play_info: &DouyuPlayInfo) -> StreamOptions {
+    StreamOptions {
+        qualities: play_info
+            .variants
+            .iter()
+            .map(|v| QualityOption {
+                id: v.rate.to_string(),
+                label: v.name.clone(),
+                bitrate: v.bit.filter(|b| *b > 0).map(i64::from),
+                codec: None,
+            })
+            .collect(),
+        lines: play_info
+            .cdns
+            .iter()
+            .map(|c| LineOption {
+                id: c.cdn.clone(),
+                label: c.name.clone(),
+            })
+            .collect(),
+    }
+}
+
+/// Qualities and CDN lines of a live room; offline rooms are an error.
+pub async fn get_stream_options(
+    client: &Client,
+    user_agent: Option<&str>,
+    room_id: &str,
+) -> Result<StreamOptions, DtvError> {
+    let douyu = DouYu::new(client, user_agent, room_id, false)?;
+    let (_, _, play_info) = douyu.live_play_info().await?;
+    Ok(stream_options(&play_info))
+}
+
+/// Headers Douyu's CDNs expect for getH5Play URLs.
+pub fn stream_headers(user_agent: Option<&str>) -> StreamHeaders {
+    crate::platforms::common::stream_headers(
+        user_agent.unwrap_or(DEFAULT_DOUYU_UA),
+        "https://www.douyu.com/",
+        None,
+    )
+}
+
+/// One stream per CDN at the top rate, for the line prober.
+pub async fn fetch_line_candidates(
+    client: &Client,
+    user_agent: Option<&str>,
+    room_id: &str,
+) -> Result<Vec<LineCandidate>, DtvError> {
+    let douyu = DouYu::new(client, user_agent, room_id, false)?;
+    let (real_room_id, sign_data, play_info) = douyu.live_play_info().await?;
+    let rate = play_info.variants.iter().map(|v| v.rate).max().unwrap_or(0);
+    let mut candidates = Vec::with_capacity(play_info.cdns.len());
+    for cdn in &play_info.cdns {
+        match douyu
+            .get_play_url(&real_room_id, &sign_data, rate, &cdn.cdn)
+            .await
+        {
+            Ok(url) => candidates.push(
+                LineCandidate::new(&cdn.cdn, &cdn.name, url)
+                    .with_headers(stream_headers(user_agent)),
+            ),
+            Err(e) => eprintln!("[Douyu] no stream for CDN {}: {}", cdn.cdn, e),
+        }
+    }
+    Ok(candidates)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -511,4 +662,31 @@ mod tests {
             Some(3)
         );
     }
+
+    #[test]
+    fn resolve_rate_accepts_option_ids() {
+        let variants = variants_sample();
+        assert_eq!(DouYu::resolve_rate_for_quality("2", &variants), Some(2));
+        assert_eq!(DouYu::resolve_rate_for_quality("0", &variants), Some(0));
+        // Not offered by this room
+        assert_eq!(DouYu::resolve_rate_for_quality("9", &variants), None);
+    }
+
+    #[test]
+    fn options_list_rates_and_named_cdns() {
+        let play_info = DouyuPlayInfo {
+            variants: variants_sample(),
+            cdns: vec![DouyuCdn {
+                cdn: "hs-h5".to_string(),
+                name: "线路3".to_string(),
+            }],
+        };
+        let options = stream_options(&play_info);
+        let ids: Vec<&str> = options.qualities.iter().map(|q| q.id.as_str()).collect();
+        assert_eq!(ids, ["0", "4", "3", "2"]);
+        assert_eq!(options.qualities[1].label, "蓝光");
+        assert_eq!(options.qualities[1].bitrate, Some(4000));
+        assert_eq!(options.lines[0].id, "hs-h5");
+        assert_eq!(options.lines[0].label, "线路3");
+    }
 }
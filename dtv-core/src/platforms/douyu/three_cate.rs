@@ -36,7 +36,10 @@ fn transform_three_cate_to_common(
         .collect()
 }
 
-pub async fn fetch_three_cate(tag_id: i32) -> Result<Vec<CommonPlatformCategory>, String> {
+pub async fn fetch_three_cate(
+    client: &reqwest::Client,
+    tag_id: i32,
+) -> Result<Vec<CommonPlatformCategory>, String> {
     let tag_id_str = tag_id.to_string();
     let url = format!(
         "https://capi.douyucdn.cn/api/v1/getThreeCate?tag_id={}&client_sys=android",
@@ -47,7 +50,7 @@ pub async fn fetch_three_cate(tag_id: i32) -> Result<Vec<CommonPlatformCategory>
         tag_id_str
     );
 
-    match reqwest::get(&url).await {
+    match client.get(&url).send().await {
         Ok(response) => {
             if response.status().is_success() {
                 let body_text = response
@@ -74,6 +74,7 @@ fn map_huya_item_to_frontend(item: &serde_json::Value) -> Option<HuyaStreamerFro
 }
 
 pub async fn fetch_huya_live_list(
+    client: &HttpClient,
     i_gid: String,
     i_page_no: u32,
     i_page_size: u32,
@@ -85,18 +86,6 @@ pub async fn fetch_huya_live_list(
         i_page_size
     );
 
-    let client = match HttpClient::new_direct_connection() {
-        Ok(c) => c,
-        Err(e) => {
-            eprintln!("[Huya Backend] Failed to init HTTP client: {}", e);
-            return HuyaLiveListFrontendResponse {
-                error: 500,
-                msg: Some(e),
-                data: None,
-            };
-        }
-    };
-
     // 修复：get_json 是异步方法，需要 .await；并直接匹配 Result 而不是对 Result 使用 .await
     let resp_value: serde_json::Value = match client.get_json::<serde_json::Value>(&url).await {
         Ok(v) => v,
@@ -3,6 +3,8 @@ use reqwest::header::{
 };
 use serde::Serialize;
 
+const DESKTOP_UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/118.0.0.0 Safari/537.36";
+
 #[derive(Debug, Serialize)]
 pub struct HuyaAnchorItem {
     pub room_id: String,
@@ -13,16 +15,17 @@ pub struct HuyaAnchorItem {
 }
 
 pub async fn search_huya_anchors(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
     keyword: String,
     page: Option<usize>,
 ) -> Result<Vec<HuyaAnchorItem>, String> {
-    let client = reqwest::Client::builder()
-        .no_proxy()
-        .build()
-        .map_err(|e| e.to_string())?;
     let url = "https://search.cdn.huya.com/";
     let mut headers = HeaderMap::new();
-    headers.insert(USER_AGENT, HeaderValue::from_static("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/118.0.0.0 Safari/537.36"));
+    headers.insert(
+        USER_AGENT,
+        HeaderValue::from_str(user_agent.unwrap_or(DESKTOP_UA)).map_err(|e| e.to_string())?,
+    );
     headers.insert(
         REFERER,
         HeaderValue::from_static("https://www.huya.com/search/"),
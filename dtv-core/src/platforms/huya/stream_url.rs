@@ -12,7 +12,10 @@ use reqwest::header::{
 use serde::Serialize;
 use serde_json::Value;
 
-use crate::platforms::common::FollowHttpClient;
+use crate::platforms::common::{
+    CodecPreference, FollowHttpClient, LineCandidate, LineOption, QualityOption, StreamHeaders,
+    StreamOptions,
+};
 use crate::platforms::common::signing::query::join_kv_pairs;
 
 use crate::platforms::common::errors::DtvError;
@@ -39,6 +42,8 @@ pub struct HuyaUnifiedResponse {
     pub is_live: bool,
     pub flv_tx_urls: Vec<HuyaUnifiedStreamEntry>,
     pub selected_url: Option<String>,
+    // "hevc" when the H.265 rate was picked
+    pub codec: Option<String>,
 }
 
 fn current_millis() -> i64 {
@@ -175,6 +180,7 @@ pub struct RoomDetail {
     pub title: Option<String>,
     pub nick: Option<String>,
     pub avatar180: Option<String>,
+    pub category: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -183,10 +189,20 @@ struct WebStreamCandidate {
     cdn: String,
 }
 
+// One `vMultiStreamInfo` entry; a bit rate of 0 is the source. Rates that
+// also exist in H.265 carry that rate in `iHEVCBitRate` (-1 otherwise).
+#[derive(Clone, Debug, PartialEq)]
+struct HuyaQuality {
+    name: String,
+    bit_rate: i32,
+    hevc_bit_rate: Option<i32>,
+}
+
 #[derive(Clone, Debug)]
 struct HuyaWebStreamData {
     is_live: bool,
     candidates: Vec<WebStreamCandidate>,
+    qualities: Vec<HuyaQuality>,
 }
 
 pub async fn fetch_room_detail(
@@ -214,6 +230,7 @@ pub async fn fetch_room_detail(
             title: None,
             nick: None,
             avatar180: None,
+            category: None,
         });
     }
 
@@ -223,6 +240,7 @@ pub async fn fetch_room_detail(
             title: None,
             nick: None,
             avatar180: None,
+            category: None,
         });
     };
 
@@ -243,12 +261,18 @@ pub async fn fetch_room_detail(
         .and_then(|ld| ld.get("avatar180"))
         .and_then(|x| x.as_str())
         .map(|s| s.to_string());
+    let category = data
+        .get("liveData")
+        .and_then(|ld| ld.get("gameFullName"))
+        .and_then(|x| x.as_str())
+        .map(|s| s.to_string());
 
     Ok(RoomDetail {
         status: stream_ok,
         title,
         nick,
         avatar180,
+        category,
     })
 }
 
@@ -319,6 +343,7 @@ async fn fetch_web_stream_data_with_headers(
         return Ok(HuyaWebStreamData {
             is_live: false,
             candidates: Vec::new(),
+            qualities: Vec::new(),
         });
     };
     let json_fragment = caps.get(1).map(|m| m.as_str()).unwrap_or("");
@@ -331,6 +356,7 @@ async fn fetch_web_stream_data_with_headers(
             return Ok(HuyaWebStreamData {
                 is_live: false,
                 candidates: Vec::new(),
+                qualities: Vec::new(),
             })
         }
     };
@@ -343,6 +369,7 @@ async fn fetch_web_stream_data_with_headers(
             return Ok(HuyaWebStreamData {
                 is_live: false,
                 candidates: Vec::new(),
+                qualities: Vec::new(),
             })
         }
     };
@@ -405,9 +432,146 @@ async fn fetch_web_stream_data_with_headers(
     Ok(HuyaWebStreamData {
         is_live: !candidates.is_empty(),
         candidates,
+        qualities: parse_multi_stream_info(&value),
     })
 }
 
+// Rates the room offers, best first; empty when the page omits them
+fn parse_multi_stream_info(stream: &Value) -> Vec<HuyaQuality> {
+    let mut qualities: Vec<HuyaQuality> = stream
+        .get("vMultiStreamInfo")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|item| {
+                    let name = item.get("sDisplayName").and_then(|v| v.as_str())?;
+                    let bit_rate = item.get("iBitRate").and_then(|v| v.as_i64())? as i32;
+                    let hevc_bit_rate = item
+                        .get("iHEVCBitRate")
+                        .and_then(|v| v.as_i64())
+                        .filter(|r| *r > 0)
+                        .map(|r| r as i32);
+                    Some(HuyaQuality {
+                        name: name.to_string(),
+                        bit_rate,
+                        hevc_bit_rate,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    qualities.sort_by_key(|q| if q.bit_rate == 0 { i32::MIN } else { -q.bit_rate });
+    qualities.dedup_by_key(|q| q.bit_rate);
+    qualities
+}
+
+// The fixed rates used when a room does not list its own
+fn default_qualities() -> Vec<HuyaQuality> {
+    [("原画", 0), ("高清", 4000), ("标清", 2000)]
+        .iter()
+        .map(|(name, bit_rate)| HuyaQuality {
+            name: name.to_string(),
+            bit_rate: *bit_rate,
+            hevc_bit_rate: None,
+        })
+        .collect()
+}
+
+fn cdn_label(cdn: &str) -> String {
+    match cdn.to_ascii_uppercase().as_str() {
+        "TX" => "腾讯云".to_string(),
+        "AL" => "阿里云".to_string(),
+        "HS" => "火山引擎".to_string(),
+        "HW" => "华为云".to_string(),
+        "WS" => "网宿".to_string(),
+        _ => cdn.to_string(),
+    }
+}
+
+fn stream_options(data: &HuyaWebStreamData) -> StreamOptions {
+    let qualities = if data.qualities.is_empty() {
+        default_qualities()
+    } else {
+        data.qualities.clone()
+    };
+    let mut lines: Vec<LineOption> = Vec::new();
+    for candidate in &data.candidates {
+        let id = candidate.cdn.to_ascii_lowercase();
+        if id.is_empty() || lines.iter().any(|l| l.id == id) {
+            continue;
+        }
+        lines.push(LineOption {
+            label: cdn_label(&candidate.cdn),
+            id,
+        });
+    }
+    StreamOptions {
+        qualities: qualities
+            .into_iter()
+            .map(|q| QualityOption {
+                id: q.bit_rate.to_string(),
+                label: q.name,
+                bitrate: Some(i64::from(q.bit_rate)).filter(|b| *b > 0),
+                codec: None,
+            })
+            .collect(),
+        lines,
+    }
+}
+
+/// Rates and CDN lines of a live room, from the web page's stream info.
+pub async fn fetch_stream_options(
+    client: &reqwest::Client,
+    room_id: &str,
+) -> Result<StreamOptions, DtvError> {
+    let data = fetch_web_stream_data(client, room_id)
+        .await
+        .map_err(|e| DtvError::api(e.to_string()))?;
+    if !data.is_live {
+        return Err(DtvError::offline("主播未开播"));
+    }
+    Ok(stream_options(&data))
+}
+
+/// Headers Huya's CDNs expect; stream URLs come from the desktop page.
+pub fn stream_headers() -> StreamHeaders {
+    crate::platforms::common::stream_headers(
+        DESKTOP_UA,
+        "https://www.huya.com/",
+        Some("https://www.huya.com"),
+    )
+}
+
+// The source stream of each CDN, ids as in `stream_options`
+fn line_candidates(data: &HuyaWebStreamData) -> Vec<LineCandidate> {
+    let mut candidates: Vec<LineCandidate> = Vec::new();
+    for candidate in &data.candidates {
+        let id = candidate.cdn.to_ascii_lowercase();
+        if id.is_empty() || candidates.iter().any(|c| c.line == id) {
+            continue;
+        }
+        let url = adjust_tx_stream_url(&candidate.base_flv, &candidate.cdn);
+        candidates.push(
+            LineCandidate::new(id, cdn_label(&candidate.cdn), url).with_headers(stream_headers()),
+        );
+    }
+    candidates
+}
+
+/// One source stream per CDN, for the line prober.
+pub async fn fetch_line_candidates(
+    client: &reqwest::Client,
+    room_id: &str,
+) -> Result<Vec<LineCandidate>, DtvError> {
+    let data = fetch_web_stream_data(client, room_id)
+        .await
+        .map_err(|e| DtvError::api(e.to_string()))?;
+    if !data.is_live {
+        return Err(DtvError::offline("主播未开播"));
+    }
+    Ok(line_candidates(&data))
+}
+
 fn cdn_priority(cdn: &str) -> usize {
     if cdn.eq_ignore_ascii_case("tx") {
         0
@@ -434,10 +598,11 @@ fn adjust_tx_stream_url(url: &str, cdn: &str) -> String {
     }
 }
 
+// Any CDN the room lists is a valid line; unknown ones fall back to the first
 fn normalize_huya_line(input: Option<&str>) -> Option<String> {
     input
         .map(|s| s.trim().to_ascii_lowercase())
-        .filter(|s| matches!(s.as_str(), "tx" | "al" | "hs"))
+        .filter(|s| !s.is_empty())
 }
 
 fn prioritize_candidates(candidates: Vec<WebStreamCandidate>) -> Vec<WebStreamCandidate> {
@@ -492,11 +657,43 @@ fn resolve_ratio(quality: Option<&str>) -> Option<i32> {
         if trimmed.contains("原画") || lower == "source" || lower == "uhd" {
             return None;
         }
+        // A bit rate from the room's options; 0 is the source
+        if let Ok(rate) = trimmed.parse::<i32>() {
+            return Some(rate).filter(|r| *r > 0);
+        }
         return Some(4000);
     }
     None
 }
 
+// The H.265 rate for a requested ratio, when HEVC is preferred and listed
+fn hevc_ratio(
+    qualities: &[HuyaQuality],
+    ratio: Option<i32>,
+    codec: CodecPreference,
+) -> Option<i32> {
+    if codec != CodecPreference::Hevc {
+        return None;
+    }
+    let bit_rate = ratio.unwrap_or(0);
+    qualities
+        .iter()
+        .find(|q| q.bit_rate == bit_rate)
+        .and_then(|q| q.hevc_bit_rate)
+}
+
+// The anti-code asks for H.264; FLV URLs switch to H.265 with its rate
+fn with_hevc(url: String, hevc_ratio: i32) -> Option<String> {
+    if !is_flv_url(&url) || !url.contains("&codec=264") {
+        return None;
+    }
+    let url = url.replace("&codec=264", "&codec=265");
+    Some(match url.find("&ratio=") {
+        Some(pos) => format!("{}&ratio={}", &url[..pos], hevc_ratio),
+        None => format!("{}&ratio={}", url, hevc_ratio),
+    })
+}
+
 fn pick_stream_url(
     candidates: &[WebStreamCandidate],
     ratio: Option<i32>,
@@ -526,39 +723,42 @@ fn pick_stream_url(
     }
 }
 
-fn build_flv_tx_urls(candidate: Option<&WebStreamCandidate>) -> Vec<HuyaUnifiedStreamEntry> {
+fn build_flv_tx_urls(
+    candidate: Option<&WebStreamCandidate>,
+    qualities: &[HuyaQuality],
+) -> Vec<HuyaUnifiedStreamEntry> {
     let Some(base) = candidate else {
         return Vec::new();
     };
 
-    let mut entries = Vec::new();
     let adjusted_base = adjust_tx_stream_url(&base.base_flv, &base.cdn);
-    entries.push(HuyaUnifiedStreamEntry {
-        quality: "原画".to_string(),
-        bitRate: 0,
-        url: adjusted_base.clone(),
-    });
-
-    if is_flv_url(&adjusted_base) {
-        entries.push(HuyaUnifiedStreamEntry {
-            quality: "高清".to_string(),
-            bitRate: 4000,
-            url: format!("{}&ratio={}", adjusted_base, 4000),
-        });
-        entries.push(HuyaUnifiedStreamEntry {
-            quality: "标清".to_string(),
-            bitRate: 2000,
-            url: format!("{}&ratio={}", adjusted_base, 2000),
-        });
-    }
-
-    entries
+    let qualities = if qualities.is_empty() {
+        default_qualities()
+    } else {
+        qualities.to_vec()
+    };
+    // Only FLV URLs take a ratio; anything else is the source alone
+    let flv = is_flv_url(&adjusted_base);
+    qualities
+        .into_iter()
+        .filter(|q| flv || q.bit_rate == 0)
+        .map(|q| HuyaUnifiedStreamEntry {
+            url: if q.bit_rate == 0 {
+                adjusted_base.clone()
+            } else {
+                format!("{}&ratio={}", adjusted_base, q.bit_rate)
+            },
+            quality: q.name,
+            bitRate: q.bit_rate,
+        })
+        .collect()
 }
 
 pub async fn get_huya_unified_cmd(
     room_id: String,
     quality: Option<String>,
     line: Option<String>,
+    codec: CodecPreference,
     follow_http: &FollowHttpClient,
 ) -> Result<HuyaUnifiedResponse, DtvError> {
     let client = &follow_http.0.inner;
@@ -586,11 +786,19 @@ pub async fn get_huya_unified_cmd(
                 is_live: detail.status || web_stream.is_live,
                 flv_tx_urls: Vec::new(),
                 selected_url: None,
+                codec: None,
             });
         }
     };
-    let tx_entries = build_flv_tx_urls(web_stream.candidates.get(selected_index));
+    let tx_entries = build_flv_tx_urls(
+        web_stream.candidates.get(selected_index),
+        &web_stream.qualities,
+    );
     let is_live = detail.status || web_stream.is_live;
+    let hevc = hevc_ratio(&web_stream.qualities, ratio, codec)
+        .and_then(|rate| with_hevc(selected_url.clone(), rate));
+    let selected_codec = if hevc.is_some() { "hevc" } else { "avc" };
+    let selected_url = hevc.unwrap_or(selected_url);
     tracing::debug!(
         "[Huya] requested quality: {:?}, resolved ratio: {:?}, preferred line: {:?}, selected line: {:?}",
         quality,
@@ -611,7 +819,101 @@ pub async fn get_huya_unified_cmd(
         is_live,
         flv_tx_urls: tx_entries,
         selected_url: Some(selected_url),
+        codec: Some(selected_codec.to_string()),
     })
 }
 #[allow(dead_code)]
 const HEARTBEAT_BASE64: &str = "ABQdAAwsNgBM"; // same as Python
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn options_follow_multi_stream_info_and_cdns() {
+        let stream = json!({
+            "data": [],
+            "vMultiStreamInfo": [
+                { "sDisplayName": "超清", "iBitRate": 2000 },
+                { "sDisplayName": "蓝光10M", "iBitRate": 0 },
+                { "sDisplayName": "蓝光4M", "iBitRate": 4000 },
+                { "sDisplayName": "流畅", "iBitRate": 500 }
+            ]
+        });
+        let candidate = |cdn: &str| WebStreamCandidate {
+            base_flv: format!("https://{}.flv.huya.com/src/1.flv?wsSecret=x", cdn),
+            cdn: cdn.to_string(),
+        };
+        let data = HuyaWebStreamData {
+            is_live: true,
+            candidates: vec![candidate("TX"), candidate("AL"), candidate("tx"), candidate("HY")],
+            qualities: parse_multi_stream_info(&stream),
+        };
+        let options = stream_options(&data);
+        let qualities: Vec<(&str, &str)> = options
+            .qualities
+            .iter()
+            .map(|q| (q.id.as_str(), q.label.as_str()))
+            .collect();
+        assert_eq!(
+            qualities,
+            [("0", "蓝光10M"), ("4000", "蓝光4M"), ("2000", "超清"), ("500", "流畅")]
+        );
+        assert_eq!(options.qualities[0].bitrate, None);
+        let lines: Vec<(&str, &str)> = options
+            .lines
+            .iter()
+            .map(|l| (l.id.as_str(), l.label.as_str()))
+            .collect();
+        assert_eq!(lines, [("tx", "腾讯云"), ("al", "阿里云"), ("hy", "HY")]);
+
+        let urls = build_flv_tx_urls(data.candidates.get(1), &data.qualities);
+        assert_eq!(urls.len(), 4);
+        assert!(urls[3].url.ends_with("&ratio=500"));
+
+        // The prober sees the same lines, each with its source stream
+        let probes = line_candidates(&data);
+        let ids: Vec<&str> = probes.iter().map(|c| c.line.as_str()).collect();
+        assert_eq!(ids, ["tx", "al", "hy"]);
+        assert_eq!(
+            probes[1].url,
+            "https://AL.flv.huya.com/src/1.flv?wsSecret=x"
+        );
+    }
+
+    #[test]
+    fn ratio_accepts_option_ids() {
+        assert_eq!(resolve_ratio(Some("500")), Some(500));
+        assert_eq!(resolve_ratio(Some("0")), None);
+        assert_eq!(resolve_ratio(Some("高清")), Some(4000));
+        assert_eq!(resolve_ratio(None), None);
+    }
+
+    #[test]
+    fn hevc_swaps_codec_and_rate() {
+        let stream = json!({
+            "vMultiStreamInfo": [
+                { "sDisplayName": "蓝光", "iBitRate": 0, "iHEVCBitRate": 8000 },
+                { "sDisplayName": "超清", "iBitRate": 2000, "iHEVCBitRate": -1 }
+            ]
+        });
+        let qualities = parse_multi_stream_info(&stream);
+        assert_eq!(
+            hevc_ratio(&qualities, None, CodecPreference::Hevc),
+            Some(8000)
+        );
+        assert_eq!(hevc_ratio(&qualities, None, CodecPreference::Avc), None);
+        assert_eq!(
+            hevc_ratio(&qualities, Some(2000), CodecPreference::Hevc),
+            None
+        );
+
+        let url = "https://tx.flv.huya.com/src/1.flv?wsSecret=x&codec=264".to_string();
+        assert_eq!(
+            with_hevc(format!("{}&ratio=4000", url), 8000).as_deref(),
+            Some("https://tx.flv.huya.com/src/1.flv?wsSecret=x&codec=265&ratio=8000")
+        );
+        assert!(with_hevc("https://a/1.m3u8?codec=264".to_string(), 8000).is_none());
+    }
+}
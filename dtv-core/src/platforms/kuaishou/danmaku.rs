@@ -0,0 +1,408 @@
+// Kuaishou web danmaku: protobuf `SocketMessage` frames over a WebSocket.
+// The token comes from `websocketinfo` for the room's current live stream
+// id; after CS_ENTER_ROOM the server pushes SC_FEED_PUSH batches (comments,
+// gifts, likes, ...) and expects a heartbeat every 20 seconds.
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::Arc;
+
+use flate2::read::GzDecoder;
+use futures_util::{SinkExt, StreamExt};
+use log::info;
+use prost::Message as ProstMessage;
+use rand::distributions::Alphanumeric;
+use rand::Rng;
+use reqwest::header::{HeaderMap, HeaderValue, COOKIE, REFERER, USER_AGENT};
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::time::{sleep, Duration};
+use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
+
+use super::gen::{
+    CompressionType, CsWebEnterRoom, CsWebHeartbeat, PayloadType, ScWebError, ScWebFeedPush,
+    SocketMessage,
+};
+use super::room::{fetch_room, DESKTOP_UA, REFERER_URL};
+use crate::danmaku::DanmakuHandler;
+use crate::platforms::common::DanmakuFrontendPayload;
+
+const WEBSOCKET_INFO_URL: &str = "https://live.kuaishou.com/live_api/liveroom/websocketinfo";
+const ALL_GIFTS_URL: &str = "https://live.kuaishou.com/live_api/emoji/allgifts";
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(20);
+
+/// Typed non-chat events from a feed push.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum KuaishouEvent {
+    Gift {
+        user_id: String,
+        user: String,
+        gift_id: u32,
+        gift: String,
+        count: u32,
+        // Running combo number, 0 outside a combo
+        combo: u32,
+    },
+    Like {
+        user_id: String,
+        user: String,
+    },
+    Share {
+        user_id: String,
+        user: String,
+    },
+    SystemNotice {
+        content: String,
+    },
+    // Display strings such as "1.2万"
+    OnlineCount {
+        watching: String,
+        likes: String,
+    },
+}
+
+/// What one feed push carries, in display order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedItem {
+    Chat { user: String, content: String },
+    Event(KuaishouEvent),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ServerFrame {
+    Feed(Box<ScWebFeedPush>),
+    // The server closes the socket after these
+    Error { code: u32, message: String },
+    Other(i32),
+}
+
+fn socket_message(payload_type: PayloadType, payload: Vec<u8>) -> Vec<u8> {
+    SocketMessage {
+        payload_type: payload_type as i32,
+        compression_type: CompressionType::None as i32,
+        payload,
+    }
+    .encode_to_vec()
+}
+
+/// `pageId` the web client sends: 16 random characters and a timestamp.
+pub fn new_page_id() -> String {
+    let random: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(16)
+        .map(char::from)
+        .collect();
+    format!("{}_{}", random, chrono::Utc::now().timestamp_millis())
+}
+
+pub fn encode_enter_room(token: &str, live_stream_id: &str, page_id: &str) -> Vec<u8> {
+    let enter = CsWebEnterRoom {
+        token: token.to_string(),
+        live_stream_id: live_stream_id.to_string(),
+        page_id: page_id.to_string(),
+        ..Default::default()
+    };
+    socket_message(PayloadType::CsEnterRoom, enter.encode_to_vec())
+}
+
+pub fn encode_heartbeat(timestamp_ms: u64) -> Vec<u8> {
+    let heartbeat = CsWebHeartbeat {
+        timestamp: timestamp_ms,
+    };
+    socket_message(PayloadType::CsHeartbeat, heartbeat.encode_to_vec())
+}
+
+/// Decodes one binary frame from the server.
+pub fn decode_frame(bin: &[u8]) -> Result<ServerFrame, String> {
+    let message = SocketMessage::decode(bin).map_err(|e| format!("SocketMessage: {}", e))?;
+    let payload = match CompressionType::try_from(message.compression_type) {
+        Ok(CompressionType::Gzip) => {
+            let mut out = Vec::new();
+            GzDecoder::new(message.payload.as_slice())
+                .read_to_end(&mut out)
+                .map_err(|e| format!("gzip: {}", e))?;
+            out
+        }
+        Ok(CompressionType::Aes) => return Err("AES payloads are not supported".to_string()),
+        _ => message.payload,
+    };
+    match PayloadType::try_from(message.payload_type) {
+        Ok(PayloadType::ScFeedPush) => ScWebFeedPush::decode(payload.as_slice())
+            .map(|push| ServerFrame::Feed(Box::new(push)))
+            .map_err(|e| format!("SCWebFeedPush: {}", e)),
+        Ok(PayloadType::ScError) => {
+            let error = ScWebError::decode(payload.as_slice()).unwrap_or_default();
+            Ok(ServerFrame::Error {
+                code: error.code,
+                message: error.msg,
+            })
+        }
+        _ => Ok(ServerFrame::Other(message.payload_type)),
+    }
+}
+
+/// Flattens a feed push. Gift names come from `gift_names` when known.
+pub fn feed_items(push: &ScWebFeedPush, gift_names: &HashMap<u32, String>) -> Vec<FeedItem> {
+    let user = |u: &Option<super::gen::SimpleUserInfo>| {
+        u.as_ref()
+            .map(|u| (u.principal_id.clone(), u.user_name.clone()))
+            .unwrap_or_default()
+    };
+    let mut items = Vec::new();
+    if !push.display_watching_count.is_empty() || !push.display_like_count.is_empty() {
+        items.push(FeedItem::Event(KuaishouEvent::OnlineCount {
+            watching: push.display_watching_count.clone(),
+            likes: push.display_like_count.clone(),
+        }));
+    }
+    for feed in &push.comment_feeds {
+        if feed.content.is_empty() {
+            continue;
+        }
+        items.push(FeedItem::Chat {
+            user: user(&feed.user).1,
+            content: feed.content.clone(),
+        });
+    }
+    for feed in &push.gift_feeds {
+        let (user_id, user) = user(&feed.user);
+        items.push(FeedItem::Event(KuaishouEvent::Gift {
+            user_id,
+            user,
+            gift_id: feed.gift_id,
+            gift: gift_names
+                .get(&feed.gift_id)
+                .cloned()
+                .unwrap_or_else(|| format!("礼物{}", feed.gift_id)),
+            count: feed.batch_size.max(1),
+            combo: feed.combo_count,
+        }));
+    }
+    for feed in &push.like_feeds {
+        let (user_id, user) = user(&feed.user);
+        items.push(FeedItem::Event(KuaishouEvent::Like { user_id, user }));
+    }
+    for feed in &push.share_feeds {
+        let (user_id, user) = user(&feed.user);
+        items.push(FeedItem::Event(KuaishouEvent::Share { user_id, user }));
+    }
+    for feed in &push.system_info_feeds {
+        if !feed.content.is_empty() {
+            items.push(FeedItem::Event(KuaishouEvent::SystemNotice {
+                content: feed.content.clone(),
+            }));
+        }
+    }
+    items
+}
+
+fn emit_item(handler: &Arc<dyn DanmakuHandler>, room_id: &str, item: FeedItem) {
+    let event = match item {
+        FeedItem::Chat { user, content } => {
+            handler.on_danmaku(DanmakuFrontendPayload {
+                room_id: room_id.to_string(),
+                user,
+                content,
+                user_level: 0,
+                fans_club_level: 0,
+            });
+            return;
+        }
+        FeedItem::Event(event) => event,
+    };
+    // Gifts also go to the shared chat list, like the other platforms
+    if let KuaishouEvent::Gift {
+        user, gift, count, ..
+    } = &event
+    {
+        handler.on_danmaku(DanmakuFrontendPayload {
+            room_id: room_id.to_string(),
+            user: user.clone(),
+            content: format!("[礼物] {} x{}", gift, count),
+            user_level: 0,
+            fans_club_level: 0,
+        });
+    }
+    if let Ok(mut v) = serde_json::to_value(&event) {
+        v["room_id"] = Value::String(room_id.to_string());
+        handler.emit_json(&format!("danmaku-{}", room_id), v);
+    }
+}
+
+fn api_headers(cookie: Option<&str>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(USER_AGENT, HeaderValue::from_static(DESKTOP_UA));
+    headers.insert(REFERER, HeaderValue::from_static(REFERER_URL));
+    if let Some(value) = cookie.and_then(|c| HeaderValue::from_str(c.trim()).ok()) {
+        headers.insert(COOKIE, value);
+    }
+    headers
+}
+
+// (token, websocket url) for a live stream
+async fn fetch_ws_info(
+    client: &reqwest::Client,
+    live_stream_id: &str,
+    cookie: Option<&str>,
+) -> Result<(String, String), String> {
+    let v: Value = client
+        .get(WEBSOCKET_INFO_URL)
+        .headers(api_headers(cookie))
+        .query(&[("liveStreamId", live_stream_id)])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())?;
+    let data = v.get("data").ok_or("websocketinfo: missing data")?;
+    let token = data
+        .get("token")
+        .and_then(Value::as_str)
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| format!("websocketinfo: no token ({})", data))?;
+    let url = data
+        .get("websocketUrls")
+        .and_then(Value::as_array)
+        .and_then(|urls| urls.iter().filter_map(Value::as_str).next())
+        .ok_or("websocketinfo: no websocket url")?;
+    Ok((token.to_string(), url.to_string()))
+}
+
+// Best effort: an unknown gift still shows up by id
+async fn fetch_gift_names(client: &reqwest::Client) -> HashMap<u32, String> {
+    let resp = match client
+        .get(ALL_GIFTS_URL)
+        .headers(api_headers(None))
+        .send()
+        .await
+    {
+        Ok(r) => r,
+        Err(_) => return HashMap::new(),
+    };
+    let Ok(v) = resp.json::<Value>().await else {
+        return HashMap::new();
+    };
+    v.get("data")
+        .and_then(Value::as_object)
+        .map(|gifts| {
+            gifts
+                .iter()
+                .filter_map(|(id, gift)| {
+                    let name = gift.get("name").and_then(Value::as_str)?;
+                    Some((id.parse().ok()?, name.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+enum ConnectionOutcome {
+    Stop,
+    Disconnected,
+}
+
+pub async fn run_kuaishou_danmaku_listener(
+    room_id: String,
+    cookie: Option<String>,
+    mut rx_shutdown: tokio_mpsc::Receiver<()>,
+    handler: Arc<dyn DanmakuHandler>,
+) {
+    info!("[Kuaishou Danmaku] spawned worker for room_id={}", room_id);
+    let client = match reqwest::Client::builder().no_proxy().build() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("[Kuaishou Danmaku] http client: {}", e);
+            return;
+        }
+    };
+    let gift_names = fetch_gift_names(&client).await;
+
+    let mut backoff_secs = 1u64;
+    loop {
+        let result: anyhow::Result<ConnectionOutcome> = async {
+            // The live stream id changes with every broadcast, so look it up per connection
+            let room = fetch_room(&client, &room_id, cookie.as_deref())
+                .await
+                .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+            let live_stream_id = room
+                .live_stream_id
+                .ok_or_else(|| anyhow::anyhow!("room {} is not live", room_id))?;
+            let (token, ws_url) = fetch_ws_info(&client, &live_stream_id, cookie.as_deref())
+                .await
+                .map_err(|e| anyhow::anyhow!(e))?;
+
+            let (ws_stream, _) = connect_async(&ws_url).await?;
+            let (mut ws_write, mut ws_read) = ws_stream.split();
+            ws_write
+                .send(WsMessage::Binary(encode_enter_room(
+                    &token,
+                    &live_stream_id,
+                    &new_page_id(),
+                )))
+                .await?;
+
+            let hb_task = async {
+                loop {
+                    sleep(HEARTBEAT_INTERVAL).await;
+                    let now = chrono::Utc::now().timestamp_millis() as u64;
+                    if ws_write
+                        .send(WsMessage::Binary(encode_heartbeat(now)))
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+                Err::<(), anyhow::Error>(anyhow::anyhow!("Kuaishou heartbeat send failed"))
+            };
+
+            let recv_task = async {
+                while let Some(m) = ws_read.next().await {
+                    let WsMessage::Binary(bin) = m? else {
+                        continue;
+                    };
+                    match decode_frame(&bin) {
+                        Ok(ServerFrame::Feed(push)) => {
+                            for item in feed_items(&push, &gift_names) {
+                                emit_item(&handler, &room_id, item);
+                            }
+                        }
+                        Ok(ServerFrame::Error { code, message }) => {
+                            return Err(anyhow::anyhow!("server error {}: {}", code, message));
+                        }
+                        Ok(ServerFrame::Other(_)) => {}
+                        Err(e) => eprintln!("[Kuaishou Danmaku] bad frame: {}", e),
+                    }
+                }
+                anyhow::Ok(())
+            };
+
+            tokio::select! {
+                _ = rx_shutdown.recv() => Ok(ConnectionOutcome::Stop),
+                it = hb_task => {
+                    if let Err(e) = it { eprintln!("[Kuaishou Danmaku] {}", e); }
+                    Ok(ConnectionOutcome::Disconnected)
+                }
+                it = recv_task => {
+                    if let Err(e) = it { eprintln!("[Kuaishou Danmaku] recv error: {}", e); }
+                    Ok(ConnectionOutcome::Disconnected)
+                }
+            }
+        }
+        .await;
+
+        match result {
+            Ok(ConnectionOutcome::Stop) => break,
+            Ok(ConnectionOutcome::Disconnected) => {}
+            Err(e) => eprintln!("[Kuaishou Danmaku] room {}: {}", room_id, e),
+        }
+
+        tokio::select! {
+            _ = sleep(Duration::from_secs(backoff_secs)) => {}
+            _ = rx_shutdown.recv() => break,
+        }
+        backoff_secs = (backoff_secs * 2).min(30);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod danmaku;
+pub mod room;
+pub mod search;
+
+pub mod gen {
+    #![allow(clippy::all, warnings)]
+    include!(concat!(env!("OUT_DIR"), "/kuaishou.rs"));
+}
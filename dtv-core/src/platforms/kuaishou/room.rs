@@ -0,0 +1,443 @@
+// Room resolution for live.kuaishou.com. Rooms are keyed by the streamer's
+// principal id (`/u/{id}`); the page embeds everything we need in
+// `window.__INITIAL_STATE__`, with `livedetail` as a fallback when the page
+// is served without it (usually a captcha for cookie-less clients).
+use reqwest::header::{
+    HeaderMap, HeaderValue, ACCEPT, ACCEPT_LANGUAGE, COOKIE, REFERER, USER_AGENT,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::platforms::common::errors::DtvError;
+use crate::platforms::common::{CodecPreference, QualityOption, StreamHeaders, StreamOptions};
+
+const PAGE_URL: &str = "https://live.kuaishou.com/u/";
+const LIVE_DETAIL_URL: &str = "https://live.kuaishou.com/live_api/liveroom/livedetail";
+pub(crate) const REFERER_URL: &str = "https://live.kuaishou.com/";
+pub(crate) const DESKTOP_UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+/// Headers Kuaishou's stream CDNs expect.
+pub fn stream_headers() -> StreamHeaders {
+    crate::platforms::common::stream_headers(
+        DESKTOP_UA,
+        REFERER_URL,
+        Some("https://live.kuaishou.com"),
+    )
+}
+
+/// One playable rendition of a room.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct KuaishouStream {
+    pub url: String,
+    // "flv" or "hls"
+    pub format: String,
+    // 原画 / 蓝光 / 超清 / 高清 / 标清
+    pub name: String,
+    // Higher is better; HLS has none
+    pub level: i64,
+    pub bitrate: Option<i64>,
+    // "avc" or "hevc" when `playUrls` is keyed by codec
+    pub codec: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KuaishouRoom {
+    pub principal_id: String,
+    // Needed for the danmaku socket; only set while live
+    pub live_stream_id: Option<String>,
+    pub is_live: bool,
+    pub title: Option<String>,
+    pub anchor_name: Option<String>,
+    pub avatar: Option<String>,
+    pub cover: Option<String>,
+    pub streams: Vec<KuaishouStream>,
+}
+
+/// What a user typed as a Kuaishou room.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoomInput {
+    Principal(String),
+    // v.kuaishou.com share links redirect to the room page
+    ShortLink(String),
+}
+
+fn is_principal_id(s: &str) -> bool {
+    !s.is_empty()
+        && s.len() <= 64
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Accepts `live.kuaishou.com/u/{id}` URLs (with or without scheme),
+/// `v.kuaishou.com` short links and bare principal ids.
+pub fn parse_room_input(input: &str) -> Option<RoomInput> {
+    let input = input.trim();
+    let lower = input.to_ascii_lowercase();
+    let without_scheme = lower
+        .strip_prefix("https://")
+        .or_else(|| lower.strip_prefix("http://"))
+        .unwrap_or(&lower);
+    if without_scheme.starts_with("v.kuaishou.com/") {
+        let url = if lower.starts_with("http") {
+            input.to_string()
+        } else {
+            format!("https://{}", input)
+        };
+        return Some(RoomInput::ShortLink(url));
+    }
+    if without_scheme.contains("kuaishou.com/") {
+        // Keep the original case: ids are case-sensitive
+        let offset = input.len() - without_scheme.len();
+        return principal_from_url(&input[offset..]).map(RoomInput::Principal);
+    }
+    is_principal_id(input).then(|| RoomInput::Principal(input.to_string()))
+}
+
+// "live.kuaishou.com/u/3xabc?foo" -> "3xabc"
+fn principal_from_url(url: &str) -> Option<String> {
+    let path = url.split(['?', '#']).next()?;
+    let mut segments = path.split('/');
+    segments.find(|s| *s == "u" || *s == "profile")?;
+    let id = segments.next()?;
+    is_principal_id(id).then(|| id.to_string())
+}
+
+fn request_headers(cookie: Option<&str>) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(USER_AGENT, HeaderValue::from_static(DESKTOP_UA));
+    headers.insert(REFERER, HeaderValue::from_static(REFERER_URL));
+    headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("zh-CN,zh;q=0.9"));
+    if let Some(value) = cookie.and_then(|c| HeaderValue::from_str(c.trim()).ok()) {
+        headers.insert(COOKIE, value);
+    }
+    headers
+}
+
+/// The principal id behind `input`, following short links.
+pub async fn resolve_principal_id(
+    client: &reqwest::Client,
+    input: &str,
+) -> Result<String, DtvError> {
+    match parse_room_input(input) {
+        Some(RoomInput::Principal(id)) => Ok(id),
+        Some(RoomInput::ShortLink(url)) => {
+            let resp = client
+                .get(&url)
+                .headers(request_headers(None))
+                .send()
+                .await
+                .map_err(|e| DtvError::network(format!("Kuaishou short link: {}", e)))?;
+            principal_from_url(resp.url().as_str()).ok_or_else(|| {
+                DtvError::api(format!(
+                    "Kuaishou short link is not a live room: {}",
+                    resp.url()
+                ))
+            })
+        }
+        None => Err(DtvError::api(format!("Invalid Kuaishou room: {}", input))),
+    }
+}
+
+/// The JSON assigned to `window.__INITIAL_STATE__` in a room page.
+pub fn parse_initial_state(html: &str) -> Result<Value, DtvError> {
+    const MARKER: &str = "window.__INITIAL_STATE__=";
+    let start = html
+        .find(MARKER)
+        .map(|i| i + MARKER.len())
+        .ok_or_else(|| DtvError::api("Kuaishou page has no initial state"))?;
+    let rest = &html[start..];
+    let end = rest
+        .find(";(function")
+        .or_else(|| rest.find("</script>"))
+        .unwrap_or(rest.len());
+    // The state is a JS literal; `undefined` is the only non-JSON value in it
+    let json = rest[..end]
+        .trim()
+        .trim_end_matches(';')
+        .replace(":undefined", ":null");
+    serde_json::from_str(&json).map_err(|e| DtvError::api(format!("Kuaishou initial state: {}", e)))
+}
+
+fn str_field(v: &Value, key: &str) -> Option<String> {
+    v.get(key)
+        .and_then(|x| match x {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+        .filter(|s| !s.trim().is_empty())
+}
+
+// `playUrls` is either `{ h264: {adaptationSet}, hevc: {...} }` or a list of
+// `{adaptationSet}`; each representation comes with its set's codec, H.264 first.
+fn representations(play_urls: &Value) -> Vec<(Value, Option<&'static str>)> {
+    let sets: Vec<(&Value, Option<&'static str>)> = match play_urls {
+        Value::Object(map) => {
+            let mut sets: Vec<(&Value, Option<&'static str>)> = map
+                .iter()
+                .map(|(key, set)| {
+                    let codec = match key.as_str() {
+                        "h264" => Some("avc"),
+                        "hevc" | "h265" => Some("hevc"),
+                        _ => None,
+                    };
+                    (set, codec)
+                })
+                .collect();
+            sets.sort_by_key(|(_, codec)| *codec != Some("avc"));
+            sets
+        }
+        Value::Array(list) => list.first().map(|s| (s, None)).into_iter().collect(),
+        _ => Vec::new(),
+    };
+    sets.into_iter()
+        .flat_map(|(set, codec)| {
+            set.pointer("/adaptationSet/representation")
+                .and_then(Value::as_array)
+                .cloned()
+                .unwrap_or_default()
+                .into_iter()
+                .map(move |r| (r, codec))
+        })
+        .collect()
+}
+
+fn streams(live_stream: &Value) -> Vec<KuaishouStream> {
+    let mut streams: Vec<KuaishouStream> = live_stream
+        .get("playUrls")
+        .map(representations)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|(r, codec)| {
+            let url = str_field(r, "url").filter(|u| u.starts_with("http"))?;
+            Some(KuaishouStream {
+                format: if url.contains(".m3u8") { "hls" } else { "flv" }.to_string(),
+                url,
+                name: str_field(r, "name")
+                    .or_else(|| str_field(r, "shortName"))
+                    .unwrap_or_else(|| "默认".to_string()),
+                level: r.get("level").and_then(Value::as_i64).unwrap_or(0),
+                bitrate: r.get("bitrate").and_then(Value::as_i64),
+                codec: codec.map(str::to_string),
+            })
+        })
+        .collect();
+    streams.sort_by_key(|s| std::cmp::Reverse(s.level));
+    if let Some(url) = str_field(live_stream, "hlsPlayUrl").filter(|u| u.starts_with("http")) {
+        streams.push(KuaishouStream {
+            url,
+            format: "hls".to_string(),
+            name: "自适应".to_string(),
+            level: 0,
+            bitrate: None,
+            codec: None,
+        });
+    }
+    streams
+}
+
+/// One `playList` entry (page state) or `livedetail` payload.
+pub fn parse_play_item(principal_id: &str, item: &Value) -> Result<KuaishouRoom, DtvError> {
+    if let Some(error) = item.get("errorType").filter(|e| !e.is_null()) {
+        let message = str_field(error, "title")
+            .or_else(|| str_field(error, "content"))
+            .unwrap_or_else(|| error.to_string());
+        return Err(DtvError::api(format!("Kuaishou: {}", message)));
+    }
+    let author = item.get("author").cloned().unwrap_or(Value::Null);
+    let live_stream = item.get("liveStream").cloned().unwrap_or(Value::Null);
+    let streams = streams(&live_stream);
+    let is_live = item
+        .get("isLiving")
+        .and_then(Value::as_bool)
+        .unwrap_or(!streams.is_empty());
+    Ok(KuaishouRoom {
+        principal_id: str_field(&author, "id").unwrap_or_else(|| principal_id.to_string()),
+        live_stream_id: str_field(&live_stream, "id").filter(|_| is_live),
+        is_live,
+        title: str_field(&live_stream, "caption"),
+        anchor_name: str_field(&author, "name"),
+        avatar: str_field(&author, "avatar"),
+        cover: str_field(&live_stream, "poster").or_else(|| str_field(&live_stream, "coverUrl")),
+        streams: if is_live { streams } else { Vec::new() },
+    })
+}
+
+/// The room in a page's initial state.
+pub fn parse_room_page(principal_id: &str, html: &str) -> Result<KuaishouRoom, DtvError> {
+    let state = parse_initial_state(html)?;
+    let item = state
+        .pointer("/liveroom/playList/0")
+        .ok_or_else(|| DtvError::api("Kuaishou page has no play list"))?;
+    parse_play_item(principal_id, item)
+}
+
+/// Picks a rendition. FLV is preferred because the local proxy only relays
+/// FLV; `原画` (or nothing) means the best one, other names match exactly.
+/// Between codecs of one quality, `codec` decides.
+pub fn select_stream<'a>(
+    streams: &'a [KuaishouStream],
+    quality: Option<&str>,
+    codec: CodecPreference,
+) -> Option<&'a KuaishouStream> {
+    let flv: Vec<&KuaishouStream> = streams.iter().filter(|s| s.format == "flv").collect();
+    let candidates = if flv.is_empty() {
+        streams.iter().collect()
+    } else {
+        flv
+    };
+    let rank = |s: &&KuaishouStream| codec.rank(s.codec.as_deref());
+    let best = candidates
+        .iter()
+        .min_by_key(|s| (std::cmp::Reverse(s.level), rank(s)))
+        .copied();
+    match quality.map(str::trim) {
+        None | Some("") | Some("原画") => best,
+        Some(name) => candidates
+            .iter()
+            .filter(|s| s.name == name)
+            .min_by_key(|s| rank(s))
+            .copied()
+            .or(best),
+    }
+}
+
+/// One quality per name, best first; ids are the names `select_stream`
+/// takes. Kuaishou has no lines.
+pub fn stream_options(streams: &[KuaishouStream]) -> StreamOptions {
+    let mut sorted: Vec<&KuaishouStream> = streams.iter().collect();
+    sorted.sort_by_key(|s| std::cmp::Reverse(s.level));
+    let mut qualities: Vec<QualityOption> = Vec::new();
+    for s in sorted {
+        if qualities.iter().any(|q| q.id == s.name) {
+            continue;
+        }
+        qualities.push(QualityOption {
+            id: s.name.clone(),
+            label: s.name.clone(),
+            bitrate: s.bitrate,
+            codec: None,
+        });
+    }
+    StreamOptions {
+        qualities,
+        lines: Vec::new(),
+    }
+}
+
+async fn fetch_live_detail(
+    client: &reqwest::Client,
+    principal_id: &str,
+    cookie: Option<&str>,
+) -> Result<KuaishouRoom, DtvError> {
+    let mut headers = request_headers(cookie);
+    headers.insert(
+        ACCEPT,
+        HeaderValue::from_static("application/json, text/plain, */*"),
+    );
+    let v: Value = client
+        .get(LIVE_DETAIL_URL)
+        .headers(headers)
+        .query(&[("principalId", principal_id)])
+        .send()
+        .await
+        .map_err(|e| DtvError::network(format!("Kuaishou livedetail: {}", e)))?
+        .json()
+        .await
+        .map_err(|e| DtvError::api(format!("Kuaishou livedetail: {}", e)))?;
+    let data = v
+        .get("data")
+        .filter(|d| d.get("result").and_then(Value::as_i64).unwrap_or(1) == 1)
+        .ok_or_else(|| DtvError::api(format!("Kuaishou livedetail rejected: {}", v)))?;
+    parse_play_item(principal_id, data)
+}
+
+/// Room info and streams for `input` (URL, short link or principal id).
+pub async fn fetch_room(
+    client: &reqwest::Client,
+    input: &str,
+    cookie: Option<&str>,
+) -> Result<KuaishouRoom, DtvError> {
+    let principal_id = resolve_principal_id(client, input).await?;
+    let mut headers = request_headers(cookie);
+    headers.insert(ACCEPT, HeaderValue::from_static("text/html,*/*;q=0.8"));
+    let html = client
+        .get(format!("{}{}", PAGE_URL, principal_id))
+        .headers(headers)
+        .send()
+        .await
+        .map_err(|e| DtvError::network(format!("Kuaishou room page: {}", e)))?
+        .text()
+        .await
+        .map_err(|e| DtvError::network(format!("Kuaishou room page: {}", e)))?;
+    match parse_room_page(&principal_id, &html) {
+        Ok(room) => Ok(room),
+        Err(e) => {
+            eprintln!(
+                "[Kuaishou] room page for {} unusable ({}), trying livedetail",
+                principal_id, e
+            );
+            fetch_live_detail(client, &principal_id, cookie).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_room_inputs() {
+        let principal = |s: &str| Some(RoomInput::Principal(s.to_string()));
+        assert_eq!(parse_room_input(" 3xAbC_9 "), principal("3xAbC_9"));
+        assert_eq!(
+            parse_room_input("https://live.kuaishou.com/u/3xAbC_9?from=share"),
+            principal("3xAbC_9")
+        );
+        assert_eq!(
+            parse_room_input("live.kuaishou.com/u/KPL704668133"),
+            principal("KPL704668133")
+        );
+        assert_eq!(
+            parse_room_input("https://www.kuaishou.com/profile/3xabc"),
+            principal("3xabc")
+        );
+        assert_eq!(
+            parse_room_input("v.kuaishou.com/Xy12Ab"),
+            Some(RoomInput::ShortLink("https://v.kuaishou.com/Xy12Ab".into()))
+        );
+        assert_eq!(parse_room_input("https://live.kuaishou.com/"), None);
+        assert_eq!(parse_room_input("not a room"), None);
+    }
+
+    #[test]
+    fn selects_streams() {
+        let stream = |name: &str, format: &str, level: i64| KuaishouStream {
+            url: format!("https://cdn/{}.{}", level, format),
+            format: format.into(),
+            name: name.into(),
+            level,
+            bitrate: None,
+            codec: None,
+        };
+        let streams = vec![
+            stream("高清", "flv", 20),
+            stream("原画", "flv", 40),
+            stream("超清", "flv", 30),
+            stream("自适应", "hls", 0),
+        ];
+        let avc = CodecPreference::Avc;
+        assert_eq!(select_stream(&streams, None, avc).unwrap().level, 40);
+        assert_eq!(
+            select_stream(&streams, Some("高清"), avc).unwrap().level,
+            20
+        );
+        assert_eq!(select_stream(&streams, Some("4K"), avc).unwrap().level, 40);
+        let hls_only = vec![stream("自适应", "hls", 0)];
+        assert_eq!(
+            select_stream(&hls_only, Some("原画"), avc).unwrap().format,
+            "hls"
+        );
+        assert!(select_stream(&[], None, avc).is_none());
+    }
+}
@@ -0,0 +1,92 @@
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, COOKIE, REFERER, USER_AGENT};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::room::{DESKTOP_UA, REFERER_URL};
+
+const SEARCH_URL: &str = "https://live.kuaishou.com/live_api/search/author";
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct KuaishouSearchItem {
+    // Principal id, usable as the room id
+    pub room_id: String,
+    pub user_name: String,
+    pub avatar: String,
+    pub live_status: bool,
+    pub description: String,
+}
+
+fn text(v: &Value, key: &str) -> String {
+    match v.get(key) {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Number(n)) => n.to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Parses a `search/author` response.
+pub fn parse_search_response(body: &str) -> Result<Vec<KuaishouSearchItem>, String> {
+    let v: Value = serde_json::from_str(body).map_err(|e| e.to_string())?;
+    let data = v.get("data").ok_or("Kuaishou search: missing data")?;
+    let result = data.get("result").and_then(Value::as_i64).unwrap_or(1);
+    if result != 1 {
+        // 2 is the captcha wall; a logged-in cookie gets past it
+        return Err(format!("Kuaishou search rejected (result {})", result));
+    }
+    Ok(data
+        .get("list")
+        .and_then(Value::as_array)
+        .map(|list| {
+            list.iter()
+                .map(|item| KuaishouSearchItem {
+                    room_id: text(item, "id"),
+                    user_name: text(item, "name"),
+                    avatar: text(item, "avatar"),
+                    live_status: item.get("living").and_then(Value::as_bool).unwrap_or(false),
+                    description: text(item, "description"),
+                })
+                .filter(|item| !item.room_id.is_empty())
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+pub async fn search_kuaishou_authors(
+    client: &reqwest::Client,
+    user_agent: Option<&str>,
+    keyword: String,
+    page: Option<u32>,
+    cookie: Option<String>,
+) -> Result<Vec<KuaishouSearchItem>, String> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        USER_AGENT,
+        HeaderValue::from_str(user_agent.unwrap_or(DESKTOP_UA)).map_err(|e| e.to_string())?,
+    );
+    headers.insert(REFERER, HeaderValue::from_static(REFERER_URL));
+    headers.insert(
+        ACCEPT,
+        HeaderValue::from_static("application/json, text/plain, */*"),
+    );
+    if let Some(value) = cookie
+        .as_deref()
+        .and_then(|c| HeaderValue::from_str(c.trim()).ok())
+    {
+        headers.insert(COOKIE, value);
+    }
+
+    let page = page.unwrap_or(1).max(1).to_string();
+    let body = client
+        .get(SEARCH_URL)
+        .headers(headers)
+        .query(&[("keyword", keyword.as_str()), ("page", page.as_str())])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?
+        .text()
+        .await
+        .map_err(|e| e.to_string())?;
+    parse_search_response(&body)
+}
@@ -1,5 +1,15 @@
 pub mod common;
+#[cfg(feature = "bilibili")]
 pub mod bilibili;
+#[cfg(feature = "cc")]
+pub mod cc;
+#[cfg(feature = "douyin")]
 pub mod douyin;
+#[cfg(feature = "douyu")]
 pub mod douyu;
+#[cfg(feature = "huya")]
 pub mod huya;
+#[cfg(feature = "kuaishou")]
+pub mod kuaishou;
+#[cfg(feature = "twitch")]
+pub mod twitch;
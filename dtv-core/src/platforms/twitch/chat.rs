@@ -0,0 +1,357 @@
+// Twitch chat: IRC over a WebSocket, joined anonymously as a `justinfan`
+// user. With the tags capability every PRIVMSG carries badges, emotes and
+// bits; USERNOTICE covers subs, gift subs and raids. The server PINGs every
+// few minutes and may ask clients to RECONNECT.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures_util::{SinkExt, StreamExt};
+use log::info;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::sync::mpsc as tokio_mpsc;
+use tokio::time::{sleep, Duration};
+use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
+
+use super::gql::parse_channel_input;
+use crate::danmaku::DanmakuHandler;
+use crate::platforms::common::DanmakuFrontendPayload;
+
+pub const IRC_WS_URL: &str = "wss://irc-ws.chat.twitch.tv:443";
+const EMOTE_URL: &str = "https://static-cdn.jtvnw.net/emoticons/v2";
+
+/// One parsed IRC line. The trailing parameter is the last of `params`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IrcMessage {
+    pub tags: HashMap<String, String>,
+    pub prefix: Option<String>,
+    pub command: String,
+    pub params: Vec<String>,
+}
+
+impl IrcMessage {
+    fn tag(&self, key: &str) -> Option<&str> {
+        self.tags
+            .get(key)
+            .map(String::as_str)
+            .filter(|v| !v.is_empty())
+    }
+
+    fn trailing(&self) -> &str {
+        self.params.last().map(String::as_str).unwrap_or_default()
+    }
+
+    // display-name, else the nick from the prefix
+    fn user(&self) -> String {
+        self.tag("display-name")
+            .or_else(|| self.tag("login"))
+            .or_else(|| self.prefix.as_deref().and_then(|p| p.split('!').next()))
+            .unwrap_or_default()
+            .to_string()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct Badge {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct Emote {
+    pub id: String,
+    pub name: String,
+    // Character (not byte) offsets into the message, inclusive
+    pub start: usize,
+    pub end: usize,
+    pub url: String,
+}
+
+/// Typed chat events, emitted on `danmaku-{room_id}`.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TwitchEvent {
+    Chat {
+        user_id: String,
+        user: String,
+        content: String,
+        color: Option<String>,
+        badges: Vec<Badge>,
+        emotes: Vec<Emote>,
+    },
+    Cheer {
+        user_id: String,
+        user: String,
+        bits: u64,
+        content: String,
+    },
+    // `kind` is Twitch's msg-id: "sub", "resub", "subgift", "raid", ...
+    UserNotice {
+        kind: String,
+        user_id: String,
+        user: String,
+        system_message: String,
+        content: String,
+    },
+}
+
+fn unescape_tag(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('s') => out.push(' '),
+            Some(':') => out.push(';'),
+            Some('r') => out.push('\r'),
+            Some('n') => out.push('\n'),
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+pub fn parse_irc_line(line: &str) -> Option<IrcMessage> {
+    let mut rest = line.trim_end_matches(['\r', '\n']);
+    let mut tags = HashMap::new();
+    if let Some(tagged) = rest.strip_prefix('@') {
+        let (raw, after) = tagged.split_once(' ')?;
+        for pair in raw.split(';') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            tags.insert(key.to_string(), unescape_tag(value));
+        }
+        rest = after.trim_start();
+    }
+    let mut prefix = None;
+    if let Some(prefixed) = rest.strip_prefix(':') {
+        let (p, after) = prefixed.split_once(' ')?;
+        prefix = Some(p.to_string());
+        rest = after.trim_start();
+    }
+    let (middle, trailing) = match rest.split_once(" :") {
+        Some((middle, trailing)) => (middle, Some(trailing)),
+        None => (rest, None),
+    };
+    let mut words = middle.split_whitespace();
+    let command = words.next()?.to_string();
+    let mut params: Vec<String> = words.map(str::to_string).collect();
+    params.extend(trailing.map(str::to_string));
+    Some(IrcMessage {
+        tags,
+        prefix,
+        command,
+        params,
+    })
+}
+
+/// `badges` / `badge-info` tag: `name/version,name/version`.
+pub fn parse_badges(value: &str) -> Vec<Badge> {
+    value
+        .split(',')
+        .filter_map(|b| {
+            let (name, version) = b.split_once('/')?;
+            Some(Badge {
+                name: name.to_string(),
+                version: version.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// `emotes` tag: `id:start-end,start-end/id:start-end`, sorted by position.
+pub fn parse_emotes(value: &str, content: &str) -> Vec<Emote> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut emotes: Vec<Emote> = value
+        .split('/')
+        .filter_map(|entry| entry.split_once(':'))
+        .flat_map(|(id, ranges)| {
+            ranges.split(',').filter_map(move |range| {
+                let (start, end) = range.split_once('-')?;
+                Some((id, start.parse::<usize>().ok()?, end.parse::<usize>().ok()?))
+            })
+        })
+        .filter(|(_, start, end)| start <= end && *end < chars.len())
+        .map(|(id, start, end)| Emote {
+            id: id.to_string(),
+            name: chars[start..=end].iter().collect(),
+            start,
+            end,
+            url: format!("{}/{}/default/dark/1.0", EMOTE_URL, id),
+        })
+        .collect();
+    emotes.sort_by_key(|e| e.start);
+    emotes
+}
+
+/// Typed events for one IRC message; empty for anything but chat and notices.
+pub fn message_events(msg: &IrcMessage) -> Vec<TwitchEvent> {
+    let user_id = msg.tag("user-id").unwrap_or_default().to_string();
+    match msg.command.as_str() {
+        "PRIVMSG" => {
+            let content = msg.trailing().to_string();
+            let mut events = vec![TwitchEvent::Chat {
+                user_id: user_id.clone(),
+                user: msg.user(),
+                emotes: parse_emotes(msg.tag("emotes").unwrap_or_default(), &content),
+                badges: parse_badges(msg.tag("badges").unwrap_or_default()),
+                color: msg.tag("color").map(str::to_string),
+                content: content.clone(),
+            }];
+            if let Some(bits) = msg.tag("bits").and_then(|b| b.parse().ok()) {
+                events.push(TwitchEvent::Cheer {
+                    user_id,
+                    user: msg.user(),
+                    bits,
+                    content,
+                });
+            }
+            events
+        }
+        "USERNOTICE" => vec![TwitchEvent::UserNotice {
+            kind: msg.tag("msg-id").unwrap_or_default().to_string(),
+            user_id,
+            user: msg.user(),
+            system_message: msg.tag("system-msg").unwrap_or_default().to_string(),
+            // Resubs may carry the viewer's own message
+            content: msg.params.get(1).cloned().unwrap_or_default(),
+        }],
+        _ => Vec::new(),
+    }
+}
+
+// Subscriber months from `badge-info`, shown like a fan club level
+fn sub_months(msg: &IrcMessage) -> i32 {
+    parse_badges(msg.tag("badge-info").unwrap_or_default())
+        .iter()
+        .find(|b| b.name == "subscriber" || b.name == "founder")
+        .and_then(|b| b.version.parse().ok())
+        .unwrap_or(0)
+}
+
+fn emit_message(handler: &Arc<dyn DanmakuHandler>, room_id: &str, msg: &IrcMessage) {
+    for event in message_events(msg) {
+        match &event {
+            TwitchEvent::Chat { user, content, .. } => {
+                handler.on_danmaku(DanmakuFrontendPayload {
+                    room_id: room_id.to_string(),
+                    user: user.clone(),
+                    content: content.clone(),
+                    user_level: 0,
+                    fans_club_level: sub_months(msg),
+                });
+            }
+            // Bits also go to the shared chat list, like gifts elsewhere
+            TwitchEvent::Cheer { user, bits, .. } => {
+                handler.on_danmaku(DanmakuFrontendPayload {
+                    room_id: room_id.to_string(),
+                    user: user.clone(),
+                    content: format!("[礼物] Bits x{}", bits),
+                    user_level: 0,
+                    fans_club_level: sub_months(msg),
+                });
+            }
+            TwitchEvent::UserNotice { .. } => {}
+        }
+        if let Ok(mut v) = serde_json::to_value(&event) {
+            v["room_id"] = Value::String(room_id.to_string());
+            handler.emit_json(&format!("danmaku-{}", room_id), v);
+        }
+    }
+}
+
+/// Commands sent on connect: capabilities, anonymous login, join.
+pub fn login_lines(channel: &str, nick: &str) -> Vec<String> {
+    vec![
+        "CAP REQ :twitch.tv/tags twitch.tv/commands".to_string(),
+        "PASS SCHMOOPIIE".to_string(),
+        format!("NICK {}", nick),
+        format!("JOIN #{}", channel),
+    ]
+}
+
+enum ConnectionOutcome {
+    Stop,
+    Disconnected,
+}
+
+pub async fn run_twitch_danmaku_listener(
+    room_id: String,
+    rx_shutdown: tokio_mpsc::Receiver<()>,
+    handler: Arc<dyn DanmakuHandler>,
+) {
+    run_twitch_chat(IRC_WS_URL.to_string(), room_id, rx_shutdown, handler).await
+}
+
+/// The listener loop against any IRC-over-WebSocket endpoint.
+pub async fn run_twitch_chat(
+    ws_url: String,
+    room_id: String,
+    mut rx_shutdown: tokio_mpsc::Receiver<()>,
+    handler: Arc<dyn DanmakuHandler>,
+) {
+    info!("[Twitch Chat] spawned worker for room_id={}", room_id);
+    let Some(channel) = parse_channel_input(&room_id) else {
+        eprintln!("[Twitch Chat] invalid channel: {}", room_id);
+        return;
+    };
+
+    let mut backoff_secs = 1u64;
+    loop {
+        let result: anyhow::Result<ConnectionOutcome> = async {
+            let (mut ws, _) = connect_async(&ws_url).await?;
+            let nick = format!("justinfan{}", 10_000 + rand::random::<u32>() % 90_000);
+            for line in login_lines(&channel, &nick) {
+                ws.send(WsMessage::Text(line)).await?;
+            }
+
+            let recv_task = async {
+                while let Some(m) = ws.next().await {
+                    let WsMessage::Text(text) = m? else {
+                        continue;
+                    };
+                    // One frame may carry several lines
+                    for line in text.split("\r\n").filter(|l| !l.is_empty()) {
+                        let Some(msg) = parse_irc_line(line) else {
+                            continue;
+                        };
+                        match msg.command.as_str() {
+                            "PING" => {
+                                let pong = format!("PONG :{}", msg.trailing());
+                                ws.send(WsMessage::Text(pong)).await?;
+                            }
+                            "RECONNECT" => return anyhow::Ok(()),
+                            "NOTICE" => eprintln!("[Twitch Chat] notice: {}", msg.trailing()),
+                            _ => emit_message(&handler, &room_id, &msg),
+                        }
+                    }
+                }
+                anyhow::Ok(())
+            };
+
+            tokio::select! {
+                _ = rx_shutdown.recv() => Ok(ConnectionOutcome::Stop),
+                it = recv_task => {
+                    if let Err(e) = it { eprintln!("[Twitch Chat] recv error: {}", e); }
+                    Ok(ConnectionOutcome::Disconnected)
+                }
+            }
+        }
+        .await;
+
+        match result {
+            Ok(ConnectionOutcome::Stop) => break,
+            Ok(ConnectionOutcome::Disconnected) => {}
+            Err(e) => eprintln!("[Twitch Chat] room {}: {}", room_id, e),
+        }
+
+        tokio::select! {
+            _ = sleep(Duration::from_secs(backoff_secs)) => {}
+            _ = rx_shutdown.recv() => break,
+        }
+        backoff_secs = (backoff_secs * 2).min(30);
+    }
+}
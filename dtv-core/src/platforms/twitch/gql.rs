@@ -0,0 +1,193 @@
+// Twitch's public GraphQL endpoint, as used by the web player. Anonymous
+// requests only need the web client's Client-ID.
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::platforms::common::errors::DtvError;
+
+const GQL_URL: &str = "https://gql.twitch.tv/gql";
+const CLIENT_ID: &str = "kimne78kx3ncx6brgo4mv6wki5h1ko";
+pub(crate) const DESKTOP_UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+const PLAYBACK_TOKEN_QUERY: &str = r#"query PlaybackAccessToken($login: String!) {
+  streamPlaybackAccessToken(channelName: $login, params: {platform: "web", playerBackend: "mediaplayer", playerType: "site"}) {
+    value
+    signature
+  }
+}"#;
+
+const CHANNEL_QUERY: &str = r#"query ChannelMeta($login: String!) {
+  user(login: $login) {
+    id
+    login
+    displayName
+    profileImageURL(width: 300)
+    broadcastSettings { title game { displayName } }
+    stream { id type viewersCount previewImageURL(width: 640, height: 360) game { displayName } }
+  }
+}"#;
+
+/// Signed token the usher endpoint wants for a channel's playlist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaybackToken {
+    pub value: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TwitchChannel {
+    pub user_id: String,
+    pub login: String,
+    pub display_name: String,
+    pub avatar: Option<String>,
+    pub title: Option<String>,
+    pub category: Option<String>,
+    pub is_live: bool,
+    pub viewers: Option<i64>,
+    pub preview: Option<String>,
+}
+
+/// Accepts `twitch.tv/{login}` URLs (with or without scheme) and bare logins.
+pub fn parse_channel_input(input: &str) -> Option<String> {
+    let input = input.trim();
+    let without_scheme = input
+        .strip_prefix("https://")
+        .or_else(|| input.strip_prefix("http://"))
+        .unwrap_or(input);
+    let login = match without_scheme.split_once('/') {
+        Some((host, rest)) if host == "twitch.tv" || host.ends_with(".twitch.tv") => {
+            rest.split(['/', '?', '#']).next().unwrap_or("")
+        }
+        Some(_) => return None,
+        None => without_scheme,
+    };
+    let login = login.to_ascii_lowercase();
+    (!login.is_empty()
+        && login.len() <= 25
+        && login
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'))
+    .then_some(login)
+}
+
+fn text(v: &Value, pointer: &str) -> Option<String> {
+    v.pointer(pointer)
+        .and_then(Value::as_str)
+        .filter(|s| !s.trim().is_empty())
+        .map(str::to_string)
+}
+
+// The `data` of a GQL response; GQL reports failures in `errors` with a 200
+fn data(body: &str) -> Result<Value, DtvError> {
+    let v: Value =
+        serde_json::from_str(body).map_err(|e| DtvError::api(format!("Twitch GQL: {}", e)))?;
+    if let Some(message) = v.pointer("/errors/0/message").and_then(Value::as_str) {
+        return Err(DtvError::api(format!("Twitch GQL: {}", message)));
+    }
+    v.get("data")
+        .filter(|d| d.is_object())
+        .cloned()
+        .ok_or_else(|| DtvError::api("Twitch GQL: missing data"))
+}
+
+pub fn parse_playback_token(body: &str) -> Result<PlaybackToken, DtvError> {
+    let data = data(body)?;
+    let token = data
+        .get("streamPlaybackAccessToken")
+        .filter(|t| t.is_object())
+        .ok_or_else(|| DtvError::api("Twitch channel does not exist"))?;
+    match (text(token, "/value"), text(token, "/signature")) {
+        (Some(value), Some(signature)) => Ok(PlaybackToken { value, signature }),
+        _ => Err(DtvError::api("Twitch playback token is incomplete")),
+    }
+}
+
+pub fn parse_channel(body: &str) -> Result<TwitchChannel, DtvError> {
+    let data = data(body)?;
+    let user = data
+        .get("user")
+        .filter(|u| u.is_object())
+        .ok_or_else(|| DtvError::api("Twitch channel does not exist"))?;
+    let stream = user.get("stream").filter(|s| s.is_object());
+    // Reruns and premieres also have a stream; only "live" counts
+    let is_live = stream.is_some_and(|s| {
+        s.get("type")
+            .and_then(Value::as_str)
+            .is_none_or(|t| t == "live")
+    });
+    let login = text(user, "/login").unwrap_or_default();
+    Ok(TwitchChannel {
+        user_id: text(user, "/id").unwrap_or_default(),
+        display_name: text(user, "/displayName").unwrap_or_else(|| login.clone()),
+        login,
+        avatar: text(user, "/profileImageURL"),
+        title: text(user, "/broadcastSettings/title"),
+        category: stream
+            .and_then(|s| text(s, "/game/displayName"))
+            .or_else(|| text(user, "/broadcastSettings/game/displayName")),
+        is_live,
+        viewers: stream
+            .and_then(|s| s.get("viewersCount"))
+            .and_then(Value::as_i64),
+        preview: stream.and_then(|s| text(s, "/previewImageURL")),
+    })
+}
+
+pub(crate) async fn post(
+    client: &reqwest::Client,
+    query: &str,
+    variables: Value,
+) -> Result<String, DtvError> {
+    let mut headers = HeaderMap::new();
+    headers.insert("Client-ID", HeaderValue::from_static(CLIENT_ID));
+    headers.insert(USER_AGENT, HeaderValue::from_static(DESKTOP_UA));
+    headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+    client
+        .post(GQL_URL)
+        .headers(headers)
+        .json(&json!({ "query": query, "variables": variables }))
+        .send()
+        .await
+        .map_err(|e| DtvError::network(format!("Twitch GQL: {}", e)))?
+        .text()
+        .await
+        .map_err(|e| DtvError::network(format!("Twitch GQL: {}", e)))
+}
+
+pub async fn fetch_playback_token(
+    client: &reqwest::Client,
+    login: &str,
+) -> Result<PlaybackToken, DtvError> {
+    let body = post(client, PLAYBACK_TOKEN_QUERY, json!({ "login": login })).await?;
+    parse_playback_token(&body)
+}
+
+pub async fn fetch_channel(
+    client: &reqwest::Client,
+    login: &str,
+) -> Result<TwitchChannel, DtvError> {
+    let body = post(client, CHANNEL_QUERY, json!({ "login": login })).await?;
+    parse_channel(&body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_channel_inputs() {
+        assert_eq!(parse_channel_input(" Shroud ").as_deref(), Some("shroud"));
+        assert_eq!(
+            parse_channel_input("https://www.twitch.tv/shroud?sr=a").as_deref(),
+            Some("shroud")
+        );
+        assert_eq!(
+            parse_channel_input("twitch.tv/some_one").as_deref(),
+            Some("some_one")
+        );
+        assert_eq!(parse_channel_input("https://example.com/shroud"), None);
+        assert_eq!(parse_channel_input("bad name"), None);
+        assert_eq!(parse_channel_input(""), None);
+    }
+}
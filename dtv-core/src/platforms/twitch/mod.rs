@@ -0,0 +1,4 @@
+pub mod chat;
+pub mod gql;
+pub mod playlist;
+pub mod search;
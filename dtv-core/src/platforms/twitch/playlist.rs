@@ -0,0 +1,227 @@
+// Usher master playlists: one `#EXT-X-MEDIA` + `#EXT-X-STREAM-INF` pair per
+// rendition, best first, each pointing at a media playlist.
+use serde::Serialize;
+
+use super::gql::{PlaybackToken, DESKTOP_UA};
+use crate::platforms::common::errors::DtvError;
+use crate::platforms::common::{
+    normalize_codec, CodecPreference, QualityOption, StreamHeaders, StreamOptions,
+};
+
+const USHER_URL: &str = "https://usher.ttvnw.net/api/channel/hls";
+
+/// Headers for usher and the media playlists, as the web player sends them.
+pub fn stream_headers() -> StreamHeaders {
+    crate::platforms::common::stream_headers(
+        DESKTOP_UA,
+        "https://www.twitch.tv/",
+        Some("https://www.twitch.tv"),
+    )
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TwitchRendition {
+    // e.g. "1080p60 (source)", "720p60", "audio_only"
+    pub name: String,
+    pub group_id: String,
+    pub url: String,
+    pub bandwidth: Option<u64>,
+    pub resolution: Option<String>,
+    pub frame_rate: Option<f64>,
+    pub codecs: Option<String>,
+}
+
+impl TwitchRendition {
+    pub fn is_audio_only(&self) -> bool {
+        self.group_id == "audio_only"
+    }
+
+    /// "avc", "hevc" or "av1", from the first `CODECS` entry.
+    pub fn video_codec(&self) -> Option<&'static str> {
+        self.codecs
+            .as_deref()
+            .and_then(|c| c.split(',').next())
+            .and_then(normalize_codec)
+    }
+}
+
+// `KEY=value,KEY="quoted, value"` attribute lists
+fn attributes(list: &str) -> Vec<(&str, &str)> {
+    let mut out = Vec::new();
+    let mut rest = list;
+    while let Some((key, after)) = rest.split_once('=') {
+        let (value, next) = if let Some(quoted) = after.strip_prefix('"') {
+            match quoted.split_once('"') {
+                Some((value, next)) => (value, next.strip_prefix(',').unwrap_or(next)),
+                None => (quoted, ""),
+            }
+        } else {
+            after.split_once(',').unwrap_or((after, ""))
+        };
+        out.push((key.trim(), value));
+        rest = next;
+    }
+    out
+}
+
+fn attribute<'a>(attrs: &[(&str, &'a str)], key: &str) -> Option<&'a str> {
+    attrs.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
+
+/// Renditions in playlist order. An empty list means no `#EXT-X-STREAM-INF`.
+pub fn parse_master_playlist(body: &str) -> Result<Vec<TwitchRendition>, DtvError> {
+    if !body.trim_start().starts_with("#EXTM3U") {
+        return Err(DtvError::api("Twitch usher: not an HLS playlist"));
+    }
+    let mut renditions = Vec::new();
+    let mut media_name: Option<(String, String)> = None;
+    let mut stream_inf: Option<String> = None;
+    for line in body.lines().map(str::trim) {
+        if let Some(list) = line.strip_prefix("#EXT-X-MEDIA:") {
+            let attrs = attributes(list);
+            if let (Some(group), Some(name)) =
+                (attribute(&attrs, "GROUP-ID"), attribute(&attrs, "NAME"))
+            {
+                media_name = Some((group.to_string(), name.to_string()));
+            }
+        } else if let Some(list) = line.strip_prefix("#EXT-X-STREAM-INF:") {
+            stream_inf = Some(list.to_string());
+        } else if !line.is_empty() && !line.starts_with('#') {
+            let Some(list) = stream_inf.take() else {
+                continue;
+            };
+            let attrs = attributes(&list);
+            let group_id = attribute(&attrs, "VIDEO").unwrap_or_default().to_string();
+            // NAME comes from the matching EXT-X-MEDIA; fall back to the group
+            let name = match media_name.take() {
+                Some((group, name)) if group == group_id => name,
+                _ => group_id.clone(),
+            };
+            renditions.push(TwitchRendition {
+                name,
+                group_id,
+                url: line.to_string(),
+                bandwidth: attribute(&attrs, "BANDWIDTH").and_then(|b| b.parse().ok()),
+                resolution: attribute(&attrs, "RESOLUTION").map(str::to_string),
+                frame_rate: attribute(&attrs, "FRAME-RATE").and_then(|f| f.parse().ok()),
+                codecs: attribute(&attrs, "CODECS").map(str::to_string),
+            });
+        }
+    }
+    Ok(renditions)
+}
+
+/// Picks a rendition. Nothing or `原画` is the source (first video)
+/// rendition; otherwise a name match ("720p60"), then a prefix match
+/// ("720p" matches "720p60"). Unknown names fall back to the source.
+/// When a name is offered in several codecs, `codec` picks one.
+pub fn select_rendition<'a>(
+    renditions: &'a [TwitchRendition],
+    quality: Option<&str>,
+    codec: CodecPreference,
+) -> Option<&'a TwitchRendition> {
+    let by_codec = |picked: &'a TwitchRendition| {
+        renditions
+            .iter()
+            .filter(|r| r.name == picked.name)
+            .min_by_key(|r| codec.rank(r.video_codec()))
+            .unwrap_or(picked)
+    };
+    let source = renditions
+        .iter()
+        .find(|r| !r.is_audio_only())
+        .or_else(|| renditions.first());
+    let quality = match quality.map(str::trim) {
+        None | Some("") | Some("原画") => return source.map(by_codec),
+        Some(q) => q,
+    };
+    renditions
+        .iter()
+        .find(|r| r.name == quality || r.group_id == quality)
+        .or_else(|| renditions.iter().find(|r| r.name.starts_with(quality)))
+        .or(source)
+        .map(by_codec)
+}
+
+/// One quality per rendition name. Twitch has no lines.
+pub fn stream_options(renditions: &[TwitchRendition]) -> StreamOptions {
+    let mut seen: Vec<&str> = Vec::new();
+    StreamOptions {
+        qualities: renditions
+            .iter()
+            .filter(|r| {
+                let new = !seen.contains(&r.name.as_str());
+                seen.push(&r.name);
+                new
+            })
+            .map(|r| QualityOption {
+                id: r.name.clone(),
+                label: r.name.clone(),
+                bitrate: r.bandwidth.map(|b| (b / 1000) as i64),
+                // The video codec; audio-only renditions only list audio
+                codec: r
+                    .codecs
+                    .as_deref()
+                    .and_then(|c| c.split(',').next())
+                    .map(str::to_string),
+            })
+            .collect(),
+        lines: Vec::new(),
+    }
+}
+
+/// Master playlist URL. `supported_codecs` limits what usher returns; left
+/// out for [`CodecPreference::Auto`] so Twitch picks.
+pub fn usher_url(
+    login: &str,
+    token: &PlaybackToken,
+    codec: CodecPreference,
+) -> Result<String, DtvError> {
+    let p = (rand::random::<u32>() % 10_000_000).to_string();
+    let mut params = vec![
+        ("sig", token.signature.as_str()),
+        ("token", token.value.as_str()),
+        ("allow_source", "true"),
+        ("allow_audio_only", "true"),
+        ("fast_bread", "true"),
+        ("player_backend", "mediaplayer"),
+        ("playlist_include_framerate", "true"),
+        ("p", &p),
+    ];
+    match codec {
+        CodecPreference::Avc => params.push(("supported_codecs", "h264")),
+        CodecPreference::Hevc => params.push(("supported_codecs", "h265,h264")),
+        CodecPreference::Auto => {}
+    }
+    reqwest::Url::parse_with_params(&format!("{}/{}.m3u8", USHER_URL, login), &params)
+        .map(|u| u.to_string())
+        .map_err(|e| DtvError::internal(format!("usher url: {}", e)))
+}
+
+/// Renditions for a live channel. Usher answers 404 while offline.
+pub async fn fetch_renditions(
+    client: &reqwest::Client,
+    login: &str,
+    token: &PlaybackToken,
+    codec: CodecPreference,
+) -> Result<Vec<TwitchRendition>, DtvError> {
+    let resp = client
+        .get(usher_url(login, token, codec)?)
+        .header(reqwest::header::USER_AGENT, DESKTOP_UA)
+        .send()
+        .await
+        .map_err(|e| DtvError::network(format!("Twitch usher: {}", e)))?;
+    if resp.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(DtvError::offline(format!(
+            "Twitch channel {} is offline",
+            login
+        )));
+    }
+    let body = resp
+        .error_for_status()
+        .map_err(|e| DtvError::network(format!("Twitch usher: {}", e)))?
+        .text()
+        .await
+        .map_err(|e| DtvError::network(format!("Twitch usher: {}", e)))?;
+    parse_master_playlist(&body)
+}
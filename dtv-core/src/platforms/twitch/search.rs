@@ -0,0 +1,97 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::gql;
+
+const SEARCH_QUERY: &str = r#"query SearchUsers($query: String!, $first: Int!) {
+  searchUsers(userQuery: $query, first: $first) {
+    edges {
+      node {
+        login
+        displayName
+        description
+        profileImageURL(width: 150)
+        broadcastSettings { title }
+        stream { type viewersCount game { displayName } }
+      }
+    }
+  }
+}"#;
+
+const PAGE_SIZE: u32 = 20;
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct TwitchSearchItem {
+    // Login, usable as the room id
+    pub room_id: String,
+    pub user_name: String,
+    pub avatar: String,
+    pub title: String,
+    pub category: String,
+    pub live_status: bool,
+    pub viewers: i64,
+}
+
+fn text(v: &Value, pointer: &str) -> String {
+    v.pointer(pointer)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Parses a `searchUsers` response.
+pub fn parse_search_response(body: &str) -> Result<Vec<TwitchSearchItem>, String> {
+    let v: Value = serde_json::from_str(body).map_err(|e| e.to_string())?;
+    if let Some(message) = v.pointer("/errors/0/message").and_then(Value::as_str) {
+        return Err(format!("Twitch search: {}", message));
+    }
+    let edges = v
+        .pointer("/data/searchUsers/edges")
+        .and_then(Value::as_array)
+        .ok_or("Twitch search: missing results")?;
+    Ok(edges
+        .iter()
+        .filter_map(|edge| edge.get("node").filter(|n| n.is_object()))
+        .map(|node| {
+            let stream = node.get("stream").filter(|s| s.is_object());
+            TwitchSearchItem {
+                room_id: text(node, "/login"),
+                user_name: text(node, "/displayName"),
+                avatar: text(node, "/profileImageURL"),
+                title: text(node, "/broadcastSettings/title"),
+                category: stream
+                    .map(|s| text(s, "/game/displayName"))
+                    .unwrap_or_default(),
+                live_status: stream
+                    .is_some_and(|s| s.get("type").and_then(Value::as_str) == Some("live")),
+                viewers: stream
+                    .and_then(|s| s.get("viewersCount"))
+                    .and_then(Value::as_i64)
+                    .unwrap_or(0),
+            }
+        })
+        .filter(|item| !item.room_id.is_empty())
+        .collect())
+}
+
+/// Channels matching `keyword`. GQL search has no offsets for anonymous
+/// clients, so page `n` asks for `n` pages and returns the last one.
+pub async fn search_twitch_channels(
+    client: &reqwest::Client,
+    keyword: String,
+    page: Option<u32>,
+) -> Result<Vec<TwitchSearchItem>, String> {
+    let page = page.unwrap_or(1).clamp(1, 5);
+    let body = gql::post(
+        client,
+        SEARCH_QUERY,
+        json!({ "query": keyword, "first": page * PAGE_SIZE }),
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    let items = parse_search_response(&body)?;
+    Ok(items
+        .into_iter()
+        .skip(((page - 1) * PAGE_SIZE) as usize)
+        .collect())
+}
@@ -0,0 +1,320 @@
+// External players (mpv, VLC, ffplay) and playlist files for a resolved
+// stream. Everything here is plain data; launching is left to the shell app.
+use serde::{Deserialize, Serialize};
+
+use crate::platforms::common::StreamHeaders;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalPlayer {
+    Mpv,
+    Vlc,
+    Ffplay,
+}
+
+impl ExternalPlayer {
+    /// Executable name looked up on PATH.
+    pub fn program(self) -> &'static str {
+        match self {
+            ExternalPlayer::Mpv => "mpv",
+            ExternalPlayer::Vlc => "vlc",
+            ExternalPlayer::Ffplay => "ffplay",
+        }
+    }
+}
+
+/// What to play: a stream URL, the headers its server wants and extras.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlayerSource {
+    pub url: String,
+    // Empty for the local proxy, which sends them itself
+    pub headers: StreamHeaders,
+    pub title: Option<String>,
+    // ASS danmaku track (path or URL), for players that take one
+    pub subtitle: Option<String>,
+}
+
+impl PlayerSource {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    // Headers without a dedicated player flag
+    fn other_headers(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.headers.iter().filter(|(k, _)| {
+            !k.eq_ignore_ascii_case("user-agent") && !k.eq_ignore_ascii_case("referer")
+        })
+    }
+}
+
+/// A program and its arguments, ready for `Command::new(program).args(args)`.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct LaunchSpec {
+    pub program: String,
+    pub args: Vec<String>,
+}
+
+impl LaunchSpec {
+    /// The same command for a POSIX shell, each argument quoted as needed.
+    pub fn command_line(&self) -> String {
+        std::iter::once(&self.program)
+            .chain(&self.args)
+            .map(|arg| shell_quote(arg))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn shell_quote(arg: &str) -> String {
+    let plain = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=,+@%".contains(c));
+    if plain {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+/// Arguments that make `player` fetch `source` the way DTV would. VLC has no
+/// flag for arbitrary headers, and ffplay takes no external subtitle file;
+/// those are dropped. `program` overrides the executable (e.g. a full path).
+/// Only http(s) URLs are accepted, so a URL can never pass as an option.
+pub fn launch_spec(
+    player: ExternalPlayer,
+    source: &PlayerSource,
+    program: Option<&str>,
+) -> Result<LaunchSpec, String> {
+    if !(source.url.starts_with("http://") || source.url.starts_with("https://")) {
+        return Err(format!("Not an http(s) stream URL: {}", source.url));
+    }
+    let mut args: Vec<String> = Vec::new();
+    let ua = source.header("user-agent");
+    let referer = source.header("referer");
+    match player {
+        ExternalPlayer::Mpv => {
+            if let Some(title) = &source.title {
+                args.push(format!("--force-media-title={}", title));
+            }
+            if let Some(ua) = ua {
+                args.push(format!("--user-agent={}", ua));
+            }
+            if let Some(referer) = referer {
+                args.push(format!("--referrer={}", referer));
+            }
+            // One header per option, so commas in values survive
+            for (name, value) in source.other_headers() {
+                args.push(format!("--http-header-fields-append={}: {}", name, value));
+            }
+            if let Some(subtitle) = &source.subtitle {
+                args.push(format!("--sub-file={}", subtitle));
+            }
+        }
+        ExternalPlayer::Vlc => {
+            if let Some(title) = &source.title {
+                args.push(format!("--meta-title={}", title));
+            }
+            if let Some(ua) = ua {
+                args.push(format!("--http-user-agent={}", ua));
+            }
+            if let Some(referer) = referer {
+                args.push(format!("--http-referrer={}", referer));
+            }
+            if let Some(subtitle) = &source.subtitle {
+                args.push(format!("--sub-file={}", subtitle));
+            }
+        }
+        ExternalPlayer::Ffplay => {
+            if let Some(title) = &source.title {
+                args.extend(["-window_title".to_string(), title.clone()]);
+            }
+            if let Some(ua) = ua {
+                args.extend(["-user_agent".to_string(), ua.to_string()]);
+            }
+            if let Some(referer) = referer {
+                args.extend(["-referer".to_string(), referer.to_string()]);
+            }
+            let headers: String = source
+                .other_headers()
+                .map(|(name, value)| format!("{}: {}\r\n", name, value))
+                .collect();
+            if !headers.is_empty() {
+                args.extend(["-headers".to_string(), headers]);
+            }
+        }
+    }
+    // Everything after this is a file or URL, never an option
+    if player != ExternalPlayer::Ffplay {
+        args.push("--".to_string());
+    }
+    args.push(source.url.clone());
+    Ok(LaunchSpec {
+        program: program.unwrap_or(player.program()).to_string(),
+        args,
+    })
+}
+
+// Titles go on one line after the comma
+fn m3u_title(title: Option<&str>) -> String {
+    title
+        .unwrap_or("")
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect()
+}
+
+/// One `#EXTINF` entry; User-Agent and Referer as VLC/Kodi `#EXTVLCOPT`s.
+pub fn m3u_entry(source: &PlayerSource) -> String {
+    m3u_channel(source, &[])
+}
+
+/// `m3u_entry` with IPTV attributes (`tvg-id`, `tvg-logo`, `group-title`)
+/// on the `#EXTINF` line.
+pub fn m3u_channel(source: &PlayerSource, attributes: &[(&str, &str)]) -> String {
+    let mut entry = String::from("#EXTINF:-1");
+    for (name, value) in attributes {
+        // Attribute values cannot escape a quote
+        let value = m3u_title(Some(value)).replace('"', "'");
+        entry.push_str(&format!(" {}=\"{}\"", name, value));
+    }
+    entry.push_str(&format!(",{}\n", m3u_title(source.title.as_deref())));
+    if let Some(ua) = source.header("user-agent") {
+        entry.push_str(&format!("#EXTVLCOPT:http-user-agent={}\n", ua));
+    }
+    if let Some(referer) = source.header("referer") {
+        entry.push_str(&format!("#EXTVLCOPT:http-referrer={}\n", referer));
+    }
+    entry.push_str(&source.url);
+    entry.push('\n');
+    entry
+}
+
+pub fn m3u(sources: &[PlayerSource]) -> String {
+    let mut playlist = String::from("#EXTM3U\n");
+    for source in sources {
+        playlist.push_str(&m3u_entry(source));
+    }
+    playlist
+}
+
+/// Kodi `.strm`: the URL, then every header after a `|`, URL-encoded.
+pub fn strm(source: &PlayerSource) -> String {
+    if source.headers.is_empty() {
+        return format!("{}\n", source.url);
+    }
+    let headers = source
+        .headers
+        .iter()
+        .map(|(name, value)| format!("{}={}", name, urlencoding::encode(value)))
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{}|{}\n", source.url, headers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platforms::common::stream_headers;
+
+    fn source() -> PlayerSource {
+        let mut headers = stream_headers(
+            "Mozilla/5.0 (X11)",
+            "https://www.huya.com/",
+            Some("https://www.huya.com"),
+        );
+        headers.insert("Cookie".to_string(), "a=1, b=2".to_string());
+        PlayerSource {
+            url: "https://tx.flv.huya.com/src/1.flv?wsSecret=x&t=1".to_string(),
+            headers,
+            title: Some("主播's room".to_string()),
+            subtitle: Some("/tmp/danmaku.ass".to_string()),
+        }
+    }
+
+    #[test]
+    fn builds_player_arguments() {
+        let mpv = launch_spec(ExternalPlayer::Mpv, &source(), None).unwrap();
+        assert_eq!(
+            mpv.args,
+            [
+                "--force-media-title=主播's room",
+                "--user-agent=Mozilla/5.0 (X11)",
+                "--referrer=https://www.huya.com/",
+                "--http-header-fields-append=Cookie: a=1, b=2",
+                "--http-header-fields-append=Origin: https://www.huya.com",
+                "--sub-file=/tmp/danmaku.ass",
+                "--",
+                "https://tx.flv.huya.com/src/1.flv?wsSecret=x&t=1",
+            ]
+        );
+        assert_eq!(
+            mpv.command_line(),
+            "mpv '--force-media-title=主播'\\''s room' '--user-agent=Mozilla/5.0 (X11)' \
+             --referrer=https://www.huya.com/ '--http-header-fields-append=Cookie: a=1, b=2' \
+             '--http-header-fields-append=Origin: https://www.huya.com' --sub-file=/tmp/danmaku.ass -- \
+             'https://tx.flv.huya.com/src/1.flv?wsSecret=x&t=1'"
+        );
+
+        let ffplay = launch_spec(ExternalPlayer::Ffplay, &source(), Some("/opt/ffplay")).unwrap();
+        assert_eq!(ffplay.program, "/opt/ffplay");
+        let headers = ffplay.args.iter().position(|a| a == "-headers").unwrap();
+        assert_eq!(
+            ffplay.args[headers + 1],
+            "Cookie: a=1, b=2\r\nOrigin: https://www.huya.com\r\n"
+        );
+        assert!(!ffplay.args.iter().any(|a| a.contains("danmaku.ass")));
+
+        // The local proxy needs nothing but the URL
+        let proxied = PlayerSource {
+            url: "http://127.0.0.1:34719/live/huya/1.flv".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            launch_spec(ExternalPlayer::Vlc, &proxied, None)
+                .unwrap()
+                .command_line(),
+            "vlc -- http://127.0.0.1:34719/live/huya/1.flv"
+        );
+
+        // A URL that would read as a player option
+        let option = PlayerSource {
+            url: "--script=/tmp/evil.lua".to_string(),
+            ..Default::default()
+        };
+        assert!(launch_spec(ExternalPlayer::Mpv, &option, None).is_err());
+    }
+
+    #[test]
+    fn writes_playlists() {
+        assert_eq!(
+            m3u(&[source()]),
+            "#EXTM3U\n#EXTINF:-1,主播's room\n\
+             #EXTVLCOPT:http-user-agent=Mozilla/5.0 (X11)\n\
+             #EXTVLCOPT:http-referrer=https://www.huya.com/\n\
+             https://tx.flv.huya.com/src/1.flv?wsSecret=x&t=1\n"
+        );
+        let channel = PlayerSource {
+            url: "http://127.0.0.1:34719/live/huya/1.flv".to_string(),
+            title: Some("主播".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            m3u_channel(
+                &channel,
+                &[("tvg-id", "huya.1"), ("group-title", "say \"hi\"")]
+            ),
+            "#EXTINF:-1 tvg-id=\"huya.1\" group-title=\"say 'hi'\",主播\n\
+             http://127.0.0.1:34719/live/huya/1.flv\n"
+        );
+        assert_eq!(
+            strm(&source()),
+            "https://tx.flv.huya.com/src/1.flv?wsSecret=x&t=1|Cookie=a%3D1%2C%20b%3D2\
+             &Origin=https%3A%2F%2Fwww.huya.com&Referer=https%3A%2F%2Fwww.huya.com%2F\
+             &User-Agent=Mozilla%2F5.0%20%28X11%29\n"
+        );
+    }
+}
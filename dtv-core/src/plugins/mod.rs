@@ -0,0 +1,291 @@
+// Platforms implemented in JS. Each `*.js` file in a directory may call
+// `dtv.register({ id, name, meta, resolve, search })`; the plugin then serves
+// `SupportedPlatform::Custom(id)`. Plugins run in their own V8 isolates with
+// `dtv.fetch` and `dtv.crypto` as their only ops, and results are validated
+// before they reach callers. Files are re-read when they change.
+mod ops;
+mod validate;
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+
+use crate::platforms::common::js_function_worker::{JsFunctionWorker, JsWorkerOptions};
+use crate::platforms::common::types::{is_valid_custom_platform_id, SupportedPlatform};
+use crate::platforms::common::{
+    GetLiveStreamRequest, LiveStatus, LiveStreamMode, LiveStreamResponse,
+};
+use crate::scripting::source::{self, Fingerprint};
+
+pub use validate::PluginSearchItem;
+use validate::{live_stream_response, plugin_room, search_items, PluginRoom};
+
+const PRELUDE: &str = include_str!("prelude.js");
+const USER_AGENT: &str = "dtv-plugins";
+// Minimum gap between directory scans
+const RESCAN_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A loaded plugin, as listed by `PluginHost::plugins`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginInfo {
+    pub id: String,
+    pub name: String,
+    pub file: String,
+    // Which of meta / resolve / search it implements
+    pub methods: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PluginLog {
+    file: String,
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoadReply {
+    plugins: Vec<PluginInfo>,
+    #[serde(default)]
+    errors: Vec<PluginLog>,
+    #[serde(default)]
+    logs: Vec<PluginLog>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CallReply {
+    ok: bool,
+    #[serde(default)]
+    value: Value,
+    #[serde(default)]
+    error: Option<String>,
+    #[serde(default)]
+    logs: Vec<PluginLog>,
+}
+
+#[derive(Default)]
+struct Loaded {
+    generation: u64,
+    fingerprint: Fingerprint,
+    // Sources as a JS array literal, sent along with every call so any
+    // runtime in the pool can catch up
+    sources: Arc<String>,
+    plugins: Option<Vec<PluginInfo>>,
+    checked_at: Option<Instant>,
+}
+
+struct Inner {
+    dir: PathBuf,
+    worker: JsFunctionWorker,
+    loaded: Mutex<Loaded>,
+}
+
+/// Cheap to clone; clones share the isolates.
+#[derive(Clone)]
+pub struct PluginHost {
+    inner: Arc<Inner>,
+}
+
+impl PluginHost {
+    /// Plugins are read from `dir` on first use. A missing directory just
+    /// means no plugins until one appears.
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self, String> {
+        let options = JsWorkerOptions {
+            // A slow site should not hold up every other plugin
+            pool_size: 2,
+            job_timeout: Duration::from_secs(20),
+            queue_timeout: Duration::from_secs(30),
+            max_heap_bytes: 64 * 1024 * 1024,
+            extensions: Some(ops::extensions),
+            ..JsWorkerOptions::default()
+        };
+        let worker =
+            JsFunctionWorker::with_options(USER_AGENT, vec![("[dtv-plugins]", PRELUDE)], options)?;
+        Ok(Self {
+            inner: Arc::new(Inner {
+                dir: dir.into(),
+                worker,
+                loaded: Mutex::new(Loaded::default()),
+            }),
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.inner.dir
+    }
+
+    /// Plugins currently registered, in file order.
+    pub async fn plugins(&self) -> Vec<PluginInfo> {
+        let mut loaded = self.inner.loaded.lock().await;
+        self.rescan(&mut loaded);
+        if loaded.plugins.is_none() {
+            let expr = format!(
+                "__dtvPlugins.load({}, {})",
+                loaded.generation, loaded.sources
+            );
+            let reply = self.inner.worker.eval_string(expr).await.and_then(|raw| {
+                serde_json::from_str::<LoadReply>(&raw)
+                    .map_err(|e| format!("bad reply from plugins: {}", e))
+            });
+            loaded.plugins = Some(match reply {
+                Ok(reply) => {
+                    log_lines(&reply.logs);
+                    for e in &reply.errors {
+                        eprintln!("[Plugins] {} failed to load: {}", e.file, e.message);
+                    }
+                    // The prelude checks ids too; this keeps the two in step
+                    reply
+                        .plugins
+                        .into_iter()
+                        .filter(|p| is_valid_custom_platform_id(&p.id))
+                        .collect()
+                }
+                Err(e) => {
+                    eprintln!(
+                        "[Plugins] Loading {} failed: {}",
+                        self.inner.dir.display(),
+                        e
+                    );
+                    Vec::new()
+                }
+            });
+        }
+        loaded.plugins.clone().unwrap_or_default()
+    }
+
+    /// The plugin serving platform `id`, if one is loaded.
+    pub async fn plugin(&self, id: &str) -> Option<PluginInfo> {
+        self.plugins().await.into_iter().find(|p| p.id == id)
+    }
+
+    /// `DtvClient::get_live_stream` for a plugin platform. Meta requests use
+    /// the plugin's `meta` when it has one, `resolve` otherwise.
+    pub async fn live_stream(
+        &self,
+        id: &str,
+        request: &GetLiveStreamRequest,
+        room_id: String,
+        debug_enabled: bool,
+    ) -> LiveStreamResponse {
+        let platform = SupportedPlatform::Custom(id.to_string());
+        let failed = |room_id: String, message: String| LiveStreamResponse {
+            status: LiveStatus::Error,
+            room: plugin_room(platform.clone(), room_id),
+            playback: None,
+            options: None,
+            error: Some(message),
+        };
+        let Some(plugin) = self.plugin(id).await else {
+            return failed(room_id, format!("No plugin provides platform {}", id));
+        };
+
+        let method = if request.mode() == LiveStreamMode::Meta
+            && plugin.methods.iter().any(|m| m == "meta")
+        {
+            "meta"
+        } else {
+            "resolve"
+        };
+        let options = json!({
+            "quality": request.quality,
+            "line": request.line,
+            "codec": request.codec(),
+            "cookie": request.cookie,
+            "mode": if request.mode() == LiveStreamMode::Meta { "meta" } else { "playback" },
+        });
+        let value = match self.call(id, method, json!([room_id, options])).await {
+            Ok(v) => v,
+            Err(e) => return failed(room_id, e),
+        };
+        match serde_json::from_value::<PluginRoom>(value) {
+            Ok(raw) => live_stream_response(id, request, room_id, raw, debug_enabled),
+            Err(e) => failed(
+                room_id,
+                format!("plugin {} returned an invalid room: {}", id, e),
+            ),
+        }
+    }
+
+    /// Rooms matching `keyword` on a plugin platform.
+    pub async fn search(
+        &self,
+        id: &str,
+        keyword: &str,
+        page: u32,
+    ) -> Result<Vec<PluginSearchItem>, String> {
+        let Some(plugin) = self.plugin(id).await else {
+            return Err(format!("No plugin provides platform {}", id));
+        };
+        if !plugin.methods.iter().any(|m| m == "search") {
+            return Err(format!("plugin {} does not support search", id));
+        }
+        let value = self.call(id, "search", json!([keyword, page])).await?;
+        search_items(id, value)
+    }
+
+    // Reloads the sources when files changed since the last scan
+    fn rescan(&self, loaded: &mut Loaded) {
+        let due = loaded
+            .checked_at
+            .is_none_or(|at| at.elapsed() >= RESCAN_INTERVAL);
+        if !due {
+            return;
+        }
+        loaded.checked_at = Some(Instant::now());
+        let fingerprint = source::fingerprint(&self.inner.dir);
+        if loaded.generation > 0 && fingerprint == loaded.fingerprint {
+            return;
+        }
+        // Plugins have no config file; `load` only reads one for scripts
+        let (sources, _) = source::load(&self.inner.dir);
+        let sources = serde_json::to_string(&sources).unwrap_or_else(|_| "[]".to_string());
+        loaded.generation += 1;
+        loaded.fingerprint = fingerprint;
+        loaded.sources = Arc::new(sources);
+        loaded.plugins = None;
+    }
+
+    async fn call(&self, id: &str, method: &str, args: Value) -> Result<Value, String> {
+        let (generation, sources) = {
+            let mut loaded = self.inner.loaded.lock().await;
+            self.rescan(&mut loaded);
+            (loaded.generation, loaded.sources.clone())
+        };
+        let expr = format!(
+            "(__dtvPlugins.generation() === {gen} || __dtvPlugins.load({gen}, {sources}), \
+             __dtvPlugins.call({id}, {method}, {args}))",
+            gen = generation,
+            sources = sources,
+            id = json!(id),
+            method = json!(method),
+            args = args,
+        );
+        let raw = self
+            .inner
+            .worker
+            .eval_string(expr)
+            .await
+            .map_err(|e| format!("plugin {} failed: {}", id, e))?;
+        let reply: CallReply = serde_json::from_str(&raw)
+            .map_err(|e| format!("bad reply from plugin {}: {}", id, e))?;
+        log_lines(&reply.logs);
+        if reply.ok {
+            Ok(reply.value)
+        } else {
+            Err(format!(
+                "plugin {} {} failed: {}",
+                id,
+                method,
+                reply.error.unwrap_or_default()
+            ))
+        }
+    }
+}
+
+fn log_lines(logs: &[PluginLog]) {
+    for line in logs {
+        eprintln!("[Plugins] {}: {}", line.file, line.message);
+    }
+}
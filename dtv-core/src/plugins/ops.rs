@@ -0,0 +1,144 @@
+// The only ops plugin runtimes get: a blocking HTTP fetch and digest helpers.
+// They run on the V8 worker threads, which are plain threads, so blocking is fine;
+// the job timeout still bounds a plugin call as a whole.
+use std::collections::HashMap;
+use std::io::Read;
+use std::time::Duration;
+
+use base64::{engine::general_purpose, Engine as _};
+use deno_core::error::AnyError;
+use deno_core::{anyhow, op2};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::platforms::common::signing::hash::{hmac_sha256_hex, md5_hex};
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+// Larger bodies are an error rather than a silent truncation
+const MAX_BODY_BYTES: u64 = 8 * 1024 * 1024;
+
+static HTTP: Lazy<Result<reqwest::blocking::Client, String>> = Lazy::new(|| {
+    reqwest::blocking::Client::builder()
+        .timeout(FETCH_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())
+});
+
+#[derive(Deserialize)]
+struct FetchRequest {
+    url: String,
+    #[serde(default)]
+    method: Option<String>,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    #[serde(default)]
+    body: Option<String>,
+}
+
+#[derive(Serialize)]
+struct FetchResponse {
+    status: u16,
+    // Final URL after redirects
+    url: String,
+    // Lower-cased names; repeated headers joined with ", "
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+#[op2]
+#[serde]
+fn op_dtv_plugin_fetch(#[serde] request: FetchRequest) -> Result<FetchResponse, AnyError> {
+    let url = url::Url::parse(&request.url)?;
+    if url.scheme() != "http" && url.scheme() != "https" {
+        anyhow::bail!("fetch only supports http(s) URLs: {}", request.url);
+    }
+    let client = HTTP.as_ref().map_err(|e| anyhow::anyhow!("{}", e))?;
+    let method = reqwest::Method::from_bytes(
+        request
+            .method
+            .as_deref()
+            .unwrap_or("GET")
+            .to_uppercase()
+            .as_bytes(),
+    )?;
+
+    let mut builder = client.request(method, url);
+    for (name, value) in &request.headers {
+        builder = builder.header(name.as_str(), value.as_str());
+    }
+    if let Some(body) = request.body {
+        builder = builder.body(body);
+    }
+    let response = builder.send()?;
+
+    let status = response.status().as_u16();
+    let final_url = response.url().to_string();
+    let mut headers: HashMap<String, String> = HashMap::new();
+    for (name, value) in response.headers() {
+        let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+        headers
+            .entry(name.as_str().to_string())
+            .and_modify(|v| {
+                v.push_str(", ");
+                v.push_str(&value);
+            })
+            .or_insert(value);
+    }
+    let mut bytes = Vec::new();
+    response.take(MAX_BODY_BYTES + 1).read_to_end(&mut bytes)?;
+    if bytes.len() as u64 > MAX_BODY_BYTES {
+        anyhow::bail!(
+            "response from {} exceeds {} bytes",
+            final_url,
+            MAX_BODY_BYTES
+        );
+    }
+
+    Ok(FetchResponse {
+        status,
+        url: final_url,
+        headers,
+        body: String::from_utf8_lossy(&bytes).into_owned(),
+    })
+}
+
+#[derive(Deserialize)]
+struct DigestArgs {
+    algo: String,
+    data: String,
+    #[serde(default)]
+    key: Option<String>,
+}
+
+#[op2]
+#[string]
+fn op_dtv_plugin_digest(#[serde] args: DigestArgs) -> Result<String, AnyError> {
+    digest(&args.algo, &args.data, args.key.as_deref()).map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+fn digest(algo: &str, data: &str, key: Option<&str>) -> Result<String, String> {
+    match algo {
+        "md5" => Ok(md5_hex(data)),
+        "sha256" => Ok(format!("{:x}", Sha256::digest(data.as_bytes()))),
+        "hmac-sha256" => {
+            let key = key.ok_or("hmacSha256 needs a key")?;
+            Ok(hmac_sha256_hex(key.as_bytes(), data.as_bytes()))
+        }
+        "base64" => Ok(general_purpose::STANDARD.encode(data)),
+        "base64-decode" => general_purpose::STANDARD
+            .decode(data.trim())
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+            .map_err(|e| format!("invalid base64: {}", e)),
+        other => Err(format!("unknown digest: {}", other)),
+    }
+}
+
+deno_core::extension!(
+    dtv_plugin_ops,
+    ops = [op_dtv_plugin_fetch, op_dtv_plugin_digest],
+);
+
+pub(super) fn extensions() -> Vec<deno_core::Extension> {
+    vec![dtv_plugin_ops::init_ops()]
+}
@@ -0,0 +1,199 @@
+// Host side of platform plugins. Runs once per runtime, before any plugin.
+// Plugins are compiled with `new Function("dtv", "console", source)`; the
+// fetch and digest ops are captured here and Deno is hidden from them.
+(function () {
+  "use strict";
+
+  var ops = globalThis.Deno && Deno.core && Deno.core.ops;
+  if (!ops || typeof ops.op_dtv_plugin_fetch !== "function") {
+    throw new Error("plugin ops are not registered");
+  }
+  var opFetch = ops.op_dtv_plugin_fetch;
+  var opDigest = ops.op_dtv_plugin_digest;
+  try {
+    delete globalThis.Deno;
+  } catch (_) {}
+  if (typeof globalThis.Deno !== "undefined") {
+    throw new Error("cannot hide Deno from plugins");
+  }
+
+  var ID_PATTERN = /^[a-z][a-z0-9_-]{0,31}$/;
+  var RESERVED = ["douyu", "bilibili", "douyin", "huya", "kuaishou", "cc", "twitch"];
+  var METHODS = ["meta", "resolve", "search"];
+
+  var generation = -1;
+  var plugins = Object.create(null);
+  // Log lines of the current load or call, returned with its reply
+  var logs = [];
+
+  function text(v) {
+    if (typeof v === "string") return v;
+    try {
+      return JSON.stringify(v);
+    } catch (_) {
+      return String(v);
+    }
+  }
+
+  function errorText(e) {
+    return String((e && e.stack) || e);
+  }
+
+  function response(raw) {
+    return Object.freeze({
+      ok: raw.status >= 200 && raw.status < 300,
+      status: raw.status,
+      url: raw.url,
+      headers: Object.freeze(raw.headers),
+      text: function () {
+        return Promise.resolve(raw.body);
+      },
+      json: function () {
+        return new Promise(function (resolve) {
+          resolve(JSON.parse(raw.body));
+        });
+      },
+    });
+  }
+
+  // Fetch-like, but synchronous underneath: the promise is settled on return
+  function fetch(url, init) {
+    return new Promise(function (resolve) {
+      init = init || {};
+      var headers = {};
+      Object.keys(init.headers || {}).forEach(function (name) {
+        headers[name] = String(init.headers[name]);
+      });
+      var body = init.body;
+      if (body != null && typeof body !== "string") {
+        body = JSON.stringify(body);
+        if (!Object.keys(headers).some(function (h) { return h.toLowerCase() === "content-type"; })) {
+          headers["content-type"] = "application/json";
+        }
+      }
+      resolve(
+        response(
+          opFetch({
+            url: String(url),
+            method: init.method ? String(init.method) : "GET",
+            headers: headers,
+            body: body == null ? null : body,
+          })
+        )
+      );
+    });
+  }
+
+  function digest(algo) {
+    return function (data) {
+      return opDigest({ algo: algo, data: String(data) });
+    };
+  }
+
+  var crypto = Object.freeze({
+    md5: digest("md5"),
+    sha256: digest("sha256"),
+    hmacSha256: function (key, data) {
+      return opDigest({ algo: "hmac-sha256", data: String(data), key: String(key) });
+    },
+    base64Encode: digest("base64"),
+    base64Decode: digest("base64-decode"),
+  });
+
+  function makeApi(file, registered) {
+    var log = function () {
+      logs.push({ file: file, message: Array.prototype.map.call(arguments, text).join(" ") });
+    };
+    return {
+      dtv: Object.freeze({
+        // dtv.register({ id, name, meta?, resolve, search? })
+        register: function (def) {
+          if (!def || typeof def !== "object") {
+            throw new TypeError("dtv.register needs a plugin object");
+          }
+          registered.push(def);
+        },
+        fetch: fetch,
+        crypto: crypto,
+        log: log,
+      }),
+      console: Object.freeze({ log: log, info: log, warn: log, error: log }),
+    };
+  }
+
+  function check(def, taken) {
+    var id = def.id;
+    if (typeof id !== "string" || !ID_PATTERN.test(id) || RESERVED.indexOf(id) >= 0) {
+      return "invalid plugin id: " + text(id);
+    }
+    if (taken[id]) return "duplicate plugin id: " + id;
+    if (typeof def.resolve !== "function") return id + ": resolve must be a function";
+    return null;
+  }
+
+  globalThis.__dtvPlugins = Object.freeze({
+    generation: function () {
+      return generation;
+    },
+
+    // sources: [{ name, source }]; replaces every plugin
+    load: function (gen, sources) {
+      var loaded = Object.create(null);
+      var manifests = [];
+      var errors = [];
+      logs = [];
+      sources.forEach(function (s) {
+        var registered = [];
+        var api = makeApi(s.name, registered);
+        try {
+          new Function("dtv", "console", s.source)(api.dtv, api.console);
+        } catch (e) {
+          errors.push({ file: s.name, message: errorText(e) });
+          return;
+        }
+        registered.forEach(function (def) {
+          var problem = check(def, loaded);
+          if (problem) {
+            errors.push({ file: s.name, message: problem });
+            return;
+          }
+          loaded[def.id] = def;
+          manifests.push({
+            id: def.id,
+            name: typeof def.name === "string" && def.name ? def.name : def.id,
+            file: s.name,
+            methods: METHODS.filter(function (m) {
+              return typeof def[m] === "function";
+            }),
+          });
+        });
+      });
+      plugins = loaded;
+      generation = gen;
+      return JSON.stringify({ plugins: manifests, errors: errors, logs: logs });
+    },
+
+    // Always fulfills, with {ok, value} or {ok: false, error}
+    call: function (id, method, args) {
+      logs = [];
+      var plugin = plugins[id];
+      var reply = function (result) {
+        result.logs = logs;
+        return JSON.stringify(result);
+      };
+      if (!plugin || typeof plugin[method] !== "function") {
+        return Promise.resolve(reply({ ok: false, error: id + " does not implement " + method }));
+      }
+      return new Promise(function (resolve) {
+        resolve(plugin[method].apply(plugin, args));
+      }).then(
+        function (value) {
+          return reply({ ok: true, value: value === undefined ? null : value });
+        },
+        function (e) {
+          return reply({ ok: false, error: errorText(e) });
+        }
+      );
+    },
+  });
+})();
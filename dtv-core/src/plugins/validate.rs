@@ -0,0 +1,425 @@
+// Turns what plugins return into the crate's types. Plugin output is untrusted:
+// strings are trimmed and capped, URLs must be http(s), unknown fields ignored.
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::platforms::common::types::{StreamVariant, SupportedPlatform};
+use crate::platforms::common::{
+    infer_stream_type, normalize_codec, truncate_variants, GetLiveStreamRequest, LiveStatus,
+    LiveStreamMode, LiveStreamResponse, Playback, RoomMeta, StreamHeaders, StreamType,
+};
+
+const MAX_TEXT_CHARS: usize = 256;
+const MAX_URL_CHARS: usize = 4096;
+const MAX_SEARCH_RESULTS: usize = 50;
+const MAX_HEADERS: usize = 16;
+
+/// `resolve` / `meta` result.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct PluginRoom {
+    status: Option<String>,
+    #[serde(default)]
+    room_id: Value,
+    title: Option<String>,
+    anchor_name: Option<String>,
+    avatar: Option<String>,
+    #[serde(default)]
+    streams: Vec<PluginStream>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PluginStream {
+    url: String,
+    quality: Option<String>,
+    line: Option<String>,
+    // "flv" or "hls"; inferred from the URL otherwise
+    #[serde(rename = "type")]
+    stream_type: Option<String>,
+    // "avc" / "hevc" / "av1", or a name like "h265"
+    codec: Option<String>,
+    // Request headers the CDN checks, e.g. {"Referer": "..."}
+    #[serde(default)]
+    headers: BTreeMap<String, Value>,
+}
+
+impl PluginStream {
+    fn codec(&self) -> Option<&'static str> {
+        self.codec.as_deref().and_then(normalize_codec)
+    }
+}
+
+/// One room from a plugin's `search`.
+#[derive(Debug, Clone, Serialize)]
+pub struct PluginSearchItem {
+    pub platform: SupportedPlatform,
+    pub room_id: String,
+    pub title: Option<String>,
+    pub anchor_name: Option<String>,
+    pub avatar: Option<String>,
+    pub cover: Option<String>,
+    pub is_live: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawSearchItem {
+    room_id: Value,
+    title: Option<String>,
+    anchor_name: Option<String>,
+    avatar: Option<String>,
+    cover: Option<String>,
+    is_live: Option<bool>,
+}
+
+fn clean_text(value: Option<String>) -> Option<String> {
+    let value = value?;
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    Some(trimmed.chars().take(MAX_TEXT_CHARS).collect())
+}
+
+fn clean_url(value: Option<&str>) -> Option<String> {
+    let url = value?.trim();
+    let lower = url.to_ascii_lowercase();
+    ((lower.starts_with("https://") || lower.starts_with("http://"))
+        && url.len() <= MAX_URL_CHARS
+        && !url.chars().any(char::is_whitespace))
+    .then(|| url.to_string())
+}
+
+// String values under token names only, so nothing can split the request
+fn clean_headers(headers: &BTreeMap<String, Value>) -> StreamHeaders {
+    headers
+        .iter()
+        .filter(|(name, _)| {
+            !name.is_empty()
+                && name.len() <= 64
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        .filter_map(|(name, value)| {
+            let value = value.as_str()?.trim();
+            (value.len() <= MAX_URL_CHARS && !value.chars().any(char::is_control))
+                .then(|| (name.clone(), value.to_string()))
+        })
+        .take(MAX_HEADERS)
+        .collect()
+}
+
+// Numeric ids are common in page JSON; accept them as strings
+fn clean_room_id(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => clean_text(Some(s.clone())),
+        Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+fn stream_type(stream: &PluginStream, url: &str) -> StreamType {
+    match stream
+        .stream_type
+        .as_deref()
+        .map(str::to_ascii_lowercase)
+        .as_deref()
+    {
+        Some("flv") => StreamType::Flv,
+        Some("hls") | Some("m3u8") => StreamType::Hls,
+        _ => infer_stream_type(url),
+    }
+}
+
+fn error_response(room: RoomMeta, message: String) -> LiveStreamResponse {
+    LiveStreamResponse {
+        status: LiveStatus::Error,
+        room,
+        playback: None,
+        options: None,
+        error: Some(message),
+    }
+}
+
+pub(crate) fn plugin_room(platform: SupportedPlatform, room_id: String) -> RoomMeta {
+    RoomMeta {
+        platform,
+        room_id,
+        normalized_room_id: None,
+        web_rid: None,
+        title: None,
+        anchor_name: None,
+        avatar: None,
+        category: None,
+    }
+}
+
+/// Validate a plugin's room into a response for `request`. The requested
+/// quality and line pick the stream when a plugin offers several.
+pub(crate) fn live_stream_response(
+    id: &str,
+    request: &GetLiveStreamRequest,
+    room_id: String,
+    raw: PluginRoom,
+    debug_enabled: bool,
+) -> LiveStreamResponse {
+    let platform = SupportedPlatform::Custom(id.to_string());
+    let normalized_room_id = clean_room_id(&raw.room_id).filter(|r| *r != room_id);
+    let room = RoomMeta {
+        normalized_room_id,
+        title: clean_text(raw.title),
+        anchor_name: clean_text(raw.anchor_name),
+        avatar: clean_url(raw.avatar.as_deref()),
+        ..plugin_room(platform, room_id)
+    };
+
+    let status = match raw.status.as_deref().map(str::trim) {
+        Some("live") => LiveStatus::Live,
+        Some("offline") => LiveStatus::Offline,
+        other => {
+            return error_response(
+                room,
+                format!("plugin {} returned an invalid status: {:?}", id, other),
+            )
+        }
+    };
+    if status == LiveStatus::Offline || request.mode() == LiveStreamMode::Meta {
+        return LiveStreamResponse {
+            status,
+            room,
+            playback: None,
+            options: None,
+            error: None,
+        };
+    }
+
+    let streams: Vec<(String, PluginStream)> = raw
+        .streams
+        .into_iter()
+        .filter_map(|s| clean_url(Some(&s.url)).map(|url| (url, s)))
+        .collect();
+    let wanted = |value: &Option<String>, want: &Option<String>| {
+        want.as_deref()
+            .is_none_or(|w| value.as_deref().is_some_and(|v| v.trim() == w.trim()))
+    };
+    // Among the matches, the requested codec ranks first
+    let codec = request.codec();
+    let best = |matches: &dyn Fn(&PluginStream) -> bool| {
+        streams
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, s))| matches(s))
+            .min_by_key(|(_, (_, s))| codec.rank(s.codec()))
+            .map(|(i, _)| i)
+    };
+    let picked = best(&|s| wanted(&s.quality, &request.quality) && wanted(&s.line, &request.line))
+        .or_else(|| best(&|s| wanted(&s.quality, &request.quality)))
+        .unwrap_or(0);
+    let Some((url, stream)) = streams.get(picked) else {
+        return error_response(room, format!("plugin {} returned no playable stream", id));
+    };
+
+    let playback = Playback {
+        url: url.clone(),
+        stream_type: stream_type(stream, url),
+        upstream_url: debug_enabled.then(|| url.clone()),
+        codec: stream.codec().map(str::to_string),
+        headers: clean_headers(&stream.headers),
+        variants: debug_enabled.then(|| {
+            truncate_variants(
+                streams
+                    .iter()
+                    .map(|(url, s)| StreamVariant {
+                        url: url.clone(),
+                        format: Some(
+                            match stream_type(s, url) {
+                                StreamType::Flv => "flv",
+                                StreamType::Hls => "hls",
+                                StreamType::Unknown => "unknown",
+                            }
+                            .to_string(),
+                        ),
+                        desc: clean_text(s.quality.clone()),
+                        qn: None,
+                        protocol: url.split(':').next().map(|p| p.to_string()),
+                        codec: s.codec().map(str::to_string),
+                    })
+                    .collect(),
+            )
+        }),
+        media: None,
+    };
+    LiveStreamResponse {
+        status,
+        room,
+        playback: Some(playback),
+        options: None,
+        error: None,
+    }
+}
+
+/// Validate a plugin's search results; entries without a room id are dropped.
+pub(crate) fn search_items(id: &str, raw: Value) -> Result<Vec<PluginSearchItem>, String> {
+    let items: Vec<Value> = match raw {
+        Value::Array(items) => items,
+        Value::Null => Vec::new(),
+        _ => return Err(format!("plugin {} search must return an array", id)),
+    };
+    Ok(items
+        .into_iter()
+        .filter_map(|item| serde_json::from_value::<RawSearchItem>(item).ok())
+        .filter_map(|item| {
+            Some(PluginSearchItem {
+                platform: SupportedPlatform::Custom(id.to_string()),
+                room_id: clean_room_id(&item.room_id)?,
+                title: clean_text(item.title),
+                anchor_name: clean_text(item.anchor_name),
+                avatar: clean_url(item.avatar.as_deref()),
+                cover: clean_url(item.cover.as_deref()),
+                is_live: item.is_live,
+            })
+        })
+        .take(MAX_SEARCH_RESULTS)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn request(
+        mode: LiveStreamMode,
+        quality: Option<&str>,
+        line: Option<&str>,
+    ) -> GetLiveStreamRequest {
+        GetLiveStreamRequest {
+            platform: SupportedPlatform::Custom("demo".into()),
+            room_id: "42".into(),
+            quality: quality.map(Into::into),
+            line: line.map(Into::into),
+            cookie: None,
+            debug: None,
+            mode: Some(mode),
+            with_options: None,
+            codec: None,
+            probe: None,
+            audio_only: None,
+        }
+    }
+
+    fn room(value: Value) -> PluginRoom {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn validates_plugin_rooms() {
+        let raw = || {
+            room(json!({
+                "status": "live",
+                "title": "  hello  ",
+                "avatar": "javascript:alert(1)",
+                "streams": [
+                    { "url": "file:///etc/passwd", "quality": "原画" },
+                    { "url": "https://cdn.example.com/a.flv", "quality": "高清", "line": "a" },
+                    { "url": "https://cdn.example.com/b/index", "quality": "原画", "line": "b", "type": "hls" },
+                    { "url": "https://cdn.example.com/c.flv", "quality": "原画", "line": "c", "headers": {
+                        "Referer": " https://example.com/ ",
+                        "X-Bad\r\nHost": "evil",
+                        "X-Split": "a\r\nHost: evil",
+                        "X-Number": 1
+                    } }
+                ],
+                "extra": { "ignored": true }
+            }))
+        };
+
+        let res = live_stream_response(
+            "demo",
+            &request(LiveStreamMode::Playback, Some("原画"), Some("c")),
+            "42".into(),
+            raw(),
+            false,
+        );
+        assert_eq!(res.status, LiveStatus::Live);
+        assert_eq!(res.room.platform.as_str(), "demo");
+        assert_eq!(res.room.title.as_deref(), Some("hello"));
+        assert!(res.room.avatar.is_none());
+        let playback = res.playback.unwrap();
+        assert_eq!(playback.url, "https://cdn.example.com/c.flv");
+        assert_eq!(playback.stream_type, StreamType::Flv);
+        assert!(playback.variants.is_none());
+        let headers: Vec<(&str, &str)> = playback
+            .headers
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(headers, [("Referer", "https://example.com/")]);
+
+        // Unknown line falls back to the quality, then to the first stream
+        let res = live_stream_response(
+            "demo",
+            &request(LiveStreamMode::Playback, Some("原画"), Some("z")),
+            "42".into(),
+            raw(),
+            true,
+        );
+        let playback = res.playback.unwrap();
+        assert_eq!(playback.stream_type, StreamType::Hls);
+        assert_eq!(playback.variants.unwrap().len(), 3);
+        let res = live_stream_response(
+            "demo",
+            &request(LiveStreamMode::Playback, Some("4K"), None),
+            "42".into(),
+            raw(),
+            false,
+        );
+        assert_eq!(res.playback.unwrap().url, "https://cdn.example.com/a.flv");
+
+        let res = live_stream_response(
+            "demo",
+            &request(LiveStreamMode::Meta, None, None),
+            "42".into(),
+            raw(),
+            false,
+        );
+        assert!(res.status == LiveStatus::Live && res.playback.is_none());
+
+        let res = live_stream_response(
+            "demo",
+            &request(LiveStreamMode::Playback, None, None),
+            "42".into(),
+            room(json!({ "status": "live", "streams": [{ "url": "rtmp://x/y" }] })),
+            false,
+        );
+        assert_eq!(res.status, LiveStatus::Error);
+        let res = live_stream_response(
+            "demo",
+            &request(LiveStreamMode::Playback, None, None),
+            "42".into(),
+            room(json!({ "status": "on air" })),
+            false,
+        );
+        assert_eq!(res.status, LiveStatus::Error);
+    }
+
+    #[test]
+    fn validates_search_items() {
+        let items = search_items(
+            "demo",
+            json!([
+                { "room_id": 42, "title": "t", "is_live": true },
+                { "room_id": " ", "title": "no id" },
+                { "title": "missing id" },
+                { "room_id": "abc", "cover": "https://img.example.com/c.jpg" }
+            ]),
+        )
+        .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].room_id, "42");
+        assert_eq!(
+            items[1].cover.as_deref(),
+            Some("https://img.example.com/c.jpg")
+        );
+        assert!(search_items("demo", json!({ "rooms": [] })).is_err());
+    }
+}
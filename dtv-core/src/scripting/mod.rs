@@ -0,0 +1,375 @@
+// User scripts on danmaku events. `*.js` files in a directory run in a
+// dedicated V8 isolate with no ops: they can filter or rewrite events, keep
+// state, and ask the host to highlight, notify or POST (allow-listed URLs
+// only). Files are re-read when they change.
+pub(crate) mod source;
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::sync::{mpsc, Mutex};
+
+use crate::danmaku::{event_kind, DanmakuHandler};
+use crate::platforms::common::js_function_worker::{JsFunctionWorker, JsWorkerOptions};
+use crate::platforms::common::types::SupportedPlatform;
+
+use source::{Fingerprint, ScriptConfig, ScriptSource};
+
+const PRELUDE: &str = include_str!("prelude.js");
+const USER_AGENT: &str = "dtv-scripts";
+// Minimum gap between directory scans
+const RESCAN_INTERVAL: Duration = Duration::from_secs(2);
+// Per-room events waiting for the scripts; beyond this events skip them
+const EVENT_BUFFER: usize = 1024;
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ScriptAction {
+    Highlight {
+        script: String,
+        text: String,
+        #[serde(default)]
+        extra: Value,
+    },
+    Notify {
+        script: String,
+        title: String,
+        body: String,
+    },
+    Post {
+        script: String,
+        url: String,
+        #[serde(default)]
+        body: Value,
+    },
+    Log {
+        script: String,
+        message: String,
+    },
+    Error {
+        script: String,
+        message: String,
+    },
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Reply {
+    #[serde(default)]
+    reload: bool,
+    #[serde(default)]
+    dropped: bool,
+    #[serde(default)]
+    event: Option<Value>,
+    #[serde(default)]
+    actions: Vec<ScriptAction>,
+}
+
+#[derive(Default)]
+struct Loaded {
+    generation: u64,
+    fingerprint: Fingerprint,
+    scripts: Vec<ScriptSource>,
+    config: ScriptConfig,
+    checked_at: Option<Instant>,
+}
+
+struct Inner {
+    dir: PathBuf,
+    worker: JsFunctionWorker,
+    // Also serializes evaluation so events keep their order
+    loaded: Mutex<Loaded>,
+    http: reqwest::Client,
+}
+
+/// Cheap to clone; clones share the isolate and script state.
+#[derive(Clone)]
+pub struct ScriptHost {
+    inner: Arc<Inner>,
+}
+
+impl ScriptHost {
+    /// Scripts are read from `dir` on first use. A missing directory just
+    /// means no scripts until one appears.
+    pub fn new(dir: impl Into<PathBuf>) -> Result<Self, String> {
+        let options = JsWorkerOptions {
+            pool_size: 1,
+            job_timeout: Duration::from_secs(1),
+            queue_timeout: Duration::from_secs(5),
+            max_heap_bytes: 64 * 1024 * 1024,
+            ..JsWorkerOptions::default()
+        };
+        let worker =
+            JsFunctionWorker::with_options(USER_AGENT, vec![("[dtv-scripts]", PRELUDE)], options)?;
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| format!("Failed to build script http client: {}", e))?;
+        Ok(Self {
+            inner: Arc::new(Inner {
+                dir: dir.into(),
+                worker,
+                loaded: Mutex::new(Loaded::default()),
+                http,
+            }),
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.inner.dir
+    }
+
+    /// Names of the scripts currently loaded, in run order.
+    pub async fn scripts(&self) -> Vec<String> {
+        let mut loaded = self.inner.loaded.lock().await;
+        self.rescan(&mut loaded, true);
+        loaded.scripts.iter().map(|s| s.name.clone()).collect()
+    }
+
+    /// Wrap a danmaku handler so events pass through the scripts before
+    /// reaching `inner`. Must be called inside a Tokio runtime.
+    pub fn danmaku_handler(
+        &self,
+        platform: SupportedPlatform,
+        room_id: String,
+        inner: Arc<dyn DanmakuHandler>,
+    ) -> Arc<dyn DanmakuHandler> {
+        let (tx, mut rx) = mpsc::channel::<(String, Value)>(EVENT_BUFFER);
+        let host = self.clone();
+        let room = room_id.clone();
+        tokio::spawn(async move {
+            // Ends when the listener drops its handler
+            while let Some((channel, payload)) = rx.recv().await {
+                host.process(&platform, &room, &channel, payload, &inner)
+                    .await;
+            }
+        });
+        Arc::new(ScriptedHandler { tx, room_id })
+    }
+
+    // Reloads when `force`d or when files changed since the last scan
+    fn rescan(&self, loaded: &mut Loaded, force: bool) {
+        let due = loaded
+            .checked_at
+            .is_none_or(|at| at.elapsed() >= RESCAN_INTERVAL);
+        if !force && !due {
+            return;
+        }
+        loaded.checked_at = Some(Instant::now());
+        let fingerprint = source::fingerprint(&self.inner.dir);
+        if loaded.generation > 0 && fingerprint == loaded.fingerprint {
+            return;
+        }
+        let (scripts, config) = source::load(&self.inner.dir);
+        if loaded.generation > 0 || !scripts.is_empty() {
+            eprintln!(
+                "[Scripts] Loaded {} scripts from {}",
+                scripts.len(),
+                self.inner.dir.display()
+            );
+        }
+        loaded.generation += 1;
+        loaded.fingerprint = fingerprint;
+        loaded.scripts = scripts;
+        loaded.config = config;
+    }
+
+    async fn eval(&self, expr: String) -> Result<Reply, String> {
+        let raw = self.inner.worker.eval_string(expr).await?;
+        serde_json::from_str(&raw).map_err(|e| format!("bad reply from scripts: {}", e))
+    }
+
+    // Push the current sources into the isolate (fresh or restarted)
+    async fn push_sources(&self, loaded: &Loaded) -> Result<Reply, String> {
+        let sources = serde_json::to_string(&loaded.scripts).map_err(|e| e.to_string())?;
+        self.eval(format!("__dtv.load({}, {})", loaded.generation, sources))
+            .await
+    }
+
+    async fn process(
+        &self,
+        platform: &SupportedPlatform,
+        room_id: &str,
+        channel: &str,
+        payload: Value,
+        inner: &Arc<dyn DanmakuHandler>,
+    ) {
+        let Some(kind) = event_kind(channel, &payload) else {
+            inner.emit_json(channel, payload);
+            return;
+        };
+
+        let mut loaded = self.inner.loaded.lock().await;
+        self.rescan(&mut loaded, false);
+        if loaded.scripts.is_empty() {
+            drop(loaded);
+            inner.emit_json(channel, payload);
+            return;
+        }
+
+        let event = json!({
+            "event": kind,
+            "channel": channel,
+            "platform": platform,
+            "room_id": room_id,
+            "data": &payload,
+        });
+        let expr = format!("__dtv.dispatch({}, {})", loaded.generation, event);
+        let mut reply = self.eval(expr.clone()).await;
+        if reply.as_ref().is_ok_and(|r| r.reload) {
+            reply = match self.push_sources(&loaded).await {
+                Ok(load) => {
+                    self.perform(
+                        platform,
+                        room_id,
+                        &kind,
+                        load.actions,
+                        &loaded.config,
+                        inner,
+                    );
+                    self.eval(expr).await
+                }
+                Err(e) => Err(e),
+            };
+        }
+        let config = loaded.config.clone();
+        drop(loaded);
+
+        match reply {
+            Ok(reply) if reply.reload => {
+                eprintln!(
+                    "[Scripts] Isolate did not take the scripts, passing {} through",
+                    kind
+                );
+                inner.emit_json(channel, payload);
+            }
+            Ok(reply) => {
+                let dropped = reply.dropped;
+                let data = reply
+                    .event
+                    .and_then(|mut e| e.get_mut("data").map(Value::take))
+                    .unwrap_or(payload);
+                self.perform(platform, room_id, &kind, reply.actions, &config, inner);
+                if !dropped {
+                    inner.emit_json(channel, data);
+                }
+            }
+            Err(e) => {
+                // Broken or slow scripts never swallow chat
+                eprintln!("[Scripts] {} failed: {}", kind, e);
+                inner.emit_json(channel, payload);
+            }
+        }
+    }
+
+    fn perform(
+        &self,
+        platform: &SupportedPlatform,
+        room_id: &str,
+        kind: &str,
+        actions: Vec<ScriptAction>,
+        config: &ScriptConfig,
+        inner: &Arc<dyn DanmakuHandler>,
+    ) {
+        for action in actions {
+            match action {
+                ScriptAction::Highlight {
+                    script,
+                    text,
+                    extra,
+                } => inner.emit_json(
+                    "danmaku-highlight",
+                    json!({
+                        "platform": platform,
+                        "room_id": room_id,
+                        "script": script,
+                        "event": kind,
+                        "text": text,
+                        "extra": extra,
+                    }),
+                ),
+                ScriptAction::Notify {
+                    script,
+                    title,
+                    body,
+                } => inner.emit_json(
+                    "danmaku-notify",
+                    json!({
+                        "platform": platform,
+                        "room_id": room_id,
+                        "script": script,
+                        "title": title,
+                        "body": body,
+                    }),
+                ),
+                ScriptAction::Post { script, url, body } => {
+                    if !config.allows(&url) {
+                        eprintln!("[Scripts] {}: post to {} is not allowed", script, url);
+                        continue;
+                    }
+                    let http = self.inner.http.clone();
+                    tokio::spawn(async move {
+                        let result = http
+                            .post(&url)
+                            .json(&body)
+                            .send()
+                            .await
+                            .and_then(|r| r.error_for_status());
+                        if let Err(e) = result {
+                            eprintln!("[Scripts] {}: post to {} failed: {}", script, url, e);
+                        }
+                    });
+                }
+                ScriptAction::Log { script, message } => {
+                    eprintln!("[Scripts] {}: {}", script, message)
+                }
+                ScriptAction::Error { script, message } => {
+                    eprintln!("[Scripts] {} error: {}", script, message)
+                }
+            }
+        }
+    }
+}
+
+// Queues events for the room's script task, which forwards them in order
+struct ScriptedHandler {
+    tx: mpsc::Sender<(String, Value)>,
+    room_id: String,
+}
+
+impl DanmakuHandler for ScriptedHandler {
+    fn emit_json(&self, event: &str, payload: Value) {
+        if let Err(e) = self.tx.try_send((event.to_string(), payload)) {
+            eprintln!(
+                "[Scripts] Room {} backlog full, dropping event: {}",
+                self.room_id, e
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_prelude_replies() {
+        let reply: Reply = serde_json::from_str(
+            r#"{"dropped":false,"event":{"event":"danmaku.gift","data":{"num":3}},"actions":[
+                {"type":"highlight","script":"a.js","text":"big","extra":null},
+                {"type":"post","script":"a.js","url":"https://x/","body":{"n":1}},
+                {"type":"error","script":"b.js","message":"boom"}]}"#,
+        )
+        .unwrap();
+        assert!(!reply.reload && !reply.dropped);
+        assert_eq!(reply.event.unwrap()["data"]["num"], 3);
+        assert!(matches!(&reply.actions[0], ScriptAction::Highlight { text, .. } if text == "big"));
+        assert!(matches!(&reply.actions[1], ScriptAction::Post { body, .. } if body["n"] == 1));
+        assert!(matches!(&reply.actions[2], ScriptAction::Error { .. }));
+
+        let reload: Reply = serde_json::from_str(r#"{"reload":true}"#).unwrap();
+        assert!(reload.reload);
+    }
+}
@@ -0,0 +1,155 @@
+// Host side of user scripts. Runs once per runtime, before any script.
+// Scripts are compiled with `new Function("dtv", "console", source)` and only
+// see the `dtv` object below; nothing here touches files or the network.
+(function () {
+  "use strict";
+
+  // deno_core's op bindings are the only way out of the isolate; refuse to
+  // run scripts at all if they cannot be hidden
+  try {
+    delete globalThis.Deno;
+  } catch (_) {}
+  if (typeof globalThis.Deno !== "undefined") {
+    throw new Error("cannot hide Deno from user scripts");
+  }
+
+  var generation = -1;
+  var scripts = [];
+  // Per-script state, kept across hot reloads while the runtime lives
+  var states = Object.create(null);
+  var actions = [];
+
+  function text(v) {
+    if (typeof v === "string") return v;
+    try {
+      return JSON.stringify(v);
+    } catch (_) {
+      return String(v);
+    }
+  }
+
+  function errorText(e) {
+    return String((e && e.stack) || e);
+  }
+
+  function matches(filter, name) {
+    if (filter === "*") return true;
+    if (filter.charAt(filter.length - 1) === "*") {
+      return name.indexOf(filter.slice(0, -1)) === 0;
+    }
+    return filter === name;
+  }
+
+  function makeScript(name) {
+    var handlers = [];
+    var log = function () {
+      actions.push({
+        type: "log",
+        script: name,
+        message: Array.prototype.map.call(arguments, text).join(" "),
+      });
+    };
+    var api = Object.freeze({
+      state: states[name] || (states[name] = {}),
+      // dtv.on(fn) for every event, dtv.on("danmaku.gift", fn), dtv.on("danmaku.*", fn)
+      on: function (filter, fn) {
+        if (typeof filter === "function") {
+          fn = filter;
+          filter = "*";
+        }
+        if (typeof fn !== "function") {
+          throw new TypeError("dtv.on needs a handler function");
+        }
+        handlers.push({ filter: String(filter), fn: fn });
+      },
+      highlight: function (message, extra) {
+        actions.push({
+          type: "highlight",
+          script: name,
+          text: text(message),
+          extra: extra === undefined ? null : extra,
+        });
+      },
+      notify: function (title, body) {
+        actions.push({
+          type: "notify",
+          script: name,
+          title: text(title),
+          body: body == null ? "" : text(body),
+        });
+      },
+      // Sent by the host, and only to URLs allowed in scripts.json
+      post: function (url, body) {
+        actions.push({
+          type: "post",
+          script: name,
+          url: String(url),
+          body: body === undefined ? null : body,
+        });
+      },
+      log: log,
+    });
+    return {
+      name: name,
+      api: api,
+      console: Object.freeze({ log: log, info: log, warn: log, error: log }),
+      handlers: handlers,
+    };
+  }
+
+  globalThis.__dtv = Object.freeze({
+    // sources: [{ name, source }] in run order
+    load: function (gen, sources) {
+      var loaded = [];
+      var errors = [];
+      actions = [];
+      sources.forEach(function (s) {
+        var script = makeScript(s.name);
+        try {
+          new Function("dtv", "console", s.source)(script.api, script.console);
+          loaded.push(script);
+        } catch (e) {
+          errors.push({ type: "error", script: s.name, message: errorText(e) });
+        }
+      });
+      scripts = loaded;
+      generation = gen;
+      return JSON.stringify({ actions: actions.concat(errors) });
+    },
+
+    // Each matching handler may return null/false to drop the event, an
+    // object to replace it, or nothing to keep it (mutations included).
+    dispatch: function (gen, event) {
+      if (gen !== generation) return '{"reload":true}';
+      actions = [];
+      var current = event;
+      var dropped = false;
+      for (var i = 0; i < scripts.length && !dropped; i++) {
+        var script = scripts[i];
+        for (var j = 0; j < script.handlers.length; j++) {
+          var handler = script.handlers[j];
+          if (!matches(handler.filter, current.event)) continue;
+          try {
+            var result = handler.fn(current);
+            if (result === null || result === false) {
+              dropped = true;
+              break;
+            }
+            if (result && typeof result === "object") {
+              // The channel it is re-emitted on never changes
+              result.event = current.event;
+              current = result;
+            }
+          } catch (e) {
+            actions.push({ type: "error", script: script.name, message: errorText(e) });
+          }
+        }
+      }
+      return JSON.stringify({
+        dropped: dropped,
+        event: dropped ? null : current,
+        actions: actions,
+      });
+    },
+  });
+})();
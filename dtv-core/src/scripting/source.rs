@@ -0,0 +1,130 @@
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+
+pub(crate) const CONFIG_FILE: &str = "scripts.json";
+
+/// `scripts.json` next to the scripts.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct ScriptConfig {
+    // URL prefixes `dtv.post` may reach; empty denies every post
+    #[serde(default)]
+    pub allow_urls: Vec<String>,
+}
+
+impl ScriptConfig {
+    pub(crate) fn allows(&self, url: &str) -> bool {
+        (url.starts_with("https://") || url.starts_with("http://"))
+            && self
+                .allow_urls
+                .iter()
+                .any(|prefix| !prefix.is_empty() && url.starts_with(prefix.as_str()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ScriptSource {
+    pub name: String,
+    pub source: String,
+}
+
+/// What was on disk at the last scan; any difference triggers a reload.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct Fingerprint(Vec<(String, Option<SystemTime>, u64)>);
+
+// `*.js` files directly in `dir` plus the config, sorted by name
+fn entries(dir: &Path) -> Vec<PathBuf> {
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = read_dir
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.is_file()
+                && (p.extension().is_some_and(|ext| ext == "js")
+                    || p.file_name().is_some_and(|n| n == CONFIG_FILE))
+        })
+        .collect();
+    paths.sort();
+    paths
+}
+
+pub(crate) fn fingerprint(dir: &Path) -> Fingerprint {
+    Fingerprint(
+        entries(dir)
+            .into_iter()
+            .map(|p| {
+                let meta = std::fs::metadata(&p).ok();
+                (
+                    p.to_string_lossy().into_owned(),
+                    meta.as_ref().and_then(|m| m.modified().ok()),
+                    meta.map(|m| m.len()).unwrap_or(0),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Scripts in run order (by file name) and the config. Unreadable files are
+/// skipped with a log line so one bad file does not stop the rest.
+pub(crate) fn load(dir: &Path) -> (Vec<ScriptSource>, ScriptConfig) {
+    let mut scripts = Vec::new();
+    let mut config = ScriptConfig::default();
+    for path in entries(dir) {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let text = match std::fs::read_to_string(&path) {
+            Ok(t) => t,
+            Err(e) => {
+                eprintln!("[Scripts] Failed to read {}: {}", path.display(), e);
+                continue;
+            }
+        };
+        if name == CONFIG_FILE {
+            match serde_json::from_str(&text) {
+                Ok(c) => config = c,
+                Err(e) => eprintln!("[Scripts] Ignoring invalid {}: {}", path.display(), e),
+            }
+        } else {
+            scripts.push(ScriptSource { name, source: text });
+        }
+    }
+    (scripts, config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_scripts_in_name_order_and_tracks_changes() {
+        let dir = std::env::temp_dir().join(format!("dtv-scripts-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("b.js"), "dtv.on(() => {});").unwrap();
+        std::fs::write(dir.join("a.js"), "// first").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+        std::fs::write(
+            dir.join(CONFIG_FILE),
+            r#"{ "allow_urls": ["https://hooks.example.com/"] }"#,
+        )
+        .unwrap();
+
+        let before = fingerprint(&dir);
+        let (scripts, config) = load(&dir);
+        let names: Vec<_> = scripts.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["a.js", "b.js"]);
+        assert!(config.allows("https://hooks.example.com/chat"));
+        assert!(!config.allows("https://hooks.example.com.evil/"));
+        assert!(!config.allows("file:///etc/passwd"));
+
+        assert_eq!(before, fingerprint(&dir));
+        std::fs::write(dir.join("a.js"), "// first, edited").unwrap();
+        assert_ne!(before, fingerprint(&dir));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
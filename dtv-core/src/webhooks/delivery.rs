@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use crate::platforms::common::signing::hash::hmac_sha256_hex;
+
+use super::WebhookConfig;
+
+// Retry delays double from the configured base up to this
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// One POST of `body` to the hook. 2xx is success; anything else is an error.
+pub(crate) async fn send_once(
+    client: &reqwest::Client,
+    hook: &WebhookConfig,
+    event: &str,
+    delivery_id: &str,
+    body: &str,
+) -> Result<(), String> {
+    let mut req = client
+        .post(&hook.url)
+        .header("Content-Type", "application/json")
+        .header("X-DTV-Event", event)
+        .header("X-DTV-Delivery", delivery_id);
+    if let Some(secret) = hook.secret.as_deref().filter(|s| !s.is_empty()) {
+        let signature = hmac_sha256_hex(secret.as_bytes(), body.as_bytes());
+        req = req.header("X-DTV-Signature-256", format!("sha256={}", signature));
+    }
+    for (name, value) in &hook.headers {
+        req = req.header(name.as_str(), value.as_str());
+    }
+
+    let resp = req
+        .body(body.to_string())
+        .send()
+        .await
+        .map_err(|e| format!("request failed: {}", e))?;
+    let status = resp.status();
+    if status.is_success() {
+        Ok(())
+    } else {
+        let text = resp.text().await.unwrap_or_default();
+        let snippet: String = text.chars().take(200).collect();
+        Err(format!("HTTP {}: {}", status.as_u16(), snippet))
+    }
+}
+
+/// `send_once` plus up to `hook.max_retries` retries with exponential backoff.
+/// On failure returns the number of attempts made and the last error.
+pub(crate) async fn send_with_retries(
+    client: &reqwest::Client,
+    base_backoff: Duration,
+    hook: &WebhookConfig,
+    event: &str,
+    delivery_id: &str,
+    body: &str,
+) -> Result<(), (u32, String)> {
+    let mut attempt = 0;
+    let mut backoff = base_backoff;
+    loop {
+        attempt += 1;
+        match send_once(client, hook, event, delivery_id, body).await {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt > hook.max_retries => return Err((attempt, e)),
+            Err(e) => {
+                eprintln!(
+                    "[Webhooks] {} delivery {} attempt {} failed: {}",
+                    hook.id, delivery_id, attempt, e
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+        }
+    }
+}
@@ -0,0 +1,465 @@
+// Outbound webhooks: danmaku and live-status events POSTed to user URLs,
+// delivered in the background with retries and a persisted failure queue.
+// With no platform features there are no rooms to report on.
+#![cfg_attr(
+    not(any(
+        feature = "douyu",
+        feature = "huya",
+        feature = "bilibili",
+        feature = "douyin"
+    )),
+    allow(unused)
+)]
+mod delivery;
+mod queue;
+mod template;
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::{mpsc, Semaphore};
+
+use crate::danmaku::{event_kind, DanmakuHandler};
+use crate::platforms::common::live_stream_v2::{LiveStatus, LiveStreamResponse};
+use crate::platforms::common::types::SupportedPlatform;
+use crate::platforms::common::DanmakuFrontendPayload;
+
+pub use queue::FailedDelivery;
+pub use template::render as render_template;
+
+use queue::FailureQueue;
+
+// Events waiting for the worker; beyond this new events are dropped
+const EVENT_BUFFER: usize = 1024;
+// Deliveries in flight at once, across all hooks
+const MAX_CONCURRENT_DELIVERIES: usize = 8;
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_timeout_secs() -> u64 {
+    10
+}
+
+fn default_retry_backoff_ms() -> u64 {
+    1000
+}
+
+fn default_requeue_secs() -> u64 {
+    60
+}
+
+/// One webhook target.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    pub id: String,
+    pub url: String,
+    // Event names to send ("danmaku.gift", "live.*", "*"); empty sends everything
+    #[serde(default)]
+    pub events: Vec<String>,
+    // JSON body with `{{path}}` placeholders into the event; None posts the event as is
+    #[serde(default)]
+    pub template: Option<Value>,
+    // Signs the body as `X-DTV-Signature-256: sha256=<hmac hex>`
+    #[serde(default)]
+    pub secret: Option<String>,
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+}
+
+impl WebhookConfig {
+    pub fn new(id: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            url: url.into(),
+            events: Vec::new(),
+            template: None,
+            secret: None,
+            max_retries: default_max_retries(),
+            headers: HashMap::new(),
+        }
+    }
+
+    pub fn matches(&self, event: &str) -> bool {
+        self.events.is_empty()
+            || self
+                .events
+                .iter()
+                .any(|filter| match filter.strip_suffix('*') {
+                    Some(prefix) => event.starts_with(prefix),
+                    None => filter == event,
+                })
+    }
+}
+
+/// Contents of a webhooks JSON file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookSettings {
+    #[serde(default)]
+    pub hooks: Vec<WebhookConfig>,
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    // First retry delay; doubles per attempt up to a minute
+    #[serde(default = "default_retry_backoff_ms")]
+    pub retry_backoff_ms: u64,
+    // How often deliveries in the failure queue are tried again
+    #[serde(default = "default_requeue_secs")]
+    pub requeue_secs: u64,
+}
+
+impl Default for WebhookSettings {
+    fn default() -> Self {
+        Self {
+            hooks: Vec::new(),
+            timeout_secs: default_timeout_secs(),
+            retry_backoff_ms: default_retry_backoff_ms(),
+            requeue_secs: default_requeue_secs(),
+        }
+    }
+}
+
+impl WebhookSettings {
+    /// Read settings from `path`; a missing file means no hooks.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| format!("Failed to parse {}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("Failed to read {}: {}", path.display(), e)),
+        }
+    }
+}
+
+/// What gets posted (or fed to a hook's template).
+#[derive(Debug, Clone, Serialize)]
+pub struct HookEvent {
+    // "danmaku.message", "danmaku.gift", "live.started", ...
+    pub event: String,
+    pub platform: SupportedPlatform,
+    pub room_id: String,
+    // Unix millis
+    pub timestamp: i64,
+    pub data: Value,
+}
+
+impl HookEvent {
+    pub fn new(
+        event: impl Into<String>,
+        platform: SupportedPlatform,
+        room_id: impl Into<String>,
+        data: Value,
+    ) -> Self {
+        Self {
+            event: event.into(),
+            platform,
+            room_id: room_id.into(),
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            data,
+        }
+    }
+}
+
+struct Inner {
+    settings: WebhookSettings,
+    http: reqwest::Client,
+    queue: FailureQueue,
+    tx: mpsc::Sender<HookEvent>,
+    // Taken by the worker the first time an event arrives inside a runtime
+    rx: Mutex<Option<mpsc::Receiver<HookEvent>>>,
+    last_status: Mutex<HashMap<(SupportedPlatform, String), LiveStatus>>,
+    next_id: AtomicU64,
+}
+
+/// Cheap to clone; clones share the worker and the failure queue.
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    inner: Arc<Inner>,
+}
+
+impl WebhookDispatcher {
+    /// `queue_path` keeps failed deliveries across restarts; None keeps them in memory.
+    pub fn new(settings: WebhookSettings, queue_path: Option<PathBuf>) -> Result<Self, String> {
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(settings.timeout_secs.max(1)))
+            .build()
+            .map_err(|e| format!("Failed to build webhook client: {}", e))?;
+        let (tx, rx) = mpsc::channel(EVENT_BUFFER);
+        let dispatcher = Self {
+            inner: Arc::new(Inner {
+                settings,
+                http,
+                queue: FailureQueue::load(queue_path),
+                tx,
+                rx: Mutex::new(Some(rx)),
+                last_status: Mutex::new(HashMap::new()),
+                next_id: AtomicU64::new(0),
+            }),
+        };
+        // Inside a runtime, start now so a persisted queue is retried without
+        // waiting for the first event
+        dispatcher.ensure_worker();
+        Ok(dispatcher)
+    }
+
+    pub fn hooks(&self) -> &[WebhookConfig] {
+        &self.inner.settings.hooks
+    }
+
+    /// True when at least one hook wants `event`.
+    pub fn wants(&self, event: &str) -> bool {
+        self.inner.settings.hooks.iter().any(|h| h.matches(event))
+    }
+
+    /// Queue `event` for delivery. Never blocks; drops the event when the
+    /// backlog is full or no hook wants it.
+    pub fn dispatch(&self, event: HookEvent) {
+        if !self.wants(&event.event) {
+            return;
+        }
+        self.ensure_worker();
+        if let Err(e) = self.inner.tx.try_send(event) {
+            eprintln!("[Webhooks] Dropping event: {}", e);
+        }
+    }
+
+    /// Deliveries that ran out of retries and are waiting for the next requeue pass.
+    pub fn failed_deliveries(&self) -> Vec<FailedDelivery> {
+        self.inner.queue.snapshot()
+    }
+
+    /// Try every queued failure once now. Returns how many went through.
+    pub async fn retry_failed(&self) -> usize {
+        let mut delivered = 0;
+        for mut item in self.inner.queue.take_all() {
+            let Some(hook) = self
+                .inner
+                .settings
+                .hooks
+                .iter()
+                .find(|h| h.id == item.hook_id)
+            else {
+                eprintln!(
+                    "[Webhooks] Dropping delivery {} for removed hook {}",
+                    item.delivery_id, item.hook_id
+                );
+                continue;
+            };
+            match delivery::send_once(
+                &self.inner.http,
+                hook,
+                &item.event,
+                &item.delivery_id,
+                &item.body,
+            )
+            .await
+            {
+                Ok(()) => delivered += 1,
+                Err(e) => {
+                    item.attempts += 1;
+                    item.last_error = e;
+                    item.failed_at = chrono::Utc::now().timestamp_millis();
+                    self.inner.queue.push(item);
+                }
+            }
+        }
+        delivered
+    }
+
+    /// Wrap a danmaku handler so every event it sees is also offered to the hooks.
+    pub fn danmaku_handler(
+        &self,
+        platform: SupportedPlatform,
+        room_id: String,
+        inner: Arc<dyn DanmakuHandler>,
+    ) -> Arc<dyn DanmakuHandler> {
+        Arc::new(TeeHandler {
+            dispatcher: self.clone(),
+            platform,
+            room_id,
+            inner,
+        })
+    }
+
+    /// Feed a live-stream lookup into the status tracker. A room's first
+    /// lookup only records its status; later Live/Offline flips fire
+    /// `live.started` / `live.ended`. Errors are ignored.
+    pub fn observe_status(&self, response: &LiveStreamResponse) {
+        if response.status == LiveStatus::Error || self.inner.settings.hooks.is_empty() {
+            return;
+        }
+        let key = (
+            response.room.platform.clone(),
+            response.room.room_id.clone(),
+        );
+        let previous = match self.inner.last_status.lock() {
+            Ok(mut statuses) => statuses.insert(key.clone(), response.status),
+            Err(_) => return,
+        };
+        let event = match (previous, response.status) {
+            (Some(LiveStatus::Offline), LiveStatus::Live) => "live.started",
+            (Some(LiveStatus::Live), LiveStatus::Offline) => "live.ended",
+            _ => return,
+        };
+        let data = serde_json::to_value(&response.room).unwrap_or(Value::Null);
+        self.dispatch(HookEvent::new(event, key.0, key.1, data));
+    }
+
+    fn ensure_worker(&self) {
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let Some(rx) = self.inner.rx.lock().ok().and_then(|mut rx| rx.take()) else {
+            return;
+        };
+        handle.spawn(self.clone().worker(rx));
+    }
+
+    // Runs for the life of the runtime: fans events out to matching hooks
+    // and periodically retries the failure queue.
+    async fn worker(self, mut rx: mpsc::Receiver<HookEvent>) {
+        let permits = Arc::new(Semaphore::new(MAX_CONCURRENT_DELIVERIES));
+        let mut requeue =
+            tokio::time::interval(Duration::from_secs(self.inner.settings.requeue_secs.max(1)));
+        requeue.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                event = rx.recv() => {
+                    let Some(event) = event else { break };
+                    self.fan_out(&event, &permits).await;
+                }
+                _ = requeue.tick() => {
+                    let delivered = self.retry_failed().await;
+                    if delivered > 0 {
+                        eprintln!("[Webhooks] Redelivered {} queued deliveries", delivered);
+                    }
+                }
+            }
+        }
+    }
+
+    async fn fan_out(&self, event: &HookEvent, permits: &Arc<Semaphore>) {
+        let event_json = match serde_json::to_value(event) {
+            Ok(v) => v,
+            Err(e) => {
+                eprintln!("[Webhooks] Failed to serialize {}: {}", event.event, e);
+                return;
+            }
+        };
+        for hook in self
+            .inner
+            .settings
+            .hooks
+            .iter()
+            .filter(|h| h.matches(&event.event))
+        {
+            let body = match &hook.template {
+                Some(template) => render_template(template, &event_json),
+                None => event_json.clone(),
+            }
+            .to_string();
+            let delivery_id = format!(
+                "{}-{}",
+                event.timestamp,
+                self.inner.next_id.fetch_add(1, Ordering::Relaxed)
+            );
+            let Ok(permit) = permits.clone().acquire_owned().await else {
+                return;
+            };
+            let dispatcher = self.clone();
+            let hook = hook.clone();
+            let name = event.event.clone();
+            tokio::spawn(async move {
+                let backoff = Duration::from_millis(dispatcher.inner.settings.retry_backoff_ms);
+                let result = delivery::send_with_retries(
+                    &dispatcher.inner.http,
+                    backoff,
+                    &hook,
+                    &name,
+                    &delivery_id,
+                    &body,
+                )
+                .await;
+                drop(permit);
+                if let Err((attempts, last_error)) = result {
+                    eprintln!(
+                        "[Webhooks] {} delivery {} queued after {} attempts: {}",
+                        hook.id, delivery_id, attempts, last_error
+                    );
+                    dispatcher.inner.queue.push(FailedDelivery {
+                        delivery_id,
+                        hook_id: hook.id,
+                        event: name,
+                        body,
+                        attempts,
+                        last_error,
+                        failed_at: chrono::Utc::now().timestamp_millis(),
+                    });
+                }
+            });
+        }
+    }
+}
+
+// Passes everything through to `inner` and copies wanted events to the dispatcher
+struct TeeHandler {
+    dispatcher: WebhookDispatcher,
+    platform: SupportedPlatform,
+    room_id: String,
+    inner: Arc<dyn DanmakuHandler>,
+}
+
+impl TeeHandler {
+    fn offer(&self, channel: &str, payload: &Value) {
+        let Some(name) = event_kind(channel, payload) else {
+            return;
+        };
+        if self.dispatcher.wants(&name) {
+            self.dispatcher.dispatch(HookEvent::new(
+                name,
+                self.platform.clone(),
+                self.room_id.clone(),
+                payload.clone(),
+            ));
+        }
+    }
+}
+
+impl DanmakuHandler for TeeHandler {
+    fn emit_json(&self, event: &str, payload: Value) {
+        self.offer(event, &payload);
+        self.inner.emit_json(event, payload);
+    }
+
+    fn on_danmaku(&self, payload: DanmakuFrontendPayload) {
+        if self.dispatcher.wants("danmaku.message") {
+            if let Ok(v) = serde_json::to_value(&payload) {
+                self.offer("danmaku-message", &v);
+            }
+        }
+        self.inner.on_danmaku(payload);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_filters() {
+        let mut hook = WebhookConfig::new("h", "http://127.0.0.1/");
+        assert!(hook.matches("danmaku.gift"));
+
+        hook.events = vec!["live.*".into(), "danmaku.super_chat".into()];
+        assert!(hook.matches("live.started"));
+        assert!(hook.matches("danmaku.super_chat"));
+        assert!(!hook.matches("danmaku.gift"));
+    }
+}
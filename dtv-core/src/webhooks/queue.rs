@@ -0,0 +1,105 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+// Oldest entries are dropped past this many
+const MAX_QUEUED: usize = 1000;
+
+/// A delivery that exhausted its retries; kept until a later retry succeeds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedDelivery {
+    pub delivery_id: String,
+    pub hook_id: String,
+    pub event: String,
+    // Rendered request body, re-sent as is
+    pub body: String,
+    pub attempts: u32,
+    pub last_error: String,
+    // Unix millis of the last failed attempt
+    pub failed_at: i64,
+}
+
+/// Failed deliveries, mirrored to a JSON file when a path is configured.
+pub(crate) struct FailureQueue {
+    path: Option<PathBuf>,
+    items: Mutex<VecDeque<FailedDelivery>>,
+}
+
+impl FailureQueue {
+    pub(crate) fn load(path: Option<PathBuf>) -> Self {
+        let items = path
+            .as_ref()
+            .and_then(|p| std::fs::read(p).ok())
+            .and_then(
+                |bytes| match serde_json::from_slice::<VecDeque<FailedDelivery>>(&bytes) {
+                    Ok(items) => Some(items),
+                    Err(e) => {
+                        eprintln!("[Webhooks] Ignoring unreadable failure queue: {}", e);
+                        None
+                    }
+                },
+            )
+            .unwrap_or_default();
+        Self {
+            path,
+            items: Mutex::new(items),
+        }
+    }
+
+    pub(crate) fn push(&self, item: FailedDelivery) {
+        let Ok(mut items) = self.items.lock() else {
+            return;
+        };
+        if items.len() >= MAX_QUEUED {
+            if let Some(dropped) = items.pop_front() {
+                eprintln!(
+                    "[Webhooks] Failure queue full, dropping delivery {} ({})",
+                    dropped.delivery_id, dropped.event
+                );
+            }
+        }
+        items.push_back(item);
+        self.persist(&items);
+    }
+
+    /// Remove everything for a retry pass; failures go back through `push`.
+    pub(crate) fn take_all(&self) -> Vec<FailedDelivery> {
+        let Ok(mut items) = self.items.lock() else {
+            return Vec::new();
+        };
+        let taken: Vec<_> = items.drain(..).collect();
+        if !taken.is_empty() {
+            self.persist(&items);
+        }
+        taken
+    }
+
+    pub(crate) fn snapshot(&self) -> Vec<FailedDelivery> {
+        self.items
+            .lock()
+            .map(|items| items.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // Write-then-rename so a crash never leaves a truncated file
+    fn persist(&self, items: &VecDeque<FailedDelivery>) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let result = serde_json::to_vec_pretty(items)
+            .map_err(|e| e.to_string())
+            .and_then(|json| {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+                }
+                let tmp = path.with_extension("json.tmp");
+                std::fs::write(&tmp, json).map_err(|e| e.to_string())?;
+                std::fs::rename(&tmp, path).map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            eprintln!("[Webhooks] Failed to persist {}: {}", path.display(), e);
+        }
+    }
+}
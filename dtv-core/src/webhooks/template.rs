@@ -0,0 +1,97 @@
+use serde_json::Value;
+
+// Look up a dotted path ("data.user", "room.title") in the event JSON
+fn lookup<'a>(root: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .filter(|s| !s.is_empty())
+        .try_fold(root, |v, key| match v {
+            Value::Object(map) => map.get(key),
+            Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
+
+fn as_text(v: &Value) -> String {
+    match v {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn interpolate(s: &str, event: &Value) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let path = rest[start + 2..start + 2 + len].trim();
+        out.push_str(&lookup(event, path).map(as_text).unwrap_or_default());
+        rest = &rest[start + 2 + len + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Fill `{{path}}` placeholders in every string of `template` from `event`.
+/// A string that is exactly one placeholder takes the value with its JSON
+/// type (numbers stay numbers); otherwise values are spliced in as text and
+/// missing paths become empty.
+pub fn render(template: &Value, event: &Value) -> Value {
+    match template {
+        Value::String(s) => {
+            let trimmed = s.trim();
+            if let Some(path) = trimmed
+                .strip_prefix("{{")
+                .and_then(|t| t.strip_suffix("}}"))
+                .filter(|p| !p.contains("{{") && !p.contains("}}"))
+            {
+                return lookup(event, path.trim()).cloned().unwrap_or(Value::Null);
+            }
+            Value::String(interpolate(s, event))
+        }
+        Value::Array(items) => Value::Array(items.iter().map(|v| render(v, event)).collect()),
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), render(v, event)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn renders_text_and_typed_placeholders() {
+        let event = json!({
+            "event": "danmaku.super_chat",
+            "room_id": "6",
+            "data": { "user": "alice", "price": 30, "tags": ["a", "b"] }
+        });
+        let template = json!({
+            "text": "{{data.user}} paid ¥{{data.price}} in {{ room_id }}",
+            "amount": "{{data.price}}",
+            "first_tag": "{{data.tags.0}}",
+            "missing": "{{data.nope}}",
+            "unclosed": "x {{data.user",
+            "kept": 1
+        });
+        assert_eq!(
+            render(&template, &event),
+            json!({
+                "text": "alice paid ¥30 in 6",
+                "amount": 30,
+                "first_tag": "a",
+                "missing": null,
+                "unclosed": "x {{data.user",
+                "kept": 1
+            })
+        );
+    }
+}
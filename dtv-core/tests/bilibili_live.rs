@@ -81,6 +81,8 @@ async fn bilibili_7734200_stream_urls_live() {
         client,
         TARGET_ROOM_ID,
         "原画",
+        None,
+        dtv_core::platforms::common::CodecPreference::Avc,
         None
     ).await.expect("get_bilibili_stream_url");
 
@@ -0,0 +1,152 @@
+use dtv_core::platforms::cc::danmaku::{
+    chat_messages, decode_frame, encode_heartbeat, encode_join, encode_register, CcChat,
+};
+use dtv_core::platforms::cc::live_list::{parse_categories, parse_live_list};
+use dtv_core::platforms::cc::msgpack;
+use dtv_core::platforms::cc::room::{parse_room_page, select_stream, stream_options};
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = format!("{}/tests/fixtures/cc/{}", env!("CARGO_MANIFEST_DIR"), name);
+    std::fs::read(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+fn fixture_text(name: &str) -> String {
+    String::from_utf8(fixture(name)).unwrap()
+}
+
+#[test]
+fn parses_live_room_page() {
+    let room = parse_room_page("361433", &fixture_text("room_live.html")).unwrap();
+    assert!(room.is_live);
+    assert_eq!(room.channel_id, Some(4241234));
+    assert_eq!(room.gametype, Some(2033));
+    assert_eq!(room.title.as_deref(), Some("深夜上分局"));
+    assert_eq!(room.anchor_name.as_deref(), Some("阿北"));
+    assert!(room.hls_url.as_deref().unwrap().ends_with(".m3u8"));
+
+    // Best quality first, every line of a quality together
+    let keys: Vec<(&str, &str)> = room
+        .streams
+        .iter()
+        .map(|s| (s.quality.as_str(), s.line.as_str()))
+        .collect();
+    assert_eq!(
+        keys,
+        [
+            ("蓝光", "ali"),
+            ("蓝光", "ks"),
+            ("高清", "ali"),
+            ("高清", "ks"),
+            ("标清", "ali")
+        ]
+    );
+
+    let best = select_stream(&room.streams, None, None).unwrap();
+    assert!(best.url.contains("_bd.flv"));
+    assert_eq!(best.vbr, Some(6000));
+    let hd_ks = select_stream(&room.streams, Some("高清"), Some("ks")).unwrap();
+    assert!(hd_ks.url.starts_with("https://ks.") && hd_ks.url.contains("_hd.flv"));
+    // Unknown line and quality fall back
+    let fallback = select_stream(&room.streams, Some("4K"), Some("nope")).unwrap();
+    assert_eq!(
+        (fallback.quality_key.as_str(), fallback.line.as_str()),
+        ("blueray", "ali")
+    );
+
+    // Option ids select the same streams
+    let options = stream_options(&room.streams);
+    let qualities: Vec<&str> = options.qualities.iter().map(|q| q.id.as_str()).collect();
+    let lines: Vec<&str> = options.lines.iter().map(|l| l.id.as_str()).collect();
+    assert_eq!(qualities.len(), 3);
+    assert_eq!(qualities[0], "blueray");
+    assert_eq!(options.qualities[0].bitrate, Some(6000));
+    assert_eq!(lines, ["ali", "ks"]);
+    let by_id = select_stream(&room.streams, Some(qualities[1]), Some("ks")).unwrap();
+    assert_eq!(by_id.url, hd_ks.url);
+}
+
+#[test]
+fn parses_offline_and_missing_rooms() {
+    let room = parse_room_page("361433", &fixture_text("room_offline.html")).unwrap();
+    assert!(!room.is_live);
+    assert!(room.streams.is_empty() && room.hls_url.is_none());
+    assert_eq!(
+        room.avatar.as_deref(),
+        Some("https://cc.fp.ps.netease.com/file/avatar.png")
+    );
+
+    assert!(parse_room_page("1", &fixture_text("room_missing.html")).is_err());
+    assert!(parse_room_page("1", "<html>blocked</html>").is_err());
+}
+
+#[test]
+fn parses_categories_and_live_list() {
+    let group = parse_categories(&fixture_text("categories.json"), 1).unwrap();
+    assert_eq!(group.group_name, "网游");
+    let names: Vec<(&str, &str)> = group
+        .categories
+        .iter()
+        .map(|c| (c.id.as_str(), c.name.as_str()))
+        .collect();
+    assert_eq!(names, [("2033", "英雄联盟"), ("1005", "梦幻西游")]);
+    assert_eq!(group.categories[0].parent_id.as_deref(), Some("1"));
+    assert!(parse_categories(r#"{"code":"ERR"}"#, 1).is_err());
+
+    let list = parse_live_list(&fixture_text("live_list.json"), 20).unwrap();
+    assert!(!list.has_more);
+    assert_eq!(list.rooms.len(), 2);
+    assert_eq!(list.rooms[0].room.room_id, "361433");
+    assert_eq!(list.rooms[0].viewers, 12345);
+    assert_eq!(list.rooms[0].category.as_deref(), Some("英雄联盟"));
+    assert_eq!(list.rooms[1].room.room_id, "200100");
+    assert!(list.rooms[1].cover.is_some() && list.rooms[1].room.avatar.is_none());
+    assert!(
+        parse_live_list(&fixture_text("live_list.json"), 2)
+            .unwrap()
+            .has_more
+    );
+}
+
+#[test]
+fn decodes_chat_frames() {
+    let expected = [
+        CcChat {
+            user: "观众甲".into(),
+            content: "晚上好".into(),
+        },
+        CcChat {
+            user: "观众乙".into(),
+            content: "来了".into(),
+        },
+    ];
+    for name in ["chat.bin", "chat_zlib.bin"] {
+        let frame = decode_frame(&fixture(name)).unwrap();
+        assert_eq!((frame.sid, frame.cid), (512, 32784), "{}", name);
+        assert_eq!(chat_messages(&frame), expected, "{}", name);
+    }
+    assert!(decode_frame(&[0, 2]).is_err());
+}
+
+#[test]
+fn encodes_client_frames() {
+    let join = decode_frame(&encode_join(4241234, 2033, 361433)).unwrap();
+    assert_eq!((join.sid, join.cid), (512, 1));
+    assert_eq!(
+        join.body.get("cid").and_then(msgpack::Value::as_i64),
+        Some(4241234)
+    );
+    assert_eq!(
+        join.body.get("roomId").and_then(msgpack::Value::as_i64),
+        Some(361433)
+    );
+    // Not a chat frame
+    assert!(chat_messages(&join).is_empty());
+
+    let register = decode_frame(&encode_register(1_700_000_000_000, "dev")).unwrap();
+    assert_eq!((register.sid, register.cid), (6144, 2));
+    assert_eq!(
+        register.body.get("web-cc").and_then(msgpack::Value::as_i64),
+        Some(1_700_000_000_000_000)
+    );
+    assert_eq!(&encode_heartbeat()[..4], &[0x00, 0x18, 0x05, 0x00]);
+}
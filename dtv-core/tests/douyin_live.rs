@@ -29,6 +29,8 @@ async fn douyin_room_info_live() {
     let info = dtv_core::platforms::douyin::douyin_streamer_info::fetch_douyin_streamer_info(
         payload,
         &follow_http,
+        dtv_core::platforms::douyin::signed_url::global_builder(),
+        None,
     )
     .await
     .expect("fetch_douyin_streamer_info");
@@ -1,6 +1,6 @@
 mod common;
 
-use dtv_core::platforms::common::FollowHttpClient;
+use dtv_core::platforms::common::{CodecPreference, FollowHttpClient};
 use tracing::debug;
 use common::{live_enabled, TestHandler};
 
@@ -57,8 +57,15 @@ async fn douyu_74960_stream_urls_live() {
     let qualities = ["原画", "高清", "标清"];
     let mut ok_count = 0usize;
     for q in qualities {
-        match dtv_core::platforms::douyu::stream_url::get_stream_url_with_quality("74960", q, None)
-            .await
+        match dtv_core::platforms::douyu::stream_url::get_stream_url_with_quality(
+            &follow_http.0.inner,
+            None,
+            "74960",
+            q,
+            None,
+            CodecPreference::Avc,
+        )
+        .await
         {
             Ok(url) => {
                 assert!(!url.trim().is_empty());
@@ -1,6 +1,6 @@
 mod common;
 
-use dtv_core::platforms::common::FollowHttpClient;
+use dtv_core::platforms::common::{CodecPreference, FollowHttpClient};
 use common::{live_enabled, TestHandler};
 use tracing::debug;
 
@@ -24,6 +24,7 @@ async fn huya_880201_unified_live() {
         TARGET_ROOM_ID.to_string(),
         Some("原画".to_string()),
         None,
+        CodecPreference::Avc,
         &follow_http,
     )
     .await
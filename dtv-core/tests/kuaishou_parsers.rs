@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use dtv_core::platforms::common::CodecPreference;
+use dtv_core::platforms::kuaishou::danmaku::{
+    decode_frame, encode_enter_room, encode_heartbeat, feed_items, FeedItem, KuaishouEvent,
+    ServerFrame,
+};
+use dtv_core::platforms::kuaishou::gen::{CsWebEnterRoom, PayloadType, SocketMessage};
+use dtv_core::platforms::kuaishou::room::{parse_room_page, select_stream, stream_options};
+use dtv_core::platforms::kuaishou::search::parse_search_response;
+use prost::Message;
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = format!(
+        "{}/tests/fixtures/kuaishou/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    std::fs::read(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+fn fixture_text(name: &str) -> String {
+    String::from_utf8(fixture(name)).unwrap()
+}
+
+#[test]
+fn parses_live_room_page() {
+    let room = parse_room_page("3xk7dq2m9abc", &fixture_text("room_live.html")).unwrap();
+    assert!(room.is_live);
+    assert_eq!(room.live_stream_id.as_deref(), Some("Zq9kX2bTmHc"));
+    assert_eq!(room.title.as_deref(), Some("周末夜谈"));
+    assert_eq!(room.anchor_name.as_deref(), Some("夜猫子小北"));
+    assert!(room.cover.is_some() && room.avatar.is_some());
+
+    // Renditions best first, H.264 before HEVC, then the HLS playlist
+    let names: Vec<&str> = room.streams.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["原画", "原画", "超清", "高清", "自适应"]);
+    let codecs: Vec<Option<&str>> = room.streams.iter().map(|s| s.codec.as_deref()).collect();
+    assert_eq!(
+        codecs,
+        [Some("avc"), Some("hevc"), Some("avc"), Some("avc"), None]
+    );
+    assert_eq!(room.streams[4].format, "hls");
+
+    let best = select_stream(&room.streams, Some("原画"), CodecPreference::Avc).unwrap();
+    assert!(best.url.contains("_bluray.flv"));
+    assert_eq!(best.bitrate, Some(8000));
+    assert!(
+        select_stream(&room.streams, Some("高清"), CodecPreference::Avc)
+            .unwrap()
+            .url
+            .contains("_hd2000.flv")
+    );
+    let hevc = select_stream(&room.streams, None, CodecPreference::Hevc).unwrap();
+    assert!(hevc.url.contains("_hevc.flv"));
+    // Qualities without an HEVC rendition stay on H.264
+    assert!(
+        select_stream(&room.streams, Some("超清"), CodecPreference::Hevc)
+            .unwrap()
+            .url
+            .contains("_hd4000.flv")
+    );
+    assert_eq!(
+        select_stream(&room.streams, None, CodecPreference::Auto)
+            .unwrap()
+            .codec
+            .as_deref(),
+        Some("avc")
+    );
+
+    let options = stream_options(&room.streams);
+    let ids: Vec<&str> = options.qualities.iter().map(|q| q.id.as_str()).collect();
+    assert_eq!(ids, ["原画", "超清", "高清", "自适应"]);
+    assert_eq!(options.qualities[0].bitrate, Some(8000));
+    assert!(options.lines.is_empty());
+}
+
+#[test]
+fn parses_offline_and_missing_rooms() {
+    let room = parse_room_page("3xk7dq2m9abc", &fixture_text("room_offline.html")).unwrap();
+    assert!(!room.is_live);
+    assert!(room.live_stream_id.is_none() && room.streams.is_empty());
+    assert_eq!(room.anchor_name.as_deref(), Some("夜猫子小北"));
+
+    let err = parse_room_page("nobody", &fixture_text("room_missing.html")).unwrap_err();
+    assert!(err.to_string().contains("用户不存在"), "{}", err);
+    assert!(parse_room_page("x", "<html>captcha</html>").is_err());
+}
+
+#[test]
+fn parses_search_results() {
+    let items = parse_search_response(&fixture_text("search.json")).unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].room_id, "3xk7dq2m9abc");
+    assert!(items[0].live_status);
+    assert!(!items[1].live_status);
+
+    assert!(parse_search_response(r#"{"data":{"result":2}}"#).is_err());
+    assert!(parse_search_response(r#"{"data":{"result":1}}"#)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn decodes_feed_push() {
+    let ServerFrame::Feed(push) = decode_frame(&fixture("feed_push.bin")).unwrap() else {
+        panic!("expected a feed push");
+    };
+    let gift_names = HashMap::from([(1u32, "小心心".to_string())]);
+    let items = feed_items(&push, &gift_names);
+
+    let chats: Vec<(&str, &str)> = items
+        .iter()
+        .filter_map(|i| match i {
+            FeedItem::Chat { user, content } => Some((user.as_str(), content.as_str())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(chats, [("观众甲", "主播晚上好"), ("观众乙", "来了来了")]);
+
+    let events: Vec<&KuaishouEvent> = items
+        .iter()
+        .filter_map(|i| match i {
+            FeedItem::Event(e) => Some(e),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        events[0],
+        &KuaishouEvent::OnlineCount {
+            watching: "1.2万".into(),
+            likes: "3456".into()
+        }
+    );
+    assert!(events.contains(&&KuaishouEvent::Gift {
+        user_id: "u4".into(),
+        user: "观众丁".into(),
+        gift_id: 1,
+        gift: "小心心".into(),
+        count: 10,
+        combo: 3,
+    }));
+    // Unknown gifts fall back to their id, a missing batch size to one
+    assert!(events.contains(&&KuaishouEvent::Gift {
+        user_id: "u2".into(),
+        user: "观众乙".into(),
+        gift_id: 9999,
+        gift: "礼物9999".into(),
+        count: 1,
+        combo: 0,
+    }));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, KuaishouEvent::Like { user, .. } if user == "观众丙")));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, KuaishouEvent::Share { user, .. } if user == "观众戊")));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, KuaishouEvent::SystemNotice { content } if content.contains("文明"))));
+
+    let json = serde_json::to_value(events[0]).unwrap();
+    assert_eq!(json["type"], "online_count");
+}
+
+#[test]
+fn decodes_errors_and_encodes_requests() {
+    assert_eq!(
+        decode_frame(&fixture("error.bin")).unwrap(),
+        ServerFrame::Error {
+            code: 1,
+            message: "token expired".into()
+        }
+    );
+    assert!(decode_frame(&[0xff, 0xff]).is_err());
+
+    let enter = SocketMessage::decode(encode_enter_room("tok", "Zq9kX2bTmHc", "page_1").as_slice())
+        .unwrap();
+    assert_eq!(enter.payload_type, PayloadType::CsEnterRoom as i32);
+    let body = CsWebEnterRoom::decode(enter.payload.as_slice()).unwrap();
+    assert_eq!(
+        (
+            body.token.as_str(),
+            body.live_stream_id.as_str(),
+            body.page_id.as_str()
+        ),
+        ("tok", "Zq9kX2bTmHc", "page_1")
+    );
+
+    let heartbeat = SocketMessage::decode(encode_heartbeat(1_700_000_000_000).as_slice()).unwrap();
+    assert_eq!(heartbeat.payload_type, PayloadType::CsHeartbeat as i32);
+    // Our own heartbeat decodes as an uninteresting frame
+    assert_eq!(
+        decode_frame(&encode_heartbeat(1)).unwrap(),
+        ServerFrame::Other(PayloadType::CsHeartbeat as i32)
+    );
+}
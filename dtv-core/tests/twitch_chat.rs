@@ -0,0 +1,115 @@
+// The chat listener against a local IRC-over-WebSocket stand-in; no network needed.
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use serde_json::Value;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio_tungstenite::{accept_async, tungstenite::Message};
+
+use dtv_core::danmaku::DanmakuHandler;
+use dtv_core::platforms::twitch::chat::run_twitch_chat;
+
+#[derive(Default)]
+struct Recorder(Mutex<Vec<(String, Value)>>);
+
+impl DanmakuHandler for Recorder {
+    fn emit_json(&self, event: &str, payload: Value) {
+        self.0.lock().unwrap().push((event.to_string(), payload));
+    }
+}
+
+const CHAT: &str = "@badge-info=subscriber/14;badges=subscriber/12;color=#1E90FF;display-name=ChatterOne;emotes=25:0-4;user-id=1 :chatterone!chatterone@chatterone.tmi.twitch.tv PRIVMSG #xqc :Kappa hi\r\n@badge-info=;badges=;bits=50;display-name=Cheerer;emotes=;user-id=2 :cheerer!cheerer@cheerer.tmi.twitch.tv PRIVMSG #xqc :Cheer50\r\n";
+
+// Accepts one client, checks its login, answers a PING round trip, sends
+// `CHAT`, then reports the client lines it saw and holds the socket open.
+async fn stand_in() -> (String, mpsc::UnboundedReceiver<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+        let mut seen = Vec::new();
+        while let Some(Ok(Message::Text(line))) = ws.next().await {
+            let join = line.starts_with("JOIN");
+            seen.push(line);
+            if join {
+                break;
+            }
+        }
+        ws.send(Message::Text(
+            ":tmi.twitch.tv 001 justinfan :Welcome, GLHF!\r\nPING :tmi.twitch.tv\r\n".into(),
+        ))
+        .await
+        .unwrap();
+        if let Some(Ok(Message::Text(pong))) = ws.next().await {
+            seen.push(pong);
+        }
+        ws.send(Message::Text(CHAT.into())).await.unwrap();
+        tx.send(seen).unwrap();
+        while ws.next().await.is_some() {}
+    });
+    (url, rx)
+}
+
+#[tokio::test]
+async fn joins_anonymously_and_emits_chat() {
+    let (url, mut seen_rx) = stand_in().await;
+    let recorder = Arc::new(Recorder::default());
+    let (shutdown_tx, shutdown_rx) = mpsc::channel(1);
+    let listener = tokio::spawn(run_twitch_chat(
+        url,
+        "https://www.twitch.tv/XQC".to_string(),
+        shutdown_rx,
+        recorder.clone(),
+    ));
+
+    let seen = tokio::time::timeout(Duration::from_secs(5), seen_rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(seen[0], "CAP REQ :twitch.tv/tags twitch.tv/commands");
+    assert!(seen.iter().any(|l| l.starts_with("NICK justinfan")));
+    assert!(seen.contains(&"JOIN #xqc".to_string()));
+    assert_eq!(seen.last().unwrap(), "PONG :tmi.twitch.tv");
+
+    // Chat + typed chat, then cheer chat line + typed chat + typed cheer
+    let deadline = tokio::time::Instant::now() + Duration::from_secs(5);
+    while recorder.0.lock().unwrap().len() < 5 {
+        assert!(
+            tokio::time::Instant::now() < deadline,
+            "timed out waiting for events"
+        );
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let events = recorder.0.lock().unwrap().clone();
+    let room = "danmaku-https://www.twitch.tv/XQC";
+
+    assert_eq!(events[0].0, "danmaku-message");
+    assert_eq!(events[0].1["user"], "ChatterOne");
+    assert_eq!(events[0].1["content"], "Kappa hi");
+    assert_eq!(events[0].1["fans_club_level"], 14);
+
+    assert_eq!(events[1].0, room);
+    assert_eq!(events[1].1["type"], "chat");
+    assert_eq!(events[1].1["emotes"][0]["name"], "Kappa");
+    assert_eq!(events[1].1["badges"][0]["name"], "subscriber");
+
+    let gift_lines: Vec<&Value> = events
+        .iter()
+        .filter(|(e, _)| e == "danmaku-message")
+        .map(|(_, v)| &v["content"])
+        .collect();
+    assert!(gift_lines.contains(&&Value::from("[礼物] Bits x50")));
+    assert!(events
+        .iter()
+        .any(|(e, v)| e == room && v["type"] == "cheer" && v["bits"] == 50));
+
+    shutdown_tx.send(()).await.unwrap();
+    tokio::time::timeout(Duration::from_secs(5), listener)
+        .await
+        .expect("listener did not stop")
+        .unwrap();
+}
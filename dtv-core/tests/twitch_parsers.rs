@@ -0,0 +1,256 @@
+use dtv_core::platforms::common::CodecPreference;
+use dtv_core::platforms::twitch::chat::{
+    message_events, parse_irc_line, Badge, Emote, IrcMessage, TwitchEvent,
+};
+use dtv_core::platforms::twitch::gql::{parse_channel, parse_playback_token};
+use dtv_core::platforms::twitch::playlist::{
+    parse_master_playlist, select_rendition, stream_options, usher_url,
+};
+use dtv_core::platforms::twitch::search::parse_search_response;
+
+fn fixture(name: &str) -> String {
+    let path = format!(
+        "{}/tests/fixtures/twitch/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        name
+    );
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+fn chat_lines() -> Vec<IrcMessage> {
+    fixture("chat.irc")
+        .split("\r\n")
+        .filter(|l| !l.is_empty())
+        .map(|l| parse_irc_line(l).unwrap())
+        .collect()
+}
+
+#[test]
+fn parses_channel_metadata() {
+    let live = parse_channel(&fixture("channel_live.json")).unwrap();
+    assert!(live.is_live);
+    assert_eq!(
+        (live.login.as_str(), live.display_name.as_str()),
+        ("xqc", "xQc")
+    );
+    assert_eq!(live.title.as_deref(), Some("REACTING TO EVERYTHING"));
+    assert_eq!(live.category.as_deref(), Some("Just Chatting"));
+    assert_eq!(live.viewers, Some(53211));
+
+    let offline = parse_channel(&fixture("channel_offline.json")).unwrap();
+    assert!(!offline.is_live && offline.viewers.is_none());
+    // Category falls back to the channel's broadcast settings
+    assert_eq!(offline.category.as_deref(), Some("Just Chatting"));
+
+    assert!(
+        !parse_channel(&fixture("channel_rerun.json"))
+            .unwrap()
+            .is_live
+    );
+    let err = parse_channel(&fixture("channel_missing.json")).unwrap_err();
+    assert!(err.to_string().contains("does not exist"), "{}", err);
+}
+
+#[test]
+fn parses_playback_token_and_errors() {
+    let token = parse_playback_token(&fixture("token.json")).unwrap();
+    assert!(token.value.contains("\"channel\":\"xqc\""));
+    assert_eq!(token.signature, "4d2c8f3b6a0e9d1c7b5a3f2e1d0c9b8a7f6e5d4c");
+
+    let url = usher_url("xqc", &token, CodecPreference::Avc).unwrap();
+    assert!(url.starts_with("https://usher.ttvnw.net/api/channel/hls/xqc.m3u8?sig=4d2c8f"));
+    // The JSON token is percent-encoded into the query
+    assert!(url.contains("token=%7B%22adblock%22"));
+    assert!(url.ends_with("&supported_codecs=h264"));
+    let url = usher_url("xqc", &token, CodecPreference::Hevc).unwrap();
+    assert!(url.ends_with("&supported_codecs=h265%2Ch264"));
+    let url = usher_url("xqc", &token, CodecPreference::Auto).unwrap();
+    assert!(!url.contains("supported_codecs"));
+
+    let err = parse_playback_token(&fixture("gql_error.json")).unwrap_err();
+    assert!(err.to_string().contains("service timeout"), "{}", err);
+}
+
+#[test]
+fn parses_usher_playlist() {
+    let renditions = parse_master_playlist(&fixture("usher_master.m3u8")).unwrap();
+    let names: Vec<&str> = renditions.iter().map(|r| r.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["1080p60 (source)", "720p60", "480p", "160p", "audio_only"]
+    );
+    let source = &renditions[0];
+    assert_eq!(source.group_id, "chunked");
+    assert_eq!(source.bandwidth, Some(8354286));
+    assert_eq!(source.resolution.as_deref(), Some("1920x1080"));
+    assert_eq!(source.frame_rate, Some(60.0));
+    // Quoted attribute values keep their commas
+    assert_eq!(source.codecs.as_deref(), Some("avc1.64002A,mp4a.40.2"));
+    assert!(renditions[4].is_audio_only() && renditions[4].resolution.is_none());
+
+    assert_eq!(source.video_codec(), Some("avc"));
+    let pick = |q: Option<&str>| {
+        select_rendition(&renditions, q, CodecPreference::Avc)
+            .unwrap()
+            .name
+            .clone()
+    };
+    assert_eq!(pick(None), "1080p60 (source)");
+    assert_eq!(pick(Some("原画")), "1080p60 (source)");
+    assert_eq!(pick(Some("720p")), "720p60");
+    assert_eq!(pick(Some("480p30")), "480p");
+    assert_eq!(pick(Some("audio_only")), "audio_only");
+    assert_eq!(pick(Some("4K")), "1080p60 (source)");
+
+    let options = stream_options(&renditions);
+    assert_eq!(options.qualities.len(), 5);
+    assert_eq!(options.qualities[0].id, "1080p60 (source)");
+    assert_eq!(options.qualities[0].bitrate, Some(8354));
+    assert_eq!(options.qualities[0].codec.as_deref(), Some("avc1.64002A"));
+    assert!(options.lines.is_empty());
+
+    // The same name in two codecs
+    let both = parse_master_playlist(
+        "#EXTM3U\n\
+         #EXT-X-MEDIA:TYPE=VIDEO,GROUP-ID=\"chunked\",NAME=\"1080p60\"\n\
+         #EXT-X-STREAM-INF:BANDWIDTH=6000000,CODECS=\"hev1.1.6.L150,mp4a.40.2\",VIDEO=\"chunked\"\n\
+         https://edge/hevc.m3u8\n\
+         #EXT-X-MEDIA:TYPE=VIDEO,GROUP-ID=\"chunked\",NAME=\"1080p60\"\n\
+         #EXT-X-STREAM-INF:BANDWIDTH=8000000,CODECS=\"avc1.64002A,mp4a.40.2\",VIDEO=\"chunked\"\n\
+         https://edge/avc.m3u8\n",
+    )
+    .unwrap();
+    let url = |codec| select_rendition(&both, None, codec).unwrap().url.as_str();
+    assert_eq!(url(CodecPreference::Avc), "https://edge/avc.m3u8");
+    assert_eq!(url(CodecPreference::Hevc), "https://edge/hevc.m3u8");
+    assert_eq!(url(CodecPreference::Auto), "https://edge/hevc.m3u8");
+    assert_eq!(stream_options(&both).qualities.len(), 1);
+
+    assert!(parse_master_playlist("<html>").is_err());
+    assert!(parse_master_playlist("#EXTM3U\n").unwrap().is_empty());
+}
+
+#[test]
+fn parses_search_results() {
+    let items = parse_search_response(&fixture("search.json")).unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0].room_id, "xqc");
+    assert!(items[0].live_status);
+    assert_eq!(items[0].viewers, 53211);
+    assert_eq!(items[0].category, "Just Chatting");
+    assert!(!items[1].live_status && items[1].category.is_empty());
+
+    assert!(parse_search_response(&fixture("gql_error.json")).is_err());
+}
+
+#[test]
+fn maps_chat_lines_to_events() {
+    let lines = chat_lines();
+    let events: Vec<TwitchEvent> = lines.iter().flat_map(message_events).collect();
+    assert_eq!(events.len(), 6);
+
+    assert_eq!(
+        events[0],
+        TwitchEvent::Chat {
+            user_id: "11111111".into(),
+            user: "ChatterOne".into(),
+            content: "Kappa Keepo Kappa".into(),
+            color: Some("#1E90FF".into()),
+            badges: vec![
+                Badge {
+                    name: "subscriber".into(),
+                    version: "12".into()
+                },
+                Badge {
+                    name: "premium".into(),
+                    version: "1".into()
+                },
+            ],
+            emotes: ["25:0:4:Kappa", "1902:6:10:Keepo", "25:12:16:Kappa"]
+                .iter()
+                .map(|e| {
+                    let p: Vec<&str> = e.split(':').collect();
+                    Emote {
+                        id: p[0].into(),
+                        name: p[3].into(),
+                        start: p[1].parse().unwrap(),
+                        end: p[2].parse().unwrap(),
+                        url: format!(
+                            "https://static-cdn.jtvnw.net/emoticons/v2/{}/default/dark/1.0",
+                            p[0]
+                        ),
+                    }
+                })
+                .collect(),
+        }
+    );
+
+    // Empty tags: no name, so the nick from the prefix is used
+    let TwitchEvent::Chat {
+        user,
+        color,
+        badges,
+        content,
+        ..
+    } = &events[1]
+    else {
+        panic!("expected chat, got {:?}", events[1]);
+    };
+    assert_eq!(
+        (user.as_str(), content.as_str()),
+        ("lurker", "hello 你好 LUL")
+    );
+    assert!(color.is_none() && badges.is_empty());
+
+    assert!(matches!(&events[2], TwitchEvent::Chat { user, .. } if user == "Cheerer"));
+    assert_eq!(
+        events[3],
+        TwitchEvent::Cheer {
+            user_id: "33333333".into(),
+            user: "Cheerer".into(),
+            bits: 100,
+            content: "Cheer100 great stream".into(),
+        }
+    );
+    assert_eq!(
+        events[4],
+        TwitchEvent::UserNotice {
+            kind: "resub".into(),
+            user_id: "44444444".into(),
+            user: "Resubber".into(),
+            system_message: "Resubber subscribed at Tier 1. They've subscribed for 6 months!"
+                .into(),
+            content: "six months already".into(),
+        }
+    );
+    let TwitchEvent::UserNotice {
+        kind,
+        content,
+        system_message,
+        ..
+    } = &events[5]
+    else {
+        panic!("expected a notice");
+    };
+    assert_eq!(kind, "raid");
+    assert!(content.is_empty() && system_message.starts_with("42 raiders"));
+
+    let json = serde_json::to_value(&events[3]).unwrap();
+    assert_eq!(json["type"], "cheer");
+}
+
+#[test]
+fn parses_irc_framing() {
+    let ping = parse_irc_line("PING :tmi.twitch.tv\r\n").unwrap();
+    assert_eq!(ping.command, "PING");
+    assert_eq!(ping.params, ["tmi.twitch.tv"]);
+    assert!(ping.prefix.is_none() && ping.tags.is_empty());
+
+    let lines = chat_lines();
+    assert_eq!(lines[1].command, "001");
+    assert_eq!(lines[1].params, ["justinfan12345", "Welcome, GLHF!"]);
+    assert_eq!(lines[3].tags["room-id"], "71092938");
+    // Escaped tag values are decoded
+    assert!(lines[7].tags["system-msg"].contains("subscribed at Tier 1."));
+    assert!(parse_irc_line("").is_none());
+}
@@ -0,0 +1,197 @@
+// Webhook delivery against a local HTTP stand-in; no network needed.
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+use dtv_core::danmaku::DanmakuHandler;
+use dtv_core::platforms::common::signing::hash::hmac_sha256_hex;
+use dtv_core::platforms::common::types::SupportedPlatform;
+use dtv_core::platforms::common::{
+    DanmakuFrontendPayload, LiveStatus, LiveStreamResponse, RoomMeta,
+};
+use dtv_core::webhooks::{WebhookConfig, WebhookDispatcher, WebhookSettings};
+
+struct Received {
+    headers: HashMap<String, String>,
+    body: Value,
+}
+
+// Minimal HTTP/1.1 server: answers with the queued status codes (200 once
+// they run out) and reports every request it reads.
+async fn stand_in(statuses: Vec<u16>) -> (String, mpsc::UnboundedReceiver<Received>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let statuses = Arc::new(Mutex::new(VecDeque::from(statuses)));
+    let (tx, rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                return;
+            };
+            let statuses = statuses.clone();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                let mut reader = BufReader::new(stream);
+                let mut headers = HashMap::new();
+                let mut line = String::new();
+                reader.read_line(&mut line).await.unwrap();
+                loop {
+                    line.clear();
+                    reader.read_line(&mut line).await.unwrap();
+                    let Some((name, value)) = line.trim_end().split_once(':') else {
+                        break;
+                    };
+                    headers.insert(name.trim().to_lowercase(), value.trim().to_string());
+                }
+                let len: usize = headers["content-length"].parse().unwrap();
+                let mut body = vec![0; len];
+                reader.read_exact(&mut body).await.unwrap();
+
+                let status = statuses.lock().unwrap().pop_front().unwrap_or(200);
+                let response = format!(
+                    "HTTP/1.1 {} X\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                    status
+                );
+                reader
+                    .get_mut()
+                    .write_all(response.as_bytes())
+                    .await
+                    .unwrap();
+                let _ = tx.send(Received {
+                    headers,
+                    body: serde_json::from_slice(&body).unwrap(),
+                });
+            });
+        }
+    });
+    (url, rx)
+}
+
+async fn next(rx: &mut mpsc::UnboundedReceiver<Received>) -> Received {
+    tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("webhook not delivered in time")
+        .unwrap()
+}
+
+#[derive(Default)]
+struct Counter(Mutex<usize>);
+
+impl DanmakuHandler for Counter {
+    fn emit_json(&self, _event: &str, _payload: Value) {
+        *self.0.lock().unwrap() += 1;
+    }
+}
+
+fn settings(hook: WebhookConfig) -> WebhookSettings {
+    WebhookSettings {
+        hooks: vec![hook],
+        retry_backoff_ms: 10,
+        // Tests drive the requeue pass by hand
+        requeue_secs: 3600,
+        ..WebhookSettings::default()
+    }
+}
+
+#[tokio::test]
+async fn delivers_filtered_templated_signed_danmaku() {
+    let (url, mut rx) = stand_in(vec![]).await;
+    let mut hook = WebhookConfig::new("chat", url);
+    hook.events = vec!["danmaku.message".into()];
+    hook.secret = Some("s3cret".into());
+    hook.template =
+        Some(json!({ "text": "{{data.user}}: {{data.content}}", "room": "{{room_id}}" }));
+    let dispatcher = WebhookDispatcher::new(settings(hook), None).unwrap();
+
+    let inner = Arc::new(Counter::default());
+    let handler = dispatcher.danmaku_handler(SupportedPlatform::Douyu, "6".into(), inner.clone());
+    // Filtered out, but still passed through
+    handler.emit_json("danmaku-6", json!({ "type": "uenter", "nickname": "bob" }));
+    handler.on_danmaku(DanmakuFrontendPayload {
+        room_id: "6".into(),
+        user: "alice".into(),
+        content: "hi".into(),
+        user_level: 1,
+        fans_club_level: 0,
+    });
+
+    let got = next(&mut rx).await;
+    assert_eq!(got.body, json!({ "text": "alice: hi", "room": "6" }));
+    assert_eq!(got.headers["x-dtv-event"], "danmaku.message");
+    assert_eq!(
+        got.headers["x-dtv-signature-256"],
+        format!(
+            "sha256={}",
+            hmac_sha256_hex(b"s3cret", got.body.to_string().as_bytes())
+        )
+    );
+    assert_eq!(*inner.0.lock().unwrap(), 2);
+    assert!(tokio::time::timeout(Duration::from_millis(200), rx.recv())
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn failed_status_events_are_queued_and_redelivered() {
+    // Initial attempt + one retry fail, the requeue pass succeeds
+    let (url, mut rx) = stand_in(vec![500, 503]).await;
+    let mut hook = WebhookConfig::new("status", url);
+    hook.events = vec!["live.*".into()];
+    hook.max_retries = 1;
+    let queue_path =
+        std::env::temp_dir().join(format!("dtv-webhook-queue-{}.json", std::process::id()));
+    let _ = std::fs::remove_file(&queue_path);
+    let dispatcher = WebhookDispatcher::new(settings(hook), Some(queue_path.clone())).unwrap();
+
+    let response = |status| LiveStreamResponse {
+        status,
+        room: RoomMeta {
+            platform: SupportedPlatform::Douyu,
+            room_id: "6".into(),
+            normalized_room_id: None,
+            web_rid: None,
+            title: Some("title".into()),
+            anchor_name: None,
+            avatar: None,
+            category: None,
+        },
+        playback: None,
+        options: None,
+        error: None,
+    };
+    // First sighting is only a baseline
+    dispatcher.observe_status(&response(LiveStatus::Offline));
+    dispatcher.observe_status(&response(LiveStatus::Live));
+
+    assert_eq!(next(&mut rx).await.body["event"], "live.started");
+    assert_eq!(next(&mut rx).await.body["event"], "live.started");
+    let mut failed = Vec::new();
+    for _ in 0..50 {
+        failed = dispatcher.failed_deliveries();
+        if !failed.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].attempts, 2);
+    assert!(failed[0].last_error.contains("503"));
+    let persisted: Vec<Value> =
+        serde_json::from_slice(&std::fs::read(&queue_path).unwrap()).unwrap();
+    assert_eq!(persisted.len(), 1);
+
+    assert_eq!(dispatcher.retry_failed().await, 1);
+    let redelivered = next(&mut rx).await;
+    assert_eq!(redelivered.headers["x-dtv-delivery"], failed[0].delivery_id);
+    assert_eq!(redelivered.body["data"]["title"], "title");
+    assert!(dispatcher.failed_deliveries().is_empty());
+    let persisted: Vec<Value> =
+        serde_json::from_slice(&std::fs::read(&queue_path).unwrap()).unwrap();
+    assert!(persisted.is_empty());
+    let _ = std::fs::remove_file(&queue_path);
+}
@@ -0,0 +1,539 @@
+// REST equivalents of the Tauri commands, plus follows and per-room danmaku.
+// Handlers read parameters only for the platforms compiled in.
+#![cfg_attr(
+    not(all(
+        feature = "douyu",
+        feature = "huya",
+        feature = "bilibili",
+        feature = "douyin",
+        feature = "kuaishou",
+        feature = "cc",
+        feature = "twitch"
+    )),
+    allow(unused)
+)]
+use std::sync::Arc;
+
+use actix_web::http::StatusCode;
+use actix_web::{web, HttpRequest, HttpResponse};
+use futures_util::StreamExt;
+use serde::Deserialize;
+use serde_json::Value;
+
+use dtv_core::platforms::common::types::SupportedPlatform;
+use dtv_core::platforms::common::{
+    GetLiveStreamRequest, LiveStatus, LiveStreamMode, LiveStreamResponse, StreamType,
+};
+use dtv_core::DtvClient;
+
+use crate::auth::ApiToken;
+use crate::danmaku_http;
+use crate::danmaku_hub::DanmakuHub;
+use crate::follows::{FollowEntry, FollowStore};
+use crate::proxy::{StreamEntry, StreamUrlStore};
+
+// Concurrent room lookups when refreshing follows
+const FOLLOW_STATUS_CONCURRENCY: usize = 4;
+
+pub struct ApiState {
+    pub dtv: DtvClient,
+    pub streams: StreamUrlStore,
+    pub follows: Arc<FollowStore>,
+    pub hub: Arc<DanmakuHub>,
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/api")
+            .route("/health", web::get().to(health))
+            .route("/live_stream", web::post().to(live_stream))
+            .route("/search/{platform}", web::get().to(search))
+            .route("/categories/{platform}", web::get().to(categories))
+            .route("/categories/{platform}/{id}", web::get().to(sub_categories))
+            .route("/live_list/{platform}", web::get().to(live_list))
+            .route("/lines/{platform}/{room_id}", web::get().to(probe_lines))
+            .route("/follows", web::get().to(list_follows))
+            .route("/follows/status", web::get().to(follow_status))
+            .route("/follows/{platform}/{room_id}", web::put().to(put_follow))
+            .route("/follows/{platform}/{room_id}", web::delete().to(delete_follow))
+            .route("/danmaku/{platform}/{room_id}/ws", web::get().to(danmaku_http::websocket))
+            .route("/danmaku/{platform}/{room_id}/sse", web::get().to(danmaku_http::sse))
+            .route("/plugins", web::get().to(list_plugins))
+            .route("/webhooks/failed", web::get().to(failed_webhooks))
+            .route("/webhooks/retry", web::post().to(retry_webhooks)),
+    );
+}
+
+pub(crate) fn error(status: StatusCode, message: impl Into<String>) -> HttpResponse {
+    HttpResponse::build(status).json(serde_json::json!({ "error": message.into() }))
+}
+
+fn unsupported(platform: &SupportedPlatform, what: &str) -> HttpResponse {
+    error(
+        StatusCode::NOT_IMPLEMENTED,
+        format!("{} is not available for {}", what, platform.as_str()),
+    )
+}
+
+pub(crate) fn parse_platform(raw: &str) -> Result<SupportedPlatform, HttpResponse> {
+    serde_json::from_value(Value::String(raw.to_lowercase())).map_err(|_| {
+        error(
+            StatusCode::NOT_FOUND,
+            format!("Unknown or disabled platform: {}", raw),
+        )
+    })
+}
+
+// Some platform calls hand back the upstream JSON as a string
+fn raw_json(body: String) -> Value {
+    serde_json::from_str(&body).unwrap_or(Value::String(body))
+}
+
+fn upstream<T: serde::Serialize>(result: Result<T, String>) -> HttpResponse {
+    match result {
+        Ok(v) => HttpResponse::Ok().json(v),
+        Err(e) => error(StatusCode::BAD_GATEWAY, e),
+    }
+}
+
+async fn health(state: web::Data<ApiState>) -> HttpResponse {
+    let rooms: Vec<Value> = state
+        .hub
+        .active_rooms()
+        .into_iter()
+        .map(|(platform, room_id, subscribers)| {
+            serde_json::json!({ "platform": platform, "room_id": room_id, "subscribers": subscribers })
+        })
+        .collect();
+    #[allow(unused_mut)]
+    let mut platforms = SupportedPlatform::all();
+    #[cfg(feature = "js-runtime")]
+    if let Some(plugins) = state.dtv.plugins() {
+        platforms.extend(
+            plugins
+                .plugins()
+                .await
+                .into_iter()
+                .map(|p| SupportedPlatform::Custom(p.id)),
+        );
+    }
+    HttpResponse::Ok().json(serde_json::json!({
+        "ok": true,
+        "platforms": platforms,
+        "danmaku_rooms": rooms,
+    }))
+}
+
+#[derive(Deserialize)]
+struct LiveStreamQuery {
+    // false returns the upstream URL instead of this server's /live proxy URL
+    proxy: Option<bool>,
+}
+
+async fn live_stream(
+    req: HttpRequest,
+    state: web::Data<ApiState>,
+    token: web::Data<ApiToken>,
+    query: web::Query<LiveStreamQuery>,
+    body: web::Json<GetLiveStreamRequest>,
+) -> HttpResponse {
+    let audio_only = body.audio_only();
+    let mut response = state.dtv.get_live_stream(body.into_inner()).await;
+    if query.proxy == Some(false) || response.status != LiveStatus::Live {
+        return HttpResponse::Ok().json(response);
+    }
+    let platform = response.room.platform.clone();
+    let key = (platform.as_str().to_string(), response.room.room_id.clone());
+    let Some(playback) = response.playback.as_mut() else {
+        return HttpResponse::Ok().json(response);
+    };
+    // Same rule as the desktop app: Bilibili HLS plays directly
+    #[cfg(feature = "bilibili")]
+    if platform == SupportedPlatform::Bilibili && playback.stream_type != StreamType::Flv {
+        return HttpResponse::Ok().json(response);
+    }
+
+    // Audio-only FLV goes out with the video tags stripped
+    let (entries, route) = if audio_only && playback.stream_type == StreamType::Flv {
+        (&state.streams.audio_urls, "audio")
+    } else {
+        (&state.streams.urls, "live")
+    };
+    if let Ok(mut urls) = entries.lock() {
+        urls.insert(key.clone(), StreamEntry::from(&*playback));
+    }
+    let info = req.connection_info();
+    let mut url = format!(
+        "{}://{}/{}/{}/{}.flv",
+        info.scheme(),
+        info.host(),
+        route,
+        key.0,
+        key.1
+    );
+    if let Some(t) = token.0.as_deref() {
+        url.push_str("?token=");
+        url.push_str(&urlencode(t));
+    }
+    playback.url = url;
+    // The proxy sends them upstream; the local URL needs none
+    playback.headers.clear();
+    HttpResponse::Ok().json(response)
+}
+
+pub(crate) fn urlencode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct SearchQuery {
+    keyword: String,
+    page: Option<u32>,
+}
+
+async fn search(
+    path: web::Path<String>,
+    query: web::Query<SearchQuery>,
+    state: web::Data<ApiState>,
+) -> HttpResponse {
+    let platform = match parse_platform(&path) {
+        Ok(p) => p,
+        Err(res) => return res,
+    };
+    let SearchQuery { keyword, page } = query.into_inner();
+    #[allow(unreachable_patterns)]
+    match platform {
+        #[cfg(feature = "douyu")]
+        SupportedPlatform::Douyu => upstream(state.dtv.douyu_search(&keyword).await.map(raw_json)),
+        #[cfg(feature = "huya")]
+        SupportedPlatform::Huya => {
+            upstream(state.dtv.huya_search(keyword, page.map(|p| p as usize)).await)
+        }
+        #[cfg(feature = "bilibili")]
+        SupportedPlatform::Bilibili => {
+            upstream(state.dtv.bilibili_search(keyword, page, None).await)
+        }
+        #[cfg(feature = "kuaishou")]
+        SupportedPlatform::Kuaishou => {
+            upstream(state.dtv.kuaishou_search(keyword, page, None).await)
+        }
+        #[cfg(feature = "twitch")]
+        SupportedPlatform::Twitch => upstream(state.dtv.twitch_search(keyword, page).await),
+        #[cfg(feature = "js-runtime")]
+        SupportedPlatform::Custom(id) => {
+            if let Err(res) = known_plugin(&state, &id).await {
+                return res;
+            }
+            upstream(state.dtv.plugin_search(&id, &keyword, page).await)
+        }
+        _ => unsupported(&platform, "Search"),
+    }
+}
+
+async fn categories(path: web::Path<String>, state: web::Data<ApiState>) -> HttpResponse {
+    let platform = match parse_platform(&path) {
+        Ok(p) => p,
+        Err(res) => return res,
+    };
+    #[allow(unreachable_patterns)]
+    match platform {
+        #[cfg(feature = "douyu")]
+        SupportedPlatform::Douyu => upstream(state.dtv.douyu_categories().await),
+        #[cfg(feature = "cc")]
+        SupportedPlatform::Cc => upstream(state.dtv.cc_categories().await),
+        // The other platforms ship static category tables in the frontend
+        _ => unsupported(&platform, "Category listing"),
+    }
+}
+
+async fn sub_categories(
+    path: web::Path<(String, String)>,
+    state: web::Data<ApiState>,
+) -> HttpResponse {
+    let (platform, id) = path.into_inner();
+    let platform = match parse_platform(&platform) {
+        Ok(p) => p,
+        Err(res) => return res,
+    };
+    #[allow(unreachable_patterns)]
+    match platform {
+        #[cfg(feature = "douyu")]
+        SupportedPlatform::Douyu => match id.parse::<i32>() {
+            Ok(tag_id) => upstream(state.dtv.douyu_three_cate(tag_id).await),
+            Err(_) => error(StatusCode::BAD_REQUEST, "Douyu category id must be numeric"),
+        },
+        _ => unsupported(&platform, "Sub-category listing"),
+    }
+}
+
+// Speed test of every CDN line, best first; takes a few seconds
+async fn probe_lines(
+    path: web::Path<(String, String)>,
+    state: web::Data<ApiState>,
+) -> HttpResponse {
+    let (platform, room_id) = path.into_inner();
+    let platform = match parse_platform(&platform) {
+        Ok(p) => p,
+        Err(res) => return res,
+    };
+    upstream(
+        state
+            .dtv
+            .probe_lines(platform, &room_id)
+            .await
+            .map_err(|e| e.to_string()),
+    )
+}
+
+// Union of every platform's list parameters; each platform reads its own
+#[derive(Deserialize)]
+struct LiveListQuery {
+    // Douyu
+    cate2: Option<String>,
+    cate3: Option<String>,
+    limit: Option<u32>,
+    // Huya
+    gid: Option<String>,
+    page_size: Option<u32>,
+    // Bilibili
+    area_id: Option<String>,
+    parent_area_id: Option<String>,
+    // Douyin
+    partition: Option<String>,
+    partition_type: Option<String>,
+    ms_token: Option<String>,
+    // CC; without it, the recommended list
+    gametype: Option<String>,
+    // Shared paging
+    offset: Option<u32>,
+    page: Option<u32>,
+}
+
+fn required(value: Option<String>, name: &str) -> Result<String, HttpResponse> {
+    value
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| error(StatusCode::BAD_REQUEST, format!("Missing query parameter: {}", name)))
+}
+
+async fn live_list(
+    path: web::Path<String>,
+    query: web::Query<LiveListQuery>,
+    state: web::Data<ApiState>,
+) -> HttpResponse {
+    let platform = match parse_platform(&path) {
+        Ok(p) => p,
+        Err(res) => return res,
+    };
+    let q = query.into_inner();
+    match platform {
+        #[cfg(feature = "douyu")]
+        SupportedPlatform::Douyu => {
+            let limit = q.limit.unwrap_or(20);
+            if let Some(cate3) = q.cate3.filter(|c| !c.is_empty()) {
+                let list = state
+                    .dtv
+                    .douyu_live_list_for_cate3(cate3, q.page.unwrap_or(1), limit)
+                    .await;
+                return HttpResponse::Ok().json(list);
+            }
+            let cate2 = match required(q.cate2, "cate2") {
+                Ok(v) => v,
+                Err(res) => return res,
+            };
+            let list = state
+                .dtv
+                .douyu_live_list(q.offset.unwrap_or(0), cate2, limit)
+                .await;
+            HttpResponse::Ok().json(list)
+        }
+        #[cfg(feature = "huya")]
+        SupportedPlatform::Huya => {
+            let gid = match required(q.gid, "gid") {
+                Ok(v) => v,
+                Err(res) => return res,
+            };
+            let list = state
+                .dtv
+                .huya_live_list(gid, q.page.unwrap_or(1), q.page_size.unwrap_or(120))
+                .await;
+            HttpResponse::Ok().json(list)
+        }
+        #[cfg(feature = "bilibili")]
+        SupportedPlatform::Bilibili => {
+            let (area_id, parent_area_id) = match (
+                required(q.area_id, "area_id"),
+                required(q.parent_area_id, "parent_area_id"),
+            ) {
+                (Ok(a), Ok(p)) => (a, p),
+                (Err(res), _) | (_, Err(res)) => return res,
+            };
+            let list = state
+                .dtv
+                .bilibili_live_list(area_id, parent_area_id, q.page.unwrap_or(1))
+                .await;
+            upstream(list.map(raw_json))
+        }
+        #[cfg(feature = "douyin")]
+        SupportedPlatform::Douyin => {
+            let (partition, partition_type) = match (
+                required(q.partition, "partition"),
+                required(q.partition_type, "partition_type"),
+            ) {
+                (Ok(a), Ok(p)) => (a, p),
+                (Err(res), _) | (_, Err(res)) => return res,
+            };
+            let offset = q.offset.unwrap_or(0) as i32;
+            upstream(
+                state
+                    .dtv
+                    .douyin_partition_rooms(partition, partition_type, offset, q.ms_token)
+                    .await,
+            )
+        }
+        // Kuaishou rooms are found by search only
+        #[cfg(feature = "kuaishou")]
+        SupportedPlatform::Kuaishou => unsupported(&platform, "Live list"),
+        #[cfg(feature = "cc")]
+        SupportedPlatform::Cc => upstream(
+            state
+                .dtv
+                .cc_live_list(q.gametype, q.page.unwrap_or(1), q.page_size.unwrap_or(20))
+                .await,
+        ),
+        // Twitch channels are found by search only
+        #[cfg(feature = "twitch")]
+        SupportedPlatform::Twitch => unsupported(&platform, "Live list"),
+        // Plugins have no category pages
+        SupportedPlatform::Custom(_) => unsupported(&platform, "Live list"),
+    }
+}
+
+async fn list_follows(state: web::Data<ApiState>) -> HttpResponse {
+    HttpResponse::Ok().json(state.follows.list().await)
+}
+
+#[derive(Deserialize, Default)]
+struct FollowBody {
+    name: Option<String>,
+}
+
+async fn put_follow(
+    path: web::Path<(String, String)>,
+    body: Option<web::Json<FollowBody>>,
+    state: web::Data<ApiState>,
+) -> HttpResponse {
+    let (platform, room_id) = path.into_inner();
+    let platform = match parse_platform(&platform) {
+        Ok(p) => p,
+        Err(res) => return res,
+    };
+    let entry = FollowEntry {
+        platform,
+        room_id,
+        name: body.map(|b| b.into_inner()).unwrap_or_default().name,
+    };
+    match state.follows.upsert(entry.clone()).await {
+        Ok(()) => HttpResponse::Ok().json(entry),
+        Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+async fn delete_follow(
+    path: web::Path<(String, String)>,
+    state: web::Data<ApiState>,
+) -> HttpResponse {
+    let (platform, room_id) = path.into_inner();
+    let platform = match parse_platform(&platform) {
+        Ok(p) => p,
+        Err(res) => return res,
+    };
+    match state.follows.remove(&platform, &room_id).await {
+        Ok(true) => HttpResponse::NoContent().finish(),
+        Ok(false) => error(StatusCode::NOT_FOUND, "Room is not followed"),
+        Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+/// Meta-mode lookups for every followed room. Going through the client also
+/// feeds its webhook status tracker.
+pub(crate) async fn follow_statuses(dtv: &DtvClient, follows: &FollowStore) -> Vec<LiveStreamResponse> {
+    room_statuses(dtv, follows.list().await).await
+}
+
+/// Meta-mode lookups for `entries`, in the same order.
+pub(crate) async fn room_statuses(dtv: &DtvClient, entries: Vec<FollowEntry>) -> Vec<LiveStreamResponse> {
+    futures_util::stream::iter(entries)
+        .map(|entry| {
+            let dtv = dtv.clone();
+            async move {
+                dtv.get_live_stream(GetLiveStreamRequest {
+                    platform: entry.platform,
+                    room_id: entry.room_id,
+                    quality: None,
+                    line: None,
+                    cookie: None,
+                    debug: Some(false),
+                    mode: Some(LiveStreamMode::Meta),
+                    // Status polling only; skip the extra stream requests
+                    with_options: Some(false),
+                    codec: None,
+                    probe: None,
+                    audio_only: None,
+                })
+                .await
+            }
+        })
+        .buffered(FOLLOW_STATUS_CONCURRENCY)
+        .collect()
+        .await
+}
+
+async fn follow_status(state: web::Data<ApiState>) -> HttpResponse {
+    HttpResponse::Ok().json(follow_statuses(&state.dtv, &state.follows).await)
+}
+
+// Plugin ids parse without the plugin being loaded; 404 like an unknown platform
+#[cfg(feature = "js-runtime")]
+async fn known_plugin(state: &ApiState, id: &str) -> Result<(), HttpResponse> {
+    match state.dtv.plugins() {
+        Some(plugins) if plugins.plugin(id).await.is_some() => Ok(()),
+        _ => Err(error(
+            StatusCode::NOT_FOUND,
+            format!("Unknown or disabled platform: {}", id),
+        )),
+    }
+}
+
+#[cfg_attr(not(feature = "js-runtime"), allow(unused_variables))]
+async fn list_plugins(state: web::Data<ApiState>) -> HttpResponse {
+    #[cfg(feature = "js-runtime")]
+    if let Some(plugins) = state.dtv.plugins() {
+        return HttpResponse::Ok().json(plugins.plugins().await);
+    }
+    HttpResponse::Ok().json(Vec::<Value>::new())
+}
+
+async fn failed_webhooks(state: web::Data<ApiState>) -> HttpResponse {
+    match state.dtv.webhooks() {
+        Some(hooks) => HttpResponse::Ok().json(hooks.failed_deliveries()),
+        None => HttpResponse::Ok().json(Vec::<Value>::new()),
+    }
+}
+
+async fn retry_webhooks(state: web::Data<ApiState>) -> HttpResponse {
+    let Some(hooks) = state.dtv.webhooks() else {
+        return error(StatusCode::NOT_FOUND, "No webhooks configured");
+    };
+    let delivered = hooks.retry_failed().await;
+    HttpResponse::Ok().json(serde_json::json!({
+        "delivered": delivered,
+        "failed": hooks.failed_deliveries().len(),
+    }))
+}
@@ -0,0 +1,176 @@
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::ORIGIN;
+use actix_web::middleware::Next;
+use actix_web::{web, Error, HttpResponse};
+
+/// Shared secret for API clients. `None` disables the check (loopback only).
+#[derive(Clone)]
+pub struct ApiToken(pub Option<String>);
+
+// Paths reachable without a token (liveness probes)
+const PUBLIC_PATHS: &[&str] = &["/api/health"];
+
+/// Accepts `Authorization: Bearer <token>` or `?token=<token>`; the query
+/// form exists for players and `EventSource`, which cannot set headers.
+/// Without a token, browser requests from pages on other hosts are refused.
+pub async fn require_token(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let expected = req
+        .app_data::<web::Data<ApiToken>>()
+        .and_then(|t| t.0.clone());
+    let Some(expected) = expected else {
+        // CORS only hides the response; a cross-site form POST still runs
+        let foreign = req
+            .headers()
+            .get(ORIGIN)
+            .is_some_and(|origin| !is_loopback_origin(origin.as_bytes()));
+        if foreign {
+            let res = HttpResponse::Forbidden()
+                .json(serde_json::json!({ "error": "cross-origin requests need a token" }));
+            return Ok(req.into_response(res).map_into_right_body());
+        }
+        return next.call(req).await.map(|res| res.map_into_left_body());
+    };
+    if PUBLIC_PATHS.contains(&req.path()) {
+        return next.call(req).await.map(|res| res.map_into_left_body());
+    }
+
+    let presented = bearer_token(&req).or_else(|| query_token(req.query_string()));
+    match presented {
+        Some(token) if constant_time_eq(token.as_bytes(), expected.as_bytes()) => {
+            next.call(req).await.map(|res| res.map_into_left_body())
+        }
+        _ => {
+            let res = HttpResponse::Unauthorized()
+                .insert_header(("WWW-Authenticate", "Bearer"))
+                .json(serde_json::json!({ "error": "missing or invalid token" }));
+            Ok(req.into_response(res).map_into_right_body())
+        }
+    }
+}
+
+/// Whether an `Origin` header names a page served from this machine.
+pub fn is_loopback_origin(origin: &[u8]) -> bool {
+    let Some((_, rest)) = std::str::from_utf8(origin)
+        .ok()
+        .and_then(|origin| origin.split_once("://"))
+    else {
+        return false;
+    };
+    let host = match rest.strip_prefix('[') {
+        Some(v6) => v6.split(']').next(),
+        None => rest.split(':').next(),
+    }
+    .unwrap_or_default();
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+fn bearer_token(req: &ServiceRequest) -> Option<String> {
+    let value = req.headers().get("Authorization")?.to_str().ok()?;
+    value
+        .strip_prefix("Bearer ")
+        .map(|t| t.trim().to_string())
+}
+
+fn query_token(query: &str) -> Option<String> {
+    web::Query::<std::collections::HashMap<String, String>>::from_query(query)
+        .ok()
+        .and_then(|q| q.get("token").cloned())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{middleware::from_fn, test, App, HttpResponse};
+
+    async fn ok() -> HttpResponse {
+        HttpResponse::Ok().finish()
+    }
+
+    #[actix_web::test]
+    async fn rejects_missing_and_wrong_tokens() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(ApiToken(Some("s3cret".into()))))
+                .wrap(from_fn(require_token))
+                .route("/api/health", web::get().to(ok))
+                .route("/api/x", web::get().to(ok)),
+        )
+        .await;
+
+        let res = test::call_service(&app, test::TestRequest::get().uri("/api/x").to_request()).await;
+        assert_eq!(res.status(), 401);
+        let req = test::TestRequest::get()
+            .uri("/api/x")
+            .insert_header(("Authorization", "Bearer nope"))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 401);
+
+        let req = test::TestRequest::get()
+            .uri("/api/x")
+            .insert_header(("Authorization", "Bearer s3cret"))
+            .to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 200);
+        let req = test::TestRequest::get().uri("/api/x?token=s3cret").to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 200);
+        let req = test::TestRequest::get().uri("/api/health").to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 200);
+    }
+
+    #[actix_web::test]
+    async fn tokenless_server_refuses_other_sites() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(ApiToken(None)))
+                .wrap(from_fn(require_token))
+                .route("/api/x", web::post().to(ok)),
+        )
+        .await;
+
+        let req = test::TestRequest::post().uri("/api/x").to_request();
+        assert_eq!(test::call_service(&app, req).await.status(), 200);
+        for origin in [
+            "http://localhost:5173",
+            "http://127.0.0.1",
+            "http://[::1]:8080",
+        ] {
+            let req = test::TestRequest::post()
+                .uri("/api/x")
+                .insert_header(("Origin", origin))
+                .to_request();
+            assert_eq!(
+                test::call_service(&app, req).await.status(),
+                200,
+                "{origin}"
+            );
+        }
+        for origin in [
+            "https://evil.example",
+            "http://localhost.evil.example",
+            "null",
+        ] {
+            let req = test::TestRequest::post()
+                .uri("/api/x")
+                .insert_header(("Origin", origin))
+                .to_request();
+            assert_eq!(
+                test::call_service(&app, req).await.status(),
+                403,
+                "{origin}"
+            );
+        }
+    }
+}
@@ -0,0 +1,128 @@
+// Danmaku over HTTP for overlays (OBS browser sources) and API clients.
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_web::{web, HttpRequest, HttpResponse};
+use futures_util::StreamExt;
+
+use crate::api::parse_platform;
+use crate::danmaku_hub::{DanmakuHub, DanmakuSubscription};
+
+// SSE comment lines keep idle proxies from closing the stream
+const SSE_KEEPALIVE: Duration = Duration::from_secs(15);
+
+const OVERLAY_HTML: &str = include_str!("overlay.html");
+
+/// `/danmaku/{platform}/{room_id}` (WebSocket, or SSE for plain GETs) and
+/// its `/overlay` page. Expects `web::Data<Arc<DanmakuHub>>` in app data.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/danmaku/{platform}/{room_id}", web::get().to(stream))
+        .route("/danmaku/{platform}/{room_id}/overlay", web::get().to(overlay));
+}
+
+fn subscribe(
+    hub: &Arc<DanmakuHub>,
+    path: web::Path<(String, String)>,
+) -> Result<DanmakuSubscription, HttpResponse> {
+    let (platform, room_id) = path.into_inner();
+    let platform = parse_platform(&platform)?;
+    Ok(hub.subscribe(platform, room_id))
+}
+
+fn is_websocket_upgrade(req: &HttpRequest) -> bool {
+    req.headers()
+        .get("Upgrade")
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.eq_ignore_ascii_case("websocket"))
+}
+
+async fn stream(
+    req: HttpRequest,
+    body: web::Payload,
+    path: web::Path<(String, String)>,
+    hub: web::Data<Arc<DanmakuHub>>,
+) -> Result<HttpResponse, actix_web::Error> {
+    if is_websocket_upgrade(&req) {
+        websocket(req, body, path, hub).await
+    } else {
+        Ok(sse(path, hub).await)
+    }
+}
+
+pub async fn websocket(
+    req: HttpRequest,
+    body: web::Payload,
+    path: web::Path<(String, String)>,
+    hub: web::Data<Arc<DanmakuHub>>,
+) -> Result<HttpResponse, actix_web::Error> {
+    let mut subscription = match subscribe(&hub, path) {
+        Ok(s) => s,
+        Err(res) => return Ok(res),
+    };
+    let (res, mut session, mut incoming) = actix_ws::handle(&req, body)?;
+
+    actix_web::rt::spawn(async move {
+        loop {
+            tokio::select! {
+                frame = subscription.recv() => match frame {
+                    Some(frame) => {
+                        if session.text(frame.json().to_string()).await.is_err() {
+                            break;
+                        }
+                    }
+                    None => break,
+                },
+                msg = incoming.next() => match msg {
+                    Some(Ok(actix_ws::Message::Ping(bytes))) => {
+                        if session.pong(&bytes).await.is_err() {
+                            break;
+                        }
+                    }
+                    Some(Ok(actix_ws::Message::Close(_))) | Some(Err(_)) | None => break,
+                    // Clients have nothing to send; ignore text/binary frames
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
+        let _ = session.close(None).await;
+    });
+
+    Ok(res)
+}
+
+pub async fn sse(path: web::Path<(String, String)>, hub: web::Data<Arc<DanmakuHub>>) -> HttpResponse {
+    let subscription = match subscribe(&hub, path) {
+        Ok(s) => s,
+        Err(res) => return res,
+    };
+    let keepalive = tokio::time::interval(SSE_KEEPALIVE);
+
+    // The subscription lives inside the body stream, so a client hang-up drops it
+    let body = futures_util::stream::unfold(
+        (subscription, keepalive),
+        |(mut subscription, mut keepalive)| async move {
+            let chunk = tokio::select! {
+                frame = subscription.recv() => web::Bytes::from(format!("data: {}\n\n", frame?.json())),
+                _ = keepalive.tick() => web::Bytes::from_static(b": keepalive\n\n"),
+            };
+            Some((Ok::<_, actix_web::Error>(chunk), (subscription, keepalive)))
+        },
+    );
+
+    HttpResponse::Ok()
+        .content_type("text/event-stream")
+        .insert_header(("Cache-Control", "no-cache"))
+        .insert_header(("X-Accel-Buffering", "no"))
+        .streaming(body)
+}
+
+// Static page; it reads platform/room from its own URL and options from the query
+async fn overlay(path: web::Path<(String, String)>) -> HttpResponse {
+    if let Some(res) = parse_platform(&path.0).err() {
+        return res;
+    }
+    HttpResponse::Ok()
+        .content_type("text/html; charset=utf-8")
+        .insert_header(("Cache-Control", "no-cache"))
+        .body(OVERLAY_HTML)
+}
@@ -0,0 +1,204 @@
+// A build with no platform features has no listeners to start.
+#![cfg_attr(
+    not(any(
+        feature = "douyu",
+        feature = "huya",
+        feature = "bilibili",
+        feature = "douyin",
+        feature = "kuaishou",
+        feature = "cc",
+        feature = "twitch"
+    )),
+    allow(unused)
+)]
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::{broadcast, mpsc};
+
+use dtv_core::danmaku::DanmakuHandler;
+use dtv_core::platforms::common::types::SupportedPlatform;
+use dtv_core::DtvClient;
+
+// Per-room backlog before slow subscribers start skipping messages
+const ROOM_CHANNEL_CAPACITY: usize = 512;
+
+type RoomKey = (SupportedPlatform, String);
+
+/// One event from a room's listener, as `DanmakuHandler::emit_json` saw it.
+pub struct DanmakuFrame {
+    pub event: String,
+    pub data: serde_json::Value,
+    json: String,
+}
+
+impl DanmakuFrame {
+    /// `{"event": ..., "data": ...}`, serialized once for every WS/SSE client.
+    pub fn json(&self) -> &str {
+        &self.json
+    }
+}
+
+#[derive(Clone)]
+enum HubEvent {
+    Message(Arc<DanmakuFrame>),
+    // The upstream listener exited on its own
+    Closed,
+}
+
+struct Session {
+    id: u64,
+    tx: broadcast::Sender<HubEvent>,
+    stop: mpsc::Sender<()>,
+    subscribers: usize,
+}
+
+/// One upstream danmaku listener per room, fanned out to every subscriber
+/// (the desktop webview, overlays, API clients). The listener starts with the
+/// first subscriber and stops when the last one leaves.
+pub struct DanmakuHub {
+    dtv: DtvClient,
+    sessions: Mutex<HashMap<RoomKey, Session>>,
+    next_id: AtomicU64,
+}
+
+struct BroadcastHandler {
+    tx: broadcast::Sender<HubEvent>,
+}
+
+impl DanmakuHandler for BroadcastHandler {
+    fn emit_json(&self, event: &str, payload: serde_json::Value) {
+        let json = serde_json::json!({ "event": event, "data": &payload }).to_string();
+        let frame = DanmakuFrame {
+            event: event.to_string(),
+            data: payload,
+            json,
+        };
+        // No receivers just means everyone disconnected between messages
+        let _ = self.tx.send(HubEvent::Message(Arc::new(frame)));
+    }
+}
+
+impl DanmakuHub {
+    pub fn new(dtv: DtvClient) -> Arc<Self> {
+        Arc::new(Self {
+            dtv,
+            sessions: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+        })
+    }
+
+    pub fn subscribe(self: &Arc<Self>, platform: SupportedPlatform, room_id: String) -> DanmakuSubscription {
+        let key = (platform, room_id);
+        let mut sessions = self.sessions.lock().unwrap();
+        if let Some(session) = sessions.get_mut(&key) {
+            session.subscribers += 1;
+            return DanmakuSubscription {
+                rx: session.tx.subscribe(),
+                _lease: Lease { hub: self.clone(), key, id: session.id },
+            };
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, rx) = broadcast::channel(ROOM_CHANNEL_CAPACITY);
+        let (stop, rx_shutdown) = mpsc::channel(1);
+        sessions.insert(
+            key.clone(),
+            Session {
+                id,
+                tx: tx.clone(),
+                stop,
+                subscribers: 1,
+            },
+        );
+        drop(sessions);
+
+        tracing::info!("[dtv-server danmaku] starting listener for {}/{}", key.0.as_str(), key.1);
+        let hub = self.clone();
+        let listener_key = key.clone();
+        tokio::spawn(async move {
+            let handler: Arc<dyn DanmakuHandler> = Arc::new(BroadcastHandler { tx: tx.clone() });
+            hub.dtv
+                .run_danmaku(listener_key.0.clone(), listener_key.1.clone(), rx_shutdown, handler)
+                .await;
+            hub.remove_session(&listener_key, id);
+            let _ = tx.send(HubEvent::Closed);
+            tracing::info!(
+                "[dtv-server danmaku] listener for {}/{} stopped",
+                listener_key.0.as_str(),
+                listener_key.1
+            );
+        });
+
+        DanmakuSubscription {
+            rx,
+            _lease: Lease { hub: self.clone(), key, id },
+        }
+    }
+
+    /// Rooms with a running listener and how many clients are attached.
+    pub fn active_rooms(&self) -> Vec<(SupportedPlatform, String, usize)> {
+        let sessions = self.sessions.lock().unwrap();
+        sessions
+            .iter()
+            .map(|((platform, room_id), s)| (platform.clone(), room_id.clone(), s.subscribers))
+            .collect()
+    }
+
+    fn remove_session(&self, key: &RoomKey, id: u64) {
+        let mut sessions = self.sessions.lock().unwrap();
+        if sessions.get(key).is_some_and(|s| s.id == id) {
+            sessions.remove(key);
+        }
+    }
+
+    fn release(&self, key: &RoomKey, id: u64) {
+        let mut sessions = self.sessions.lock().unwrap();
+        let Some(session) = sessions.get_mut(key) else {
+            return;
+        };
+        if session.id != id {
+            return;
+        }
+        session.subscribers = session.subscribers.saturating_sub(1);
+        if session.subscribers == 0 {
+            if let Some(session) = sessions.remove(key) {
+                let _ = session.stop.try_send(());
+            }
+        }
+    }
+}
+
+struct Lease {
+    hub: Arc<DanmakuHub>,
+    key: RoomKey,
+    id: u64,
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        self.hub.release(&self.key, self.id);
+    }
+}
+
+/// A client's view of a room; dropping it detaches the client.
+pub struct DanmakuSubscription {
+    rx: broadcast::Receiver<HubEvent>,
+    _lease: Lease,
+}
+
+impl DanmakuSubscription {
+    /// Next frame, or `None` once the room's listener has stopped.
+    pub async fn recv(&mut self) -> Option<Arc<DanmakuFrame>> {
+        loop {
+            match self.rx.recv().await {
+                Ok(HubEvent::Message(frame)) => return Some(frame),
+                Ok(HubEvent::Closed) | Err(broadcast::error::RecvError::Closed) => return None,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!("[dtv-server danmaku] slow subscriber skipped {} messages", skipped);
+                }
+            }
+        }
+    }
+}
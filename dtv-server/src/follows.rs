@@ -0,0 +1,100 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use dtv_core::platforms::common::types::SupportedPlatform;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FollowEntry {
+    pub platform: SupportedPlatform,
+    pub room_id: String,
+    // Display name chosen by the client; refreshed metadata lives in /api/follows/status
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Server-side follow list, kept as a JSON array on disk.
+pub struct FollowStore {
+    // None keeps the list in memory only
+    path: Option<PathBuf>,
+    entries: Mutex<Vec<FollowEntry>>,
+}
+
+impl FollowStore {
+    pub async fn load(path: PathBuf) -> Result<Self, String> {
+        let entries = match tokio::fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+        };
+        Ok(Self {
+            path: Some(path),
+            entries: Mutex::new(entries),
+        })
+    }
+
+    /// A list mirrored from somewhere else (the desktop app's own follows).
+    pub fn in_memory() -> Self {
+        Self {
+            path: None,
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub async fn list(&self) -> Vec<FollowEntry> {
+        self.entries.lock().await.clone()
+    }
+
+    /// Adds or updates the entry for (platform, room_id).
+    pub async fn upsert(&self, entry: FollowEntry) -> Result<(), String> {
+        let mut entries = self.entries.lock().await;
+        match entries
+            .iter_mut()
+            .find(|e| e.platform == entry.platform && e.room_id == entry.room_id)
+        {
+            Some(existing) => *existing = entry,
+            None => entries.push(entry),
+        }
+        self.persist(&entries).await
+    }
+
+    /// Replaces the whole list, keeping the given order.
+    pub async fn replace(&self, new_entries: Vec<FollowEntry>) -> Result<(), String> {
+        let mut entries = self.entries.lock().await;
+        *entries = new_entries;
+        self.persist(&entries).await
+    }
+
+    /// Returns false when the room was not followed.
+    pub async fn remove(&self, platform: &SupportedPlatform, room_id: &str) -> Result<bool, String> {
+        let mut entries = self.entries.lock().await;
+        let before = entries.len();
+        entries.retain(|e| !(&e.platform == platform && e.room_id == room_id));
+        if entries.len() == before {
+            return Ok(false);
+        }
+        self.persist(&entries).await.map(|_| true)
+    }
+
+    // Write-then-rename so a crash never leaves a truncated file
+    async fn persist(&self, entries: &[FollowEntry]) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let json = serde_json::to_vec_pretty(entries).map_err(|e| e.to_string())?;
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, json)
+            .await
+            .map_err(|e| format!("Failed to write {}: {}", tmp.display(), e))?;
+        tokio::fs::rename(&tmp, path)
+            .await
+            .map_err(|e| format!("Failed to replace {}: {}", path.display(), e))
+    }
+}
@@ -0,0 +1,211 @@
+// Live FLV re-served as HLS for players without flv.js (Safari, iOS, smart
+// TVs): one session per room reads the shared relay, cuts it into MPEG-TS
+// segments and keeps the last few in memory for a rolling playlist.
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use actix_web::web::Bytes;
+use futures_util::StreamExt;
+use tokio::sync::watch;
+
+use dtv_core::media::flv::{self, FlvItem, TagReader};
+use dtv_core::media::hls::{HlsSegmenter, Segment};
+
+use crate::relay::RelayStream;
+
+const TARGET_SEGMENT_MS: u32 = 2000;
+// Segments in the playlist, and kept a little longer for slow clients
+const LISTED_SEGMENTS: usize = 6;
+const KEPT_SEGMENTS: usize = 9;
+// A session nobody has asked for in this long stops pulling
+const SESSION_IDLE: Duration = Duration::from_secs(30);
+const IDLE_CHECK: Duration = Duration::from_secs(5);
+/// How long the first playlist request waits for a segment.
+pub const FIRST_SEGMENT_TIMEOUT: Duration = Duration::from_secs(15);
+
+// (platform, room_id)
+type RoomKey = (String, String);
+
+struct Cached {
+    sequence: u64,
+    duration: f64,
+    data: Bytes,
+}
+
+struct Window {
+    segments: VecDeque<Cached>,
+    ended: bool,
+    last_request: Instant,
+}
+
+pub struct HlsSession {
+    window: Mutex<Window>,
+    // Segments cut so far; bumped on the end too, so waiters wake
+    cut: watch::Sender<u64>,
+}
+
+impl HlsSession {
+    fn touch(&self) {
+        self.window.lock().unwrap().last_request = Instant::now();
+    }
+
+    /// Wait until the first segment is ready or the session ends. False on
+    /// timeout.
+    pub async fn ready(&self, timeout: Duration) -> bool {
+        let mut rx = self.cut.subscribe();
+        let ready = async move {
+            let _ = rx
+                .wait_for(|_| {
+                    let window = self.window.lock().unwrap();
+                    window.ended || !window.segments.is_empty()
+                })
+                .await;
+        };
+        tokio::time::timeout(timeout, ready).await.is_ok()
+    }
+
+    /// The media playlist, with `query` appended to each segment URI so
+    /// an access token carries over. None before the first segment.
+    pub fn playlist(&self, query: &str) -> Option<String> {
+        let window = self.window.lock().unwrap();
+        let skip = window.segments.len().saturating_sub(LISTED_SEGMENTS);
+        let listed: Vec<&Cached> = window.segments.iter().skip(skip).collect();
+        let first = listed.first()?;
+        let target = listed
+            .iter()
+            .map(|segment| segment.duration.ceil() as u64)
+            .max()
+            .unwrap_or_default()
+            .max(1);
+        let query = if query.is_empty() {
+            String::new()
+        } else {
+            format!("?{}", query)
+        };
+
+        let mut out = format!(
+            "#EXTM3U\n#EXT-X-VERSION:3\n#EXT-X-TARGETDURATION:{}\n#EXT-X-MEDIA-SEQUENCE:{}\n",
+            target, first.sequence
+        );
+        for segment in &listed {
+            out.push_str(&format!(
+                "#EXTINF:{:.3},\n{}.ts{}\n",
+                segment.duration, segment.sequence, query
+            ));
+        }
+        if window.ended {
+            out.push_str("#EXT-X-ENDLIST\n");
+        }
+        Some(out)
+    }
+
+    /// A segment still in the window.
+    pub fn segment(&self, sequence: u64) -> Option<Bytes> {
+        let window = self.window.lock().unwrap();
+        window
+            .segments
+            .iter()
+            .find(|segment| segment.sequence == sequence)
+            .map(|segment| segment.data.clone())
+    }
+}
+
+/// HLS sessions running, by (platform, room_id).
+#[derive(Default, Clone)]
+pub struct HlsSessions(Arc<Mutex<HashMap<RoomKey, Arc<HlsSession>>>>);
+
+impl HlsSessions {
+    /// The running session of a room, counted as a request.
+    pub fn get(&self, key: &RoomKey) -> Option<Arc<HlsSession>> {
+        let session = self.0.lock().unwrap().get(key).cloned()?;
+        session.touch();
+        Some(session)
+    }
+
+    /// Start segmenting a relay viewer for a room. If another request got
+    /// there first, its session wins and `viewer` is dropped.
+    pub fn start(&self, key: RoomKey, viewer: RelayStream) -> Arc<HlsSession> {
+        let mut sessions = self.0.lock().unwrap();
+        if let Some(session) = sessions.get(&key) {
+            session.touch();
+            return session.clone();
+        }
+        let session = Arc::new(HlsSession {
+            window: Mutex::new(Window {
+                segments: VecDeque::new(),
+                ended: false,
+                last_request: Instant::now(),
+            }),
+            cut: watch::channel(0).0,
+        });
+        sessions.insert(key.clone(), session.clone());
+        tokio::spawn(self.clone().run(key, session.clone(), viewer));
+        session
+    }
+
+    async fn run(self, key: RoomKey, session: Arc<HlsSession>, mut viewer: RelayStream) {
+        let mut reader = TagReader::new();
+        let mut segmenter = HlsSegmenter::new(TARGET_SEGMENT_MS);
+        'pull: loop {
+            if session.window.lock().unwrap().last_request.elapsed() > SESSION_IDLE {
+                break;
+            }
+            let chunk = match tokio::time::timeout(IDLE_CHECK, viewer.next()).await {
+                Ok(Some(Ok(chunk))) => chunk,
+                Ok(Some(Err(never))) => match never {},
+                Ok(None) => break,
+                Err(_) => continue,
+            };
+            reader.push(&chunk);
+            loop {
+                let segment = match reader.next_item() {
+                    Ok(Some(FlvItem::Header(header))) => {
+                        segmenter.push_header(&header);
+                        continue;
+                    }
+                    Ok(Some(FlvItem::Tag(tag))) => segmenter.push(&tag),
+                    Ok(None) => break,
+                    Err(flv::NotFlv) => {
+                        tracing::warn!(
+                            "[dtv-server/hls.rs] {}/{} is not an FLV stream; nothing to segment",
+                            key.0,
+                            key.1
+                        );
+                        break 'pull;
+                    }
+                };
+                if let Some(Segment {
+                    sequence,
+                    duration,
+                    data,
+                }) = segment
+                {
+                    let mut window = session.window.lock().unwrap();
+                    window.segments.push_back(Cached {
+                        sequence,
+                        duration,
+                        data: Bytes::from(data),
+                    });
+                    while window.segments.len() > KEPT_SEGMENTS {
+                        window.segments.pop_front();
+                    }
+                    drop(window);
+                    session.cut.send_modify(|n| *n += 1);
+                }
+            }
+        }
+
+        session.window.lock().unwrap().ended = true;
+        session.cut.send_modify(|n| *n += 1);
+        let mut sessions = self.0.lock().unwrap();
+        if sessions.get(&key).is_some_and(|s| Arc::ptr_eq(s, &session)) {
+            sessions.remove(&key);
+        }
+        tracing::debug!(
+            "[dtv-server/hls.rs] HLS session for {}/{} stopped",
+            key.0,
+            key.1
+        );
+    }
+}
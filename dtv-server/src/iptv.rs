@@ -0,0 +1,230 @@
+// IPTV view of the follow list for TV apps: an M3U playlist of the followed
+// rooms that are live, each channel a /live URL the proxy resolves when it
+// is opened, and an XMLTV guide built from the room titles and categories.
+use actix_web::{web, HttpRequest, HttpResponse};
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+
+use dtv_core::platforms::common::LiveStatus;
+use dtv_core::player::{m3u_channel, PlayerSource};
+use dtv_core::DtvClient;
+
+use crate::api::{room_statuses, urlencode};
+use crate::auth::ApiToken;
+use crate::follows::FollowStore;
+
+// Live rooms have no schedule; the guide shows the current title this long
+const PROGRAMME_HOURS: i64 = 4;
+
+/// `/playlist.m3u` and `/epg.xml`. Expects `web::Data<DtvClient>` and
+/// `web::Data<FollowStore>` in app data next to the proxy's own.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/playlist.m3u", web::get().to(playlist))
+        .route("/epg.xml", web::get().to(epg));
+}
+
+struct Channel {
+    id: String,
+    name: String,
+    logo: Option<String>,
+    group: String,
+    title: Option<String>,
+    category: Option<String>,
+    url: String,
+}
+
+// Absolute URLs on this server, carrying the token TV apps cannot send as a header
+struct Links {
+    base: String,
+    query: String,
+}
+
+impl Links {
+    fn new(req: &HttpRequest, token: Option<&ApiToken>) -> Self {
+        let info = req.connection_info();
+        Self {
+            base: format!("{}://{}", info.scheme(), info.host()),
+            query: token
+                .and_then(|t| t.0.as_deref())
+                .map(|t| format!("?token={}", urlencode(t)))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}{}", self.base, path, self.query)
+    }
+}
+
+async fn live_channels(dtv: &DtvClient, follows: &FollowStore, links: &Links) -> Vec<Channel> {
+    let entries = follows.list().await;
+    let statuses = room_statuses(dtv, entries.clone()).await;
+    entries
+        .into_iter()
+        .zip(statuses)
+        .filter(|(_, status)| status.status == LiveStatus::Live)
+        .map(|(entry, status)| {
+            let platform = entry.platform.as_str().to_string();
+            let room = status.room;
+            Channel {
+                id: format!("{}.{}", platform, entry.room_id),
+                name: entry
+                    .name
+                    .filter(|n| !n.trim().is_empty())
+                    .or(room.anchor_name)
+                    .unwrap_or_else(|| entry.room_id.clone()),
+                logo: room.avatar,
+                url: links.url(&format!("/live/{}/{}.flv", platform, entry.room_id)),
+                group: platform,
+                title: room.title,
+                category: room.category,
+            }
+        })
+        .collect()
+}
+
+fn playlist_m3u(channels: &[Channel], epg_url: &str) -> String {
+    let mut playlist = format!("#EXTM3U x-tvg-url=\"{}\"\n", epg_url);
+    for channel in channels {
+        let source = PlayerSource {
+            url: channel.url.clone(),
+            title: Some(channel.name.clone()),
+            ..Default::default()
+        };
+        let mut attributes = vec![
+            ("tvg-id", channel.id.as_str()),
+            ("tvg-name", channel.name.as_str()),
+        ];
+        if let Some(logo) = &channel.logo {
+            attributes.push(("tvg-logo", logo.as_str()));
+        }
+        attributes.push(("group-title", channel.group.as_str()));
+        playlist.push_str(&m3u_channel(&source, &attributes));
+    }
+    playlist
+}
+
+fn xml_escape(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_control() || matches!(c, '\t' | '\n'))
+        .fold(String::with_capacity(text.len()), |mut out, c| {
+            match c {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '"' => out.push_str("&quot;"),
+                '\'' => out.push_str("&apos;"),
+                c => out.push(c),
+            }
+            out
+        })
+}
+
+// One programme per channel, from the top of the current hour
+fn epg_xml(channels: &[Channel], now: DateTime<Utc>) -> String {
+    let start = now.duration_trunc(TimeDelta::hours(1)).unwrap_or(now);
+    let stop = start + TimeDelta::hours(PROGRAMME_HOURS);
+    let stamp = |t: DateTime<Utc>| t.format("%Y%m%d%H%M%S +0000").to_string();
+
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<tv generator-info-name=\"DTV\">\n",
+    );
+    for channel in channels {
+        let id = xml_escape(&channel.id);
+        xml.push_str(&format!("  <channel id=\"{}\">\n", id));
+        xml.push_str(&format!(
+            "    <display-name>{}</display-name>\n",
+            xml_escape(&channel.name)
+        ));
+        if let Some(logo) = &channel.logo {
+            xml.push_str(&format!("    <icon src=\"{}\"/>\n", xml_escape(logo)));
+        }
+        xml.push_str("  </channel>\n");
+    }
+    for channel in channels {
+        xml.push_str(&format!(
+            "  <programme start=\"{}\" stop=\"{}\" channel=\"{}\">\n",
+            stamp(start),
+            stamp(stop),
+            xml_escape(&channel.id)
+        ));
+        let title = channel.title.as_deref().unwrap_or(&channel.name);
+        xml.push_str(&format!("    <title>{}</title>\n", xml_escape(title)));
+        if let Some(category) = &channel.category {
+            xml.push_str(&format!(
+                "    <category>{}</category>\n",
+                xml_escape(category)
+            ));
+        }
+        xml.push_str("  </programme>\n");
+    }
+    xml.push_str("</tv>\n");
+    xml
+}
+
+async fn playlist(
+    req: HttpRequest,
+    dtv: web::Data<DtvClient>,
+    follows: web::Data<FollowStore>,
+    token: Option<web::Data<ApiToken>>,
+) -> HttpResponse {
+    let links = Links::new(&req, token.as_ref().map(|t| t.get_ref()));
+    let channels = live_channels(&dtv, &follows, &links).await;
+    HttpResponse::Ok()
+        .content_type("audio/x-mpegurl; charset=utf-8")
+        .insert_header(("Cache-Control", "no-store"))
+        .body(playlist_m3u(&channels, &links.url("/epg.xml")))
+}
+
+async fn epg(
+    req: HttpRequest,
+    dtv: web::Data<DtvClient>,
+    follows: web::Data<FollowStore>,
+    token: Option<web::Data<ApiToken>>,
+) -> HttpResponse {
+    let links = Links::new(&req, token.as_ref().map(|t| t.get_ref()));
+    let channels = live_channels(&dtv, &follows, &links).await;
+    HttpResponse::Ok()
+        .content_type("application/xml; charset=utf-8")
+        .insert_header(("Cache-Control", "no-store"))
+        .body(epg_xml(&channels, Utc::now()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn channel() -> Channel {
+        Channel {
+            id: "douyu.9999".to_string(),
+            name: "主播 <A&B>".to_string(),
+            logo: Some("https://apic.douyucdn.cn/a.png?x=1&y=2".to_string()),
+            group: "douyu".to_string(),
+            title: Some("今晚 \"决赛\"".to_string()),
+            category: Some("英雄联盟".to_string()),
+            url: "http://127.0.0.1:34800/live/douyu/9999.flv?token=t".to_string(),
+        }
+    }
+
+    #[test]
+    fn builds_playlist_and_guide() {
+        assert_eq!(
+            playlist_m3u(&[channel()], "http://127.0.0.1:34800/epg.xml?token=t"),
+            "#EXTM3U x-tvg-url=\"http://127.0.0.1:34800/epg.xml?token=t\"\n\
+             #EXTINF:-1 tvg-id=\"douyu.9999\" tvg-name=\"主播 <A&B>\" \
+             tvg-logo=\"https://apic.douyucdn.cn/a.png?x=1&y=2\" group-title=\"douyu\",主播 <A&B>\n\
+             http://127.0.0.1:34800/live/douyu/9999.flv?token=t\n"
+        );
+
+        let now = Utc.with_ymd_and_hms(2026, 10, 19, 21, 37, 5).unwrap();
+        let xml = epg_xml(&[channel()], now);
+        assert!(xml.contains("<display-name>主播 &lt;A&amp;B&gt;</display-name>"));
+        assert!(xml.contains("<icon src=\"https://apic.douyucdn.cn/a.png?x=1&amp;y=2\"/>"));
+        assert!(xml.contains(
+            "<programme start=\"20261019210000 +0000\" stop=\"20261020010000 +0000\" \
+             channel=\"douyu.9999\">"
+        ));
+        assert!(xml.contains("<title>今晚 &quot;决赛&quot;</title>"));
+        assert!(xml.contains("<category>英雄联盟</category>"));
+    }
+}
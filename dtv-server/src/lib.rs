@@ -0,0 +1,14 @@
+//! HTTP side of DTV: the FLV/image proxy shared with the desktop app, and a
+//! headless REST/danmaku server for machines without a desktop.
+pub mod api;
+pub mod auth;
+pub mod danmaku_http;
+pub mod danmaku_hub;
+pub mod follows;
+pub mod hls;
+pub mod iptv;
+pub mod proxy;
+pub mod relay;
+mod server;
+
+pub use server::{run, ServerConfig};
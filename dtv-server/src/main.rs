@@ -0,0 +1,105 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use dtv_core::webhooks::{WebhookDispatcher, WebhookSettings};
+use dtv_core::DtvClient;
+use dtv_server::ServerConfig;
+
+const USAGE: &str = "Usage: dtv-server [--bind ADDR] [--token TOKEN] [--data-dir DIR] [--no-auth]
+                  [--follow-poll SECS]
+
+Environment: DTV_SERVER_BIND, DTV_SERVER_TOKEN, DTV_SERVER_DATA_DIR,
+             DTV_SERVER_FOLLOW_POLL, DTV_DEBUG=1
+
+Webhooks are read from DIR/webhooks.json, danmaku scripts from DIR/scripts/ and
+platform plugins from DIR/plugins/; see doc/headless-server.md.";
+
+fn parse_secs(raw: &str) -> Result<Duration, String> {
+    raw.parse()
+        .map(Duration::from_secs)
+        .map_err(|_| format!("Invalid number of seconds: {}", raw))
+}
+
+// Flags override environment variables
+fn parse_config() -> Result<ServerConfig, String> {
+    let mut config = ServerConfig::default();
+    if let Ok(bind) = env::var("DTV_SERVER_BIND") {
+        config.bind = bind;
+    }
+    config.token = env::var("DTV_SERVER_TOKEN").ok();
+    if let Ok(dir) = env::var("DTV_SERVER_DATA_DIR") {
+        config.data_dir = PathBuf::from(dir);
+    }
+    if let Ok(secs) = env::var("DTV_SERVER_FOLLOW_POLL") {
+        config.follow_poll = parse_secs(&secs)?;
+    }
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or_else(|| format!("{} needs a value", name));
+        match arg.as_str() {
+            "--bind" => config.bind = value("--bind")?,
+            "--token" => config.token = Some(value("--token")?),
+            "--data-dir" => config.data_dir = PathBuf::from(value("--data-dir")?),
+            "--no-auth" => config.allow_no_auth = true,
+            "--follow-poll" => config.follow_poll = parse_secs(&value("--follow-poll")?)?,
+            "-h" | "--help" => return Err(String::new()),
+            other => return Err(format!("Unknown argument: {}", other)),
+        }
+    }
+    Ok(config)
+}
+
+fn build_client(config: &ServerConfig) -> Result<DtvClient, String> {
+    let mut builder = DtvClient::builder();
+    let settings = WebhookSettings::load(&config.data_dir.join("webhooks.json"))?;
+    if !settings.hooks.is_empty() {
+        tracing::info!("[dtv-server] {} webhooks configured", settings.hooks.len());
+        let queue = config.data_dir.join("webhook_queue.json");
+        builder = builder.webhooks(WebhookDispatcher::new(settings, Some(queue))?);
+    }
+    // Picked up (and hot-reloaded) once files appear in the directory
+    #[cfg(feature = "js-runtime")]
+    {
+        let scripts = dtv_core::scripting::ScriptHost::new(config.data_dir.join("scripts"))?;
+        let plugins = dtv_core::plugins::PluginHost::new(config.data_dir.join("plugins"))?;
+        builder = builder.scripts(scripts).plugins(plugins);
+    }
+    builder.build().map_err(|e| e.to_string())
+}
+
+#[actix_web::main]
+async fn main() -> ExitCode {
+    let config = match parse_config() {
+        Ok(c) => c,
+        Err(e) => {
+            if !e.is_empty() {
+                eprintln!("{}\n", e);
+            }
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let level = if env::var("DTV_DEBUG").map(|v| v == "1").unwrap_or(false) {
+        tracing::Level::DEBUG
+    } else {
+        tracing::Level::INFO
+    };
+    tracing_subscriber::fmt().with_max_level(level).init();
+
+    let dtv = match build_client(&config) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("[dtv-server] failed to create client: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(e) = dtv_server::run(config, dtv).await {
+        eprintln!("[dtv-server] {}", e);
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,512 @@
+use actix_web::{web, HttpRequest, HttpResponse, Responder};
+use dtv_core::platforms::common::{
+    GetLiveStreamRequest, LiveStatus, LiveStreamMode, Playback, StreamHeaders, StreamType,
+};
+use dtv_core::DtvClient;
+use reqwest::Client;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::hls::{HlsSessions, FIRST_SEGMENT_TIMEOUT};
+use crate::relay::{audio_only, AudioFormat, FlvRelays, RelayStream, Upstream};
+
+// For images, and streams whose resolver named no User-Agent
+const FALLBACK_UA: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36";
+
+/// An upstream stream and the request headers its CDN expects.
+#[derive(Debug, Default, Clone)]
+pub struct StreamEntry {
+    pub url: String,
+    pub headers: StreamHeaders,
+}
+
+impl From<&Playback> for StreamEntry {
+    fn from(playback: &Playback) -> Self {
+        Self {
+            url: playback.url.clone(),
+            headers: playback.headers.clone(),
+        }
+    }
+}
+
+#[derive(Default, Clone)]
+pub struct StreamUrlStore {
+    pub urls: Arc<Mutex<HashMap<(String, String), StreamEntry>>>, // (platform, room_id) -> stream
+    // Audio-only playbacks, resolved separately at the lowest quality
+    pub audio_urls: Arc<Mutex<HashMap<(String, String), StreamEntry>>>,
+    // Upstream pulls in progress, shared by everyone watching the same stream
+    pub relays: FlvRelays,
+    // FLV rooms being re-served as HLS
+    pub hls: HlsSessions,
+}
+
+impl StreamUrlStore {
+    fn entries(&self, output: Output) -> &Mutex<HashMap<(String, String), StreamEntry>> {
+        match output {
+            Output::Flv => &self.urls,
+            Output::Audio(_) => &self.audio_urls,
+        }
+    }
+}
+
+// Long-lived upstream client for FLV/image pulls; build one per actix worker
+pub fn proxy_client() -> Client {
+    Client::builder()
+        .no_proxy()
+        .http1_only()
+        .gzip(false)
+        .brotli(false)
+        .no_deflate()
+        .pool_idle_timeout(None)
+        .pool_max_idle_per_host(4)
+        .tcp_keepalive(Duration::from_secs(60))
+        .timeout(Duration::from_secs(7200))
+        .build()
+        .expect("failed to build client")
+}
+
+/// Media routes shared by the desktop proxy and the headless server.
+/// Expects `web::Data<StreamUrlStore>` and `web::Data<Client>` in app data;
+/// with a `web::Data<DtvClient>` too, unknown rooms are resolved on request.
+/// `/audio/{platform}/{room_id}.aac` (ADTS) or `.flv` (audio tags only)
+/// serves just the sound of the room's audio-only playback.
+/// `/hls/{platform}/{room_id}/index.m3u8` remuxes the FLV stream into a
+/// rolling MPEG-TS playlist for players without flv.js.
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/live/{platform}/{room_id}", web::get().to(flv_proxy_handler))
+        .route("/audio/{platform}/{room_id}", web::get().to(audio_proxy_handler))
+        .route("/hls/{platform}/{room_id}/{file}", web::get().to(hls_handler))
+        .route("/image", web::get().to(image_proxy_handler));
+}
+
+// Kuaishou serves pages, images and streams from several CDN domains
+fn is_kuaishou_url(url: &str) -> bool {
+    ["kuaishou.com", "yximgs.com", "kwimgs.com", "kwaicdn.com", "gifshow.com"]
+        .iter()
+        .any(|domain| url.contains(domain))
+}
+
+// NetEase CC: pages on cc.163.com, images and streams on netease/126.net CDNs
+fn is_cc_url(url: &str) -> bool {
+    ["cc.163.com", "netease.com", "126.net"]
+        .iter()
+        .any(|domain| url.contains(domain))
+}
+
+#[derive(Deserialize)]
+struct ImageQuery {
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct LiveQuery {
+    // "1"/"true": start from the latest keyframe instead of the cached GOP
+    low_latency: Option<String>,
+}
+
+async fn image_proxy_handler(
+    query: web::Query<ImageQuery>,
+    client: web::Data<Client>,
+) -> impl Responder {
+    let url = query.url.clone();
+    if url.is_empty() {
+        return HttpResponse::BadRequest().body("Missing url query parameter");
+    }
+
+    let mut req = client
+        .get(&url)
+        .header("User-Agent", FALLBACK_UA)
+        .header(
+            "Accept",
+            "image/avif,image/webp,image/apng,image/*;q=0.8,*/*;q=0.5",
+        );
+
+    // Set a Referer to bypass hotlink protections
+    if url.contains("hdslb.com") || url.contains("bilibili.com") {
+        req = req
+            .header("Referer", "https://live.bilibili.com/")
+            .header("Origin", "https://live.bilibili.com");
+    } else if url.contains("huya.com") {
+        req = req
+            .header("Referer", "https://www.huya.com/")
+            .header("Origin", "https://www.huya.com");
+    } else if url.contains("douyin") || url.contains("douyinpic.com") {
+        req = req.header("Referer", "https://www.douyin.com/");
+    } else if is_kuaishou_url(&url) {
+        req = req
+            .header("Referer", "https://live.kuaishou.com/")
+            .header("Origin", "https://live.kuaishou.com");
+    } else if is_cc_url(&url) {
+        req = req.header("Referer", "https://cc.163.com/");
+    }
+
+    match req.send().await {
+        Ok(upstream_response) => {
+            let content_type = upstream_response
+                .headers()
+                .get(reqwest::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or("application/octet-stream")
+                .to_string();
+
+            // 为避免 Windows 下 chunked 传输的 Early-EOF，改为一次性读取 bytes 并返回
+            if upstream_response.status().is_success() {
+                match upstream_response.bytes().await {
+                    Ok(bytes) => HttpResponse::Ok()
+                        .content_type(content_type)
+                        .insert_header(("Content-Length", bytes.len().to_string()))
+                        .insert_header(("Cache-Control", "no-store"))
+                        .body(bytes),
+                    Err(e) => {
+                        tracing::error!("[dtv-server/proxy.rs image] Failed to read bytes: {}", e);
+                        HttpResponse::InternalServerError()
+                            .body(format!("Failed to read image bytes: {}", e))
+                    }
+                }
+            } else {
+                let status_from_reqwest = upstream_response.status();
+                let error_text = upstream_response
+                    .text()
+                    .await
+                    .unwrap_or_else(|e| format!("Failed to read error body from upstream: {}", e));
+                tracing::error!(
+                    "[dtv-server/proxy.rs image] Upstream request to {} failed with status: {}. Body: {}",
+                    url, status_from_reqwest, error_text
+                );
+                let actix_status_code =
+                    actix_web::http::StatusCode::from_u16(status_from_reqwest.as_u16())
+                        .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR);
+
+                HttpResponse::build(actix_status_code).body(format!(
+                    "Error fetching IMAGE from upstream (reqwest): {}. Status: {}. Details: {}",
+                    url, status_from_reqwest, error_text
+                ))
+            }
+        }
+        Err(e) => {
+            tracing::error!(
+                "[dtv-server/proxy.rs image] Failed to send request to upstream {}: {}",
+                url, e
+            );
+            HttpResponse::InternalServerError()
+                .body(format!("Error connecting to upstream IMAGE {}: {}", url, e))
+        }
+    }
+}
+
+// Resolve a room in playback mode, for requests that come before (or long
+// after) anyone called get_live_stream: IPTV channels, external players.
+async fn resolve_playback(
+    dtv: &DtvClient,
+    platform: &str,
+    room_id: &str,
+    audio_only: bool,
+) -> Result<Playback, String> {
+    let platform = crate::api::parse_platform(platform)
+        .map_err(|_| format!("Unknown or disabled platform: {}", platform))?;
+    let response = dtv
+        .get_live_stream(GetLiveStreamRequest {
+            platform: platform.clone(),
+            room_id: room_id.to_string(),
+            quality: None,
+            line: None,
+            cookie: None,
+            debug: Some(false),
+            mode: Some(LiveStreamMode::Playback),
+            with_options: None,
+            codec: None,
+            probe: None,
+            audio_only: Some(audio_only),
+        })
+        .await;
+    match response.playback {
+        Some(playback) if response.status == LiveStatus::Live => Ok(playback),
+        _ => Err(response
+            .error
+            .unwrap_or_else(|| format!("{}/{} is not live", platform.as_str(), room_id))),
+    }
+}
+
+async fn send_upstream(client: &Client, entry: &StreamEntry) -> reqwest::Result<reqwest::Response> {
+    let mut req = client
+        .get(&entry.url)
+        .header("Accept", "video/x-flv,application/octet-stream,*/*")
+        .header("Range", "bytes=0-")
+        .header("Connection", "keep-alive");
+    // The resolver's headers, exactly as it sent them
+    if !entry.headers.keys().any(|k| k.eq_ignore_ascii_case("user-agent")) {
+        req = req.header("User-Agent", FALLBACK_UA);
+    }
+    for (name, value) in &entry.headers {
+        req = req.header(name.as_str(), value.as_str());
+    }
+    req.send().await
+}
+
+// What a proxied room is served as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Output {
+    Flv,
+    Audio(AudioFormat),
+}
+
+fn stream_response(output: Output, viewer: RelayStream) -> HttpResponse {
+    let (content_type, body) = match output {
+        Output::Flv => ("video/x-flv", viewer),
+        Output::Audio(format @ AudioFormat::Flv) => ("video/x-flv", audio_only(viewer, format)),
+        Output::Audio(format @ AudioFormat::Adts) => ("audio/aac", audio_only(viewer, format)),
+    };
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .insert_header(("Connection", "keep-alive"))
+        .insert_header(("Cache-Control", "no-store"))
+        .insert_header(("Accept-Ranges", "bytes"))
+        .streaming(body)
+}
+
+// `/live/{platform}/{room_id}.flv`: joins the room's shared upstream relay
+// (resolving the stream first when it is not cached) and streams it as FLV.
+// `?low_latency=1` starts new viewers at the latest keyframe rather than the
+// whole GOP cache.
+async fn flv_proxy_handler(
+    _req: HttpRequest,
+    path: web::Path<(String, String)>, // (platform, room_id)
+    query: web::Query<LiveQuery>,
+    stream_url_store: web::Data<StreamUrlStore>,
+    client: web::Data<Client>,
+    dtv: Option<web::Data<DtvClient>>,
+) -> impl Responder {
+    let (platform, room_id) = path.into_inner();
+    let room_id = room_id.trim_end_matches(".flv").to_string();
+    let low_latency = matches!(query.low_latency.as_deref(), Some("1" | "true"));
+    proxy_room(
+        Output::Flv,
+        (platform, room_id),
+        low_latency,
+        &stream_url_store,
+        &client,
+        dtv.as_ref().map(|d| d.get_ref()),
+    )
+    .await
+}
+
+async fn audio_proxy_handler(
+    path: web::Path<(String, String)>, // (platform, room_id.aac|.flv)
+    stream_url_store: web::Data<StreamUrlStore>,
+    client: web::Data<Client>,
+    dtv: Option<web::Data<DtvClient>>,
+) -> impl Responder {
+    let (platform, file) = path.into_inner();
+    let (room_id, format) = match file.rsplit_once('.') {
+        Some((room_id, "flv")) => (room_id, AudioFormat::Flv),
+        Some((room_id, "aac")) => (room_id, AudioFormat::Adts),
+        _ => (file.as_str(), AudioFormat::Adts),
+    };
+    // Sound needs no head start from a cached GOP
+    proxy_room(
+        Output::Audio(format),
+        (platform, room_id.to_string()),
+        true,
+        &stream_url_store,
+        &client,
+        dtv.as_ref().map(|d| d.get_ref()),
+    )
+    .await
+}
+
+async fn hls_handler(
+    req: HttpRequest,
+    path: web::Path<(String, String, String)>, // (platform, room_id, index.m3u8|{seq}.ts)
+    stream_url_store: web::Data<StreamUrlStore>,
+    client: web::Data<Client>,
+    dtv: Option<web::Data<DtvClient>>,
+) -> impl Responder {
+    let (platform, room_id, file) = path.into_inner();
+    let key = (platform, room_id);
+
+    if let Some(sequence) = file.strip_suffix(".ts") {
+        let segment = sequence
+            .parse()
+            .ok()
+            .and_then(|sequence| stream_url_store.hls.get(&key)?.segment(sequence));
+        return match segment {
+            Some(data) => HttpResponse::Ok().content_type("video/mp2t").body(data),
+            None => HttpResponse::NotFound().body(format!("Segment {} is gone or never existed.", file)),
+        };
+    }
+    if file != "index.m3u8" {
+        return HttpResponse::NotFound().finish();
+    }
+
+    let session = match stream_url_store.hls.get(&key) {
+        Some(session) => session,
+        // The cached GOP gives the first segment a head start
+        None => match open_stream(
+            Output::Flv,
+            key.clone(),
+            false,
+            &stream_url_store,
+            &client,
+            dtv.as_ref().map(|d| d.get_ref()),
+        )
+        .await
+        {
+            Ok(viewer) => stream_url_store.hls.start(key.clone(), viewer),
+            Err(response) => return response,
+        },
+    };
+    session.ready(FIRST_SEGMENT_TIMEOUT).await;
+    match session.playlist(req.query_string()) {
+        Some(playlist) => HttpResponse::Ok()
+            .content_type("application/vnd.apple.mpegurl")
+            .insert_header(("Cache-Control", "no-cache"))
+            .body(playlist),
+        None => HttpResponse::ServiceUnavailable().body(format!(
+            "No HLS segment for {}/{} yet; only H.264/H.265 with AAC FLV streams can be remuxed.",
+            key.0, key.1
+        )),
+    }
+}
+
+async fn proxy_room(
+    output: Output,
+    key: (String, String),
+    low_latency: bool,
+    stream_url_store: &StreamUrlStore,
+    client: &Client,
+    dtv: Option<&DtvClient>,
+) -> HttpResponse {
+    match open_stream(output, key, low_latency, stream_url_store, client, dtv).await {
+        Ok(viewer) => stream_response(output, viewer),
+        Err(response) => response,
+    }
+}
+
+// A viewer of the room's relay, resolving the room or re-resolving an
+// expired URL on the way; otherwise the response to send instead.
+async fn open_stream(
+    output: Output,
+    key: (String, String),
+    low_latency: bool,
+    stream_url_store: &StreamUrlStore,
+    client: &Client,
+    dtv: Option<&DtvClient>,
+) -> Result<RelayStream, HttpResponse> {
+    let (platform, room_id) = key.clone();
+    let audio = matches!(output, Output::Audio(_));
+
+    let stored = {
+        let urls = stream_url_store.entries(output).lock().unwrap();
+        urls.get(&key).cloned().filter(|entry| !entry.url.is_empty())
+    };
+    let resolved_now = stored.is_none();
+    let mut entry = match (stored, dtv) {
+        (Some(entry), _) => entry,
+        (None, None) => {
+            return Err(HttpResponse::NotFound().body(format!("Stream URL for {}/{} is not set or empty.", platform, room_id)));
+        }
+        (None, Some(dtv)) => match resolve_playback(dtv, &platform, &room_id, audio).await {
+            // Only FLV goes through here; HLS players fetch the playlist themselves
+            Ok(playback) if playback.stream_type != StreamType::Flv => {
+                return Err(HttpResponse::Found()
+                    .insert_header(("Location", playback.url))
+                    .finish());
+            }
+            Ok(playback) => {
+                let entry = StreamEntry::from(&playback);
+                stream_url_store
+                    .entries(output)
+                    .lock()
+                    .unwrap()
+                    .insert(key.clone(), entry.clone());
+                entry
+            }
+            Err(e) => return Err(HttpResponse::NotFound().body(e)),
+        },
+    };
+
+    tracing::debug!(
+        "[dtv-server/proxy.rs handler] Incoming FLV proxy request for {}/{} -> {}",
+        platform, room_id, entry.url
+    );
+
+    let mut joined = stream_url_store
+        .relays
+        .join(&entry.url, low_latency, || connect_upstream(client, &entry))
+        .await;
+    // Signed URLs expire; a stored one that stopped working gets one fresh try
+    if joined.is_err() && !resolved_now {
+        if let Some(dtv) = dtv {
+            match resolve_playback(dtv, &platform, &room_id, audio).await {
+                Ok(playback) if playback.stream_type == StreamType::Flv => {
+                    entry = StreamEntry::from(&playback);
+                    stream_url_store
+                        .entries(output)
+                        .lock()
+                        .unwrap()
+                        .insert(key.clone(), entry.clone());
+                    joined = stream_url_store
+                        .relays
+                        .join(&entry.url, low_latency, || connect_upstream(client, &entry))
+                        .await;
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!(
+                    "[dtv-server/proxy.rs handler] Re-resolving {}/{} failed: {}",
+                    platform, room_id, e
+                ),
+            }
+        }
+    }
+
+    joined.map_err(|error| {
+        // Another viewer's connect failed; it got the details
+        error.unwrap_or_else(|| {
+            HttpResponse::BadGateway().body(format!("Error connecting to upstream FLV stream {}", entry.url))
+        })
+    })
+}
+
+// The upstream body once the CDN has answered with success; otherwise the
+// error response for the viewer.
+async fn connect_upstream(client: &Client, entry: &StreamEntry) -> Result<Upstream, HttpResponse> {
+    let url = &entry.url;
+    match send_upstream(client, entry).await {
+        Ok(upstream_response) => {
+            if upstream_response.status().is_success() {
+                Ok(Box::pin(upstream_response.bytes_stream()))
+            } else {
+                let status_from_reqwest = upstream_response.status(); // Renamed for clarity
+                let error_text = upstream_response
+                    .text()
+                    .await
+                    .unwrap_or_else(|e| format!("Failed to read error body from upstream: {}", e));
+                tracing::error!(
+                    "[dtv-server/proxy.rs handler] Upstream request to {} failed with status: {}. Body: {}",
+                    url, status_from_reqwest, error_text
+                );
+                // Convert reqwest::StatusCode to actix_web::http::StatusCode
+                let actix_status_code =
+                    actix_web::http::StatusCode::from_u16(status_from_reqwest.as_u16())
+                        .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR);
+
+                Err(HttpResponse::build(actix_status_code).body(format!(
+                    "Error fetching FLV stream from upstream (reqwest): {}. Status: {}. Details: {}",
+                    url, status_from_reqwest, error_text
+                )))
+            }
+        }
+        Err(e) => {
+            tracing::error!(
+                "[dtv-server/proxy.rs handler] Failed to send request to upstream {} with reqwest: {}",
+                url, e
+            );
+            Err(HttpResponse::InternalServerError().body(format!(
+                "Error connecting to upstream FLV stream {} with reqwest: {}",
+                url, e
+            )))
+        }
+    }
+}
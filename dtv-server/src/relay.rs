@@ -0,0 +1,517 @@
+// One upstream pull per FLV stream, shared by everyone watching it. Tags are
+// parsed on the way through and the latest GOPs kept with the stream's
+// headers, so a new viewer starts on a cached keyframe at once instead of
+// waiting seconds for the CDN's next one.
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use actix_web::web::Bytes;
+use futures_util::{stream, Stream, StreamExt};
+use tokio::sync::{broadcast, OnceCell};
+
+use dtv_core::media::aac::AdtsMuxer;
+use dtv_core::media::flv::{self, FlvItem, RawTag, TagKind, TagReader};
+
+// About ten seconds of 60 fps video plus audio; a viewer further behind skips ahead
+const CHANNEL_PACKETS: usize = 1024;
+// Beyond this the cache holds no GOP, e.g. streams with very long keyframe intervals
+const MAX_CACHE_BYTES: usize = 32 * 1024 * 1024;
+// The latest complete GOP plus the one in progress
+const CACHED_GOPS: usize = 2;
+// How often a silent upstream is checked for viewers that have all left
+const IDLE_CHECK: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Role {
+    // File header, metadata and sequence headers: every viewer needs them
+    Config,
+    // Where a viewer can start decoding
+    Keyframe,
+    Frame,
+}
+
+#[derive(Debug, Clone)]
+struct Packet {
+    bytes: Bytes,
+    role: Role,
+}
+
+#[derive(Default)]
+struct GopCache {
+    header: Option<Bytes>,
+    metadata: Option<Bytes>,
+    video_config: Option<Bytes>,
+    audio_config: Option<Bytes>,
+    gops: VecDeque<Vec<Bytes>>,
+    size: usize,
+    has_video: bool,
+}
+
+impl GopCache {
+    fn push(&mut self, tag: RawTag) -> Packet {
+        let data = tag.data();
+        self.has_video |= tag.kind == TagKind::Video;
+        let config = match tag.kind {
+            TagKind::Script if flv::parse_metadata(data).is_some() => Some(&mut self.metadata),
+            TagKind::Video if flv::is_sequence_header(tag.kind, data) => {
+                Some(&mut self.video_config)
+            }
+            TagKind::Audio if flv::is_sequence_header(tag.kind, data) => {
+                Some(&mut self.audio_config)
+            }
+            _ => None,
+        };
+        let role = match tag.kind {
+            TagKind::Video if flv::is_keyframe(data) => Role::Keyframe,
+            // Audio-only streams can start anywhere
+            TagKind::Audio if !self.has_video => Role::Keyframe,
+            _ => Role::Frame,
+        };
+        let bytes = Bytes::from(tag.bytes);
+        if let Some(slot) = config {
+            *slot = Some(bytes.clone());
+            return Packet {
+                bytes,
+                role: Role::Config,
+            };
+        }
+
+        if role == Role::Keyframe && tag.kind == TagKind::Video {
+            self.gops.push_back(Vec::new());
+            while self.gops.len() > CACHED_GOPS {
+                self.drop_oldest();
+            }
+        }
+        if let Some(gop) = self.gops.back_mut() {
+            self.size += bytes.len();
+            gop.push(bytes.clone());
+        }
+        while self.size > MAX_CACHE_BYTES && !self.gops.is_empty() {
+            self.drop_oldest();
+        }
+        Packet { bytes, role }
+    }
+
+    fn drop_oldest(&mut self) {
+        if let Some(gop) = self.gops.pop_front() {
+            self.size -= gop.iter().map(Bytes::len).sum::<usize>();
+        }
+    }
+
+    // What a new viewer gets before the live feed: headers, then the cached
+    // GOPs, or only the one from the latest keyframe for low latency
+    fn snapshot(&self, low_latency: bool) -> Vec<Bytes> {
+        let configs = [
+            &self.header,
+            &self.metadata,
+            &self.video_config,
+            &self.audio_config,
+        ];
+        let skip = if low_latency {
+            self.gops.len().saturating_sub(1)
+        } else {
+            0
+        };
+        configs
+            .into_iter()
+            .flatten()
+            .cloned()
+            .chain(self.gops.iter().skip(skip).flatten().cloned())
+            .collect()
+    }
+}
+
+struct RelayState {
+    cache: GopCache,
+    tx: broadcast::Sender<Packet>,
+    // Not FLV after all: bytes are forwarded untouched and never shared
+    passthrough: bool,
+}
+
+struct Relay {
+    url: String,
+    state: Mutex<RelayState>,
+}
+
+impl Relay {
+    // A relay and the receiver of its first viewer, taken before the pump
+    // starts so it cannot see zero receivers and stop
+    fn new(url: &str) -> (Arc<Self>, broadcast::Receiver<Packet>) {
+        let (tx, rx) = broadcast::channel(CHANNEL_PACKETS);
+        let relay = Arc::new(Relay {
+            url: url.to_string(),
+            state: Mutex::new(RelayState {
+                cache: GopCache::default(),
+                tx,
+                passthrough: false,
+            }),
+        });
+        (relay, rx)
+    }
+
+    // A viewer starting from the cache; None once it turned out not to be
+    // FLV, as those bytes cannot be joined midway
+    fn subscribe(&self, low_latency: bool) -> Option<RelayStream> {
+        let state = self.state.lock().unwrap();
+        if state.passthrough {
+            return None;
+        }
+        let snapshot = state.cache.snapshot(low_latency);
+        Some(viewer(snapshot, state.tx.subscribe()))
+    }
+}
+
+/// Byte stream for one viewer of a relayed stream.
+pub type RelayStream = std::pin::Pin<Box<dyn Stream<Item = Result<Bytes, Infallible>> + Send>>;
+
+/// An upstream body, as `reqwest::Response::bytes_stream` yields it.
+pub type Upstream = std::pin::Pin<Box<dyn Stream<Item = reqwest::Result<Bytes>> + Send>>;
+
+// Filled once the first viewer's connect finishes: the relay, or None if the
+// connect failed. Viewers arriving meanwhile wait on it.
+type Slot = OnceCell<Option<Arc<Relay>>>;
+
+/// The relays running or connecting, by upstream URL. Each stops once its
+/// last viewer disconnects; a new quality or line is a new URL and a new relay.
+#[derive(Default, Clone)]
+pub struct FlvRelays(Arc<Mutex<HashMap<String, Arc<Slot>>>>);
+
+impl FlvRelays {
+    /// A viewer of the relay of `url`. The slot is taken under the lock
+    /// before connecting, so of any number of viewers arriving together only
+    /// one runs `connect`; the rest wait and share its upstream. Err holds
+    /// this caller's connect error, or None when another viewer's connect
+    /// failed.
+    pub async fn join<F, Fut, E>(
+        &self,
+        url: &str,
+        low_latency: bool,
+        connect: F,
+    ) -> Result<RelayStream, Option<E>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<Upstream, E>>,
+    {
+        let slot = {
+            let mut relays = self.0.lock().unwrap();
+            let slot = relays.entry(url.to_string()).or_default();
+            // A failed connect not cleared yet: start over
+            if matches!(slot.get(), Some(None)) {
+                *slot = Arc::default();
+            }
+            slot.clone()
+        };
+
+        let mut connect = Some(connect);
+        let mut first_viewer = None;
+        let mut error = None;
+        let relay = slot
+            .get_or_init(|| async {
+                let connect = connect.take().expect("connect runs once");
+                match connect().await {
+                    Ok(upstream) => {
+                        let (relay, rx) = Relay::new(url);
+                        first_viewer = Some(viewer(Vec::new(), rx));
+                        tokio::spawn(self.clone().pump(slot.clone(), relay.clone(), upstream));
+                        Some(relay)
+                    }
+                    Err(e) => {
+                        error = Some(e);
+                        None
+                    }
+                }
+            })
+            .await
+            .clone();
+
+        let Some(relay) = relay else {
+            let mut relays = self.0.lock().unwrap();
+            if relays.get(url).is_some_and(|s| Arc::ptr_eq(s, &slot)) {
+                relays.remove(url);
+            }
+            return Err(error);
+        };
+        if let Some(viewer) = first_viewer.or_else(|| relay.subscribe(low_latency)) {
+            return Ok(viewer);
+        }
+        // Not FLV, so not shared: this viewer gets an upstream of its own
+        let connect = connect.expect("connect unused by a waiting viewer");
+        let upstream = connect().await.map_err(Some)?;
+        let (relay, rx) = Relay::new(url);
+        tokio::spawn(self.clone().pump(Arc::default(), relay, upstream));
+        Ok(viewer(Vec::new(), rx))
+    }
+
+    async fn pump(self, slot: Arc<Slot>, relay: Arc<Relay>, mut body: Upstream) {
+        let mut reader = TagReader::new();
+        loop {
+            let chunk = match tokio::time::timeout(IDLE_CHECK, body.next()).await {
+                Ok(Some(Ok(chunk))) => chunk,
+                Ok(Some(Err(e))) => {
+                    tracing::error!("[dtv-server/relay.rs] Upstream {} failed: {}", relay.url, e);
+                    break;
+                }
+                Ok(None) => break,
+                // A stalled upstream would otherwise be held open with nobody watching
+                Err(_) => {
+                    if relay.state.lock().unwrap().tx.receiver_count() == 0 {
+                        break;
+                    }
+                    continue;
+                }
+            };
+            let mut state = relay.state.lock().unwrap();
+            if state.passthrough {
+                let _ = state.tx.send(Packet {
+                    bytes: chunk,
+                    role: Role::Frame,
+                });
+            } else {
+                reader.push(&chunk);
+                state.forward(&mut reader);
+            }
+            if state.tx.receiver_count() == 0 {
+                break;
+            }
+        }
+
+        let mut relays = self.0.lock().unwrap();
+        if relays
+            .get(&relay.url)
+            .is_some_and(|s| Arc::ptr_eq(s, &slot))
+        {
+            relays.remove(&relay.url);
+        }
+        tracing::debug!("[dtv-server/relay.rs] Relay of {} stopped", relay.url);
+    }
+}
+
+impl RelayState {
+    // Cache and broadcast every complete item in `reader`
+    fn forward(&mut self, reader: &mut TagReader) {
+        loop {
+            let packet = match reader.next_item() {
+                Ok(Some(FlvItem::Header(header))) => {
+                    let bytes = Bytes::from(header);
+                    self.cache.header = Some(bytes.clone());
+                    Packet {
+                        bytes,
+                        role: Role::Config,
+                    }
+                }
+                Ok(Some(FlvItem::Tag(tag))) => self.cache.push(tag),
+                Ok(None) => return,
+                Err(flv::NotFlv) => {
+                    // Found out before anything was sent, so the bytes so far go out whole
+                    self.passthrough = true;
+                    Packet {
+                        bytes: Bytes::from(reader.take_buffered()),
+                        role: Role::Frame,
+                    }
+                }
+            };
+            let _ = self.tx.send(packet);
+            if self.passthrough {
+                return;
+            }
+        }
+    }
+}
+
+// The snapshot, then the live feed. A viewer that falls too far behind
+// loses what it missed and resumes at the next keyframe.
+fn viewer(snapshot: Vec<Bytes>, rx: broadcast::Receiver<Packet>) -> RelayStream {
+    let live = stream::unfold((rx, false), |(mut rx, mut skipping)| async move {
+        loop {
+            match rx.recv().await {
+                Ok(packet) => {
+                    if skipping && packet.role == Role::Frame {
+                        continue;
+                    }
+                    skipping &= packet.role != Role::Keyframe;
+                    return Some((Ok(packet.bytes), (rx, skipping)));
+                }
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    tracing::warn!(
+                        "[dtv-server/relay.rs] Viewer fell {} packets behind; skipping to the next keyframe",
+                        missed
+                    );
+                    skipping = true;
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+    Box::pin(stream::iter(snapshot.into_iter().map(Ok)).chain(live))
+}
+
+/// What the audio-only endpoint sends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFormat {
+    // FLV with the audio tags alone
+    Flv,
+    // Bare AAC frames, e.g. for <audio> or any music player
+    Adts,
+}
+
+/// Strip a viewer's stream down to its sound.
+pub fn audio_only(viewer: RelayStream, format: AudioFormat) -> RelayStream {
+    let state = (viewer, TagReader::new(), AdtsMuxer::default());
+    Box::pin(stream::unfold(
+        state,
+        move |(mut viewer, mut reader, mut adts)| async move {
+            loop {
+                let chunk = match viewer.next().await? {
+                    Ok(chunk) => chunk,
+                    Err(never) => match never {},
+                };
+                reader.push(&chunk);
+                let mut out = Vec::new();
+                loop {
+                    match reader.next_item() {
+                        Ok(Some(FlvItem::Header(mut header))) => {
+                            if format == AudioFormat::Flv {
+                                // Flags: audio only, so players do not wait for video
+                                header[4] = 0x04;
+                                out.extend_from_slice(&header);
+                            }
+                        }
+                        // Metadata goes too: it describes the video
+                        Ok(Some(FlvItem::Tag(tag))) if tag.kind == TagKind::Audio => match format {
+                            AudioFormat::Flv => out.extend_from_slice(&tag.bytes),
+                            AudioFormat::Adts => {
+                                out.extend(adts.push(tag.data()).unwrap_or_default())
+                            }
+                        },
+                        Ok(Some(FlvItem::Tag(_))) => {}
+                        Ok(None) => break,
+                        Err(flv::NotFlv) => {
+                            tracing::warn!(
+                                "[dtv-server/relay.rs] Not an FLV stream; no audio to extract"
+                            );
+                            return None;
+                        }
+                    }
+                }
+                if !out.is_empty() {
+                    return Some((Ok(Bytes::from(out)), (viewer, reader, adts)));
+                }
+            }
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Only the body matters to the cache
+    fn tag(kind: TagKind, data: &[u8]) -> RawTag {
+        let mut bytes = vec![0; flv::TAG_HEADER_LEN];
+        bytes.extend_from_slice(data);
+        bytes.extend_from_slice(&[0; 4]);
+        RawTag {
+            kind,
+            timestamp: 0,
+            bytes,
+        }
+    }
+
+    fn push(cache: &mut GopCache, tag: RawTag) -> Role {
+        cache.push(tag).role
+    }
+
+    #[test]
+    fn caches_headers_and_latest_gops() {
+        let mut cache = GopCache {
+            header: Some(Bytes::from_static(b"FLV")),
+            ..Default::default()
+        };
+        let avc_config = tag(TagKind::Video, &[0x17, 0x00, 0, 0, 0]);
+        let aac_config = tag(TagKind::Audio, &[0xaf, 0x00, 0x12, 0x10]);
+        let key = |n: u8| tag(TagKind::Video, &[0x17, 0x01, 0, 0, 0, n]);
+        let inter = |n: u8| tag(TagKind::Video, &[0x27, 0x01, 0, 0, 0, n]);
+        let audio = tag(TagKind::Audio, &[0xaf, 0x01, 0x21]);
+
+        assert_eq!(push(&mut cache, audio.clone()), Role::Keyframe);
+        assert_eq!(push(&mut cache, avc_config.clone()), Role::Config);
+        assert_eq!(push(&mut cache, aac_config.clone()), Role::Config);
+        // Frames before the first keyframe are no use to anyone joining
+        assert_eq!(push(&mut cache, inter(0)), Role::Frame);
+        assert_eq!(cache.snapshot(false).len(), 3);
+
+        for gop in 1..=3 {
+            assert_eq!(push(&mut cache, key(gop)), Role::Keyframe);
+            assert_eq!(push(&mut cache, audio.clone()), Role::Frame);
+            push(&mut cache, inter(gop));
+        }
+        let header = Bytes::from_static(b"FLV");
+        let bytes = |tag: RawTag| Bytes::from(tag.bytes);
+        let configs = vec![header, bytes(avc_config), bytes(aac_config)];
+        let gop = |n: u8| vec![bytes(key(n)), bytes(audio.clone()), bytes(inter(n))];
+
+        assert_eq!(
+            cache.snapshot(false),
+            [configs.clone(), gop(2), gop(3)].concat()
+        );
+        assert_eq!(cache.snapshot(true), [configs, gop(3)].concat());
+    }
+
+    #[tokio::test]
+    async fn concurrent_viewers_share_one_upstream() {
+        let relays = FlvRelays::default();
+        let connects = AtomicUsize::new(0);
+        let header = Bytes::from_static(b"FLV\x01\x05\x00\x00\x00\x09\x00\x00\x00\x00");
+        let connect = || async {
+            connects.fetch_add(1, Ordering::SeqCst);
+            // Slow enough that the second viewer arrives mid-connect
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            let body = stream::iter([Ok(header.clone())]).chain(stream::pending());
+            Ok::<Upstream, ()>(Box::pin(body))
+        };
+
+        let (first, second) = tokio::join!(
+            relays.join("http://cdn/1.flv", false, connect),
+            relays.join("http://cdn/1.flv", false, connect)
+        );
+        assert_eq!(connects.load(Ordering::SeqCst), 1);
+        for mut viewer in [first.unwrap(), second.unwrap()] {
+            assert_eq!(viewer.next().await.unwrap().unwrap(), header);
+        }
+
+        // A failed connect frees the slot for the next viewer
+        let failed = relays
+            .join("http://cdn/2.flv", false, || async {
+                Err::<Upstream, _>("403")
+            })
+            .await;
+        assert!(matches!(failed, Err(Some("403"))));
+        assert!(relays
+            .join("http://cdn/2.flv", false, connect)
+            .await
+            .is_ok());
+        assert_eq!(connects.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stalled_upstream_stops_after_last_viewer_leaves() {
+        let relays = FlvRelays::default();
+        let viewer = relays
+            .join("http://cdn/3.flv", false, || async {
+                Ok::<Upstream, ()>(Box::pin(stream::pending()))
+            })
+            .await
+            .unwrap();
+
+        tokio::time::sleep(IDLE_CHECK * 2).await;
+        assert!(relays.0.lock().unwrap().contains_key("http://cdn/3.flv"));
+
+        drop(viewer);
+        tokio::time::sleep(IDLE_CHECK * 2).await;
+        assert!(relays.0.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,138 @@
+use std::io;
+use std::net::ToSocketAddrs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use actix_web::middleware::from_fn;
+use actix_web::{web, App, HttpServer};
+
+use dtv_core::DtvClient;
+
+use crate::api::{self, ApiState};
+use crate::auth::{is_loopback_origin, require_token, ApiToken};
+use crate::danmaku_http;
+use crate::danmaku_hub::DanmakuHub;
+use crate::follows::FollowStore;
+use crate::iptv;
+use crate::proxy::{self, proxy_client, StreamUrlStore};
+
+pub const DEFAULT_BIND: &str = "127.0.0.1:34800";
+
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub bind: String,
+    pub token: Option<String>,
+    // Where follows.json lives
+    pub data_dir: PathBuf,
+    // Serve a non-loopback address without a token
+    pub allow_no_auth: bool,
+    // How often followed rooms are checked for live.* webhooks; zero disables
+    pub follow_poll: Duration,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind: DEFAULT_BIND.to_string(),
+            token: None,
+            data_dir: PathBuf::from("dtv-data"),
+            allow_no_auth: false,
+            follow_poll: Duration::from_secs(120),
+        }
+    }
+}
+
+// Keeps the client's status tracker fed so live.started/live.ended fire
+// without anyone having to open the follow list.
+async fn poll_follows(state: web::Data<ApiState>, every: Duration) {
+    let mut ticker = tokio::time::interval(every);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        let statuses = api::follow_statuses(&state.dtv, &state.follows).await;
+        tracing::debug!("[dtv-server] polled {} followed rooms", statuses.len());
+    }
+}
+
+fn is_loopback(bind: &str) -> io::Result<bool> {
+    Ok(bind.to_socket_addrs()?.all(|addr| addr.ip().is_loopback()))
+}
+
+// Any site may call in once a token guards the API; without one only pages
+// served from this machine may.
+fn cors(has_token: bool) -> actix_cors::Cors {
+    if has_token {
+        return actix_cors::Cors::permissive();
+    }
+    actix_cors::Cors::default()
+        .allowed_origin_fn(|origin, _| is_loopback_origin(origin.as_bytes()))
+        .allow_any_method()
+        .allow_any_header()
+        .expose_any_header()
+}
+
+/// Serve the REST API, danmaku streams and the media proxy until shutdown.
+pub async fn run(config: ServerConfig, dtv: DtvClient) -> io::Result<()> {
+    let token = config.token.clone().filter(|t| !t.is_empty());
+    if token.is_none() && !config.allow_no_auth && !is_loopback(&config.bind)? {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "refusing to serve {} without a token; set one or pass --no-auth",
+                config.bind
+            ),
+        ));
+    }
+
+    let follows = Arc::new(
+        FollowStore::load(config.data_dir.join("follows.json"))
+            .await
+            .map_err(io::Error::other)?,
+    );
+    let hub = DanmakuHub::new(dtv.clone());
+    let hub_data = web::Data::new(hub.clone());
+    let state = web::Data::new(ApiState {
+        hub,
+        dtv,
+        streams: StreamUrlStore::default(),
+        follows: follows.clone(),
+    });
+    let streams = web::Data::new(state.streams.clone());
+    // For lazy /live resolution and the IPTV playlist
+    let dtv_data = web::Data::new(state.dtv.clone());
+    let follows_data = web::Data::from(follows);
+    let has_token = token.is_some();
+    let token_data = web::Data::new(ApiToken(token));
+
+    let wants_status = state
+        .dtv
+        .webhooks()
+        .is_some_and(|hooks| hooks.wants("live.started") || hooks.wants("live.ended"));
+    if wants_status && !config.follow_poll.is_zero() {
+        actix_web::rt::spawn(poll_follows(state.clone(), config.follow_poll));
+    }
+
+    tracing::info!("[dtv-server] listening on http://{}", config.bind);
+    HttpServer::new(move || {
+        App::new()
+            .app_data(state.clone())
+            .app_data(streams.clone())
+            .app_data(hub_data.clone())
+            .app_data(dtv_data.clone())
+            .app_data(follows_data.clone())
+            .app_data(token_data.clone())
+            .app_data(web::Data::new(proxy_client()))
+            .wrap(from_fn(require_token))
+            // Outermost so CORS preflights never hit the token check
+            .wrap(cors(has_token))
+            .configure(api::configure)
+            .configure(proxy::configure)
+            .configure(iptv::configure)
+            .configure(danmaku_http::configure)
+    })
+    .keep_alive(Duration::from_secs(120))
+    .bind(&config.bind)?
+    .run()
+    .await
+}
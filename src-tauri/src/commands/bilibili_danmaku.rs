@@ -3,7 +3,8 @@ use std::sync::Arc;
 use tauri::Emitter;
 use tokio::sync::mpsc as tokio_mpsc;
 
-use dtv_core::danmaku::DanmakuHandler;
+use dtv_core::platforms::common::types::SupportedPlatform;
+use dtv_server::danmaku_hub::DanmakuHub;
 
 #[tauri::command]
 pub async fn start_bilibili_danmaku_listener(
@@ -11,6 +12,8 @@ pub async fn start_bilibili_danmaku_listener(
     cookie: Option<String>,
     app_handle: tauri::AppHandle,
     state: tauri::State<'_, dtv_core::platforms::common::BilibiliDanmakuState>,
+    hub: tauri::State<'_, Arc<DanmakuHub>>,
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
 ) -> Result<(), String> {
     let room_id = payload.args.room_id_str;
 
@@ -22,35 +25,28 @@ pub async fn start_bilibili_danmaku_listener(
         let _ = tx.send(()).await;
     }
 
-    let (tx_shutdown, rx_shutdown) = tokio_mpsc::channel::<()>(1);
+    let (tx_shutdown, mut rx_shutdown) = tokio_mpsc::channel::<()>(1);
     {
         let mut lock = state.inner().0.lock().unwrap();
         *lock = Some(tx_shutdown);
     }
 
-    struct TauriAppDanmakuHandler {
-        app_handle: tauri::AppHandle,
+    // The shared session reads the cookie from the client; without one,
+    // keep whatever is already stored
+    if let Some(cookie) = cookie.filter(|c| !c.trim().is_empty()) {
+        dtv.set_cookie(SupportedPlatform::Bilibili, Some(cookie));
     }
-
-    impl DanmakuHandler for TauriAppDanmakuHandler {
-        fn emit_json(&self, event: &str, payload: serde_json::Value) {
-            let _ = self.app_handle.emit(event, payload);
-        }
-    }
-
-    let handler: Arc<dyn DanmakuHandler> = Arc::new(TauriAppDanmakuHandler {
-        app_handle: app_handle.clone(),
-    });
-
-    tokio::spawn(async move {
-        dtv_core::platforms::bilibili::danmaku::run_bilibili_danmaku_listener(
-            room_id,
-            cookie,
-            rx_shutdown,
-            handler,
-        )
-        .await;
-    });
+    super::danmaku_session::forward_to_webview(
+        &hub,
+        SupportedPlatform::Bilibili,
+        room_id,
+        async move {
+            let _ = rx_shutdown.recv().await;
+        },
+        move |event, payload| {
+            let _ = app_handle.emit(event, payload);
+        },
+    );
 
     Ok(())
 }
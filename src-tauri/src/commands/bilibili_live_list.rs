@@ -3,13 +3,7 @@ pub async fn fetch_bilibili_live_list(
     area_id: String,
     parent_area_id: String,
     page: u32,
-    state: tauri::State<'_, dtv_core::platforms::bilibili::state::BilibiliState>,
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
 ) -> Result<String, String> {
-    dtv_core::platforms::bilibili::live_list::fetch_bilibili_live_list(
-        area_id,
-        parent_area_id,
-        page,
-        state.inner(),
-    )
-    .await
+    dtv.bilibili_live_list(area_id, parent_area_id, page).await
 }
@@ -5,6 +5,7 @@ pub async fn search_bilibili_rooms(
     keyword: String,
     page: Option<u32>,
     cookie: Option<String>,
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
 ) -> Result<Vec<BilibiliSearchItem>, String> {
-    dtv_core::platforms::bilibili::search::search_bilibili_rooms(keyword, page, cookie).await
+    dtv.bilibili_search(keyword, page, cookie).await
 }
@@ -0,0 +1,19 @@
+use dtv_core::platforms::cc::live_list::CcLiveListResponse;
+use dtv_core::platforms::common::types::CommonCategoryGroup;
+
+#[tauri::command]
+pub async fn fetch_cc_categories(
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
+) -> Result<Vec<CommonCategoryGroup>, String> {
+    dtv.cc_categories().await
+}
+
+#[tauri::command]
+pub async fn fetch_cc_live_list(
+    gametype: Option<String>,
+    page: u32,
+    page_size: u32,
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
+) -> Result<CcLiveListResponse, String> {
+    dtv.cc_live_list(gametype, page, page_size).await
+}
@@ -0,0 +1,33 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use dtv_core::platforms::common::types::SupportedPlatform;
+use dtv_server::danmaku_hub::DanmakuHub;
+
+/// Feed the webview from the room's shared listener session until `stop`
+/// resolves. Overlays on the local proxy read from the same session, so the
+/// upstream connection outlives this subscriber while any overlay is open.
+pub fn forward_to_webview<S, E>(
+    hub: &Arc<DanmakuHub>,
+    platform: SupportedPlatform,
+    room_id: String,
+    stop: S,
+    emit: E,
+) where
+    S: Future<Output = ()> + Send + 'static,
+    E: Fn(&str, serde_json::Value) + Send + 'static,
+{
+    let mut subscription = hub.subscribe(platform, room_id);
+    tokio::spawn(async move {
+        tokio::pin!(stop);
+        loop {
+            tokio::select! {
+                _ = &mut stop => break,
+                frame = subscription.recv() => match frame {
+                    Some(frame) => emit(&frame.event, frame.data.clone()),
+                    None => break,
+                },
+            }
+        }
+    });
+}
@@ -6,12 +6,8 @@ pub async fn fetch_douyin_partition_rooms(
     partition_type: String,
     offset: i32,
     ms_token: String,
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
 ) -> Result<DouyinLiveListResponse, String> {
-    dtv_core::platforms::douyin::douyin_streamer_list::fetch_douyin_partition_rooms(
-        partition,
-        partition_type,
-        offset,
-        ms_token,
-    )
-    .await
+    dtv.douyin_partition_rooms(partition, partition_type, offset, Some(ms_token))
+        .await
 }
@@ -4,6 +4,9 @@ use std::sync::{Arc, Mutex};
 use tokio::sync::oneshot;
 use tauri::Emitter;
 
+use dtv_core::platforms::common::types::SupportedPlatform;
+use dtv_server::danmaku_hub::DanmakuHub;
+
 // State for managing Douyu danmaku listener handles (stop signals)
 #[derive(Default, Clone)]
 pub struct DouyuDanmakuHandles(pub Arc<Mutex<HashMap<String, oneshot::Sender<()>>>>);
@@ -13,6 +16,7 @@ pub async fn start_danmaku_listener(
     room_id: String,
     window: tauri::Window,
     danmaku_handles: tauri::State<'_, DouyuDanmakuHandles>,
+    hub: tauri::State<'_, Arc<DanmakuHub>>,
 ) -> Result<(), String> {
     if let Some(existing_sender) = danmaku_handles.0.lock().unwrap().remove(&room_id) {
         let _ = existing_sender.send(());
@@ -25,34 +29,17 @@ pub async fn start_danmaku_listener(
         .unwrap()
         .insert(room_id.clone(), stop_tx);
 
-    struct TauriWindowDanmakuHandler {
-        window: tauri::Window,
-    }
-
-    impl dtv_core::danmaku::DanmakuHandler for TauriWindowDanmakuHandler {
-        fn emit_json(&self, event: &str, payload: serde_json::Value) {
-            let _ = self.window.emit(event, payload);
-        }
-    }
-
-    let handler: Arc<dyn dtv_core::danmaku::DanmakuHandler> = Arc::new(TauriWindowDanmakuHandler {
-        window: window.clone(),
-    });
-
-    let room_id_clone = room_id.clone();
-    tokio::spawn(async move {
-        let mut client = dtv_core::platforms::douyu::danmaku::DanmakuClient::new(
-            &room_id_clone,
-            handler,
-            stop_rx,
-        );
-        if let Err(e) = client.start().await {
-            eprintln!(
-                "[Douyu Danmaku] client for room {} failed: {}",
-                room_id_clone, e
-            );
-        }
-    });
+    super::danmaku_session::forward_to_webview(
+        &hub,
+        SupportedPlatform::Douyu,
+        room_id,
+        async move {
+            let _ = stop_rx.await;
+        },
+        move |event, payload| {
+            let _ = window.emit(event, payload);
+        },
+    );
 
     Ok(())
 }
@@ -0,0 +1,429 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Emitter, Manager, State};
+use tauri_plugin_dialog::DialogExt;
+use tokio::sync::oneshot;
+
+use dtv_core::player::{self, ExternalPlayer, PlayerSource};
+use dtv_core::DtvClient;
+use dtv_server::danmaku_hub::DanmakuHub;
+use dtv_server::proxy::StreamEntry;
+
+use crate::platforms::common::types::SupportedPlatform;
+use crate::platforms::common::{GetLiveStreamRequest, LiveStatus, LiveStreamMode, StreamType};
+use crate::proxy::{get_proxy_url, start_proxy, ProxyServerHandle};
+use crate::StreamUrlStore;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExternalPlayRequest {
+    pub platform: SupportedPlatform,
+    pub room_id: String,
+    pub player: ExternalPlayer,
+    // Default behavior: play through the local proxy, which sends the headers itself.
+    pub direct: Option<bool>,
+    pub title: Option<String>,
+    // ASS danmaku track (path or URL)
+    pub subtitle: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalExport {
+    Command,
+    M3u,
+    Strm,
+}
+
+impl ExternalExport {
+    // File extensions a saved export may use; none for a command line
+    fn extensions(self) -> &'static [&'static str] {
+        match self {
+            ExternalExport::Command => &[],
+            ExternalExport::M3u => &["m3u", "m3u8"],
+            ExternalExport::Strm => &["strm"],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExternalExportRequest {
+    #[serde(flatten)]
+    pub play: ExternalPlayRequest,
+    pub format: ExternalExport,
+    // Also save the result to a file picked in a native save dialog
+    #[serde(default)]
+    pub save: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct ExternalPlayerProcess {
+    pub pid: u32,
+    pub platform: String,
+    pub room_id: String,
+    pub player: ExternalPlayer,
+    pub command_line: String,
+}
+
+type Running = HashMap<u32, (ExternalPlayerProcess, oneshot::Sender<()>)>;
+
+// Player executables picked in the settings, for players not on PATH
+const PLAYER_PATHS_FILE: &str = "external_players.json";
+
+/// Players launched from the app, keyed by pid until they exit.
+#[derive(Default, Clone)]
+pub struct ExternalPlayers(Arc<Mutex<Running>>);
+
+// The room's current stream: the one the app is playing when there is one,
+// otherwise resolved afresh. HLS never goes through the FLV-only proxy.
+async fn resolve_source(
+    app_handle: &AppHandle,
+    stream_url_store: &State<'_, StreamUrlStore>,
+    proxy_server_handle: &State<'_, ProxyServerHandle>,
+    dtv: &State<'_, DtvClient>,
+    danmaku_hub: &State<'_, Arc<DanmakuHub>>,
+    request: &ExternalPlayRequest,
+) -> Result<PlayerSource, String> {
+    let key = (
+        request.platform.as_str().to_string(),
+        request.room_id.trim().to_string(),
+    );
+    let stored = stream_url_store
+        .urls
+        .lock()
+        .map_err(|_| "Failed to lock StreamUrlStore".to_string())?
+        .get(&key)
+        .cloned();
+    let entry = match stored {
+        Some(entry) => entry,
+        None => {
+            let response = dtv
+                .get_live_stream(GetLiveStreamRequest {
+                    platform: request.platform.clone(),
+                    room_id: key.1.clone(),
+                    quality: None,
+                    line: None,
+                    cookie: None,
+                    debug: None,
+                    mode: Some(LiveStreamMode::Playback),
+                    with_options: None,
+                    codec: None,
+                    probe: None,
+                    audio_only: None,
+                })
+                .await;
+            let playback = match response.playback {
+                Some(playback) if response.status == LiveStatus::Live => playback,
+                _ => return Err(response.error.unwrap_or_else(|| "主播未开播".to_string())),
+            };
+            if playback.stream_type != StreamType::Flv {
+                return Ok(PlayerSource {
+                    url: playback.url,
+                    headers: playback.headers,
+                    title: request.title.clone(),
+                    subtitle: request.subtitle.clone(),
+                });
+            }
+            StreamEntry::from(&playback)
+        }
+    };
+
+    if request.direct.unwrap_or(false) {
+        return Ok(PlayerSource {
+            url: entry.url,
+            headers: entry.headers,
+            title: request.title.clone(),
+            subtitle: request.subtitle.clone(),
+        });
+    }
+    stream_url_store
+        .urls
+        .lock()
+        .map_err(|_| "Failed to lock StreamUrlStore".to_string())?
+        .insert(key.clone(), entry);
+    start_proxy(
+        app_handle.clone(),
+        proxy_server_handle.clone(),
+        stream_url_store.clone(),
+        danmaku_hub.clone(),
+    )
+    .await?;
+    Ok(PlayerSource {
+        url: get_proxy_url(&key.0, &key.1).await,
+        title: request.title.clone(),
+        subtitle: request.subtitle.clone(),
+        ..Default::default()
+    })
+}
+
+/// Open the room in mpv/VLC/ffplay. Emits `external-player-exited` with the
+/// pid once the player closes.
+#[command]
+pub async fn launch_external_player(
+    app_handle: AppHandle,
+    stream_url_store: State<'_, StreamUrlStore>,
+    proxy_server_handle: State<'_, ProxyServerHandle>,
+    dtv: State<'_, DtvClient>,
+    danmaku_hub: State<'_, Arc<DanmakuHub>>,
+    players: State<'_, ExternalPlayers>,
+    request: ExternalPlayRequest,
+) -> Result<ExternalPlayerProcess, String> {
+    let source = resolve_source(
+        &app_handle,
+        &stream_url_store,
+        &proxy_server_handle,
+        &dtv,
+        &danmaku_hub,
+        &request,
+    )
+    .await?;
+    let program = player_path(&app_handle, request.player);
+    let spec = player::launch_spec(request.player, &source, program.as_deref())?;
+    let mut child = tokio::process::Command::new(&spec.program)
+        .args(&spec.args)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .map_err(|e| format!("无法启动 {}: {}", spec.program, e))?;
+    let pid = child.id().ok_or_else(|| "播放器已退出".to_string())?;
+    let process = ExternalPlayerProcess {
+        pid,
+        platform: request.platform.as_str().to_string(),
+        room_id: request.room_id.trim().to_string(),
+        player: request.player,
+        command_line: spec.command_line(),
+    };
+    tracing::info!("[ExternalPlayer] {} started: {}", pid, process.command_line);
+
+    let (stop_tx, stop_rx) = oneshot::channel();
+    players
+        .0
+        .lock()
+        .map_err(|_| "Failed to lock ExternalPlayers".to_string())?
+        .insert(pid, (process.clone(), stop_tx));
+    let running = players.0.clone();
+    tokio::spawn(async move {
+        tokio::select! {
+            status = child.wait() => {
+                tracing::info!("[ExternalPlayer] {} exited: {:?}", pid, status);
+            }
+            _ = stop_rx => {
+                let _ = child.kill().await;
+            }
+        }
+        if let Ok(mut running) = running.lock() {
+            running.remove(&pid);
+        }
+        let _ = app_handle.emit("external-player-exited", pid);
+    });
+
+    Ok(process)
+}
+
+#[command]
+pub fn list_external_players(
+    players: State<'_, ExternalPlayers>,
+) -> Result<Vec<ExternalPlayerProcess>, String> {
+    let running = players
+        .0
+        .lock()
+        .map_err(|_| "Failed to lock ExternalPlayers".to_string())?;
+    Ok(running
+        .values()
+        .map(|(process, _)| process.clone())
+        .collect())
+}
+
+#[command]
+pub fn stop_external_player(players: State<'_, ExternalPlayers>, pid: u32) -> Result<(), String> {
+    let entry = players
+        .0
+        .lock()
+        .map_err(|_| "Failed to lock ExternalPlayers".to_string())?
+        .remove(&pid);
+    match entry {
+        Some((_, stop_tx)) => {
+            let _ = stop_tx.send(());
+            Ok(())
+        }
+        None => Err(format!("没有 pid 为 {} 的播放器", pid)),
+    }
+}
+
+/// The launch command line, or a `.m3u`/`.strm` playlist, for the room's
+/// stream. With `save`, a playlist is also written to a file the user picks in
+/// a save dialog. Proxied URLs only play while the app is running, and
+/// upstream URLs expire with their signatures.
+#[command]
+pub async fn export_external_player(
+    app_handle: AppHandle,
+    stream_url_store: State<'_, StreamUrlStore>,
+    proxy_server_handle: State<'_, ProxyServerHandle>,
+    dtv: State<'_, DtvClient>,
+    danmaku_hub: State<'_, Arc<DanmakuHub>>,
+    request: ExternalExportRequest,
+) -> Result<String, String> {
+    let ExternalExportRequest {
+        play: request,
+        format,
+        save,
+    } = request;
+    let source = resolve_source(
+        &app_handle,
+        &stream_url_store,
+        &proxy_server_handle,
+        &dtv,
+        &danmaku_hub,
+        &request,
+    )
+    .await?;
+    let content = match format {
+        ExternalExport::Command => {
+            let program = player_path(&app_handle, request.player);
+            player::launch_spec(request.player, &source, program.as_deref())?.command_line()
+        }
+        ExternalExport::M3u => player::m3u(&[source]),
+        ExternalExport::Strm => player::strm(&source),
+    };
+    if save {
+        if let Some(path) = pick_export_path(&app_handle, format, &request.room_id).await? {
+            std::fs::write(&path, &content)
+                .map_err(|e| format!("写入 {} 失败: {}", path.display(), e))?;
+        }
+    }
+    Ok(content)
+}
+
+// The destination comes from a native save dialog, never from the webview;
+// `None` when the user cancels.
+async fn pick_export_path(
+    app_handle: &AppHandle,
+    format: ExternalExport,
+    room_id: &str,
+) -> Result<Option<PathBuf>, String> {
+    let extensions = format.extensions();
+    let Some(default_extension) = extensions.first() else {
+        return Err("命令行不能保存为文件".to_string());
+    };
+    let (tx, rx) = oneshot::channel();
+    app_handle
+        .dialog()
+        .file()
+        .add_filter("Playlist", extensions)
+        .set_file_name(format!("{}.{}", room_id, default_extension))
+        .save_file(move |path| {
+            let _ = tx.send(path);
+        });
+    let Some(path) = rx.await.map_err(|_| "保存对话框已关闭".to_string())? else {
+        return Ok(None);
+    };
+    let path = path
+        .into_path()
+        .map_err(|e| format!("无效的保存路径: {}", e))?;
+    check_export_path(format, &path)?;
+    Ok(Some(path))
+}
+
+// Dialog filters are only a hint on some platforms, so the extension is
+// checked again against the export format.
+fn check_export_path(format: ExternalExport, path: &Path) -> Result<(), String> {
+    let extensions = format.extensions();
+    let matches = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            extensions
+                .iter()
+                .any(|allowed| ext.eq_ignore_ascii_case(allowed))
+        });
+    if !matches {
+        return Err(format!(
+            "{} 的扩展名必须是 .{}",
+            path.display(),
+            extensions.join(" / .")
+        ));
+    }
+    Ok(())
+}
+
+fn player_paths_file(app_handle: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app_handle
+        .path()
+        .app_config_dir()
+        .map_err(|e| format!("无法获取配置目录: {}", e))?;
+    Ok(dir.join(PLAYER_PATHS_FILE))
+}
+
+fn load_player_paths(app_handle: &AppHandle) -> HashMap<ExternalPlayer, String> {
+    player_paths_file(app_handle)
+        .ok()
+        .and_then(|file| std::fs::read_to_string(file).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+// Only an existing executable named after the player itself (mpv, vlc,
+// ffplay), so the webview cannot point a launch at anything else.
+fn check_player_path(player: ExternalPlayer, path: &str) -> Result<(), String> {
+    let path = std::path::Path::new(path);
+    let named_after_player = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .is_some_and(|stem| stem.eq_ignore_ascii_case(player.program()));
+    if !path.is_absolute() || !path.is_file() || !named_after_player {
+        return Err(format!(
+            "{} 不是 {} 可执行文件的完整路径",
+            path.display(),
+            player.program()
+        ));
+    }
+    Ok(())
+}
+
+// The saved executable for `player`, if it still checks out; otherwise the
+// player is looked up on PATH.
+fn player_path(app_handle: &AppHandle, player: ExternalPlayer) -> Option<String> {
+    let path = load_player_paths(app_handle).remove(&player)?;
+    match check_player_path(player, &path) {
+        Ok(()) => Some(path),
+        Err(e) => {
+            tracing::warn!("[ExternalPlayer] Ignoring saved path: {}", e);
+            None
+        }
+    }
+}
+
+/// Player executables saved in the settings.
+#[command]
+pub fn get_external_player_paths(app_handle: AppHandle) -> HashMap<ExternalPlayer, String> {
+    load_player_paths(&app_handle)
+}
+
+/// Save the executable to launch `player` with, or forget it with `None`
+/// (look it up on PATH again).
+#[command]
+pub fn set_external_player_path(
+    app_handle: AppHandle,
+    player: ExternalPlayer,
+    path: Option<String>,
+) -> Result<(), String> {
+    let mut paths = load_player_paths(&app_handle);
+    match path.map(|p| p.trim().to_string()).filter(|p| !p.is_empty()) {
+        Some(path) => {
+            check_player_path(player, &path)?;
+            paths.insert(player, path);
+        }
+        None => {
+            paths.remove(&player);
+        }
+    }
+    let file = player_paths_file(&app_handle)?;
+    if let Some(dir) = file.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("创建 {} 失败: {}", dir.display(), e))?;
+    }
+    let json = serde_json::to_string_pretty(&paths).map_err(|e| e.to_string())?;
+    std::fs::write(&file, json).map_err(|e| format!("写入 {} 失败: {}", file.display(), e))
+}
@@ -4,6 +4,7 @@ use dtv_core::platforms::huya::search::HuyaAnchorItem;
 pub async fn search_huya_anchors(
     keyword: String,
     page: Option<usize>,
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
 ) -> Result<Vec<HuyaAnchorItem>, String> {
-    dtv_core::platforms::huya::search::search_huya_anchors(keyword, page).await
+    dtv.huya_search(keyword, page).await
 }
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use tauri::{command, AppHandle, State};
+
+use dtv_server::danmaku_hub::DanmakuHub;
+use dtv_server::follows::{FollowEntry, FollowStore};
+
+use crate::proxy::{start_proxy, ProxyServerHandle};
+use crate::StreamUrlStore;
+
+/// Mirror the follow list into the local proxy's `/playlist.m3u` and
+/// `/epg.xml`, starting the proxy if needed. Returns the playlist URL.
+#[command]
+pub async fn sync_followed_rooms(
+    app_handle: AppHandle,
+    stream_url_store: State<'_, StreamUrlStore>,
+    proxy_server_handle: State<'_, ProxyServerHandle>,
+    danmaku_hub: State<'_, Arc<DanmakuHub>>,
+    follows: State<'_, Arc<FollowStore>>,
+    rooms: Vec<FollowEntry>,
+) -> Result<String, String> {
+    follows.replace(rooms).await?;
+    let base = start_proxy(
+        app_handle,
+        proxy_server_handle,
+        stream_url_store,
+        danmaku_hub,
+    )
+    .await?;
+    Ok(format!("{}/playlist.m3u", base))
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use tauri::Emitter;
+use tokio::sync::mpsc as tokio_mpsc;
+
+use dtv_core::platforms::common::types::SupportedPlatform;
+use dtv_server::danmaku_hub::DanmakuHub;
+
+#[tauri::command]
+pub async fn start_kuaishou_danmaku_listener(
+    payload: dtv_core::platforms::common::GetStreamUrlPayload,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, dtv_core::platforms::common::KuaishouDanmakuState>,
+    hub: tauri::State<'_, Arc<DanmakuHub>>,
+) -> Result<(), String> {
+    let room_id_or_url = payload.args.room_id_str;
+
+    let previous_tx = {
+        let mut lock = state.inner().0.lock().unwrap();
+        lock.take()
+    };
+    if let Some(tx) = previous_tx {
+        let _ = tx.send(()).await;
+    }
+
+    let (tx_shutdown, mut rx_shutdown) = tokio_mpsc::channel::<()>(1);
+    {
+        let mut lock = state.inner().0.lock().unwrap();
+        *lock = Some(tx_shutdown);
+    }
+
+    super::danmaku_session::forward_to_webview(
+        &hub,
+        SupportedPlatform::Kuaishou,
+        room_id_or_url,
+        async move {
+            let _ = rx_shutdown.recv().await;
+        },
+        move |event, payload| {
+            let _ = app_handle.emit(event, payload);
+        },
+    );
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn stop_kuaishou_danmaku_listener(
+    room_id: String,
+    state: tauri::State<'_, dtv_core::platforms::common::KuaishouDanmakuState>,
+) -> Result<(), String> {
+    let _ = room_id;
+    let tx = {
+        let mut lock = state.inner().0.lock().unwrap();
+        lock.take()
+    };
+    if let Some(tx) = tx {
+        let _ = tx.send(()).await;
+    }
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use dtv_core::platforms::kuaishou::search::KuaishouSearchItem;
+
+#[tauri::command]
+pub async fn search_kuaishou_authors(
+    keyword: String,
+    page: Option<u32>,
+    cookie: Option<String>,
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
+) -> Result<Vec<KuaishouSearchItem>, String> {
+    dtv.kuaishou_search(keyword, page, cookie).await
+}
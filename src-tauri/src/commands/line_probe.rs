@@ -0,0 +1,18 @@
+use tauri::State;
+
+use dtv_core::DtvClient;
+
+use crate::platforms::common::types::SupportedPlatform;
+use crate::platforms::common::LineProbe;
+
+/// Speed test of a live room's CDN lines, fastest first.
+#[tauri::command]
+pub async fn probe_stream_lines(
+    dtv: State<'_, DtvClient>,
+    platform: SupportedPlatform,
+    room_id: String,
+) -> Result<Vec<LineProbe>, String> {
+    dtv.probe_lines(platform, &room_id)
+        .await
+        .map_err(|e| e.to_string())
+}
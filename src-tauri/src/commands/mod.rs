@@ -1,10 +1,19 @@
 pub mod live_stream_v2_cmd;
+pub mod line_probe;
+pub mod external_player;
+pub mod iptv;
 pub mod douyin_streamer_list;
 pub mod douyu_categories;
 pub mod douyu_live_list;
 pub mod huya_search;
 pub mod huya_live_list;
 pub mod huya_danmaku;
+pub mod kuaishou_danmaku;
+pub mod kuaishou_search;
+pub mod cc_danmaku;
+pub mod cc_live_list;
+pub mod twitch_danmaku;
+pub mod twitch_search;
 pub mod douyu_three_cate;
 pub mod bilibili_search;
 pub mod bilibili_danmaku;
@@ -12,3 +21,4 @@ pub mod bilibili_live_list;
 pub mod douyu_danmaku;
 pub mod douyin_danmaku;
 pub mod douyin_ms_token;
+pub mod danmaku_session;
@@ -0,0 +1,10 @@
+use dtv_core::platforms::twitch::search::TwitchSearchItem;
+
+#[tauri::command]
+pub async fn search_twitch_channels(
+    keyword: String,
+    page: Option<u32>,
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
+) -> Result<Vec<TwitchSearchItem>, String> {
+    dtv.twitch_search(keyword, page).await
+}
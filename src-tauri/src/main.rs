@@ -2,9 +2,7 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use reqwest;
-use std::collections::HashMap;
 use std::env;
-use std::sync::{Arc, Mutex};
 use tauri::Manager;
 mod commands;
 mod platforms;
@@ -13,17 +11,15 @@ use platforms::common::{DouyinDanmakuState, FollowHttpClient, HuyaDanmakuState};
 // Douyin danmaku + msToken commands live in commands module
 // use platforms::huya::get_huya_stream_url_with_quality; // removed in favor of unified cmd
 
-#[derive(Default, Clone)]
-pub struct StreamUrlStore {
-    pub urls: Arc<Mutex<HashMap<(String, String), String>>>, // (platform, room_id) -> url
-}
+pub use dtv_server::proxy::StreamUrlStore;
 
 // search_anchor seems fine, assuming douyu::search_anchor is correct
 #[tauri::command]
-async fn search_anchor(keyword: String) -> Result<String, String> {
-    platforms::douyu::perform_anchor_search(&keyword)
-        .await
-        .map_err(|e| e.to_string())
+async fn search_anchor(
+    keyword: String,
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
+) -> Result<String, String> {
+    dtv.douyu_search(&keyword).await
 }
 
 // Main function corrected
@@ -35,6 +31,39 @@ fn main() {
         .build()
         .expect("Failed to create reqwest client");
     let follow_http_client = FollowHttpClient::new().expect("Failed to create follow http client");
+    let bilibili_state = platforms::bilibili::state::BilibiliState::default();
+    // Shares the follow client and Bilibili state with the commands that still take them directly
+    let mut dtv_builder = dtv_core::DtvClient::builder()
+        .follow_http(follow_http_client.clone())
+        .bilibili_state(bilibili_state.clone());
+    // Optional webhooks file (same format as dtv-server's webhooks.json)
+    if let Ok(path) = env::var("DTV_WEBHOOKS_FILE") {
+        let path = std::path::PathBuf::from(path);
+        let queue = path.with_extension("queue.json");
+        match dtv_core::webhooks::WebhookSettings::load(&path)
+            .and_then(|settings| dtv_core::webhooks::WebhookDispatcher::new(settings, Some(queue)))
+        {
+            Ok(dispatcher) => dtv_builder = dtv_builder.webhooks(dispatcher),
+            Err(e) => eprintln!("[Webhooks] {}", e),
+        }
+    }
+    // Optional directory of danmaku scripts, hot-reloaded on change
+    if let Ok(dir) = env::var("DTV_SCRIPTS_DIR") {
+        match dtv_core::scripting::ScriptHost::new(dir) {
+            Ok(host) => dtv_builder = dtv_builder.scripts(host),
+            Err(e) => eprintln!("[Scripts] {}", e),
+        }
+    }
+    // Optional directory of JS platform plugins (`Custom` platforms)
+    if let Ok(dir) = env::var("DTV_PLUGINS_DIR") {
+        match dtv_core::plugins::PluginHost::new(dir) {
+            Ok(host) => dtv_builder = dtv_builder.plugins(host),
+            Err(e) => eprintln!("[Plugins] {}", e),
+        }
+    }
+    let dtv_client = dtv_builder.build().expect("Failed to create dtv client");
+    // One listener per room, shared by the webview and overlays on the local proxy
+    let danmaku_hub = dtv_server::danmaku_hub::DanmakuHub::new(dtv_client.clone());
 
     tauri::Builder::default()
         .plugin(tauri_plugin_log::Builder::new()
@@ -51,6 +80,7 @@ fn main() {
             .build())
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .setup(|app| {
             // Apply macOS vibrancy to the main window when running on macOS
@@ -71,18 +101,40 @@ fn main() {
         .manage(commands::douyu_danmaku::DouyuDanmakuHandles::default()) // Manage DouyuDanmakuHandles
         .manage(DouyinDanmakuState::default()) // Manage DouyinDanmakuState
         .manage(HuyaDanmakuState::default()) // Manage HuyaDanmakuState
+        .manage(platforms::common::KuaishouDanmakuState::default()) // Manage KuaishouDanmakuState
+        .manage(platforms::common::CcDanmakuState::default()) // Manage CcDanmakuState
+        .manage(platforms::common::TwitchDanmakuState::default()) // Manage TwitchDanmakuState
         .manage(platforms::common::BilibiliDanmakuState::default()) // Manage BilibiliDanmakuState
         .manage(StreamUrlStore::default())
         .manage(proxy::ProxyServerHandle::default())
-        .manage(platforms::bilibili::state::BilibiliState::default())
+        .manage(bilibili_state)
+        .manage(dtv_client)
+        .manage(danmaku_hub)
+        .manage(commands::external_player::ExternalPlayers::default())
+        // The webview owns the follow list; the proxy's IPTV playlist reads this copy
+        .manage(std::sync::Arc::new(dtv_server::follows::FollowStore::in_memory()))
         .invoke_handler(tauri::generate_handler![
             commands::live_stream_v2_cmd::get_live_stream_v2,
+            commands::line_probe::probe_stream_lines,
+            commands::external_player::launch_external_player,
+            commands::external_player::list_external_players,
+            commands::external_player::stop_external_player,
+            commands::external_player::export_external_player,
+            commands::external_player::get_external_player_paths,
+            commands::external_player::set_external_player_path,
+            commands::iptv::sync_followed_rooms,
             search_anchor,
             commands::douyu_danmaku::start_danmaku_listener,
             commands::douyu_danmaku::stop_danmaku_listener,
             commands::douyin_danmaku::start_douyin_danmu_listener,
             commands::huya_danmaku::start_huya_danmaku_listener,
             commands::huya_danmaku::stop_huya_danmaku_listener,
+            commands::kuaishou_danmaku::start_kuaishou_danmaku_listener,
+            commands::kuaishou_danmaku::stop_kuaishou_danmaku_listener,
+            commands::cc_danmaku::start_cc_danmaku_listener,
+            commands::cc_danmaku::stop_cc_danmaku_listener,
+            commands::twitch_danmaku::start_twitch_danmaku_listener,
+            commands::twitch_danmaku::stop_twitch_danmaku_listener,
             commands::bilibili_danmaku::start_bilibili_danmaku_listener,
             commands::bilibili_danmaku::stop_bilibili_danmaku_listener,
              proxy::stop_proxy,
@@ -100,6 +152,10 @@ fn main() {
               platforms::bilibili::cookie::bootstrap_bilibili_cookie,
                commands::bilibili_search::search_bilibili_rooms,
                commands::huya_search::search_huya_anchors,
+               commands::kuaishou_search::search_kuaishou_authors,
+               commands::cc_live_list::fetch_cc_categories,
+               commands::cc_live_list::fetch_cc_live_list,
+               commands::twitch_search::search_twitch_channels,
          ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -1,4 +1,2 @@
 pub mod cookie;
 pub mod state;
-pub mod stream_url;
-pub mod streamer_info;
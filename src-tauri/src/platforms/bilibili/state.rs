@@ -2,7 +2,7 @@ pub use dtv_core::platforms::bilibili::state::BilibiliState;
 
 #[tauri::command]
 pub async fn generate_bilibili_w_webid(
-    state: tauri::State<'_, BilibiliState>,
+    dtv: tauri::State<'_, dtv_core::DtvClient>,
 ) -> Result<String, String> {
-    dtv_core::platforms::bilibili::state::refresh_w_webid(state.inner()).await
+    dtv.bilibili_refresh_w_webid().await
 }
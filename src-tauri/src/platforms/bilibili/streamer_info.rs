@@ -1 +0,0 @@
-pub use dtv_core::platforms::bilibili::streamer_info::*;
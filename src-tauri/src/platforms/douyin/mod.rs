@@ -8,5 +8,3 @@ pub use dtv_core::platforms::douyin::signed_url;
 #[allow(unused_imports)]
 pub use dtv_core::platforms::douyin::web_api;
 
-pub use dtv_core::platforms::douyin::douyin_streamer_detail;
-pub use dtv_core::platforms::douyin::douyin_streamer_info;
@@ -8,11 +8,10 @@ pub use dtv_core::platforms::douyu::stream_url;
 pub use dtv_core::platforms::douyu::types;
 
 
+#[allow(unused_imports)]
 pub use dtv_core::platforms::douyu::search_anchor::*;
 #[allow(unused_imports)]
 pub use dtv_core::platforms::douyu::fetch_douyu_main_categories::*;
-pub use dtv_core::platforms::douyu::fetch_douyu_room_info::*;
-pub use dtv_core::platforms::douyu::stream_url::*;
 #[allow(unused_imports)]
 pub use dtv_core::platforms::douyu::types::*;
 
@@ -1,14 +1,15 @@
-use actix_web::{dev::ServerHandle, web, App, HttpRequest, HttpResponse, HttpServer, Responder};
-use futures_util::TryStreamExt;
-use reqwest::Client;
-// awc removed for now due to API differences; using reqwest streaming
+use actix_web::{dev::ServerHandle, web, App, HttpServer};
+// FLV/image handlers live in dtv-server so the headless mode serves the same routes
 use crate::StreamUrlStore;
-use serde::Deserialize;
+use dtv_core::DtvClient;
+use dtv_server::danmaku_hub::DanmakuHub;
+use dtv_server::follows::FollowStore;
+use dtv_server::proxy::proxy_client;
 use std::io::ErrorKind;
 use std::net::TcpStream;
-use std::sync::Mutex as StdMutex;
+use std::sync::{Arc, Mutex as StdMutex};
 use std::time::Duration;
-use tauri::{AppHandle, State};
+use tauri::{AppHandle, Manager, State};
 
 // Define a struct to hold the server handle in a Tauri managed state
 #[derive(Default)]
@@ -19,204 +20,12 @@ async fn find_free_port() -> u16 {
     34719
 }
 
-#[derive(Deserialize)]
-struct ImageQuery {
-    url: String,
-}
-
-async fn image_proxy_handler(
-    query: web::Query<ImageQuery>,
-    client: web::Data<Client>,
-) -> impl Responder {
-    let url = query.url.clone();
-    if url.is_empty() {
-        return HttpResponse::BadRequest().body("Missing url query parameter");
-    }
-
-    let mut req = client
-        .get(&url)
-        .header(
-            "User-Agent",
-            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
-        )
-        .header(
-            "Accept",
-            "image/avif,image/webp,image/apng,image/*;q=0.8,*/*;q=0.5",
-        );
-
-    // Set a Referer to bypass hotlink protections
-    if url.contains("hdslb.com") || url.contains("bilibili.com") {
-        req = req
-            .header("Referer", "https://live.bilibili.com/")
-            .header("Origin", "https://live.bilibili.com");
-    } else if url.contains("huya.com") {
-        req = req
-            .header("Referer", "https://www.huya.com/")
-            .header("Origin", "https://www.huya.com");
-    } else if url.contains("douyin") || url.contains("douyinpic.com") {
-        req = req.header("Referer", "https://www.douyin.com/");
-    }
-
-    match req.send().await {
-        Ok(upstream_response) => {
-            let content_type = upstream_response
-                .headers()
-                .get(reqwest::header::CONTENT_TYPE)
-                .and_then(|v| v.to_str().ok())
-                .unwrap_or("application/octet-stream")
-                .to_string();
-
-            // 为避免 Windows 下 chunked 传输的 Early-EOF，改为一次性读取 bytes 并返回
-            if upstream_response.status().is_success() {
-                match upstream_response.bytes().await {
-                    Ok(bytes) => HttpResponse::Ok()
-                        .content_type(content_type)
-                        .insert_header(("Content-Length", bytes.len().to_string()))
-                        .insert_header(("Cache-Control", "no-store"))
-                        .body(bytes),
-                    Err(e) => {
-                        tracing::error!("[Rust/proxy.rs image] Failed to read bytes: {}", e);
-                        HttpResponse::InternalServerError()
-                            .body(format!("Failed to read image bytes: {}", e))
-                    }
-                }
-            } else {
-                let status_from_reqwest = upstream_response.status();
-                let error_text = upstream_response
-                    .text()
-                    .await
-                    .unwrap_or_else(|e| format!("Failed to read error body from upstream: {}", e));
-                tracing::error!(
-                    "[Rust/proxy.rs image] Upstream request to {} failed with status: {}. Body: {}",
-                    url, status_from_reqwest, error_text
-                );
-                let actix_status_code =
-                    actix_web::http::StatusCode::from_u16(status_from_reqwest.as_u16())
-                        .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR);
-
-                HttpResponse::build(actix_status_code).body(format!(
-                    "Error fetching IMAGE from upstream (reqwest): {}. Status: {}. Details: {}",
-                    url, status_from_reqwest, error_text
-                ))
-            }
-        }
-        Err(e) => {
-            tracing::error!(
-                "[Rust/proxy.rs image] Failed to send request to upstream {}: {}",
-                url, e
-            );
-            HttpResponse::InternalServerError()
-                .body(format!("Error connecting to upstream IMAGE {}: {}", url, e))
-        }
-    }
-}
-
-// Your actual proxy logic - this is a simplified placeholder
-async fn flv_proxy_handler(
-    _req: HttpRequest,
-    path: web::Path<(String, String)>, // (platform, room_id)
-    stream_url_store: web::Data<StreamUrlStore>,
-    client: web::Data<Client>,
-) -> impl Responder {
-    let (platform, room_id) = path.into_inner();
-    let room_id = room_id.trim_end_matches(".flv").to_string();
-    
-    let url = {
-        let urls = stream_url_store.urls.lock().unwrap();
-        urls.get(&(platform.clone(), room_id.clone())).cloned().unwrap_or_default()
-    };
-
-    if url.is_empty() {
-        return HttpResponse::NotFound().body(format!("Stream URL for {}/{} is not set or empty.", platform, room_id));
-    }
-
-    tracing::debug!(
-        "[Rust/proxy.rs handler] Incoming FLV proxy request for {}/{} -> {}",
-        platform, room_id, url
-    );
-
-    let mut req = client
-        .get(&url)
-        .header(
-            "User-Agent",
-            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36",
-        )
-        .header("Accept", "video/x-flv,application/octet-stream,*/*")
-        .header("Range", "bytes=0-")
-        .header("Connection", "keep-alive");
-
-    // 如果是虎牙域名，添加必要的 Referer/Origin 头
-    if url.contains("huya.com") || url.contains("hy-cdn.com") || url.contains("huyaimg.com") {
-        req = req
-            .header("Referer", "https://www.huya.com/")
-            .header("Origin", "https://www.huya.com");
-    }
-    // 如果是B站域名，添加必要的 Referer 头
-    if url.contains("bilivideo") || url.contains("bilibili.com") || url.contains("hdslb.com") {
-        req = req.header("Referer", "https://live.bilibili.com/");
-    }
-
-    match req.send().await {
-        Ok(upstream_response) => {
-            if upstream_response.status().is_success() {
-                let mut response_builder = HttpResponse::Ok();
-                response_builder
-                    .content_type("video/x-flv")
-                    .insert_header(("Connection", "keep-alive"))
-                    .insert_header(("Cache-Control", "no-store"))
-                    .insert_header(("Accept-Ranges", "bytes"));
-
-                let byte_stream = upstream_response.bytes_stream().map_err(|e| {
-                    tracing::error!(
-                        "[Rust/proxy.rs handler] Error reading bytes from upstream: {}",
-                        e
-                    );
-                    actix_web::error::ErrorInternalServerError(format!(
-                        "Upstream stream error: {}",
-                        e
-                    ))
-                });
-
-                response_builder.streaming(byte_stream)
-            } else {
-                let status_from_reqwest = upstream_response.status(); // Renamed for clarity
-                let error_text = upstream_response
-                    .text()
-                    .await
-                    .unwrap_or_else(|e| format!("Failed to read error body from upstream: {}", e));
-                tracing::error!(
-                    "[Rust/proxy.rs handler] Upstream request to {} failed with status: {}. Body: {}",
-                    url, status_from_reqwest, error_text
-                );
-                // Convert reqwest::StatusCode to actix_web::http::StatusCode
-                let actix_status_code =
-                    actix_web::http::StatusCode::from_u16(status_from_reqwest.as_u16())
-                        .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR);
-
-                HttpResponse::build(actix_status_code).body(format!(
-                    "Error fetching FLV stream from upstream (reqwest): {}. Status: {}. Details: {}",
-                    url, status_from_reqwest, error_text
-                ))
-            }
-        }
-        Err(e) => {
-            tracing::error!(
-                "[Rust/proxy.rs handler] Failed to send request to upstream {} with reqwest: {}",
-                url, e
-            );
-            HttpResponse::InternalServerError().body(format!(
-                "Error connecting to upstream FLV stream {} with reqwest: {}",
-                url, e
-            ))
-        }
-    }
-}
-
 #[tauri::command]
 pub async fn start_proxy(
-    _app_handle: AppHandle,
+    app_handle: AppHandle,
     server_handle_state: State<'_, ProxyServerHandle>,
     stream_url_store: State<'_, StreamUrlStore>,
+    danmaku_hub: State<'_, Arc<DanmakuHub>>,
 ) -> Result<String, String> {
     let port = find_free_port().await;
 
@@ -230,30 +39,27 @@ pub async fn start_proxy(
 
     // stream_url_data_for_actix can be created once and cloned, as StreamUrlStore is Arc based and Send + Sync
     let stream_url_data_for_actix = web::Data::new(stream_url_store.inner().clone());
+    let danmaku_hub_for_actix = web::Data::new(danmaku_hub.inner().clone());
+    // Lets /live resolve rooms nobody has opened yet, and serves the IPTV playlist
+    let dtv_for_actix = web::Data::new(app_handle.state::<DtvClient>().inner().clone());
+    let follows_for_actix = web::Data::from(app_handle.state::<Arc<FollowStore>>().inner().clone());
 
     let server = match HttpServer::new(move || {
         let app_data_stream_url = stream_url_data_for_actix.clone();
         // Create reqwest::Client inside the closure for each worker thread (for images)
-        let app_data_reqwest_client = web::Data::new(
-            Client::builder()
-                .no_proxy()
-                .http1_only()
-                .gzip(false)
-                .brotli(false)
-                .no_deflate()
-                .pool_idle_timeout(None)
-                .pool_max_idle_per_host(4)
-                .tcp_keepalive(Duration::from_secs(60))
-                .timeout(Duration::from_secs(7200))
-                .build()
-                .expect("failed to build client"),
-        );
+        let app_data_reqwest_client = web::Data::new(proxy_client());
         App::new()
             .app_data(app_data_stream_url)
             .app_data(app_data_reqwest_client)
+            .app_data(danmaku_hub_for_actix.clone())
+            .app_data(dtv_for_actix.clone())
+            .app_data(follows_for_actix.clone())
             .wrap(actix_cors::Cors::permissive())
-            .route("/live/{platform}/{room_id}", web::get().to(flv_proxy_handler))
-            .route("/image", web::get().to(image_proxy_handler))
+            .configure(dtv_server::proxy::configure)
+            // Danmaku WS/SSE and the OBS overlay page
+            .configure(dtv_server::danmaku_http::configure)
+            // /playlist.m3u and /epg.xml of the followed rooms
+            .configure(dtv_server::iptv::configure)
     })
     .keep_alive(Duration::from_secs(120))
     .bind(("127.0.0.1", port))
@@ -300,10 +106,17 @@ pub async fn get_proxy_url(
     format!("http://127.0.0.1:{}/live/{}/{}.flv", port, platform, room_id)
 }
 
+// The room's sound alone, as FLV so the same player pipeline plays it
+pub async fn get_audio_proxy_url(platform: &str, room_id: &str) -> String {
+    let port = find_free_port().await;
+    format!("http://127.0.0.1:{}/audio/{}/{}.flv", port, platform, room_id)
+}
+
 #[tauri::command]
 pub async fn start_static_proxy_server(
     _app_handle: AppHandle,
     stream_url_store: State<'_, StreamUrlStore>,
+    danmaku_hub: State<'_, Arc<DanmakuHub>>,
 ) -> Result<String, String> {
     // Use a dedicated port for static image proxy to avoid interfering with FLV stream proxy
     let port: u16 = 34721;
@@ -314,29 +127,19 @@ pub async fn start_static_proxy_server(
     }
 
     let stream_url_data_for_actix = web::Data::new(stream_url_store.inner().clone());
+    let danmaku_hub_for_actix = web::Data::new(danmaku_hub.inner().clone());
 
     let server = match HttpServer::new(move || {
         let app_data_stream_url = stream_url_data_for_actix.clone();
-        let app_data_reqwest_client = web::Data::new(
-            Client::builder()
-                .no_proxy()
-                .http1_only()
-                .gzip(false)
-                .brotli(false)
-                .no_deflate()
-                .pool_idle_timeout(None)
-                .pool_max_idle_per_host(4)
-                .tcp_keepalive(Duration::from_secs(60))
-                .timeout(Duration::from_secs(7200))
-                .build()
-                .expect("failed to build client"),
-        );
+        let app_data_reqwest_client = web::Data::new(proxy_client());
         App::new()
             .app_data(app_data_stream_url)
             .app_data(app_data_reqwest_client)
+            .app_data(danmaku_hub_for_actix.clone())
             .wrap(actix_cors::Cors::permissive())
-            .route("/live/{platform}/{room_id}", web::get().to(flv_proxy_handler))
-            .route("/image", web::get().to(image_proxy_handler))
+            .configure(dtv_server::proxy::configure)
+            // Danmaku WS/SSE and the OBS overlay page
+            .configure(dtv_server::danmaku_http::configure)
     })
     .keep_alive(Duration::from_secs(120))
     .bind(("127.0.0.1", port))
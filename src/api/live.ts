@@ -10,6 +10,13 @@ export async function getLiveStreamV2(
   return invoke<LiveStreamResponseV2>("get_live_stream_v2", { request });
 }
 
+// 同步关注列表到本地代理的 /playlist.m3u 与 /epg.xml，返回播放列表地址
+export async function syncFollowedRooms(
+  rooms: { platform: string; room_id: string; name?: string | null }[],
+): Promise<string> {
+  return invoke<string>("sync_followed_rooms", { rooms });
+}
+
 export async function fetchDouyuCategories(): Promise<any> {
   return invoke("fetch_categories");
 }
@@ -3,7 +3,7 @@ import type { FollowedStreamer } from "../types/models/streamer";
 import { Platform } from "../types/app/platform";
 import { refreshDouyuFollowedStreamer } from "../services/platforms/douyu/followListHelper";
 import { refreshDouyinFollowedStreamer } from "../services/platforms/douyin/followListHelper";
-import { getLiveStreamV2 } from "../api/live";
+import { getLiveStreamV2, syncFollowedRooms } from "../api/live";
 
 // 文件夹类型
 export interface FollowFolder {
@@ -99,6 +99,18 @@ export const useFollowStore = defineStore("follow", {
         "followedStreamers",
         JSON.stringify(this.followedStreamers),
       );
+      this._syncIptv();
+    },
+    // IPTV 播放列表（本地代理 /playlist.m3u）读取的是 Rust 侧的副本
+    _syncIptv() {
+      const rooms = this.followedStreamers.map((s) => ({
+        platform: String(s.platform).toLowerCase(),
+        room_id: s.id,
+        name: s.nickname,
+      }));
+      syncFollowedRooms(rooms).catch((e) =>
+        console.warn("[followStore] sync_followed_rooms failed", e),
+      );
     },
     _saveFolders() {
       localStorage.setItem("followFolders", JSON.stringify(this.folders));
@@ -124,6 +136,7 @@ export const useFollowStore = defineStore("follow", {
           console.error("Error parsing followedStreamers", e);
         }
       }
+      this._syncIptv();
 
       // 2. 文件夹功能已移除，清理旧存储
       this.foldersMap = {};
@@ -3,6 +3,8 @@ import type { SupportedPlatform } from "../app/platform";
 export type LiveStatusV2 = "live" | "offline" | "error";
 export type StreamTypeV2 = "flv" | "hls" | "unknown";
 export type LiveStreamModeV2 = "playback" | "meta";
+export type CodecPreferenceV2 = "avc" | "hevc" | "auto";
+export type MediaContainerV2 = "flv" | "hls" | "mpegts" | "fmp4";
 
 export interface StreamVariant {
   url: string;
@@ -10,6 +12,7 @@ export interface StreamVariant {
   desc?: string | null;
   qn?: number | null;
   protocol?: string | null;
+  codec?: string | null;
 }
 
 export interface GetLiveStreamRequestV2 {
@@ -20,6 +23,10 @@ export interface GetLiveStreamRequestV2 {
   cookie?: string | null;
   debug?: boolean | null;
   mode?: LiveStreamModeV2 | null;
+  with_options?: boolean | null;
+  codec?: CodecPreferenceV2 | null;
+  probe?: boolean | null;
+  audio_only?: boolean | null;
 }
 
 export interface RoomMetaV2 {
@@ -30,19 +37,90 @@ export interface RoomMetaV2 {
   title?: string | null;
   anchor_name?: string | null;
   avatar?: string | null;
+  category?: string | null;
 }
 
 export interface PlaybackV2 {
   url: string;
   stream_type: StreamTypeV2;
   upstream_url?: string | null;
+  codec?: string | null;
+  headers: Record<string, string>;
   variants?: StreamVariant[] | null;
+  media?: MediaInfoV2 | null;
+}
+
+export interface MediaInfoV2 {
+  container: MediaContainerV2;
+  width?: number | null;
+  height?: number | null;
+  framerate?: number | null;
+  video_codec?: string | null;
+  audio_codec?: string | null;
+  bitrate_kbps?: number | null;
+}
+
+export interface QualityOptionV2 {
+  id: string;
+  label: string;
+  bitrate?: number | null;
+  codec?: string | null;
+}
+
+export interface LineOptionV2 {
+  id: string;
+  label: string;
+}
+
+export interface LineProbeV2 {
+  line: string;
+  label: string;
+  host: string;
+  connect_ms?: number | null;
+  ttfb_ms?: number | null;
+  throughput_kbps?: number | null;
+  bytes: number;
+  error?: string | null;
+  cached: boolean;
+}
+
+export type ExternalPlayerV2 = "mpv" | "vlc" | "ffplay";
+
+export interface ExternalPlayRequestV2 {
+  platform: SupportedPlatform;
+  room_id: string;
+  player: ExternalPlayerV2;
+  direct?: boolean | null;
+  title?: string | null;
+  subtitle?: string | null;
+}
+
+// Saved by set_external_player_path; players without one are looked up on PATH
+export type ExternalPlayerPathsV2 = Partial<Record<ExternalPlayerV2, string>>;
+
+export interface ExternalExportRequestV2 extends ExternalPlayRequestV2 {
+  format: "command" | "m3u" | "strm";
+  path?: string | null;
+}
+
+export interface ExternalPlayerProcessV2 {
+  pid: number;
+  platform: string;
+  room_id: string;
+  player: ExternalPlayerV2;
+  command_line: string;
+}
+
+export interface StreamOptionsV2 {
+  qualities: QualityOptionV2[];
+  lines: LineOptionV2[];
 }
 
 export interface LiveStreamResponseV2 {
   status: LiveStatusV2;
   room: RoomMetaV2;
   playback?: PlaybackV2 | null;
+  options?: StreamOptionsV2 | null;
   error?: string | null;
 }
 